@@ -0,0 +1,259 @@
+//! 复权因子计算
+//!
+//! 根据除权除息事件及不复权收盘价在本地计算复权因子:
+//!
+//! 除权参考价 = (前收盘价 - 每股派息 + 每股配股 * 配股价) / (1 + 每股送股 + 每股转增 + 每股配股)
+//!
+//! 后复权因子为各次除权(前收盘价 / 除权参考价)的累乘，前复权因子 = 后复权因子 / 最新的后复权因子
+
+use crate::{Bar, StockCorpAction};
+use chrono::NaiveDateTime;
+use std::collections::HashMap;
+
+/// 复权方式
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AdjustFactor {
+    /// 不复权
+    NFQ = 0,
+    /// 前复权
+    QFQ = 1,
+    /// 后复权
+    HFQ = 2,
+}
+
+impl From<i32> for AdjustFactor {
+    fn from(v: i32) -> Self {
+        match v {
+            1 => AdjustFactor::QFQ,
+            2 => AdjustFactor::HFQ,
+            _ => AdjustFactor::NFQ,
+        }
+    }
+}
+
+/// 复权k线，`bars`为不复权价格及后复权因子，可包含多个代码，顺序不限。
+///
+/// 价格乘以复权因子，成交量除以复权因子(成交额不变)，`hfq_factor`保持不变。
+/// 前复权按代码分别以`bars`中该代码最新的k线为基准，即回测时只用到截止当时的数据
+pub fn adjust_bars(bars: &mut [Bar], adjust: AdjustFactor) {
+    if adjust == AdjustFactor::NFQ {
+        return;
+    }
+    let mut latest: HashMap<&str, &Bar> = HashMap::new();
+    if adjust == AdjustFactor::QFQ {
+        for bar in bars.iter() {
+            let entry = latest.entry(bar.code.as_str()).or_insert(bar);
+            if bar.trade_date > entry.trade_date {
+                *entry = bar;
+            }
+        }
+    }
+    let base: HashMap<String, f64> = latest
+        .into_iter()
+        .map(|(code, bar)| (code.to_owned(), bar.hfq_factor as f64))
+        .collect();
+    for bar in bars.iter_mut() {
+        let base = match adjust {
+            AdjustFactor::QFQ => base.get(&bar.code).copied().unwrap_or(0.0),
+            _ => 1.0,
+        };
+        if base <= 0.0 {
+            continue;
+        }
+        let factor = bar.hfq_factor as f64 / base;
+        if factor <= 0.0 {
+            continue;
+        }
+        bar.open = (bar.open as f64 * factor) as f32;
+        bar.close = (bar.close as f64 * factor) as f32;
+        bar.high = (bar.high as f64 * factor) as f32;
+        bar.low = (bar.low as f64 * factor) as f32;
+        bar.volume = (bar.volume as f64 / factor).round() as u64;
+    }
+}
+
+/// 复权因子计算器
+#[derive(Debug, Clone, Default)]
+pub struct AdjustCalculator {
+    actions: Vec<StockCorpAction>,
+}
+
+impl AdjustCalculator {
+    /// 除权除息事件按除权日排序，同一除权日的事件合并
+    pub fn new<I: IntoIterator<Item = StockCorpAction>>(actions: I) -> Self {
+        let mut sorted: Vec<_> = actions.into_iter().collect();
+        sorted.sort_by_key(|a| a.ex_date.date());
+        let mut merged: Vec<StockCorpAction> = Vec::with_capacity(sorted.len());
+        for action in sorted {
+            match merged.last_mut() {
+                Some(last) if last.ex_date.date() == action.ex_date.date() => {
+                    last.cash += action.cash;
+                    last.bonus += action.bonus;
+                    last.transfer += action.transfer;
+                    if action.rights > 0.0 {
+                        last.rights += action.rights;
+                        last.rights_price = action.rights_price;
+                    }
+                }
+                _ => merged.push(action),
+            }
+        }
+        Self { actions: merged }
+    }
+    /// 合并后的除权除息事件
+    pub fn actions(&self) -> &[StockCorpAction] {
+        &self.actions
+    }
+    /// 单次除权的复权比例(前收盘价 / 除权参考价)，数据异常时为1
+    pub fn ratio(action: &StockCorpAction, pre_close: f32) -> f64 {
+        let pre_close = pre_close as f64;
+        let shares = 1.0 + action.bonus as f64 + action.transfer as f64 + action.rights as f64;
+        let ref_price = (pre_close - action.cash as f64
+            + action.rights as f64 * action.rights_price as f64)
+            / shares;
+        if pre_close <= 0.0 || ref_price <= 0.0 {
+            return 1.0;
+        }
+        pre_close / ref_price
+    }
+    /// 计算后复权因子，`bars`为按时间升序的不复权日线。
+    ///
+    /// `base`为`bars`之前的最后一根k线(后复权因子已计算)，从上市第一天开始计算时为None，
+    /// 此时第一根k线及之前的除权不计入。
+    ///
+    /// 返回实际生效的(除权日, 复权比例)，便于核对
+    pub fn apply_hfq(&self, bars: &mut [Bar], base: Option<&Bar>) -> Vec<(NaiveDateTime, f64)> {
+        let mut applied = Vec::new();
+        let mut factor = base.map_or(1.0, |b| b.hfq_factor as f64);
+        let mut pre_close = base.map(|b| b.close);
+        let mut index = base.map_or(0, |b| {
+            self.actions
+                .partition_point(|a| a.ex_date.date() <= b.trade_date.date())
+        });
+        for bar in bars.iter_mut() {
+            while let Some(action) = self.actions.get(index) {
+                if action.ex_date.date() > bar.trade_date.date() {
+                    break;
+                }
+                if let Some(close) = pre_close {
+                    let ratio = Self::ratio(action, close);
+                    factor *= ratio;
+                    applied.push((action.ex_date, ratio));
+                }
+                index += 1;
+            }
+            bar.hfq_factor = factor as f32;
+            pre_close = Some(bar.close);
+        }
+        applied
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn bar(date: &str, close: f32) -> Bar {
+        Bar {
+            code: "sz000001".to_string(),
+            name: "平安银行".to_string(),
+            trade_date: NaiveDateTime::parse_from_str(
+                &format!("{} 00:00:00", date),
+                "%Y%m%d %H:%M:%S",
+            )
+            .unwrap(),
+            open: close,
+            close,
+            high: close,
+            low: close,
+            volume: 0,
+            amount: 0.0,
+            turnover: 0.0,
+            chg_pct: 0.0,
+            volume_chg_pct: 0.0,
+            amount_chg_pct: 0.0,
+            hfq_factor: 1.0,
+        }
+    }
+
+    fn action(date: &str, cash: f32, bonus: f32) -> StockCorpAction {
+        StockCorpAction {
+            code: "sz000001".to_string(),
+            name: "平安银行".to_string(),
+            notice_date: None,
+            ex_date: bar(date, 0.0).trade_date,
+            cash,
+            bonus,
+            transfer: 0.0,
+            rights: 0.0,
+            rights_price: 0.0,
+        }
+    }
+
+    #[test]
+    fn test_apply_hfq() {
+        // 10派1元，10送5股，同日两条记录合并
+        let calc = AdjustCalculator::new(vec![
+            action("20230612", 0.0, 0.5),
+            action("20230605", 0.1, 0.0),
+            action("20230612", 0.0, 0.0),
+        ]);
+        assert_eq!(calc.actions().len(), 2);
+
+        let mut bars = vec![
+            bar("20230602", 10.0),
+            bar("20230605", 9.9),
+            bar("20230609", 9.9),
+            bar("20230612", 6.6),
+        ];
+        let applied = calc.apply_hfq(&mut bars, None);
+        assert_eq!(applied.len(), 2);
+        assert!((bars[0].hfq_factor - 1.0).abs() < 1e-6);
+        assert!((bars[1].hfq_factor - 10.0 / 9.9).abs() < 1e-6);
+        assert!((bars[3].hfq_factor - 1.5 * 10.0 / 9.9).abs() < 1e-5);
+
+        // 增量计算，与全量一致
+        let mut tail = vec![bar("20230612", 6.6)];
+        calc.apply_hfq(&mut tail, Some(&bars[2]));
+        assert!((tail[0].hfq_factor - bars[3].hfq_factor).abs() < 1e-6);
+    }
+
+    #[test]
+    fn test_adjust_bars() {
+        // 10送10，除权后价格减半，成交量翻倍
+        let mut bars = vec![bar("20230609", 10.0), bar("20230612", 5.0)];
+        bars[0].volume = 1000;
+        bars[1].volume = 2000;
+        bars[1].hfq_factor = 2.0;
+
+        let mut qfq = bars.clone();
+        adjust_bars(&mut qfq, AdjustFactor::QFQ);
+        assert!((qfq[0].close - 5.0).abs() < 1e-6);
+        assert!((qfq[1].close - 5.0).abs() < 1e-6);
+        assert_eq!(qfq[0].volume, 2000);
+        assert_eq!(qfq[1].volume, 2000);
+
+        let mut hfq = bars.clone();
+        adjust_bars(&mut hfq, AdjustFactor::HFQ);
+        assert!((hfq[0].close - 10.0).abs() < 1e-6);
+        assert!((hfq[1].close - 10.0).abs() < 1e-6);
+        assert_eq!(hfq[1].volume, 1000);
+
+        let mut nfq = bars.clone();
+        adjust_bars(&mut nfq, AdjustFactor::NFQ);
+        assert!((nfq[1].close - 5.0).abs() < 1e-6);
+
+        // 多个代码各自以最新的k线为基准
+        let mut other = vec![bar("20230612", 8.0), bar("20230613", 8.0)];
+        other.iter_mut().for_each(|bar| {
+            bar.code = "sz000002".to_string();
+            bar.hfq_factor = 3.0;
+        });
+        let mut mixed: Vec<_> = bars.iter().cloned().chain(other).collect();
+        adjust_bars(&mut mixed, AdjustFactor::QFQ);
+        assert!((mixed[0].close - 5.0).abs() < 1e-6);
+        assert!((mixed[1].close - 5.0).abs() < 1e-6);
+        assert!((mixed[2].close - 8.0).abs() < 1e-6);
+        assert!((mixed[3].close - 8.0).abs() < 1e-6);
+    }
+}
@@ -0,0 +1,162 @@
+//! 交易日历
+
+use chrono::{Datelike, NaiveDate};
+use std::collections::BTreeSet;
+use std::sync::Arc;
+
+/// 交易日历，按日期排序，查找均为二分查找
+///
+/// 内部数据共享，clone的开销很小
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct TradeCalendar {
+    dates: Arc<Vec<NaiveDate>>,
+}
+
+impl TradeCalendar {
+    pub fn new<I: IntoIterator<Item = NaiveDate>>(dates: I) -> Self {
+        let mut dates: Vec<_> = dates.into_iter().collect();
+        dates.sort_unstable();
+        dates.dedup();
+        Self {
+            dates: Arc::new(dates),
+        }
+    }
+    /// 由YYYYMMDD格式的交易日生成，非法日期忽略
+    pub fn from_i32<'a, I: IntoIterator<Item = &'a i32>>(dates: I) -> Self {
+        Self::new(dates.into_iter().filter_map(|d| {
+            NaiveDate::from_ymd_opt(d / 10000, (d / 100 % 100) as u32, (d % 100) as u32)
+        }))
+    }
+    /// YYYYMMDD格式的交易日
+    pub fn to_i32(&self) -> BTreeSet<i32> {
+        self.dates.iter().map(date_to_i32).collect()
+    }
+    /// 交易日数量
+    pub fn len(&self) -> usize {
+        self.dates.len()
+    }
+    pub fn is_empty(&self) -> bool {
+        self.dates.is_empty()
+    }
+    /// 第一个交易日
+    pub fn first(&self) -> Option<NaiveDate> {
+        self.dates.first().copied()
+    }
+    /// 最后一个交易日
+    pub fn last(&self) -> Option<NaiveDate> {
+        self.dates.last().copied()
+    }
+    /// 是否交易日
+    pub fn contains(&self, date: &NaiveDate) -> bool {
+        self.dates.binary_search(date).is_ok()
+    }
+    /// 是否在日历覆盖的范围内
+    pub fn covers(&self, date: &NaiveDate) -> bool {
+        match (self.first(), self.last()) {
+            (Some(first), Some(last)) => first <= *date && *date <= last,
+            _ => false,
+        }
+    }
+    /// 某日之后的第一个交易日(不含当日)
+    pub fn next(&self, date: &NaiveDate) -> Option<NaiveDate> {
+        let index = self.dates.partition_point(|d| d <= date);
+        self.dates.get(index).copied()
+    }
+    /// 某日之前的第一个交易日(不含当日)
+    pub fn prev(&self, date: &NaiveDate) -> Option<NaiveDate> {
+        let index = self.dates.partition_point(|d| d < date);
+        index.checked_sub(1).map(|i| self.dates[i])
+    }
+    /// 某日前后第n个交易日，n > 0往后，n < 0往前，如: `offset(date, -60)`为60个交易日前。
+    ///
+    /// 不含当日，n为0时，当日为交易日返回当日，否则返回None
+    pub fn offset(&self, date: &NaiveDate, n: i32) -> Option<NaiveDate> {
+        let index = match n {
+            0 => return self.contains(date).then_some(*date),
+            n if n > 0 => self.dates.partition_point(|d| d <= date) + n as usize - 1,
+            n => self
+                .dates
+                .partition_point(|d| d < date)
+                .checked_sub(n.unsigned_abs() as usize)?,
+        };
+        self.dates.get(index).copied()
+    }
+    /// [start, end]之间的交易日数量
+    pub fn trading_days_between(&self, start: &NaiveDate, end: &NaiveDate) -> usize {
+        self.slice(start, end).len()
+    }
+    /// [start, end]之间的交易日
+    pub fn range(
+        &self,
+        start: &NaiveDate,
+        end: &NaiveDate,
+    ) -> std::iter::Copied<std::slice::Iter<'_, NaiveDate>> {
+        self.slice(start, end).iter().copied()
+    }
+    /// 全部交易日
+    pub fn iter(&self) -> std::iter::Copied<std::slice::Iter<'_, NaiveDate>> {
+        self.dates.iter().copied()
+    }
+
+    fn slice(&self, start: &NaiveDate, end: &NaiveDate) -> &[NaiveDate] {
+        let from = self.dates.partition_point(|d| d < start);
+        let to = self.dates.partition_point(|d| d <= end);
+        if from >= to {
+            return &[];
+        }
+        &self.dates[from..to]
+    }
+}
+
+pub(crate) fn date_to_i32(date: &NaiveDate) -> i32 {
+    date.year() * 10000 + date.month() as i32 * 100 + date.day() as i32
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn date(d: i32) -> NaiveDate {
+        NaiveDate::parse_from_str(&d.to_string(), "%Y%m%d").unwrap()
+    }
+
+    #[test]
+    fn test_trade_calendar() {
+        // 2024春节前后
+        let calendar = TradeCalendar::from_i32(&[
+            20240205, 20240206, 20240207, 20240208, 20240219, 20240220, 20240221,
+        ]);
+        assert_eq!(calendar.len(), 7);
+        assert!(calendar.contains(&date(20240208)));
+        assert!(!calendar.contains(&date(20240209)));
+
+        assert_eq!(calendar.next(&date(20240208)), Some(date(20240219)));
+        assert_eq!(calendar.next(&date(20240210)), Some(date(20240219)));
+        assert_eq!(calendar.next(&date(20240221)), None);
+        assert_eq!(calendar.prev(&date(20240219)), Some(date(20240208)));
+        assert_eq!(calendar.prev(&date(20240205)), None);
+
+        assert_eq!(calendar.offset(&date(20240206), 3), Some(date(20240219)));
+        assert_eq!(calendar.offset(&date(20240210), 1), Some(date(20240219)));
+        assert_eq!(calendar.offset(&date(20240220), -3), Some(date(20240207)));
+        assert_eq!(calendar.offset(&date(20240210), -1), Some(date(20240208)));
+        assert_eq!(calendar.offset(&date(20240220), -6), None);
+        assert_eq!(calendar.offset(&date(20240220), 2), None);
+        assert_eq!(calendar.offset(&date(20240210), 0), None);
+
+        assert_eq!(
+            calendar.trading_days_between(&date(20240207), &date(20240219)),
+            3
+        );
+        assert_eq!(
+            calendar.trading_days_between(&date(20240219), &date(20240207)),
+            0
+        );
+        assert_eq!(
+            calendar
+                .range(&date(20240208), &date(20240220))
+                .collect::<Vec<_>>(),
+            vec![date(20240208), date(20240219), date(20240220)]
+        );
+    }
+}
@@ -0,0 +1,354 @@
+//! 交易时钟，交易日及交易时段判断
+//!
+//! 时间均为交易所当地时间
+
+use crate::calendar::date_to_i32;
+use crate::{Exchange, TradeCalendar};
+use chrono::{Datelike, NaiveDate, NaiveDateTime, NaiveTime, Weekday};
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeSet;
+
+/// 交易时段
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum SessionPhase {
+    /// 非交易日
+    Closed,
+    /// 开盘前
+    PreOpen,
+    /// 开盘集合竞价
+    OpeningAuction,
+    /// 开盘集合竞价撮合后至开盘(A股9:25-9:30，港股9:20-9:30)，不撮合、不可撤单，非交易中
+    AuctionMatched,
+    /// 上午连续竞价
+    Morning,
+    /// 午间休市
+    LunchBreak,
+    /// 下午连续竞价
+    Afternoon,
+    /// 收盘集合竞价
+    ClosingAuction,
+    /// 收盘后
+    AfterClose,
+}
+
+impl SessionPhase {
+    /// 是否交易中(集合竞价或连续竞价)
+    pub fn is_trading(&self) -> bool {
+        matches!(
+            self,
+            SessionPhase::OpeningAuction
+                | SessionPhase::Morning
+                | SessionPhase::Afternoon
+                | SessionPhase::ClosingAuction
+        )
+    }
+}
+
+/// 一个交易日的时段安排
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Schedule {
+    /// 各时段开始时间，按时间排序，第一个时段前为开盘前
+    pub phases: Vec<(NaiveTime, SessionPhase)>,
+    /// 收盘后数据可用(可同步)的时间
+    pub settle: NaiveTime,
+}
+
+fn hm(hour: u32, min: u32) -> NaiveTime {
+    NaiveTime::from_hms_opt(hour, min, 0).unwrap()
+}
+
+impl Schedule {
+    /// 交易所全天交易的时段安排
+    pub fn full_day(exchange: Exchange) -> Self {
+        use SessionPhase::*;
+        match exchange {
+            Exchange::SH | Exchange::SZ | Exchange::BJ => Self {
+                phases: vec![
+                    (hm(9, 15), OpeningAuction),
+                    (hm(9, 25), AuctionMatched),
+                    (hm(9, 30), Morning),
+                    (hm(11, 30), LunchBreak),
+                    (hm(13, 0), Afternoon),
+                    (hm(14, 57), ClosingAuction),
+                    (hm(15, 0), AfterClose),
+                ],
+                settle: hm(15, 5),
+            },
+            Exchange::HK => Self {
+                phases: vec![
+                    (hm(9, 0), OpeningAuction),
+                    (hm(9, 20), AuctionMatched),
+                    (hm(9, 30), Morning),
+                    (hm(12, 0), LunchBreak),
+                    (hm(13, 0), Afternoon),
+                    (hm(16, 0), ClosingAuction),
+                    (hm(16, 10), AfterClose),
+                ],
+                settle: hm(16, 15),
+            },
+            // 美股无午休，以12:00划分上下午
+            Exchange::US => Self {
+                phases: vec![
+                    (hm(9, 30), Morning),
+                    (hm(12, 0), Afternoon),
+                    (hm(16, 0), AfterClose),
+                ],
+                settle: hm(16, 5),
+            },
+        }
+    }
+
+    /// 交易所半日交易的时段安排，A股无半日交易
+    pub fn half_day(exchange: Exchange) -> Self {
+        use SessionPhase::*;
+        match exchange {
+            Exchange::HK => Self {
+                phases: vec![
+                    (hm(9, 0), OpeningAuction),
+                    (hm(9, 20), AuctionMatched),
+                    (hm(9, 30), Morning),
+                    (hm(12, 0), ClosingAuction),
+                    (hm(12, 10), AfterClose),
+                ],
+                settle: hm(12, 15),
+            },
+            Exchange::US => Self {
+                phases: vec![(hm(9, 30), Morning), (hm(13, 0), AfterClose)],
+                settle: hm(13, 5),
+            },
+            _ => Self::full_day(exchange),
+        }
+    }
+
+    /// 某时刻所处时段
+    pub fn phase(&self, time: &NaiveTime) -> SessionPhase {
+        self.phases
+            .iter()
+            .take_while(|(start, _)| start <= time)
+            .last()
+            .map_or(SessionPhase::PreOpen, |(_, phase)| *phase)
+    }
+
+    /// 开盘时间(第一个时段开始)
+    pub fn open(&self) -> NaiveTime {
+        self.phases.first().map_or(self.settle, |(t, _)| *t)
+    }
+
+    /// 收盘时间
+    pub fn close(&self) -> NaiveTime {
+        self.phases
+            .iter()
+            .find(|(_, phase)| *phase == SessionPhase::AfterClose)
+            .map_or(self.settle, |(t, _)| *t)
+    }
+}
+
+/// 交易时钟
+///
+/// 未设置交易日历或日期超出交易日历最后一天时，周一至周五均视为交易日
+#[derive(Debug, Clone)]
+pub struct MarketClock {
+    exchange: Exchange,
+    calendar: Option<TradeCalendar>,
+    half_days: BTreeSet<i32>,
+}
+
+impl MarketClock {
+    pub fn new(exchange: Exchange) -> Self {
+        Self {
+            exchange,
+            calendar: None,
+            half_days: BTreeSet::new(),
+        }
+    }
+    /// 指定交易日历
+    pub fn with_calendar(mut self, calendar: TradeCalendar) -> Self {
+        self.calendar = Some(calendar);
+        self
+    }
+    /// 指定半日交易日(YYYYMMDD)
+    pub fn with_half_days(mut self, half_days: BTreeSet<i32>) -> Self {
+        self.half_days = half_days;
+        self
+    }
+    /// 交易所
+    pub fn exchange(&self) -> Exchange {
+        self.exchange
+    }
+    /// 是否交易日，交易日历未覆盖的未来日期按工作日判断
+    pub fn is_trade_date(&self, date: &NaiveDate) -> bool {
+        match &self.calendar {
+            Some(calendar) if calendar.last().is_some_and(|last| *date <= last) => {
+                calendar.contains(date)
+            }
+            _ => !matches!(date.weekday(), Weekday::Sat | Weekday::Sun),
+        }
+    }
+    /// 是否半日交易
+    pub fn is_half_day(&self, date: &NaiveDate) -> bool {
+        self.half_days.contains(&date_to_i32(date))
+    }
+    /// 某日的时段安排，非交易日返回None
+    pub fn schedule(&self, date: &NaiveDate) -> Option<Schedule> {
+        if !self.is_trade_date(date) {
+            return None;
+        }
+        if self.is_half_day(date) {
+            Some(Schedule::half_day(self.exchange))
+        } else {
+            Some(Schedule::full_day(self.exchange))
+        }
+    }
+    /// 某时刻所处时段
+    pub fn phase(&self, time: &NaiveDateTime) -> SessionPhase {
+        self.schedule(&time.date())
+            .map_or(SessionPhase::Closed, |s| s.phase(&time.time()))
+    }
+    /// 是否交易中(集合竞价或连续竞价)
+    pub fn is_trading(&self, time: &NaiveDateTime) -> bool {
+        self.phase(time).is_trading()
+    }
+    /// 当日交易是否已结束(收盘后或非交易日)，此时当日k线已完整
+    pub fn is_closed(&self, time: &NaiveDateTime) -> bool {
+        matches!(
+            self.phase(time),
+            SessionPhase::Closed | SessionPhase::AfterClose
+        )
+    }
+    /// 当日收盘数据是否可同步(收盘后一段时间或非交易日)
+    pub fn is_settled(&self, time: &NaiveDateTime) -> bool {
+        self.schedule(&time.date())
+            .is_none_or(|s| time.time() > s.settle)
+    }
+    /// `time`及之后最早的交易时刻，交易中返回`time`，一年内无交易日返回None
+    pub fn next_trading(&self, time: &NaiveDateTime) -> Option<NaiveDateTime> {
+        let mut date = time.date();
+        for _ in 0..366 {
+            if let Some(schedule) = self.schedule(&date) {
+                let phases = &schedule.phases;
+                for (i, (start, phase)) in phases.iter().enumerate() {
+                    if !phase.is_trading() {
+                        continue;
+                    }
+                    let end = phases.get(i + 1).map(|(t, _)| date.and_time(*t));
+                    if end.is_none_or(|e| *time < e) {
+                        return Some(date.and_time(*start).max(*time));
+                    }
+                }
+            }
+            date = date.succ_opt()?;
+        }
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn at(date: &str, time: &str) -> NaiveDateTime {
+        NaiveDateTime::parse_from_str(&format!("{} {}", date, time), "%Y%m%d %H:%M:%S").unwrap()
+    }
+
+    #[test]
+    fn test_a_share_phase() {
+        let clock = MarketClock::new(Exchange::SZ)
+            .with_calendar(TradeCalendar::from_i32(&[20240208, 20240219]));
+        let day = "20240208";
+        assert_eq!(clock.phase(&at(day, "09:00:00")), SessionPhase::PreOpen);
+        assert_eq!(
+            clock.phase(&at(day, "09:20:00")),
+            SessionPhase::OpeningAuction
+        );
+        assert_eq!(
+            clock.phase(&at(day, "09:25:00")),
+            SessionPhase::AuctionMatched
+        );
+        assert_eq!(clock.phase(&at(day, "09:30:00")), SessionPhase::Morning);
+        assert_eq!(clock.phase(&at(day, "12:00:00")), SessionPhase::LunchBreak);
+        assert_eq!(clock.phase(&at(day, "14:00:00")), SessionPhase::Afternoon);
+        assert_eq!(
+            clock.phase(&at(day, "14:58:00")),
+            SessionPhase::ClosingAuction
+        );
+        assert_eq!(clock.phase(&at(day, "15:00:00")), SessionPhase::AfterClose);
+        // 春节休市
+        assert_eq!(
+            clock.phase(&at("20240209", "10:00:00")),
+            SessionPhase::Closed
+        );
+
+        assert!(clock.is_trading(&at(day, "09:24:59")));
+        assert!(!clock.is_trading(&at(day, "09:27:00")));
+        assert!(!clock.is_trading(&at(day, "12:00:00")));
+        assert!(clock.is_trading(&at(day, "10:00:00")));
+        assert!(!clock.is_closed(&at(day, "14:59:59")));
+        assert!(clock.is_closed(&at(day, "15:00:00")));
+        assert!(clock.is_closed(&at("20240209", "10:00:00")));
+        assert!(!clock.is_settled(&at(day, "15:05:00")));
+        assert!(clock.is_settled(&at(day, "15:06:00")));
+    }
+
+    #[test]
+    fn test_half_day() {
+        let clock = MarketClock::new(Exchange::HK).with_half_days([20241224].into_iter().collect());
+        assert_eq!(
+            clock.phase(&at("20241224", "12:05:00")),
+            SessionPhase::ClosingAuction
+        );
+        assert!(clock.is_closed(&at("20241224", "12:10:00")));
+        assert_eq!(
+            clock.phase(&at("20241223", "12:05:00")),
+            SessionPhase::LunchBreak
+        );
+        // 未设置交易日历时按工作日判断
+        assert_eq!(
+            clock.phase(&at("20241221", "10:00:00")),
+            SessionPhase::Closed
+        );
+    }
+
+    #[test]
+    fn test_next_trading() {
+        let clock = MarketClock::new(Exchange::SH)
+            .with_calendar(TradeCalendar::from_i32(&[20240208, 20240219]));
+        let day = "20240208";
+        assert_eq!(
+            clock.next_trading(&at(day, "10:00:00")),
+            Some(at(day, "10:00:00"))
+        );
+        assert_eq!(
+            clock.next_trading(&at(day, "08:00:00")),
+            Some(at(day, "09:15:00"))
+        );
+        assert_eq!(
+            clock.next_trading(&at(day, "09:26:00")),
+            Some(at(day, "09:30:00"))
+        );
+        assert_eq!(
+            clock.next_trading(&at(day, "12:00:00")),
+            Some(at(day, "13:00:00"))
+        );
+        // 收盘后到下一交易日(春节休市后)
+        assert_eq!(
+            clock.next_trading(&at(day, "15:00:00")),
+            Some(at("20240219", "09:15:00"))
+        );
+    }
+
+    #[test]
+    fn test_beyond_calendar() {
+        let clock = MarketClock::new(Exchange::SH)
+            .with_calendar(TradeCalendar::from_i32(&[20261230, 20261231]));
+        let date = |d: &str| NaiveDate::parse_from_str(d, "%Y%m%d").unwrap();
+        assert!(!clock.is_trade_date(&date("20261229")));
+        assert!(clock.is_trade_date(&date("20261231")));
+        // 日历之后按工作日
+        assert!(!clock.is_trade_date(&date("20270102")));
+        assert!(clock.is_trade_date(&date("20270104")));
+        assert_eq!(
+            clock.next_trading(&at("20261231", "15:00:00")),
+            Some(at("20270101", "09:15:00"))
+        );
+    }
+}
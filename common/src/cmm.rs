@@ -1,6 +1,7 @@
 //! 公共数据
 
-use chrono::NaiveDateTime;
+use crate::{ParseSecurityIdError, SecurityId};
+use chrono::{Datelike, Duration, NaiveDate, NaiveDateTime, NaiveTime};
 use serde::{Deserialize, Serialize};
 
 /// 交易日历
@@ -13,7 +14,7 @@ pub struct TradeDate {
 /// K线基本信息（可表示分钟线，日线，周线，年线等）
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Bar {
-    /// 代码
+    /// 代码，内部格式(如sz002805)，行业、概念为板块代码，转换为`SecurityId`见`security_id`
     pub code: String,
     /// 简称
     pub name: String,
@@ -44,6 +45,23 @@ pub struct Bar {
     pub hfq_factor: f32,
 }
 
+impl Bar {
+    /// 解析代码，行业、概念的板块代码返回错误
+    pub fn security_id(&self) -> Result<SecurityId, ParseSecurityIdError> {
+        SecurityId::parse(&self.code)
+    }
+    /// 由前一根k线计算成交量及成交额的变化，前值为0时不计算
+    pub fn calc_chg_pct(&mut self, pre: &Bar) {
+        if pre.volume > 0 {
+            self.volume_chg_pct =
+                ((self.volume as f64 - pre.volume as f64) * 100.0 / pre.volume as f64) as f32;
+        }
+        if pre.amount > 0.0 {
+            self.amount_chg_pct = ((self.amount - pre.amount) * 100.0 / pre.amount) as f32;
+        }
+    }
+}
+
 /// k线频率
 #[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
 pub enum BarFreq {
@@ -84,3 +102,277 @@ impl From<i32> for BarFreq {
         }
     }
 }
+
+impl BarFreq {
+    /// 是否分钟线
+    pub fn is_minute(&self) -> bool {
+        matches!(
+            self,
+            BarFreq::Min1 | BarFreq::Min5 | BarFreq::Min15 | BarFreq::Min30 | BarFreq::Min60
+        )
+    }
+    /// 每个交易日的k线数，日频及以上为1
+    pub fn bars_per_day(&self) -> usize {
+        match self {
+            BarFreq::Min1 => 240,
+            BarFreq::Min5 => 48,
+            BarFreq::Min15 => 16,
+            BarFreq::Min30 => 8,
+            BarFreq::Min60 => 4,
+            _ => 1,
+        }
+    }
+}
+
+/// 分页获取的分钟k线
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MinuteBar {
+    /// 代码
+    pub code: String,
+    /// 简称
+    pub name: String,
+    /// 频率
+    pub freq: BarFreq,
+    /// 实际覆盖的开始时间，无数据为None
+    pub start: Option<NaiveDateTime>,
+    /// 实际覆盖的结束时间，无数据为None
+    pub end: Option<NaiveDateTime>,
+    /// bar数据，按时间升序
+    pub bars: Option<Vec<Bar>>,
+}
+
+/// 交易所
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum Exchange {
+    /// 上交所
+    SH,
+    /// 深交所
+    SZ,
+    /// 北交所
+    BJ,
+    /// 港交所
+    HK,
+    /// 美股
+    US,
+}
+
+impl Exchange {
+    /// 交易货币
+    pub fn currency(&self) -> Currency {
+        match self {
+            Exchange::SH | Exchange::SZ | Exchange::BJ => Currency::CNY,
+            Exchange::HK => Currency::HKD,
+            Exchange::US => Currency::USD,
+        }
+    }
+    /// 毫秒时间戳转换为交易所当地时间，美股按美东时间(含夏令时)
+    pub fn local_time(&self, timestamp: i64) -> NaiveDateTime {
+        let epoch = NaiveDate::from_ymd_opt(1970, 1, 1).unwrap();
+        let utc =
+            epoch.and_hms_opt(0, 0, 0).unwrap() + Duration::seconds(timestamp.div_euclid(1000));
+        let offset = match self {
+            Exchange::US if is_us_dst(&utc) => -4,
+            Exchange::US => -5,
+            _ => 8,
+        };
+        utc + Duration::hours(offset)
+    }
+}
+
+/// 美国夏令时: 3月第二个周日至11月第一个周日，当地时间2点切换
+fn is_us_dst(utc: &NaiveDateTime) -> bool {
+    let sunday = |month: u32, n: i64| {
+        let first = NaiveDate::from_ymd_opt(utc.year(), month, 1).unwrap();
+        let offset = (7 - first.weekday().num_days_from_sunday() as i64) % 7;
+        first + Duration::days(offset + 7 * (n - 1))
+    };
+    let start = sunday(3, 2).and_hms_opt(7, 0, 0).unwrap();
+    let end = sunday(11, 1).and_hms_opt(6, 0, 0).unwrap();
+    *utc >= start && *utc < end
+}
+
+/// 交易货币
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum Currency {
+    /// 人民币
+    #[default]
+    CNY,
+    /// 港币
+    HKD,
+    /// 美元
+    USD,
+}
+
+impl Currency {
+    /// 由内部代码判断交易货币，B股沪市为美元、深市为港币
+    pub fn from_code(code: &str) -> Self {
+        let code = code.to_lowercase();
+        if code.starts_with("hk") || code.starts_with("sz200") {
+            Currency::HKD
+        } else if code.starts_with("us") || code.starts_with("sh900") {
+            Currency::USD
+        } else {
+            Currency::CNY
+        }
+    }
+}
+
+/// 盘口档位
+#[derive(Debug, Clone, Copy, Default, PartialEq, Serialize, Deserialize)]
+pub struct OrderBookLevel {
+    /// 价格
+    pub price: f32,
+    /// 挂单量(手)
+    pub volume: u64,
+}
+
+/// 五档盘口快照(股票、etf基金、可转债通用)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct OrderBook {
+    /// 代码
+    pub code: String,
+    /// 简称
+    pub name: String,
+    /// 行情时间
+    pub time: NaiveDateTime,
+    /// 最新价
+    pub last: f32,
+    /// 昨收价
+    pub last_close: f32,
+    /// 买一至买五，无挂单的档位价格为0
+    pub bids: Vec<OrderBookLevel>,
+    /// 卖一至卖五，无挂单的档位价格为0
+    pub asks: Vec<OrderBookLevel>,
+    /// 涨停价
+    pub limit_up: f32,
+    /// 跌停价
+    pub limit_down: f32,
+    /// 成交量(手)
+    pub volume: u64,
+    /// 外盘(主动买入成交量，手)
+    pub outer_volume: u64,
+    /// 内盘(主动卖出成交量，手)
+    pub inner_volume: u64,
+}
+
+/// 逐笔成交方向
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum TickDirection {
+    /// 主动买入
+    Buy,
+    /// 主动卖出
+    Sell,
+    /// 中性(集合竞价或无法判断)
+    Neutral,
+}
+
+/// 分笔成交(股票、可转债通用)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Tick {
+    /// 代码
+    pub code: String,
+    /// 交易日
+    pub trade_date: NaiveDateTime,
+    /// 成交时间
+    pub time: NaiveDateTime,
+    /// 成交价
+    pub price: f32,
+    /// 成交量(手)
+    pub volume: u64,
+    /// 成交方向
+    pub direction: TickDirection,
+}
+
+/// 分时数据点
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct TimeSharePoint {
+    /// 时间(分钟)
+    pub time: NaiveDateTime,
+    /// 价格
+    pub price: f32,
+    /// 均价
+    pub avg_price: f32,
+    /// 成交量(手)
+    pub volume: u64,
+    /// 成交额(元)
+    pub amount: f64,
+}
+
+/// 最新交易日的分时走势
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TimeShare {
+    /// 代码
+    pub code: String,
+    /// 简称
+    pub name: String,
+    /// 昨收价
+    pub last_close: f32,
+    /// 分时数据，按时间升序
+    pub points: Vec<TimeSharePoint>,
+}
+
+/// 集合竞价(9:15-9:25)的虚拟匹配
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AuctionSnapshot {
+    /// 代码
+    pub code: String,
+    /// 交易日
+    pub trade_date: NaiveDateTime,
+    /// 时间(分钟)
+    pub time: NaiveDateTime,
+    /// 虚拟匹配价
+    pub price: f32,
+    /// 匹配量(手)
+    pub volume: u64,
+    /// 匹配金额(元)
+    pub amount: f64,
+    /// 未匹配量(手)，正数为买方未匹配，负数为卖方未匹配，只有集合竞价中的盘口快照才有
+    #[serde(default)]
+    pub unmatched_volume: Option<i64>,
+}
+
+impl AuctionSnapshot {
+    /// 由集合竞价期间的盘口转换，此时买一卖一均为虚拟匹配价及匹配量，
+    /// 未匹配量显示在买二或卖二，不在集合竞价中(9:15-9:25内买一卖一价相同)的返回None
+    pub fn from_order_book(book: &OrderBook) -> Option<Self> {
+        let (start, end) = (
+            NaiveTime::from_hms_opt(9, 15, 0).unwrap(),
+            NaiveTime::from_hms_opt(9, 25, 0).unwrap(),
+        );
+        if book.time.time() < start || book.time.time() > end {
+            return None;
+        }
+        let (bid, ask) = (book.bids.first()?, book.asks.first()?);
+        if bid.price <= 0.0 || bid.price != ask.price {
+            return None;
+        }
+        let unmatched = |levels: &[OrderBookLevel]| levels.get(1).map_or(0, |l| l.volume as i64);
+        Some(Self {
+            code: book.code.clone(),
+            trade_date: book.time.date().and_hms_opt(0, 0, 0).unwrap(),
+            time: book.time,
+            price: bid.price,
+            volume: bid.volume,
+            amount: bid.price as f64 * bid.volume as f64 * 100.0,
+            unmatched_volume: Some(unmatched(&book.bids) - unmatched(&book.asks)),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_local_time() {
+        let at = |s: &str| NaiveDateTime::parse_from_str(s, "%Y-%m-%d %H:%M:%S").unwrap();
+        // 2023-01-03 01:30:00 UTC
+        let ts = 1672709400000;
+        assert_eq!(Exchange::SZ.local_time(ts), at("2023-01-03 09:30:00"));
+        assert_eq!(Exchange::US.local_time(ts), at("2023-01-02 20:30:00"));
+        // 2023-07-03 13:30:00 UTC
+        let ts = 1688391000000;
+        assert_eq!(Exchange::US.local_time(ts), at("2023-07-03 09:30:00"));
+        assert_eq!(Exchange::HK.currency(), Currency::HKD);
+    }
+}
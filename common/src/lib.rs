@@ -5,6 +5,12 @@
 pub mod cmm;
 pub use cmm::*;
 
+pub mod calendar;
+pub use calendar::*;
+
+pub mod clock;
+pub use clock::*;
+
 pub mod bond;
 pub use bond::*;
 
@@ -13,3 +19,9 @@ pub use fund::*;
 
 pub mod stock;
 pub use stock::*;
+
+pub mod adjust;
+pub use adjust::*;
+
+pub mod security;
+pub use security::*;
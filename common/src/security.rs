@@ -0,0 +1,312 @@
+//! 证券代码
+//!
+//! 内部格式为小写市场前缀 + 代码，如sz002805、sh600000、bj920002、hk00700、usAAPL，
+//! 可转换为各数据源的格式，序列化为内部格式的字符串，与已存储的代码字段兼容。
+//!
+//! `Bar`、`StockInfo`的代码及策略运行的(代码, 名称)仍为字符串: 行业、概念的板块代码(如BK0475)
+//! 不是证券代码，且已存储的数据以字符串为键。`SecurityId`只用于校验及数据源格式的转换，
+//! 由`security_id`方法获取。
+
+use crate::Exchange;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use std::fmt;
+use std::str::FromStr;
+
+/// 证券类别
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum AssetClass {
+    /// 股票
+    Stock,
+    /// 指数
+    Index,
+    /// etf基金
+    Fund,
+    /// 可转债
+    Bond,
+}
+
+/// 证券代码解析错误
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseSecurityIdError(String);
+
+impl fmt::Display for ParseSecurityIdError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Invalid security id: {}", self.0)
+    }
+}
+
+impl std::error::Error for ParseSecurityIdError {}
+
+/// 证券代码，包含市场、原始代码及证券类别
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct SecurityId {
+    exchange: Exchange,
+    code: String,
+    asset: AssetClass,
+}
+
+impl SecurityId {
+    /// 校验代码格式: A股为6位数字，港股为5位数字，美股为字母(可含.或-)
+    pub fn new(
+        exchange: Exchange,
+        code: &str,
+        asset: AssetClass,
+    ) -> Result<Self, ParseSecurityIdError> {
+        let valid = match exchange {
+            Exchange::SH | Exchange::SZ | Exchange::BJ => {
+                code.len() == 6 && code.bytes().all(|b| b.is_ascii_digit())
+            }
+            Exchange::HK => code.len() == 5 && code.bytes().all(|b| b.is_ascii_digit()),
+            Exchange::US => {
+                !code.is_empty()
+                    && code.starts_with(|c: char| c.is_ascii_alphabetic())
+                    && code
+                        .bytes()
+                        .all(|b| b.is_ascii_alphanumeric() || b == b'.' || b == b'-')
+            }
+        };
+        if !valid {
+            return Err(ParseSecurityIdError(format!("{:?} {}", exchange, code)));
+        }
+        let code = match exchange {
+            Exchange::US => code.to_uppercase(),
+            _ => code.to_owned(),
+        };
+        Ok(Self {
+            exchange,
+            code,
+            asset,
+        })
+    }
+
+    /// 解析代码，根据代码规则推断证券类别，支持以下格式:
+    ///
+    /// - 内部及腾讯格式: sz002805、hk00700、usAAPL(不区分大小写)
+    /// - 后缀格式: 002805.SZ、00700.HK、AAPL.US
+    /// - 东方财富secid: 0.002805、1.600000、116.00700
+    pub fn parse(s: &str) -> Result<Self, ParseSecurityIdError> {
+        let s = s.trim();
+        let err = || ParseSecurityIdError(s.to_owned());
+        let (exchange, code) = if let Some((code, exchange)) = s
+            .rsplit_once('.')
+            .and_then(|(code, suffix)| Some((code, exchange_of(suffix)?)))
+        {
+            (exchange, code)
+        } else if let Some((id, code)) = s
+            .split_once('.')
+            .filter(|(id, _)| id.bytes().all(|b| b.is_ascii_digit()))
+        {
+            let exchange = match id {
+                "1" => Exchange::SH,
+                "0" if is_bj_code(code) => Exchange::BJ,
+                "0" => Exchange::SZ,
+                "116" => Exchange::HK,
+                "105" | "106" | "107" => Exchange::US,
+                _ => return Err(err()),
+            };
+            (exchange, code)
+        } else if s.len() > 2 && s.is_char_boundary(2) {
+            let (prefix, code) = s.split_at(2);
+            (exchange_of(prefix).ok_or_else(err)?, code)
+        } else {
+            return Err(err());
+        };
+        Self::new(exchange, code, infer_asset(exchange, code)).map_err(|_| err())
+    }
+
+    /// 修改证券类别，用于代码规则无法区分的情形
+    pub fn with_asset(mut self, asset: AssetClass) -> Self {
+        self.asset = asset;
+        self
+    }
+    /// 市场
+    pub fn exchange(&self) -> Exchange {
+        self.exchange
+    }
+    /// 原始代码，如002805
+    pub fn code(&self) -> &str {
+        &self.code
+    }
+    /// 证券类别
+    pub fn asset(&self) -> AssetClass {
+        self.asset
+    }
+
+    /// 内部格式，如sz002805
+    pub fn to_std(&self) -> String {
+        format!("{}{}", prefix_of(self.exchange), self.code)
+    }
+    /// 东方财富secid，如0.002805，美股需区分交易所，返回None
+    pub fn to_east(&self) -> Option<String> {
+        let id = match self.exchange {
+            Exchange::SH => 1,
+            Exchange::SZ | Exchange::BJ => 0,
+            Exchange::HK => 116,
+            Exchange::US => return None,
+        };
+        Some(format!("{}.{}", id, self.code))
+    }
+    /// 雪球格式，如SZ002805，港股美股为原始代码
+    pub fn to_xueqiu(&self) -> String {
+        match self.exchange {
+            Exchange::HK | Exchange::US => self.code.clone(),
+            _ => self.to_std().to_uppercase(),
+        }
+    }
+    /// 腾讯及新浪格式，与内部格式相同
+    pub fn to_tencent(&self) -> String {
+        self.to_std()
+    }
+    /// 后缀格式，如002805.SZ
+    pub fn to_suffix(&self) -> String {
+        format!("{}.{}", self.code, prefix_of(self.exchange).to_uppercase())
+    }
+}
+
+fn prefix_of(exchange: Exchange) -> &'static str {
+    match exchange {
+        Exchange::SH => "sh",
+        Exchange::SZ => "sz",
+        Exchange::BJ => "bj",
+        Exchange::HK => "hk",
+        Exchange::US => "us",
+    }
+}
+
+fn exchange_of(prefix: &str) -> Option<Exchange> {
+    match prefix.to_lowercase().as_str() {
+        "sh" => Some(Exchange::SH),
+        "sz" => Some(Exchange::SZ),
+        "bj" => Some(Exchange::BJ),
+        "hk" => Some(Exchange::HK),
+        "us" => Some(Exchange::US),
+        _ => None,
+    }
+}
+
+fn is_bj_code(code: &str) -> bool {
+    code.starts_with('4') || code.starts_with('8') || code.starts_with("92")
+}
+
+/// 根据代码规则推断证券类别，无法判断的为股票
+fn infer_asset(exchange: Exchange, code: &str) -> AssetClass {
+    let starts = |prefixes: &[&str]| prefixes.iter().any(|p| code.starts_with(p));
+    match exchange {
+        Exchange::SH if starts(&["000"]) => AssetClass::Index,
+        Exchange::SH if starts(&["5"]) => AssetClass::Fund,
+        Exchange::SH if starts(&["11"]) => AssetClass::Bond,
+        Exchange::SZ if starts(&["399"]) => AssetClass::Index,
+        Exchange::SZ if starts(&["15", "16"]) => AssetClass::Fund,
+        Exchange::SZ if starts(&["12"]) => AssetClass::Bond,
+        Exchange::BJ if starts(&["899"]) => AssetClass::Index,
+        _ => AssetClass::Stock,
+    }
+}
+
+impl FromStr for SecurityId {
+    type Err = ParseSecurityIdError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::parse(s)
+    }
+}
+
+impl TryFrom<&str> for SecurityId {
+    type Error = ParseSecurityIdError;
+
+    fn try_from(s: &str) -> Result<Self, Self::Error> {
+        Self::parse(s)
+    }
+}
+
+/// 显示为内部格式
+impl fmt::Display for SecurityId {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}{}", prefix_of(self.exchange), self.code)
+    }
+}
+
+impl From<SecurityId> for String {
+    fn from(id: SecurityId) -> Self {
+        id.to_std()
+    }
+}
+
+impl Serialize for SecurityId {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(&self.to_std())
+    }
+}
+
+impl<'de> Deserialize<'de> for SecurityId {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let s = String::deserialize(deserializer)?;
+        Self::parse(&s).map_err(serde::de::Error::custom)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse() {
+        let id = SecurityId::parse("sz002805").unwrap();
+        assert_eq!(id.exchange(), Exchange::SZ);
+        assert_eq!(id.code(), "002805");
+        assert_eq!(id.asset(), AssetClass::Stock);
+        assert_eq!(SecurityId::parse("SZ002805").unwrap(), id);
+        assert_eq!(SecurityId::parse("002805.SZ").unwrap(), id);
+        assert_eq!(SecurityId::parse("0.002805").unwrap(), id);
+        assert_eq!(id.to_string(), "sz002805");
+
+        assert_eq!(
+            SecurityId::parse("0.920002").unwrap().exchange(),
+            Exchange::BJ
+        );
+        assert_eq!(
+            SecurityId::parse("sh000001").unwrap().asset(),
+            AssetClass::Index
+        );
+        assert_eq!(
+            SecurityId::parse("sz159915").unwrap().asset(),
+            AssetClass::Fund
+        );
+        assert_eq!(
+            SecurityId::parse("sh113052").unwrap().asset(),
+            AssetClass::Bond
+        );
+        assert_eq!(SecurityId::parse("usaapl").unwrap().code(), "AAPL");
+        assert_eq!(SecurityId::parse("BRK.B.US").unwrap().to_std(), "usBRK.B");
+        assert_eq!(
+            SecurityId::parse("usBRK.B").unwrap(),
+            SecurityId::parse("BRK.B.US").unwrap()
+        );
+
+        assert!(SecurityId::parse("sz00280").is_err());
+        assert!(SecurityId::parse("xx002805").is_err());
+        assert!(SecurityId::parse("hk700").is_err());
+        assert!(SecurityId::parse("002805").is_err());
+    }
+
+    #[test]
+    fn test_vendor_format() {
+        let id = SecurityId::parse("bj920002").unwrap();
+        let json = serde_json::to_string(&id).unwrap();
+        assert_eq!(json, "\"bj920002\"");
+        assert_eq!(serde_json::from_str::<SecurityId>(&json).unwrap(), id);
+        assert_eq!(id.to_east().unwrap(), "0.920002");
+
+        let id = SecurityId::parse("sh600000").unwrap();
+        assert_eq!(id.to_east().unwrap(), "1.600000");
+        assert_eq!(id.to_xueqiu(), "SH600000");
+        assert_eq!(id.to_tencent(), "sh600000");
+        assert_eq!(id.to_suffix(), "600000.SH");
+
+        let id = SecurityId::parse("hk00700").unwrap();
+        assert_eq!(id.to_east().unwrap(), "116.00700");
+        assert_eq!(id.to_xueqiu(), "00700");
+        assert_eq!(id.to_suffix(), "00700.HK");
+        assert!(SecurityId::parse("usAAPL").unwrap().to_east().is_none());
+    }
+}
@@ -1,13 +1,14 @@
 //! 股票基本数据
 
-use crate::{Bar, BarFreq};
-use chrono::NaiveDateTime;
+use crate::{Bar, BarFreq, Currency, Exchange, ParseSecurityIdError, SecurityId};
+use chrono::{NaiveDate, NaiveDateTime};
 use serde::{Deserialize, Serialize};
+use std::fmt;
 
 /// 股票基本信息
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct StockInfo {
-    /// 代码
+    /// 代码，内部格式(如sz002805)，转换为`SecurityId`见`security_id`
     pub code: String,
     /// 股票简称
     pub name: String,
@@ -17,6 +18,121 @@ pub struct StockInfo {
     pub is_margin: bool,
     /// 上市日期
     pub listing_date: NaiveDateTime,
+    /// 板块分类，决定涨跌幅限制
+    #[serde(default)]
+    pub board: Board,
+    /// 交易货币
+    #[serde(default)]
+    pub currency: Currency,
+    /// 每手股数，港股每只股票不同，数据源未提供时为None
+    #[serde(default = "default_lot_size")]
+    pub lot_size: Option<u32>,
+}
+
+/// 早期数据只有A股，缺省为100
+fn default_lot_size() -> Option<u32> {
+    Some(100)
+}
+
+impl StockInfo {
+    /// 解析代码
+    pub fn security_id(&self) -> Result<SecurityId, ParseSecurityIdError> {
+        SecurityId::parse(&self.code)
+    }
+    /// 由内部代码判断的每手股数，港股每只股票不同，返回None
+    pub fn lot_size_of(code: &str) -> Option<u32> {
+        let code = code.to_lowercase();
+        if code.starts_with("us") {
+            Some(1)
+        } else if code.starts_with("hk") {
+            None
+        } else {
+            Some(100)
+        }
+    }
+}
+
+/// 股票板块分类
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum Board {
+    /// 主板
+    #[default]
+    Main,
+    /// 创业板
+    ChiNext,
+    /// 科创板
+    Star,
+    /// 北交所
+    Bse,
+    /// B股
+    BShare,
+}
+
+impl Board {
+    /// 由内部代码(sz/sh/bj + 代码)判断板块
+    pub fn from_code(code: &str) -> Self {
+        let code = code.to_lowercase();
+        if code.len() != 8 {
+            return Board::Main;
+        }
+        let (market, code) = code.split_at(2);
+        match market {
+            "bj" => Board::Bse,
+            "sh" if code.starts_with("68") => Board::Star,
+            "sh" if code.starts_with("900") => Board::BShare,
+            "sz" if code.starts_with("30") => Board::ChiNext,
+            "sz" if code.starts_with("200") => Board::BShare,
+            _ => Board::Main,
+        }
+    }
+    /// 涨跌幅限制(百分比)，不考虑ST及新股上市初期
+    pub fn limit_pct(&self) -> f32 {
+        match self {
+            Board::Main | Board::BShare => 10.0,
+            Board::ChiNext | Board::Star => 20.0,
+            Board::Bse => 30.0,
+        }
+    }
+    /// 某交易日的涨跌幅限制(百分比)，`st`为当日是否处于风险警示，不考虑新股上市初期。
+    /// 创业板2020-08-24前为10%；主板风险警示2025-07-07前为5%，之后与主板相同
+    pub fn limit_pct_on(&self, date: &NaiveDate, st: bool) -> f32 {
+        match self {
+            Board::ChiNext if *date < NaiveDate::from_ymd_opt(2020, 8, 24).unwrap() => 10.0,
+            Board::Main | Board::BShare
+                if st && *date < NaiveDate::from_ymd_opt(2025, 7, 7).unwrap() =>
+            {
+                5.0
+            }
+            _ => self.limit_pct(),
+        }
+    }
+    /// 中文名称
+    pub fn name(&self) -> &'static str {
+        match self {
+            Board::Main => "主板",
+            Board::ChiNext => "创业板",
+            Board::Star => "科创板",
+            Board::Bse => "北交所",
+            Board::BShare => "B股",
+        }
+    }
+}
+
+/// 指数成分股及权重，按权重日期保存，以便查询历史某日的成分股
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct IndexConstituent {
+    /// 指数代码
+    pub index_code: String,
+    /// 指数简称
+    pub index_name: String,
+    /// 成分股代码
+    pub code: String,
+    /// 成分股简称
+    pub name: String,
+    /// 权重(%)
+    pub weight: f32,
+    /// 权重日期
+    pub trade_date: NaiveDateTime,
 }
 
 /// 股票k线基本信息
@@ -160,6 +276,142 @@ pub struct StockYJBB {
     pub xs_mll: f32,
 }
 
+/// 资产负债表(报告期)，回测时应以公告日期作为数据可用日期，避免未来函数
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct StockBalanceSheet {
+    /// 代码
+    pub code: String,
+    /// 简称
+    pub name: String,
+    /// 报告期
+    pub report_date: NaiveDateTime,
+    /// 公告日期
+    pub notice_date: NaiveDateTime,
+    /// 货币资金
+    pub monetary_funds: f64,
+    /// 应收账款
+    pub accounts_rece: f64,
+    /// 存货
+    pub inventory: f64,
+    /// 总资产
+    pub total_assets: f64,
+    /// 应付账款
+    pub accounts_payable: f64,
+    /// 预收账款
+    pub advance_receivables: f64,
+    /// 总负债
+    pub total_liab: f64,
+    /// 股东权益合计
+    pub total_equity: f64,
+    /// 资产负债率(%)
+    pub debt_asset_ratio: f32,
+}
+
+/// 利润表(报告期，累计值)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct StockIncome {
+    /// 代码
+    pub code: String,
+    /// 简称
+    pub name: String,
+    /// 报告期
+    pub report_date: NaiveDateTime,
+    /// 公告日期
+    pub notice_date: NaiveDateTime,
+    /// 营业总收入
+    pub total_operate_income: f64,
+    /// 营业成本
+    pub operate_cost: f64,
+    /// 销售费用
+    pub sale_expense: f64,
+    /// 管理费用
+    pub manage_expense: f64,
+    /// 财务费用
+    pub finance_expense: f64,
+    /// 营业总支出
+    pub total_operate_cost: f64,
+    /// 营业利润
+    pub operate_profit: f64,
+    /// 利润总额
+    pub total_profit: f64,
+    /// 归属母公司净利润
+    pub parent_netprofit: f64,
+}
+
+/// 现金流量表(报告期，累计值)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct StockCashFlow {
+    /// 代码
+    pub code: String,
+    /// 简称
+    pub name: String,
+    /// 报告期
+    pub report_date: NaiveDateTime,
+    /// 公告日期
+    pub notice_date: NaiveDateTime,
+    /// 经营活动现金流量净额
+    pub netcash_operate: f64,
+    /// 投资活动现金流量净额
+    pub netcash_invest: f64,
+    /// 筹资活动现金流量净额
+    pub netcash_finance: f64,
+    /// 购建固定资产、无形资产和其他长期资产支付的现金(资本开支)
+    #[serde(default)]
+    pub construct_long_asset: f64,
+    /// 现金及现金等价物净增加额
+    pub cce_add: f64,
+}
+
+/// 股东户数(报告期)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct StockHolderNum {
+    /// 代码
+    pub code: String,
+    /// 简称
+    pub name: String,
+    /// 报告期
+    pub report_date: NaiveDateTime,
+    /// 公告日期
+    pub notice_date: NaiveDateTime,
+    /// 股东户数
+    pub holder_num: i64,
+    /// 上期股东户数
+    pub pre_holder_num: i64,
+    /// 较上期变化户数
+    pub holder_num_change: i64,
+    /// 较上期变化(%)
+    pub holder_num_ratio: f32,
+    /// 户均持有流通股数
+    pub avg_free_shares: f64,
+}
+
+/// 十大股东/十大流通股东(报告期)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct StockTopHolder {
+    /// 代码
+    pub code: String,
+    /// 报告期
+    pub report_date: NaiveDateTime,
+    /// 公告日期
+    pub notice_date: NaiveDateTime,
+    /// 是否十大流通股东
+    pub is_float: bool,
+    /// 排名
+    pub rank: i32,
+    /// 股东名称
+    pub holder_name: String,
+    /// 股东性质
+    pub holder_type: String,
+    /// 持股数
+    pub hold_num: f64,
+    /// 占总股本或流通股本比例(%)
+    pub hold_ratio: f32,
+    /// 较上期增减股数，新进为持股数
+    pub hold_change: f64,
+    /// 较上期变动: 新进、增加、减少、不变
+    pub change_type: String,
+}
+
 /// 股票融资融券余额
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct StockMargin {
@@ -199,6 +451,219 @@ pub struct StockMargin {
     pub rz_rq_ye_cz: f64,
 }
 
+/// 北向资金(沪股通、深股通)个股持股
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct StockNorthHold {
+    /// 代码
+    pub code: String,
+    /// 简称
+    pub name: String,
+    /// 交易日期
+    pub trade_date: NaiveDateTime,
+    /// 持股数(股)，上游缺失为None，下同
+    pub hold_shares: Option<f64>,
+    /// 持股市值(元)
+    pub hold_market_cap: Option<f64>,
+    /// 占流通股比例(%)
+    pub hold_ratio: Option<f32>,
+    /// 较上一交易日增减股数
+    pub hold_shares_change: Option<f64>,
+}
+
+/// 北向资金每日成交，按通道(沪股通、深股通)统计
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct NorthFlow {
+    /// 通道，沪股通为`SH`，深股通为`SZ`
+    pub exchange: Exchange,
+    /// 交易日期
+    pub trade_date: NaiveDateTime,
+    /// 买入成交额(元)，上游缺失为None，下同
+    pub buy_amount: Option<f64>,
+    /// 卖出成交额(元)
+    pub sell_amount: Option<f64>,
+    /// 净买入额(元)，2024-08-19起交易所不再披露
+    pub net_buy: Option<f64>,
+}
+
+/// 龙虎榜席位(营业部或机构专用)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DragonTigerSeat {
+    /// 席位名称
+    pub name: String,
+    /// 买入额(元)
+    pub buy_amount: f64,
+    /// 卖出额(元)
+    pub sell_amount: f64,
+    /// 净买入额(元)
+    pub net_buy: f64,
+}
+
+/// 龙虎榜，同一股票同一交易日可因不同原因多次上榜
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct StockDragonTiger {
+    /// 代码
+    pub code: String,
+    /// 简称
+    pub name: String,
+    /// 交易日期
+    pub trade_date: NaiveDateTime,
+    /// 上榜原因
+    pub reason: String,
+    /// 收盘价
+    pub close: f32,
+    /// 涨跌幅(%)
+    pub chg_pct: f32,
+    /// 换手率(%)
+    pub turnover: f32,
+    /// 市场总成交额(元)
+    pub amount: f64,
+    /// 龙虎榜买入额(元)
+    pub buy_amount: f64,
+    /// 龙虎榜卖出额(元)
+    pub sell_amount: f64,
+    /// 龙虎榜净买入额(元)
+    pub net_buy: f64,
+    /// 买入金额最大的前五席位
+    pub buy_seats: Vec<DragonTigerSeat>,
+    /// 卖出金额最大的前五席位
+    pub sell_seats: Vec<DragonTigerSeat>,
+}
+
+/// 涨跌停股池类型
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum LimitPool {
+    /// 涨停
+    LimitUp = 1,
+    /// 跌停
+    LimitDown = 2,
+    /// 炸板，盘中触及涨停但收盘未封住
+    Broken = 3,
+}
+
+/// 无效的股池类型
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseLimitPoolError(i32);
+
+impl fmt::Display for ParseLimitPoolError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Invalid limit pool: {}", self.0)
+    }
+}
+
+impl std::error::Error for ParseLimitPoolError {}
+
+impl TryFrom<i32> for LimitPool {
+    type Error = ParseLimitPoolError;
+    fn try_from(v: i32) -> Result<Self, Self::Error> {
+        match v {
+            1 => Ok(LimitPool::LimitUp),
+            2 => Ok(LimitPool::LimitDown),
+            3 => Ok(LimitPool::Broken),
+            _ => Err(ParseLimitPoolError(v)),
+        }
+    }
+}
+
+/// 涨跌停股池，某交易日某股池中的一只股票
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct StockLimitPool {
+    /// 代码
+    pub code: String,
+    /// 简称
+    pub name: String,
+    /// 交易日期
+    pub trade_date: NaiveDateTime,
+    /// 股池类型
+    pub pool: LimitPool,
+    /// 收盘价
+    pub close: f32,
+    /// 涨跌幅(%)
+    pub chg_pct: f32,
+    /// 成交额(元)
+    pub amount: f64,
+    /// 换手率(%)
+    pub turnover: f32,
+    /// 首次封板时间，跌停股池无此数据
+    pub first_limit_time: Option<NaiveDateTime>,
+    /// 最后封板时间，炸板股池无此数据
+    pub last_limit_time: Option<NaiveDateTime>,
+    /// 连续涨停(跌停)次数，首板为1，炸板股池为0
+    pub limit_days: i32,
+    /// 开板(炸板)次数
+    pub open_times: i32,
+    /// 封单资金(元)，炸板股池为0
+    pub sealed_amount: f64,
+    /// 所属行业
+    pub industry: String,
+}
+
+/// 股票状态类型
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum StockStatusType {
+    /// 风险警示(ST)
+    ST = 1,
+    /// 退市风险警示(*ST)
+    StarST = 2,
+    /// 停牌
+    Suspend = 3,
+    /// 终止上市
+    Delist = 4,
+}
+
+impl From<i32> for StockStatusType {
+    fn from(v: i32) -> Self {
+        match v {
+            2 => StockStatusType::StarST,
+            3 => StockStatusType::Suspend,
+            4 => StockStatusType::Delist,
+            _ => StockStatusType::ST,
+        }
+    }
+}
+
+/// 股票状态区间，区间为[start_date, end_date]，end_date为空表示仍在持续，
+/// 终止上市时start_date为退市日期
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct StockStatus {
+    /// 代码
+    pub code: String,
+    /// 简称
+    pub name: String,
+    /// 状态类型
+    pub status: StockStatusType,
+    /// 开始日期
+    pub start_date: NaiveDateTime,
+    /// 结束日期
+    pub end_date: Option<NaiveDateTime>,
+    /// 原因(停牌原因、退市原因等)，交易所未提供退市原因，退市原因为按简称推断并注明
+    pub reason: String,
+    /// 上市日期，只有终止上市记录有
+    #[serde(default)]
+    pub listing_date: Option<NaiveDateTime>,
+}
+
+/// 风险警示变更公告，变更后的状态由公告标题推断
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct StockStatusNotice {
+    /// 代码
+    pub code: String,
+    /// 简称
+    pub name: String,
+    /// 公告日期
+    pub notice_date: NaiveDateTime,
+    /// 变更后的状态，None为撤销风险警示
+    pub status: Option<StockStatusType>,
+    /// 公告标题
+    pub title: String,
+}
+
+impl StockStatus {
+    /// 给定日期是否处于该状态区间
+    pub fn is_active(&self, date: &NaiveDateTime) -> bool {
+        self.start_date <= *date && self.end_date.map(|e| e >= *date).unwrap_or(true)
+    }
+}
+
 /// 实时行情
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct StockRtQuot {
@@ -232,4 +697,102 @@ pub struct StockRtQuot {
     pub currency_value: f64,
     /// 是否交易
     pub is_trading: bool,
+    /// 交易货币
+    #[serde(default)]
+    pub currency: Currency,
+}
+
+/// 除权除息事件(分红、送转、配股)，数值均为每股
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct StockCorpAction {
+    /// 代码
+    pub code: String,
+    /// 简称
+    pub name: String,
+    /// 公告日期
+    pub notice_date: Option<NaiveDateTime>,
+    /// 除权除息日
+    pub ex_date: NaiveDateTime,
+    /// 每股派息(元，税前)
+    pub cash: f32,
+    /// 每股送股
+    pub bonus: f32,
+    /// 每股转增
+    pub transfer: f32,
+    /// 每股配股
+    pub rights: f32,
+    /// 配股价(元)
+    pub rights_price: f32,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_board_from_code() {
+        assert_eq!(Board::from_code("sh600000"), Board::Main);
+        assert_eq!(Board::from_code("sh688981"), Board::Star);
+        assert_eq!(Board::from_code("sz000001"), Board::Main);
+        assert_eq!(Board::from_code("sz300750"), Board::ChiNext);
+        assert_eq!(Board::from_code("sz301236"), Board::ChiNext);
+        assert_eq!(Board::from_code("bj920002"), Board::Bse);
+        assert_eq!(Board::from_code("BJ430017"), Board::Bse);
+        assert_eq!(Board::from_code("sh900901"), Board::BShare);
+        assert_eq!(Board::from_code("sz200002"), Board::BShare);
+        assert_eq!(Board::Bse.limit_pct(), 30.0);
+        let date = |s| NaiveDate::parse_from_str(s, "%Y-%m-%d").unwrap();
+        assert_eq!(
+            Board::ChiNext.limit_pct_on(&date("2020-08-21"), false),
+            10.0
+        );
+        assert_eq!(Board::ChiNext.limit_pct_on(&date("2020-08-24"), true), 20.0);
+        assert_eq!(Board::Main.limit_pct_on(&date("2019-01-02"), true), 5.0);
+        assert_eq!(Board::Main.limit_pct_on(&date("2025-07-07"), true), 10.0);
+        assert_eq!(Board::Star.limit_pct_on(&date("2019-07-22"), true), 20.0);
+    }
+
+    #[test]
+    fn test_limit_pool_try_from() {
+        assert_eq!(LimitPool::try_from(2), Ok(LimitPool::LimitDown));
+        assert!(LimitPool::try_from(0).is_err());
+        assert!(LimitPool::try_from(4).is_err());
+    }
+
+    #[test]
+    fn test_currency_lot_size() {
+        assert_eq!(Currency::from_code("sz002805"), Currency::CNY);
+        assert_eq!(Currency::from_code("sh900901"), Currency::USD);
+        assert_eq!(Currency::from_code("sz200002"), Currency::HKD);
+        assert_eq!(Currency::from_code("hk00700"), Currency::HKD);
+        assert_eq!(Currency::from_code("usBABA"), Currency::USD);
+        assert_eq!(StockInfo::lot_size_of("usBABA"), Some(1));
+        assert_eq!(StockInfo::lot_size_of("sh600000"), Some(100));
+        assert_eq!(StockInfo::lot_size_of("hk00700"), None);
+    }
+
+    #[test]
+    fn test_stock_status_active() {
+        let dt = |s: &str| {
+            chrono::NaiveDate::parse_from_str(s, "%Y-%m-%d")
+                .unwrap()
+                .and_hms_opt(0, 0, 0)
+                .unwrap()
+        };
+        let mut status = StockStatus {
+            code: String::from("sz000001"),
+            name: String::from("ST平安"),
+            status: StockStatusType::from(1),
+            start_date: dt("2020-05-06"),
+            end_date: Some(dt("2021-05-06")),
+            reason: String::new(),
+            listing_date: None,
+        };
+        assert!(!status.is_active(&dt("2020-05-05")));
+        assert!(status.is_active(&dt("2020-05-06")));
+        assert!(status.is_active(&dt("2021-05-06")));
+        assert!(!status.is_active(&dt("2021-05-07")));
+        status.end_date = None;
+        assert!(status.is_active(&dt("2030-01-01")));
+    }
 }
@@ -1,7 +1,8 @@
 use anyhow::Context;
 use argh::FromArgs;
-use hiq_data::{HiqSync, HiqSyncDataType, HiqSyncDest};
+use hiq_data::{FetchConfig, HiqSync, HiqSyncDataType, HiqSyncDest};
 use std::str::FromStr;
+use std::time::Duration;
 
 use tokio::{signal, sync::broadcast};
 
@@ -70,7 +71,8 @@ async fn build_index(cmd: BuildIndexCommand) -> anyhow::Result<()> {
             .with_context(|| format!("failed to convert to HiqSyncDest, ({}, {})", source, url))?;
 
         let funcs = None;
-        let (_, s) = hiq_data::store::get_store(&di, true, 0, &funcs, false)
+        let config = FetchConfig::default();
+        let (_, s) = hiq_data::store::get_store(&di, true, 0, &funcs, &config, false)
             .await
             .with_context(|| format!("failed to get store"))?;
 
@@ -104,7 +106,13 @@ async fn sync_cmd(cmd: SyncCommand) -> anyhow::Result<()> {
     }
     let funcs = if funcs.len() > 0 { Some(funcs) } else { None };
     let (shutdown_tx, _) = broadcast::channel(1);
-    let mut s = HiqSync::new(dest, shutdown_tx.subscribe(), funcs);
+    let config = FetchConfig {
+        proxy: cmd.proxy.clone(),
+        connect_timeout: cmd.connect_timeout.map(Duration::from_secs),
+        read_timeout: cmd.read_timeout.map(Duration::from_secs),
+        ..Default::default()
+    };
+    let mut s = HiqSync::new(dest, shutdown_tx.subscribe(), funcs).with_fetch_config(config);
     tokio::select! {
         res = s.sync(cmd.skip_basic, cmd.concurrent, cmd.split_count) => {
             log::info!("sync done, result: {:?}", res);
@@ -185,11 +193,26 @@ struct SyncCommand {
     /// trade_date, index_info, index_daily,
     /// stock_info, stock_daily, stock_index, stock_industry, stock_industry_detail,
     /// stock_industry_daily, stock_concept, stock_concept_detail,
-    /// stock_concept_daily, stock_yjbb, stock_margin,
+    /// stock_concept_daily, stock_yjbb, stock_margin, stock_corp_action, stock_tick,
+    /// overseas_stock_info, overseas_stock_daily, index_constituent,
+    /// stock_balance_sheet, stock_income, stock_cash_flow, stock_holder_num, stock_top_holder,
+    /// stock_north_hold, north_flow, stock_dragon_tiger, stock_limit_pool, stock_status, stock_auction,
     /// fund_info, fund_net, fund_daily,
     /// bond_info, bond_daily,
+    /// 不指定时同步全部，stock_tick、stock_auction、overseas_stock_daily数据量大，需明确指定，
+    /// overseas_stock_daily的代码取自已同步的overseas_stock_info
     #[argh(option, short = 'f')]
     funcs: Vec<String>,
+
+    /// 远程获取数据的代理，如: http://127.0.0.1:3128
+    #[argh(option)]
+    proxy: Option<String>,
+    /// 远程获取数据的连接超时(秒)
+    #[argh(option)]
+    connect_timeout: Option<u64>,
+    /// 远程获取数据的请求超时(秒)
+    #[argh(option)]
+    read_timeout: Option<u64>,
 }
 
 #[derive(FromArgs, PartialEq, Debug)]
@@ -1,12 +1,15 @@
 use std::{
-    collections::{BTreeSet, HashMap},
+    collections::{BTreeSet, HashMap, HashSet},
     ops::Add,
     sync::Arc,
 };
 
 use chrono::{Duration, NaiveDate, NaiveDateTime, NaiveTime};
-use hiq_fetch::{BondInfo, FundInfo, StockInfo};
-use mongodb::bson::Document;
+use hiq_fetch::{
+    adjust_bars, AdjustFactor, BondInfo, FetchConfig, FundInfo, LimitPool, StockCorpAction,
+    StockInfo, StockStatusType, TradeCalendar,
+};
+use mongodb::bson::{doc, to_bson, Document};
 use serde::{Deserialize, Serialize};
 
 use crate::{
@@ -35,12 +38,14 @@ use self::mongo::MongoLoader;
 /// `skip_basic` 初始化数据是否从远程获取，true在从数据库获取, false则从远程获取    
 /// `split_count` 代码切分份数，同一份数据在同一个task里处理  
 /// `funcs` 过滤的同步类型，None则全部同步
+/// `config` 远程获取数据的http配置(代理、超时等)
 /// `try_init` 是否初始化
 pub async fn get_store(
     dest: &HiqSyncDest,
     skip_basic: bool,
     split_count: usize,
     funcs: &Option<Vec<HiqSyncDataType>>,
+    config: &FetchConfig,
     try_init: bool,
 ) -> Result<(HiqSyncDestType, Box<dyn Store>)> {
     match dest {
@@ -51,7 +56,8 @@ pub async fn get_store(
                 skip_basic,
                 split_count,
                 funcs,
-            ));
+                config,
+            )?);
             if try_init {
                 store.init().await?;
             }
@@ -101,11 +107,12 @@ pub trait Store: Sync + Send {
 }
 
 pub(crate) struct HiqCache {
-    trade_date: Option<BTreeSet<i32>>,
+    trade_date: Option<TradeCalendar>,
     index_info: Option<HashMap<String, StockInfo>>,
     stock_info: Option<HashMap<String, StockInfo>>,
     bond_info: Option<HashMap<String, BondInfo>>,
     fund_info: Option<HashMap<String, FundInfo>>,
+    corp_action: Option<HashMap<String, Vec<StockCorpAction>>>,
 }
 
 impl HiqCache {
@@ -116,30 +123,22 @@ impl HiqCache {
             stock_info: None,
             bond_info: None,
             fund_info: None,
+            corp_action: None,
         }
     }
+    /// 下一个交易日，超出交易日历时为下一自然日
     pub fn next_trade_date(&self, date: &NaiveDate) -> NaiveDate {
-        let trade_date = if let Some(cache) = &self.trade_date {
-            let mut next_date = date.clone();
-            loop {
-                next_date = next_date.add(Duration::days(1));
-                let d: i32 = next_date.format("%Y%m%d").to_string().parse().unwrap();
-
-                if cache.contains(&d) {
-                    break next_date;
-                }
-            }
-        } else {
-            date.clone()
-        };
-        trade_date
+        match &self.trade_date {
+            Some(cache) => cache
+                .next(date)
+                .unwrap_or_else(|| date.add(Duration::days(1))),
+            None => *date,
+        }
     }
     pub fn cache_trade_date(&mut self, data: &BTreeSet<i32>) {
-        let mut cache = BTreeSet::new();
-        cache.extend(data.iter());
-        self.trade_date = Some(cache);
+        self.trade_date = Some(TradeCalendar::from_i32(data));
     }
-    pub fn trade_date(&self) -> &Option<BTreeSet<i32>> {
+    pub fn trade_date(&self) -> &Option<TradeCalendar> {
         &self.trade_date
     }
     pub fn cache_index_info(&mut self, data: &Vec<StockInfo>) {
@@ -174,6 +173,28 @@ impl HiqCache {
     pub fn fund_info(&self) -> &Option<HashMap<String, FundInfo>> {
         &self.fund_info
     }
+    /// 按代码缓存除权除息事件，重复的(数据库与远程重叠)只保留一条
+    pub fn cache_corp_action(&mut self, data: Vec<StockCorpAction>) {
+        let mut seen = HashSet::new();
+        let mut cache: HashMap<String, Vec<StockCorpAction>> = HashMap::new();
+        for action in data {
+            let values = [
+                action.cash,
+                action.bonus,
+                action.transfer,
+                action.rights,
+                action.rights_price,
+            ]
+            .map(f32::to_bits);
+            if seen.insert((action.code.clone(), action.ex_date, values)) {
+                cache.entry(action.code.clone()).or_default().push(action);
+            }
+        }
+        self.corp_action = Some(cache)
+    }
+    pub fn corp_action(&self) -> &Option<HashMap<String, Vec<StockCorpAction>>> {
+        &self.corp_action
+    }
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
@@ -191,6 +212,10 @@ pub trait Loader: Sync + Send {
     async fn init(&mut self) -> Result<()> {
         Ok(())
     }
+    /// 交易日历，默认使用内置(或已更新)的交易日历
+    async fn load_trade_calendar(&self) -> Result<TradeCalendar> {
+        Ok(hiq_fetch::trade_calendar())
+    }
     async fn load_bond_info(
         &self,
         filter: Document,
@@ -237,6 +262,41 @@ pub trait Loader: Sync + Send {
         limit: Option<i64>,
     ) -> Result<Vec<hiq_fetch::Bar>>;
 
+    async fn load_index_constituent(
+        &self,
+        filter: Document,
+        sort: Document,
+        limit: Option<i64>,
+    ) -> Result<Vec<hiq_fetch::IndexConstituent>>;
+
+    /// 指数在`date`当日的成分股，即不晚于`date`的最近一期权重
+    async fn load_index_members(
+        &self,
+        index_code: &str,
+        date: &NaiveDate,
+    ) -> Result<Vec<hiq_fetch::IndexConstituent>> {
+        let dt = self.naive_date_to_datetime_str(date)?;
+        let last = self
+            .load_index_constituent(
+                doc! {"index_code": index_code, "trade_date": {"$lte": dt}},
+                doc! {"trade_date": -1},
+                Some(1),
+            )
+            .await?;
+        match last.first() {
+            Some(last) => {
+                let dt = self.naive_date_time_to_datetime_str(&last.trade_date)?;
+                self.load_index_constituent(
+                    doc! {"index_code": index_code, "trade_date": dt},
+                    doc! {"weight": -1},
+                    None,
+                )
+                .await
+            }
+            None => Ok(Vec::new()),
+        }
+    }
+
     async fn load_stock_info(
         &self,
         filter: Document,
@@ -304,6 +364,68 @@ pub trait Loader: Sync + Send {
         limit: Option<i64>,
     ) -> Result<Vec<hiq_fetch::StockYJBB>>;
 
+    async fn load_stock_balance_sheet(
+        &self,
+        filter: Document,
+        sort: Document,
+        limit: Option<i64>,
+    ) -> Result<Vec<hiq_fetch::StockBalanceSheet>>;
+
+    async fn load_stock_income(
+        &self,
+        filter: Document,
+        sort: Document,
+        limit: Option<i64>,
+    ) -> Result<Vec<hiq_fetch::StockIncome>>;
+
+    async fn load_stock_cash_flow(
+        &self,
+        filter: Document,
+        sort: Document,
+        limit: Option<i64>,
+    ) -> Result<Vec<hiq_fetch::StockCashFlow>>;
+
+    /// `date`当日已公告的最近一期财务报表(资产负债表、利润表、现金流量表)，按公告日期过滤避免未来数据
+    async fn load_stock_finance_as_of(
+        &self,
+        code: &str,
+        date: &NaiveDate,
+    ) -> Result<(
+        Option<hiq_fetch::StockBalanceSheet>,
+        Option<hiq_fetch::StockIncome>,
+        Option<hiq_fetch::StockCashFlow>,
+    )> {
+        let dt = self.naive_date_to_datetime_str(date)?;
+        let filter = doc! {"code": code, "notice_date": {"$lte": dt}};
+        let sort = doc! {"report_date": -1, "notice_date": -1};
+        let balance_sheet = self
+            .load_stock_balance_sheet(filter.clone(), sort.clone(), Some(1))
+            .await?;
+        let income = self
+            .load_stock_income(filter.clone(), sort.clone(), Some(1))
+            .await?;
+        let cash_flow = self.load_stock_cash_flow(filter, sort, Some(1)).await?;
+        Ok((
+            balance_sheet.into_iter().next(),
+            income.into_iter().next(),
+            cash_flow.into_iter().next(),
+        ))
+    }
+
+    async fn load_stock_holder_num(
+        &self,
+        filter: Document,
+        sort: Document,
+        limit: Option<i64>,
+    ) -> Result<Vec<hiq_fetch::StockHolderNum>>;
+
+    async fn load_stock_top_holder(
+        &self,
+        filter: Document,
+        sort: Document,
+        limit: Option<i64>,
+    ) -> Result<Vec<hiq_fetch::StockTopHolder>>;
+
     async fn load_stock_margin(
         &self,
         filter: Document,
@@ -311,6 +433,174 @@ pub trait Loader: Sync + Send {
         limit: Option<i64>,
     ) -> Result<Vec<hiq_fetch::StockMargin>>;
 
+    async fn load_stock_north_hold(
+        &self,
+        filter: Document,
+        sort: Document,
+        limit: Option<i64>,
+    ) -> Result<Vec<hiq_fetch::StockNorthHold>>;
+
+    async fn load_north_flow(
+        &self,
+        filter: Document,
+        sort: Document,
+        limit: Option<i64>,
+    ) -> Result<Vec<hiq_fetch::NorthFlow>>;
+
+    async fn load_stock_dragon_tiger(
+        &self,
+        filter: Document,
+        sort: Document,
+        limit: Option<i64>,
+    ) -> Result<Vec<hiq_fetch::StockDragonTiger>>;
+
+    async fn load_stock_limit_pool(
+        &self,
+        filter: Document,
+        sort: Document,
+        limit: Option<i64>,
+    ) -> Result<Vec<hiq_fetch::StockLimitPool>>;
+
+    /// `date`当日的股池，按连板数降序、首次封板时间升序，当日股池未同步(或非交易日)返回错误
+    async fn load_stock_limit_pool_on(
+        &self,
+        date: &NaiveDate,
+        pool: LimitPool,
+    ) -> Result<Vec<hiq_fetch::StockLimitPool>> {
+        let dt = self.naive_date_to_datetime_str(date)?;
+        let pool = to_bson(&pool).map_err(|e| Error::Custom(format!("to_bson error: {}", e)))?;
+        let data = self
+            .load_stock_limit_pool(
+                doc! {"pool": pool, "trade_date": dt.clone()},
+                doc! {"limit_days": -1, "first_limit_time": 1},
+                None,
+            )
+            .await?;
+        if data.is_empty() {
+            // 该股池当日可能确实为空，以当日是否有任一股池的数据判断是否已同步
+            let any = self
+                .load_stock_limit_pool(doc! {"trade_date": dt}, doc! {}, Some(1))
+                .await?;
+            if any.is_empty() {
+                return Err(Error::Custom(format!(
+                    "stock_limit_pool of {} is not found",
+                    date
+                )));
+            }
+        }
+        Ok(data)
+    }
+
+    async fn load_stock_status(
+        &self,
+        filter: Document,
+        sort: Document,
+        limit: Option<i64>,
+    ) -> Result<Vec<hiq_fetch::StockStatus>>;
+
+    /// `date`当日的股票池(代码，简称)，包含当时已上市、之后才退市的股票，避免幸存者偏差，
+    /// `exclude_st`剔除当日处于ST、*ST的股票，`exclude_suspend`剔除当日停牌的股票。
+    /// 退市股票的日线由单独的任务按退市记录同步，需先同步股票状态
+    async fn load_stock_universe(
+        &self,
+        date: &NaiveDate,
+        exclude_st: bool,
+        exclude_suspend: bool,
+    ) -> Result<Vec<(String, String)>> {
+        let dt = self.naive_date_to_datetime_str(date)?;
+        let to_bson_status = |status: StockStatusType| {
+            to_bson(&status).map_err(|e| Error::Custom(format!("to_bson error: {}", e)))
+        };
+
+        let mut universe: Vec<_> = self
+            .load_stock_info(doc! {"listing_date": {"$lte": &dt}}, doc! {"code": 1}, None)
+            .await?
+            .into_iter()
+            .map(|info| (info.code, info.name))
+            .collect();
+        let mut codes: BTreeSet<_> = universe.iter().map(|(code, _)| code.clone()).collect();
+
+        // 之后退市的股票不在当前股票列表中，从退市记录补充
+        let delist = self
+            .load_stock_status(
+                doc! {
+                    "status": to_bson_status(StockStatusType::Delist)?,
+                    "start_date": {"$gt": &dt},
+                    "listing_date": {"$lte": &dt},
+                },
+                doc! {"code": 1},
+                None,
+            )
+            .await?;
+        for status in delist.into_iter() {
+            if codes.insert(status.code.clone()) {
+                universe.push((status.code, status.name));
+            }
+        }
+
+        let mut excludes = Vec::new();
+        if exclude_st {
+            excludes.push(to_bson_status(StockStatusType::ST)?);
+            excludes.push(to_bson_status(StockStatusType::StarST)?);
+        }
+        if exclude_suspend {
+            excludes.push(to_bson_status(StockStatusType::Suspend)?);
+        }
+        if !excludes.is_empty() {
+            let active: BTreeSet<_> = self
+                .load_stock_status(
+                    doc! {
+                        "status": {"$in": excludes},
+                        "start_date": {"$lte": &dt},
+                        "$or": [{"end_date": null}, {"end_date": {"$gte": &dt}}],
+                    },
+                    doc! {},
+                    None,
+                )
+                .await?
+                .into_iter()
+                .map(|status| status.code)
+                .collect();
+            universe.retain(|(code, _)| !active.contains(code));
+        }
+        Ok(universe)
+    }
+
+    async fn load_stock_corp_action(
+        &self,
+        filter: Document,
+        sort: Document,
+        limit: Option<i64>,
+    ) -> Result<Vec<hiq_fetch::StockCorpAction>>;
+
+    async fn load_stock_tick(
+        &self,
+        filter: Document,
+        sort: Document,
+        limit: Option<i64>,
+    ) -> Result<Vec<hiq_fetch::Tick>>;
+
+    async fn load_stock_auction(
+        &self,
+        filter: Document,
+        sort: Document,
+        limit: Option<i64>,
+    ) -> Result<Vec<hiq_fetch::AuctionSnapshot>>;
+
+    async fn load_overseas_stock_info(
+        &self,
+        filter: Document,
+        sort: Document,
+        limit: Option<i64>,
+    ) -> Result<Vec<hiq_fetch::StockInfo>>;
+
+    async fn load_overseas_stock_daily(
+        &self,
+        filter: Document,
+        sort: Document,
+        limit: Option<i64>,
+    ) -> Result<Vec<hiq_fetch::Bar>>;
+
     async fn load_info(
         &self,
         typ: DataType,
@@ -358,14 +648,16 @@ pub trait Loader: Sync + Send {
         };
         Ok(data)
     }
+    /// 加载日线，`adjust`为复权方式，前复权按代码以加载数据中该代码最新的k线为基准
     async fn load_daily(
         &self,
         typ: DataType,
         filter: Document,
         sort: Document,
         limit: Option<i64>,
+        adjust: AdjustFactor,
     ) -> Result<Vec<hiq_fetch::Bar>> {
-        let data: Vec<_> = match typ {
+        let mut data: Vec<_> = match typ {
             DataType::Bond => self.load_bond_daily(filter, sort, limit).await?,
             DataType::Fund => self.load_fund_daily(filter, sort, limit).await?,
             DataType::Stock => self.load_stock_daily(filter, sort, limit).await?,
@@ -373,6 +665,7 @@ pub trait Loader: Sync + Send {
             DataType::Concept => self.load_stock_concept_daily(filter, sort, limit).await?,
             DataType::Industry => self.load_stock_industry_daily(filter, sort, limit).await?,
         };
+        adjust_bars(&mut data, adjust);
         Ok(data)
     }
     #[inline]
@@ -408,6 +701,7 @@ pub const TAB_FUND_NET: &'static str = "fund_net";
 
 pub const TAB_INDEX_INFO: &'static str = "index_info";
 pub const TAB_INDEX_DAILY: &'static str = "index_daily";
+pub const TAB_INDEX_CONSTITUENT: &'static str = "index_constituent";
 
 pub const TAB_STOCK_INFO: &'static str = "stock_info";
 pub const TAB_STOCK_DAILY: &'static str = "stock_daily";
@@ -419,4 +713,20 @@ pub const TAB_STOCK_CONCEPT: &'static str = "stock_concept";
 pub const TAB_STOCK_CONCEPT_DAILY: &'static str = "stock_concept_daily";
 pub const TAB_STOCK_CONCEPT_DETAIL: &'static str = "stock_concept_detail";
 pub const TAB_STOCK_YJBB: &'static str = "stock_yjbb";
+pub const TAB_STOCK_BALANCE_SHEET: &'static str = "stock_balance_sheet";
+pub const TAB_STOCK_INCOME: &'static str = "stock_income";
+pub const TAB_STOCK_CASH_FLOW: &'static str = "stock_cash_flow";
+pub const TAB_STOCK_HOLDER_NUM: &'static str = "stock_holder_num";
+pub const TAB_STOCK_TOP_HOLDER: &'static str = "stock_top_holder";
 pub const TAB_STOCK_MARGIN: &'static str = "stock_margin";
+pub const TAB_STOCK_NORTH_HOLD: &'static str = "stock_north_hold";
+pub const TAB_NORTH_FLOW: &'static str = "north_flow";
+pub const TAB_STOCK_DRAGON_TIGER: &'static str = "stock_dragon_tiger";
+pub const TAB_STOCK_LIMIT_POOL: &'static str = "stock_limit_pool";
+pub const TAB_STOCK_STATUS: &'static str = "stock_status";
+pub const TAB_STOCK_ST_NOTICE: &'static str = "stock_st_notice";
+pub const TAB_STOCK_CORP_ACTION: &'static str = "stock_corp_action";
+pub const TAB_STOCK_TICK: &'static str = "stock_tick";
+pub const TAB_STOCK_AUCTION: &'static str = "stock_auction";
+pub const TAB_OVERSEAS_STOCK_INFO: &'static str = "overseas_stock_info";
+pub const TAB_OVERSEAS_STOCK_DAILY: &'static str = "overseas_stock_daily";
@@ -0,0 +1,137 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use chrono::{Duration, NaiveDate};
+use hiq_fetch::StockFetch;
+use mongodb::{bson::doc, options::FindOptions, Client};
+use tokio::sync::mpsc;
+
+use crate::{
+    store::{
+        mongo::service::{insert_many, query_one},
+        DATA_DEF_START_DATE, TAB_INDEX_CONSTITUENT,
+    },
+    syncer::{retry, AsyncFunc, Syncer},
+    types::HiqSyncData,
+    Error, Result,
+};
+
+/// 同步成分股的指数: 上证50，沪深300，中证500，中证1000，科创50，创业板指
+const CONSTITUENT_INDEX: [&str; 6] = [
+    "sh000016", "sh000300", "sh000905", "sh000852", "sh000688", "sz399006",
+];
+
+struct IndexConstituentAsyncFunc<'a> {
+    fetch: Arc<dyn StockFetch>,
+    code: &'a str,
+    date: Option<NaiveDate>,
+}
+
+#[async_trait]
+impl<'a> AsyncFunc for IndexConstituentAsyncFunc<'a> {
+    async fn call(&self) -> Result<Option<HiqSyncData>> {
+        let data = self
+            .fetch
+            .fetch_index_constituents(self.code, self.date)
+            .await?;
+        if data.is_empty() {
+            Ok(None)
+        } else {
+            Ok(Some(HiqSyncData::IndexConstituent(data)))
+        }
+    }
+}
+
+/// 指数成分股及权重，每期权重单独保存，从最新一期往前回溯至已保存的最后一期或DATA_DEF_START_DATE，
+/// 按权重日期升序保存，中断后重新同步不会遗漏中间各期
+pub(crate) struct IndexConstituentSyncer {
+    fetch: Arc<dyn StockFetch>,
+    client: Client,
+}
+
+impl IndexConstituentSyncer {
+    pub fn new(client: Client, fetch: Arc<dyn StockFetch>) -> Self {
+        Self { client, fetch }
+    }
+}
+
+#[async_trait]
+impl Syncer for IndexConstituentSyncer {
+    async fn fetch(&self, tx: mpsc::UnboundedSender<HiqSyncData>) -> Result<()> {
+        for code in CONSTITUENT_INDEX.iter() {
+            log::info!("prepare sync {} {}", code, TAB_INDEX_CONSTITUENT);
+            let last: Option<hiq_fetch::IndexConstituent> = query_one(
+                self.client.clone(),
+                TAB_INDEX_CONSTITUENT,
+                doc! {"index_code": *code},
+                FindOptions::builder()
+                    .sort(doc! {"trade_date": -1})
+                    .limit(1)
+                    .build(),
+            )
+            .await?;
+
+            let start = NaiveDate::parse_from_str(DATA_DEF_START_DATE, "%Y-%m-%d").unwrap();
+            let mut periods = Vec::new();
+            let mut date = None;
+            loop {
+                let func = IndexConstituentAsyncFunc {
+                    fetch: self.fetch.clone(),
+                    code,
+                    date,
+                };
+                let data = match retry(func).await? {
+                    Some(HiqSyncData::IndexConstituent(data)) => data,
+                    _ => break,
+                };
+                let trade_date = data[0].trade_date;
+                if last.as_ref().is_some_and(|l| l.trade_date >= trade_date) {
+                    break;
+                }
+                periods.push(data);
+                // 早于开始日期的一期仍需保存，作为开始日期的成分股
+                let prev = trade_date.date() - Duration::days(1);
+                if prev < start {
+                    break;
+                }
+                date = Some(prev);
+            }
+            if periods.is_empty() {
+                log::info!("{} {} is the newest", code, TAB_INDEX_CONSTITUENT);
+                continue;
+            }
+            for data in periods.into_iter().rev() {
+                tx.send(HiqSyncData::IndexConstituent(data)).map_err(|e| {
+                    log::error!("send data error {:?}", e);
+                    Error::Custom(format!("send data error {:?}", e))
+                })?;
+            }
+            log::info!("end fetch {} {}", code, TAB_INDEX_CONSTITUENT);
+        }
+        Ok(())
+    }
+
+    async fn save(&self, data: HiqSyncData) -> Result<()> {
+        if let HiqSyncData::IndexConstituent(info) = data {
+            let elm = info.first().unwrap();
+            let len = info.len();
+            log::info!(
+                "start save {}({}) {}, trade_date={}, size={}",
+                elm.index_name.as_str(),
+                elm.index_code.as_str(),
+                TAB_INDEX_CONSTITUENT,
+                elm.trade_date,
+                len
+            );
+            insert_many(self.client.clone(), TAB_INDEX_CONSTITUENT, &info, false).await?;
+            log::info!(
+                "done save {}({}) {}, size={}",
+                elm.index_name.as_str(),
+                elm.index_code.as_str(),
+                TAB_INDEX_CONSTITUENT,
+                len
+            );
+        }
+        Ok(())
+    }
+}
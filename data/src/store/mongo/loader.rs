@@ -1,16 +1,21 @@
 use crate::{
     store::{
         Loader, TAB_BOND_DAILY, TAB_BOND_INFO, TAB_FUND_DAILY, TAB_FUND_INFO, TAB_FUND_NET,
-        TAB_INDEX_DAILY, TAB_INDEX_INFO, TAB_STOCK_CONCEPT, TAB_STOCK_CONCEPT_DAILY,
-        TAB_STOCK_CONCEPT_DETAIL, TAB_STOCK_DAILY, TAB_STOCK_INDEX, TAB_STOCK_INDUSTRY,
-        TAB_STOCK_INDUSTRY_DAILY, TAB_STOCK_INDUSTRY_DETAIL, TAB_STOCK_INFO, TAB_STOCK_MARGIN,
-        TAB_STOCK_YJBB,
+        TAB_INDEX_CONSTITUENT, TAB_INDEX_DAILY, TAB_INDEX_INFO, TAB_NORTH_FLOW,
+        TAB_OVERSEAS_STOCK_DAILY, TAB_OVERSEAS_STOCK_INFO, TAB_STOCK_BALANCE_SHEET,
+        TAB_STOCK_CASH_FLOW, TAB_STOCK_CONCEPT, TAB_STOCK_CONCEPT_DAILY, TAB_STOCK_CONCEPT_DETAIL,
+        TAB_STOCK_CORP_ACTION, TAB_STOCK_DAILY, TAB_STOCK_DRAGON_TIGER, TAB_STOCK_HOLDER_NUM,
+        TAB_STOCK_INCOME, TAB_STOCK_INDEX, TAB_STOCK_INDUSTRY, TAB_STOCK_INDUSTRY_DAILY,
+        TAB_STOCK_INDUSTRY_DETAIL, TAB_STOCK_INFO, TAB_STOCK_LIMIT_POOL, TAB_STOCK_MARGIN,
+        TAB_STOCK_AUCTION, TAB_STOCK_NORTH_HOLD, TAB_STOCK_STATUS, TAB_STOCK_TICK, TAB_STOCK_TOP_HOLDER,
+        TAB_STOCK_YJBB, TAB_TRADE_DATE,
     },
     Error, Result,
 };
 use async_trait::async_trait;
+use hiq_fetch::TradeCalendar;
 use mongodb::{
-    bson::Document,
+    bson::{doc, Document},
     options::{ClientOptions, FindOptions},
     Client,
 };
@@ -110,6 +115,15 @@ impl Loader for MongoLoader {
         self.build_client().await?;
         Ok(())
     }
+    async fn load_trade_calendar(&self) -> Result<TradeCalendar> {
+        let data: Vec<hiq_fetch::TradeDate> = self
+            .query(TAB_TRADE_DATE, doc! {}, doc! {"trade_date": 1}, None)
+            .await?;
+        if data.is_empty() {
+            return Ok(hiq_fetch::trade_calendar());
+        }
+        Ok(TradeCalendar::from_i32(data.iter().map(|t| &t.trade_date)))
+    }
     async fn load_bond_info(
         &self,
         filter: Document,
@@ -261,6 +275,51 @@ impl Loader for MongoLoader {
         self.query(TAB_STOCK_YJBB, filter, sort, limit).await
     }
 
+    async fn load_stock_balance_sheet(
+        &self,
+        filter: Document,
+        sort: Document,
+        limit: Option<i64>,
+    ) -> Result<Vec<hiq_fetch::StockBalanceSheet>> {
+        self.query(TAB_STOCK_BALANCE_SHEET, filter, sort, limit).await
+    }
+
+    async fn load_stock_income(
+        &self,
+        filter: Document,
+        sort: Document,
+        limit: Option<i64>,
+    ) -> Result<Vec<hiq_fetch::StockIncome>> {
+        self.query(TAB_STOCK_INCOME, filter, sort, limit).await
+    }
+
+    async fn load_stock_cash_flow(
+        &self,
+        filter: Document,
+        sort: Document,
+        limit: Option<i64>,
+    ) -> Result<Vec<hiq_fetch::StockCashFlow>> {
+        self.query(TAB_STOCK_CASH_FLOW, filter, sort, limit).await
+    }
+
+    async fn load_stock_holder_num(
+        &self,
+        filter: Document,
+        sort: Document,
+        limit: Option<i64>,
+    ) -> Result<Vec<hiq_fetch::StockHolderNum>> {
+        self.query(TAB_STOCK_HOLDER_NUM, filter, sort, limit).await
+    }
+
+    async fn load_stock_top_holder(
+        &self,
+        filter: Document,
+        sort: Document,
+        limit: Option<i64>,
+    ) -> Result<Vec<hiq_fetch::StockTopHolder>> {
+        self.query(TAB_STOCK_TOP_HOLDER, filter, sort, limit).await
+    }
+
     async fn load_stock_margin(
         &self,
         filter: Document,
@@ -269,6 +328,105 @@ impl Loader for MongoLoader {
     ) -> Result<Vec<hiq_fetch::StockMargin>> {
         self.query(TAB_STOCK_MARGIN, filter, sort, limit).await
     }
+
+    async fn load_stock_north_hold(
+        &self,
+        filter: Document,
+        sort: Document,
+        limit: Option<i64>,
+    ) -> Result<Vec<hiq_fetch::StockNorthHold>> {
+        self.query(TAB_STOCK_NORTH_HOLD, filter, sort, limit).await
+    }
+
+    async fn load_north_flow(
+        &self,
+        filter: Document,
+        sort: Document,
+        limit: Option<i64>,
+    ) -> Result<Vec<hiq_fetch::NorthFlow>> {
+        self.query(TAB_NORTH_FLOW, filter, sort, limit).await
+    }
+
+    async fn load_stock_dragon_tiger(
+        &self,
+        filter: Document,
+        sort: Document,
+        limit: Option<i64>,
+    ) -> Result<Vec<hiq_fetch::StockDragonTiger>> {
+        self.query(TAB_STOCK_DRAGON_TIGER, filter, sort, limit).await
+    }
+
+    async fn load_stock_limit_pool(
+        &self,
+        filter: Document,
+        sort: Document,
+        limit: Option<i64>,
+    ) -> Result<Vec<hiq_fetch::StockLimitPool>> {
+        self.query(TAB_STOCK_LIMIT_POOL, filter, sort, limit).await
+    }
+
+    async fn load_stock_status(
+        &self,
+        filter: Document,
+        sort: Document,
+        limit: Option<i64>,
+    ) -> Result<Vec<hiq_fetch::StockStatus>> {
+        self.query(TAB_STOCK_STATUS, filter, sort, limit).await
+    }
+
+    async fn load_stock_corp_action(
+        &self,
+        filter: Document,
+        sort: Document,
+        limit: Option<i64>,
+    ) -> Result<Vec<hiq_fetch::StockCorpAction>> {
+        self.query(TAB_STOCK_CORP_ACTION, filter, sort, limit).await
+    }
+
+    async fn load_stock_tick(
+        &self,
+        filter: Document,
+        sort: Document,
+        limit: Option<i64>,
+    ) -> Result<Vec<hiq_fetch::Tick>> {
+        self.query(TAB_STOCK_TICK, filter, sort, limit).await
+    }
+
+    async fn load_stock_auction(
+        &self,
+        filter: Document,
+        sort: Document,
+        limit: Option<i64>,
+    ) -> Result<Vec<hiq_fetch::AuctionSnapshot>> {
+        self.query(TAB_STOCK_AUCTION, filter, sort, limit).await
+    }
+
+    async fn load_overseas_stock_info(
+        &self,
+        filter: Document,
+        sort: Document,
+        limit: Option<i64>,
+    ) -> Result<Vec<hiq_fetch::StockInfo>> {
+        self.query(TAB_OVERSEAS_STOCK_INFO, filter, sort, limit).await
+    }
+
+    async fn load_overseas_stock_daily(
+        &self,
+        filter: Document,
+        sort: Document,
+        limit: Option<i64>,
+    ) -> Result<Vec<hiq_fetch::Bar>> {
+        self.query(TAB_OVERSEAS_STOCK_DAILY, filter, sort, limit).await
+    }
+
+    async fn load_index_constituent(
+        &self,
+        filter: Document,
+        sort: Document,
+        limit: Option<i64>,
+    ) -> Result<Vec<hiq_fetch::IndexConstituent>> {
+        self.query(TAB_INDEX_CONSTITUENT, filter, sort, limit).await
+    }
 }
 
 #[cfg(test)]
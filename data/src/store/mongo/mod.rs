@@ -12,6 +12,7 @@ mod fund_net;
 
 mod index_info;
 mod index_daily;
+mod index_constituent;
 
 mod stock_info;
 mod stock_daily;
@@ -26,7 +27,18 @@ mod stock_concept_daily;
 mod stock_concept_detail;
 
 mod stock_yjbb;
+mod stock_finance;
+mod stock_holder;
 mod stock_margin;
+mod stock_north;
+mod stock_dragon_tiger;
+mod stock_limit_pool;
+mod stock_status;
+mod stock_corp_action;
+mod stock_tick;
+mod stock_auction;
+mod overseas_stock_info;
+mod overseas_stock_daily;
 
 mod loader;
 
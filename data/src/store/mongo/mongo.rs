@@ -4,13 +4,16 @@ use std::{
 };
 
 use async_trait::async_trait;
-use hiq_fetch::{BondFetch, FundFetch, StockFetch};
+use chrono::Local;
+use hiq_fetch::{
+    BondFetch, FetchConfig, FundFetch, MarketType, StockCorpAction, StockFetch, StockInfo,
+};
 use mongodb::{bson::doc, options::ClientOptions, Client};
 
 use crate::{
     store::{
         mongo::service::query, HiqCache, Store, TAB_BOND_INFO, TAB_FUND_INFO, TAB_INDEX_INFO,
-        TAB_STOCK_INFO, TAB_TRADE_DATE,
+        TAB_STOCK_CORP_ACTION, TAB_STOCK_INFO, TAB_TRADE_DATE,
     },
     syncer::Syncer,
     types::HiqSyncDataType,
@@ -19,13 +22,25 @@ use crate::{
 
 use super::{
     bond_daily::BondDailySyncer, bond_info::BondInfoSyncer, fund_daily::FundDailySyncer,
-    fund_info::FundInfoSyncer, fund_net::FundNetSyncer, index_daily::IndexDailySyncer,
-    index_info::IndexInfoSyncer, mongo_index::build_index, stock_concept::StockConceptSyncer,
+    fund_info::FundInfoSyncer, fund_net::FundNetSyncer, index_constituent::IndexConstituentSyncer,
+    index_daily::IndexDailySyncer,
+    index_info::IndexInfoSyncer, mongo_index::build_index,
+    overseas_stock_daily::OverseasStockDailySyncer, overseas_stock_info::OverseasStockInfoSyncer,
+    stock_auction::StockAuctionSyncer,
+    stock_concept::StockConceptSyncer,
     stock_concept_daily::StockConceptDailySyncer, stock_concept_detail::StockConceptDetailSyncer,
-    stock_daily::StockDailySyncer, stock_index::StockIndexSyncer,
-    stock_industry::StockIndustrySyncer, stock_industry_daily::StockIndustryDailySyncer,
+    stock_corp_action::StockCorpActionSyncer, stock_daily::StockDailySyncer,
+    stock_dragon_tiger::StockDragonTigerSyncer,
+    stock_finance::StockFinanceSyncer, stock_holder::{StockHolderNumSyncer, StockTopHolderSyncer},
+    stock_index::StockIndexSyncer, stock_industry::StockIndustrySyncer,
+    stock_industry_daily::StockIndustryDailySyncer,
     stock_industry_detail::StockIndustryDetailSyncer, stock_info::StockInfoSyncer,
-    stock_margin::StockMarginSyncer, stock_yjbb::StockYJBBSyncer, trade_date::TradeDateSyncer,
+    stock_limit_pool::StockLimitPoolSyncer,
+    stock_margin::StockMarginSyncer,
+    stock_north::{NorthFlowSyncer, StockNorthHoldSyncer},
+    stock_status::StockStatusSyncer,
+    stock_tick::StockTickSyncer, stock_yjbb::StockYJBBSyncer,
+    trade_date::TradeDateSyncer,
 };
 
 pub(crate) struct MongoStore {
@@ -39,6 +54,7 @@ pub(crate) struct MongoStore {
     skip_basic: bool,
     split_count: usize,
     funcs: Option<Vec<HiqSyncDataType>>,
+    config: FetchConfig,
 }
 
 impl MongoStore {
@@ -47,10 +63,11 @@ impl MongoStore {
         skip_basic: bool,
         split_count: usize,
         funcs: &Option<Vec<HiqSyncDataType>>,
-    ) -> Self {
-        let bond_fetch = Arc::new(hiq_fetch::bond_fetch());
-        let fund_fetch = Arc::new(hiq_fetch::fund_fetch());
-        let stock_fetch = Arc::new(hiq_fetch::stock_fetch());
+        config: &FetchConfig,
+    ) -> Result<Self> {
+        let bond_fetch = Arc::new(hiq_fetch::bond_fetch(config)?);
+        let fund_fetch = Arc::new(hiq_fetch::fund_fetch(config)?);
+        let stock_fetch = Arc::new(hiq_fetch::stock_fetch(config)?);
 
         let syncer_vec = Vec::new();
 
@@ -61,7 +78,7 @@ impl MongoStore {
             let v: Vec<_> = funcs.iter().map(|e| (*e).clone()).collect();
             t_funcs = Some(v);
         }
-        Self {
+        Ok(Self {
             bond_fetch,
             fund_fetch,
             stock_fetch,
@@ -71,7 +88,8 @@ impl MongoStore {
             skip_basic,
             split_count,
             funcs: t_funcs,
-        }
+            config: config.clone(),
+        })
     }
     async fn prepare_cache(&mut self, client: Client) -> Result<()> {
         let (bond_info, index_info, stock_info, fund_info, trade_date) = if !self.skip_basic {
@@ -84,13 +102,25 @@ impl MongoStore {
             let index_info = self.stock_fetch.fetch_index_info().await?;
 
             log::info!("prepare cache stock_info");
-            let stock_info = self.stock_fetch.fetch_stock_info().await?;
+            let mut stock_info = self.stock_fetch.fetch_stock_info().await?;
+            // 北交所接口失败时没有北交所股票，保留数据库中的，避免保存时被清除
+            if !stock_info.iter().any(|info| info.code.starts_with("bj")) {
+                let bj: Vec<StockInfo> = query(
+                    client.clone(),
+                    TAB_STOCK_INFO,
+                    doc! {"code": {"$regex": "^bj"}},
+                    None,
+                )
+                .await?;
+                log::warn!("remote bj stock info is empty, keep {} saved", bj.len());
+                stock_info.extend(bj);
+            }
 
             log::info!("prepare cache fund_info");
             let fund_info = self.fund_fetch.fetch_fund_info().await?;
 
             log::info!("prepare cache trade_date");
-            let trade_date = hiq_fetch::fetch_trade_date().await?;
+            let trade_date = hiq_fetch::fetch_trade_date_with(&self.config).await?;
 
             (bond_info, index_info, stock_info, fund_info, trade_date)
         } else {
@@ -140,6 +170,27 @@ impl MongoStore {
             Ok(())
         }
     }
+    /// 缓存除权除息事件，股票日线据此计算复权因子，不必每只股票请求
+    ///
+    /// 数据库中已同步的加上远程从最后除权日(含)开始的，保证同步日线时除权事件是最新的
+    async fn prepare_corp_action(&mut self, client: Client) -> Result<()> {
+        log::info!("prepare cache corp_action");
+        let mut actions: Vec<StockCorpAction> =
+            query(client, TAB_STOCK_CORP_ACTION, doc! {}, None).await?;
+        let start = actions.iter().map(|a| a.ex_date.date()).max();
+        let remote = self
+            .stock_fetch
+            .fetch_stock_corp_action(None, start, Some(Local::now().date_naive()))
+            .await?;
+        actions.extend(remote);
+
+        let mut cache = self.cache.write().map_err(|e| {
+            log::error!("get cache write log error: {}", e);
+            Error::Custom(format!("get cache write log error: {}", e))
+        })?;
+        cache.cache_corp_action(actions);
+        Ok(())
+    }
     fn contains(&self, typ: &HiqSyncDataType) -> bool {
         if self.funcs.is_none() {
             return true;
@@ -153,6 +204,12 @@ impl MongoStore {
             self.syncer_vec.push(syncer);
         }
     }
+    /// 数据量大的条目，仅在`funcs`明确指定时同步
+    fn add_explicit_syncer(&mut self, typ: &HiqSyncDataType, syncer: Arc<Box<dyn Syncer>>) {
+        if self.funcs.as_ref().is_some_and(|funcs| funcs.contains(typ)) {
+            self.syncer_vec.push(syncer);
+        }
+    }
     fn prepare_heavy_syncer(&mut self, client: Client, split_count: usize) {
         let stock_codes = {
             let mut data = Vec::new();
@@ -179,6 +236,8 @@ impl MongoStore {
             }
             if sub_codes.len() >= len {
                 task_n += 1;
+                self.add_tick_syncer(client.clone(), &sub_codes, task_n);
+                self.add_auction_syncer(client.clone(), &sub_codes, task_n);
                 self.add_syncer(
                     &HiqSyncDataType::StockBar,
                     Arc::new(Box::new(StockDailySyncer::new(
@@ -217,7 +276,20 @@ impl MongoStore {
                     task_n,
                 ))),
             );
+            self.add_tick_syncer(client.clone(), &sub_codes, task_n);
+            self.add_auction_syncer(client.clone(), &sub_codes, task_n);
         }
+        // 已退市股票日线
+        task_n += 1;
+        self.add_syncer(
+            &HiqSyncDataType::StockBar,
+            Arc::new(Box::new(StockDailySyncer::new_delisted(
+                client.clone(),
+                self.stock_fetch.clone(),
+                self.cache.clone(),
+                task_n,
+            ))),
+        );
         if margin_sub_codes.len() >= len {
             task_n += 1;
             self.add_syncer(
@@ -231,6 +303,65 @@ impl MongoStore {
                 ))),
             );
         }
+
+        // 可转债分笔成交
+        let bond_codes: Vec<_> = {
+            let cache_info = self.cache.read().unwrap();
+            cache_info
+                .bond_info()
+                .as_ref()
+                .map(|info| {
+                    info.values()
+                        .map(|v| (v.code.clone(), v.name.clone()))
+                        .collect()
+                })
+                .unwrap_or_default()
+        };
+        if !bond_codes.is_empty() {
+            task_n += 1;
+            self.add_explicit_syncer(
+                &HiqSyncDataType::StockTick,
+                Arc::new(Box::new(StockTickSyncer::new(
+                    client,
+                    self.stock_fetch.clone(),
+                    self.cache.clone(),
+                    bond_codes,
+                    task_n,
+                ))),
+            );
+        }
+    }
+    fn add_tick_syncer(&mut self, client: Client, codes: &[StockInfo], task_n: usize) {
+        let codes = codes
+            .iter()
+            .map(|info| (info.code.clone(), info.name.clone()))
+            .collect();
+        self.add_explicit_syncer(
+            &HiqSyncDataType::StockTick,
+            Arc::new(Box::new(StockTickSyncer::new(
+                client,
+                self.stock_fetch.clone(),
+                self.cache.clone(),
+                codes,
+                task_n,
+            ))),
+        );
+    }
+    fn add_auction_syncer(&mut self, client: Client, codes: &[StockInfo], task_n: usize) {
+        let codes = codes
+            .iter()
+            .map(|info| (info.code.clone(), info.name.clone()))
+            .collect();
+        self.add_explicit_syncer(
+            &HiqSyncDataType::StockAuction,
+            Arc::new(Box::new(StockAuctionSyncer::new(
+                client,
+                self.stock_fetch.clone(),
+                self.cache.clone(),
+                codes,
+                task_n,
+            ))),
+        );
     }
     fn prepare_syncer(&mut self, client: Client, split_count: usize) {
         if !self.skip_basic {
@@ -377,6 +508,109 @@ impl MongoStore {
                 self.stock_fetch.clone(),
             ))),
         );
+        self.add_syncer(
+            &HiqSyncDataType::StockCorpAction,
+            Arc::new(Box::new(StockCorpActionSyncer::new(
+                client.clone(),
+                self.stock_fetch.clone(),
+            ))),
+        );
+        self.add_syncer(
+            &HiqSyncDataType::StockNorthHold,
+            Arc::new(Box::new(StockNorthHoldSyncer::new(
+                client.clone(),
+                self.stock_fetch.clone(),
+                self.cache.clone(),
+            ))),
+        );
+        self.add_syncer(
+            &HiqSyncDataType::NorthFlow,
+            Arc::new(Box::new(NorthFlowSyncer::new(
+                client.clone(),
+                self.stock_fetch.clone(),
+                self.cache.clone(),
+            ))),
+        );
+        self.add_syncer(
+            &HiqSyncDataType::StockDragonTiger,
+            Arc::new(Box::new(StockDragonTigerSyncer::new(
+                client.clone(),
+                self.stock_fetch.clone(),
+                self.cache.clone(),
+            ))),
+        );
+        self.add_syncer(
+            &HiqSyncDataType::StockLimitPool,
+            Arc::new(Box::new(StockLimitPoolSyncer::new(
+                client.clone(),
+                self.stock_fetch.clone(),
+                self.cache.clone(),
+            ))),
+        );
+        self.add_syncer(
+            &HiqSyncDataType::StockStatus,
+            Arc::new(Box::new(StockStatusSyncer::new(
+                client.clone(),
+                self.stock_fetch.clone(),
+                self.cache.clone(),
+            ))),
+        );
+        self.add_syncer(
+            &HiqSyncDataType::IndexConstituent,
+            Arc::new(Box::new(IndexConstituentSyncer::new(
+                client.clone(),
+                self.stock_fetch.clone(),
+            ))),
+        );
+        for typ in [
+            HiqSyncDataType::StockBalanceSheet,
+            HiqSyncDataType::StockIncome,
+            HiqSyncDataType::StockCashFlow,
+        ] {
+            self.add_syncer(
+                &typ,
+                Arc::new(Box::new(StockFinanceSyncer::new(
+                    client.clone(),
+                    self.stock_fetch.clone(),
+                    typ.clone(),
+                ))),
+            );
+        }
+        self.add_syncer(
+            &HiqSyncDataType::StockHolderNum,
+            Arc::new(Box::new(StockHolderNumSyncer::new(
+                client.clone(),
+                self.stock_fetch.clone(),
+            ))),
+        );
+        self.add_syncer(
+            &HiqSyncDataType::StockTopHolder,
+            Arc::new(Box::new(StockTopHolderSyncer::new(
+                client.clone(),
+                self.stock_fetch.clone(),
+            ))),
+        );
+        self.add_syncer(
+            &HiqSyncDataType::OverseasStockInfo,
+            Arc::new(Box::new(OverseasStockInfoSyncer::new(
+                client.clone(),
+                self.stock_fetch.clone(),
+            ))),
+        );
+        for (i, market) in [MarketType::HkStock, MarketType::UsStock]
+            .into_iter()
+            .enumerate()
+        {
+            self.add_explicit_syncer(
+                &HiqSyncDataType::OverseasStockBar,
+                Arc::new(Box::new(OverseasStockDailySyncer::new(
+                    client.clone(),
+                    self.stock_fetch.clone(),
+                    market,
+                    i + 1,
+                ))),
+            );
+        }
         self.prepare_heavy_syncer(client, split_count);
     }
 
@@ -402,6 +636,9 @@ impl Store for MongoStore {
         let client = self.build_client().await?;
 
         self.prepare_cache(client.clone()).await?;
+        if self.contains(&HiqSyncDataType::StockBar) {
+            self.prepare_corp_action(client.clone()).await?;
+        }
         self.prepare_syncer(client.clone(), self.split_count);
 
         Ok(())
@@ -1,11 +1,18 @@
-use mongodb::{bson::doc, Client, IndexModel};
+use mongodb::{
+    bson::{doc, Document},
+    Client, IndexModel,
+};
 
 use crate::store::{
     TAB_BOND_DAILY, TAB_BOND_INFO, TAB_FUND_DAILY, TAB_FUND_INFO, TAB_FUND_NET,
-    TAB_INDEX_DAILY, TAB_INDEX_INFO, TAB_STOCK_CONCEPT, TAB_STOCK_CONCEPT_DAILY,
-    TAB_STOCK_CONCEPT_DETAIL, TAB_STOCK_DAILY, TAB_STOCK_INDEX, TAB_STOCK_INDUSTRY,
-    TAB_STOCK_INDUSTRY_DAILY, TAB_STOCK_INDUSTRY_DETAIL, TAB_STOCK_INFO, TAB_STOCK_MARGIN,
-    TAB_STOCK_YJBB, TAB_TRADE_DATE, DATABASE,
+    TAB_INDEX_CONSTITUENT, TAB_INDEX_DAILY, TAB_INDEX_INFO, TAB_NORTH_FLOW,
+    TAB_OVERSEAS_STOCK_DAILY, TAB_OVERSEAS_STOCK_INFO, TAB_STOCK_BALANCE_SHEET, TAB_STOCK_CASH_FLOW,
+    TAB_STOCK_CONCEPT, TAB_STOCK_CONCEPT_DAILY, TAB_STOCK_CONCEPT_DETAIL, TAB_STOCK_CORP_ACTION,
+    TAB_STOCK_DAILY, TAB_STOCK_DRAGON_TIGER, TAB_STOCK_HOLDER_NUM, TAB_STOCK_INCOME,
+    TAB_STOCK_INDEX, TAB_STOCK_INDUSTRY, TAB_STOCK_INDUSTRY_DAILY, TAB_STOCK_INDUSTRY_DETAIL,
+    TAB_STOCK_INFO, TAB_STOCK_LIMIT_POOL, TAB_STOCK_MARGIN, TAB_STOCK_NORTH_HOLD, TAB_STOCK_STATUS,
+    TAB_STOCK_ST_NOTICE,
+    TAB_STOCK_AUCTION, TAB_STOCK_TICK, TAB_STOCK_TOP_HOLDER, TAB_STOCK_YJBB, TAB_TRADE_DATE, DATABASE,
 };
 use crate::{Error, Result};
 
@@ -106,6 +113,20 @@ pub(crate) async fn build_index(client: Client) -> Result<()> {
                     log::error!("create index err: {}", e.to_string());
                     Error::Custom(format!("create index err: {}", e.to_string()))
                 })?;
+
+            log::info!("start build {} index!", TAB_INDEX_CONSTITUENT);
+            let coll = db.collection::<hiq_fetch::IndexConstituent>(TAB_INDEX_CONSTITUENT);
+            coll.create_index(
+                IndexModel::builder()
+                    .keys(doc! {"index_code": 1, "trade_date": -1})
+                    .build(),
+                None,
+            )
+            .await
+            .map_err(|e| {
+                log::error!("create index err: {}", e.to_string());
+                Error::Custom(format!("create index err: {}", e.to_string()))
+            })?;
         }
         {
             // stock
@@ -127,6 +148,24 @@ pub(crate) async fn build_index(client: Client) -> Result<()> {
                     Error::Custom(format!("create index err: {}", e.to_string()))
                 })?;
 
+            log::info!("start build {} index!", TAB_OVERSEAS_STOCK_DAILY);
+            let coll = db.collection::<hiq_fetch::Bar>(TAB_OVERSEAS_STOCK_DAILY);
+            coll.create_indexes(indexes.clone(), None)
+                .await
+                .map_err(|e| {
+                    log::error!("create index err: {}", e.to_string());
+                    Error::Custom(format!("create index err: {}", e.to_string()))
+                })?;
+
+            log::info!("start build {} index!", TAB_OVERSEAS_STOCK_INFO);
+            let coll = db.collection::<hiq_fetch::StockInfo>(TAB_OVERSEAS_STOCK_INFO);
+            coll.create_index(IndexModel::builder().keys(doc! {"code": 1}).build(), None)
+                .await
+                .map_err(|e| {
+                    log::error!("create index err: {}", e.to_string());
+                    Error::Custom(format!("create index err: {}", e.to_string()))
+                })?;
+
             log::info!("start build {} index!", TAB_STOCK_MARGIN);
             let coll = db.collection::<hiq_fetch::StockMargin>(TAB_STOCK_MARGIN);
             coll.create_indexes(indexes.clone(), None)
@@ -135,6 +174,132 @@ pub(crate) async fn build_index(client: Client) -> Result<()> {
                     log::error!("create index err: {}", e.to_string());
                     Error::Custom(format!("create index err: {}", e.to_string()))
                 })?;
+            log::info!("start build {} index!", TAB_STOCK_NORTH_HOLD);
+            let coll = db.collection::<hiq_fetch::StockNorthHold>(TAB_STOCK_NORTH_HOLD);
+            coll.create_indexes(indexes.clone(), None)
+                .await
+                .map_err(|e| {
+                    log::error!("create index err: {}", e.to_string());
+                    Error::Custom(format!("create index err: {}", e.to_string()))
+                })?;
+            log::info!("start build {} index!", TAB_STOCK_DRAGON_TIGER);
+            let coll = db.collection::<hiq_fetch::StockDragonTiger>(TAB_STOCK_DRAGON_TIGER);
+            coll.create_indexes(indexes.clone(), None)
+                .await
+                .map_err(|e| {
+                    log::error!("create index err: {}", e.to_string());
+                    Error::Custom(format!("create index err: {}", e.to_string()))
+                })?;
+            log::info!("start build {} index!", TAB_STOCK_LIMIT_POOL);
+            let coll = db.collection::<hiq_fetch::StockLimitPool>(TAB_STOCK_LIMIT_POOL);
+            coll.create_indexes(
+                vec![
+                    IndexModel::builder().keys(doc! {"code": 1}).build(),
+                    IndexModel::builder()
+                        .keys(doc! {"pool": 1, "trade_date": -1})
+                        .build(),
+                ],
+                None,
+            )
+            .await
+            .map_err(|e| {
+                log::error!("create index err: {}", e.to_string());
+                Error::Custom(format!("create index err: {}", e.to_string()))
+            })?;
+            log::info!("start build {} index!", TAB_STOCK_STATUS);
+            let coll = db.collection::<hiq_fetch::StockStatus>(TAB_STOCK_STATUS);
+            coll.create_indexes(
+                vec![
+                    IndexModel::builder().keys(doc! {"code": 1}).build(),
+                    IndexModel::builder()
+                        .keys(doc! {"status": 1, "start_date": -1})
+                        .build(),
+                ],
+                None,
+            )
+            .await
+            .map_err(|e| {
+                log::error!("create index err: {}", e.to_string());
+                Error::Custom(format!("create index err: {}", e.to_string()))
+            })?;
+            log::info!("start build {} index!", TAB_STOCK_ST_NOTICE);
+            let coll = db.collection::<hiq_fetch::StockStatusNotice>(TAB_STOCK_ST_NOTICE);
+            coll.create_index(
+                IndexModel::builder()
+                    .keys(doc! {"notice_date": -1, "code": 1})
+                    .build(),
+                None,
+            )
+            .await
+            .map_err(|e| {
+                log::error!("create index err: {}", e.to_string());
+                Error::Custom(format!("create index err: {}", e.to_string()))
+            })?;
+            log::info!("start build {} index!", TAB_NORTH_FLOW);
+            let coll = db.collection::<hiq_fetch::NorthFlow>(TAB_NORTH_FLOW);
+            coll.create_index(
+                IndexModel::builder()
+                    .keys(doc! {"trade_date": -1, "exchange": 1})
+                    .build(),
+                None,
+            )
+            .await
+            .map_err(|e| {
+                log::error!("create index err: {}", e.to_string());
+                Error::Custom(format!("create index err: {}", e.to_string()))
+            })?;
+            log::info!("start build {} index!", TAB_STOCK_CORP_ACTION);
+            let coll = db.collection::<hiq_fetch::StockCorpAction>(TAB_STOCK_CORP_ACTION);
+            coll.create_indexes(
+                vec![
+                    IndexModel::builder().keys(doc! {"ex_date": -1}).build(),
+                    IndexModel::builder()
+                        .keys(doc! {"code": 1, "ex_date": 1})
+                        .build(),
+                ],
+                None,
+            )
+            .await
+            .map_err(|e| {
+                log::error!("create index err: {}", e.to_string());
+                Error::Custom(format!("create index err: {}", e.to_string()))
+            })?;
+            log::info!("start build {} index!", TAB_STOCK_AUCTION);
+            let coll = db.collection::<hiq_fetch::AuctionSnapshot>(TAB_STOCK_AUCTION);
+            coll.create_indexes(
+                vec![
+                    IndexModel::builder()
+                        .keys(doc! {"trade_date": -1, "code": 1})
+                        .build(),
+                    IndexModel::builder()
+                        .keys(doc! {"code": 1, "time": 1})
+                        .build(),
+                ],
+                None,
+            )
+            .await
+            .map_err(|e| {
+                log::error!("create index err: {}", e);
+                Error::Custom(format!("create index err: {}", e))
+            })?;
+            log::info!("start build {} index!", TAB_STOCK_TICK);
+            let coll = db.collection::<hiq_fetch::Tick>(TAB_STOCK_TICK);
+            coll.create_indexes(
+                vec![
+                    IndexModel::builder()
+                        .keys(doc! {"trade_date": -1, "code": 1})
+                        .build(),
+                    IndexModel::builder()
+                        .keys(doc! {"code": 1, "time": 1})
+                        .build(),
+                ],
+                None,
+            )
+            .await
+            .map_err(|e| {
+                log::error!("create index err: {}", e.to_string());
+                Error::Custom(format!("create index err: {}", e.to_string()))
+            })?;
             log::info!("start build {} index!", TAB_STOCK_INDEX);
             let coll = db.collection::<hiq_fetch::StockIndex>(TAB_STOCK_INDEX);
             coll.create_indexes(indexes.clone(), None)
@@ -153,6 +318,34 @@ pub(crate) async fn build_index(client: Client) -> Result<()> {
                     Error::Custom(format!("create index err: {}", e.to_string()))
                 })?;
 
+            // 财务报表、股东数据按公告日期过滤
+            for tab in [
+                TAB_STOCK_BALANCE_SHEET,
+                TAB_STOCK_INCOME,
+                TAB_STOCK_CASH_FLOW,
+                TAB_STOCK_HOLDER_NUM,
+                TAB_STOCK_TOP_HOLDER,
+            ] {
+                log::info!("start build {} index!", tab);
+                let coll = db.collection::<Document>(tab);
+                coll.create_indexes(
+                    vec![
+                        IndexModel::builder()
+                            .keys(doc! {"code": 1, "report_date": -1})
+                            .build(),
+                        IndexModel::builder()
+                            .keys(doc! {"notice_date": -1})
+                            .build(),
+                    ],
+                    None,
+                )
+                .await
+                .map_err(|e| {
+                    log::error!("create index err: {}", e.to_string());
+                    Error::Custom(format!("create index err: {}", e.to_string()))
+                })?;
+            }
+
             {
                 // concept
                 log::info!("start build {} index!", TAB_STOCK_CONCEPT_DAILY);
@@ -0,0 +1,200 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use chrono::{Duration, Local, NaiveDate};
+use hiq_fetch::{market_clock, Bar, BarFreq, Exchange, MarketType, StockFetch, StockInfo};
+use mongodb::{bson::doc, options::FindOptions, Client};
+use tokio::sync::mpsc;
+
+use crate::{
+    store::{
+        mongo::service::{query, query_one},
+        DATA_DEF_START_DATE, TAB_OVERSEAS_STOCK_DAILY, TAB_OVERSEAS_STOCK_INFO,
+    },
+    syncer::{retry, AsyncFunc, Syncer},
+    types::HiqSyncData,
+    Error, Result,
+};
+
+use super::service::insert_many;
+
+struct OverseasStockDailyAsyncFunc<'a> {
+    fetch: Arc<dyn StockFetch>,
+    code: &'a str,
+    name: &'a str,
+    start: Option<NaiveDate>,
+    end: Option<NaiveDate>,
+    /// 已同步的最后一根k线，用于计算增量首根k线的成交量及成交额变化
+    pre: Option<&'a Bar>,
+}
+
+#[async_trait]
+impl<'a> AsyncFunc for OverseasStockDailyAsyncFunc<'a> {
+    async fn call(&self) -> Result<Option<HiqSyncData>> {
+        let data = self
+            .fetch
+            .fetch_stock_bar(
+                self.code,
+                Some(self.name),
+                Some(BarFreq::Daily),
+                self.start,
+                self.end,
+            )
+            .await?;
+        Ok(data.bars.map(|mut bars| {
+            if let (Some(pre), Some(first)) = (self.pre, bars.first_mut()) {
+                first.calc_chg_pct(pre);
+            }
+            HiqSyncData::OverseasStockBar(bars)
+        }))
+    }
+}
+
+/// 港股或美股日线，代码取自已同步的overseas_stock_info，需先同步基本信息，
+/// 后复权因子由上游后复权k线计算，增量同步时直接请求后续k线
+pub(crate) struct OverseasStockDailySyncer {
+    fetch: Arc<dyn StockFetch>,
+    client: Client,
+    market: MarketType,
+    task_n: usize,
+}
+
+impl OverseasStockDailySyncer {
+    pub fn new(
+        client: Client,
+        fetch: Arc<dyn StockFetch>,
+        market: MarketType,
+        task_n: usize,
+    ) -> Self {
+        Self {
+            client,
+            fetch,
+            market,
+            task_n,
+        }
+    }
+
+    /// 可同步的最后日期(交易所当地日期)，未收盘的不同步当日
+    fn sync_end(&self) -> NaiveDate {
+        let exchange = match self.market {
+            MarketType::UsStock => Exchange::US,
+            _ => Exchange::HK,
+        };
+        let now = exchange.local_time(Local::now().timestamp_millis());
+        if market_clock(exchange).is_settled(&now) {
+            now.date()
+        } else {
+            now.date() - Duration::days(1)
+        }
+    }
+}
+
+#[async_trait]
+impl Syncer for OverseasStockDailySyncer {
+    async fn fetch(&self, tx: mpsc::UnboundedSender<HiqSyncData>) -> Result<()> {
+        let prefix = match self.market {
+            MarketType::UsStock => "us",
+            _ => "hk",
+        };
+        let codes: Vec<StockInfo> = query(
+            self.client.clone(),
+            TAB_OVERSEAS_STOCK_INFO,
+            doc! {"code": {"$regex": format!("^{}", prefix)}},
+            None,
+        )
+        .await?;
+        if codes.is_empty() {
+            log::info!(
+                "{} is empty, sync overseas_stock_info first, task#{}",
+                TAB_OVERSEAS_STOCK_INFO,
+                self.task_n
+            );
+        }
+        let end = self.sync_end();
+        for info in codes.iter() {
+            let bar: Option<Bar> = query_one(
+                self.client.clone(),
+                TAB_OVERSEAS_STOCK_DAILY,
+                doc! {"code": info.code.as_str()},
+                FindOptions::builder()
+                    .sort(doc! {"trade_date": -1})
+                    .limit(1)
+                    .build(),
+            )
+            .await?;
+            let start = bar
+                .as_ref()
+                .map(|b| b.trade_date.date() + Duration::days(1))
+                .unwrap_or(NaiveDate::parse_from_str(DATA_DEF_START_DATE, "%Y-%m-%d").unwrap());
+            if start > end {
+                log::info!(
+                    "{}({}) {} is the newest, task#{}",
+                    info.name.as_str(),
+                    info.code.as_str(),
+                    TAB_OVERSEAS_STOCK_DAILY,
+                    self.task_n
+                );
+                continue;
+            }
+
+            log::info!(
+                "start fetch {}({}) {}, start={}, end={}, task#{}",
+                info.name.as_str(),
+                info.code.as_str(),
+                TAB_OVERSEAS_STOCK_DAILY,
+                &start,
+                &end,
+                self.task_n
+            );
+            let func = OverseasStockDailyAsyncFunc {
+                fetch: self.fetch.clone(),
+                code: info.code.as_str(),
+                name: info.name.as_str(),
+                start: Some(start),
+                end: Some(end),
+                pre: bar.as_ref(),
+            };
+            let data = retry(func).await?;
+            if let Some(data) = data {
+                tx.send(data).map_err(|e| {
+                    log::error!("send data error {:?}", e);
+                    Error::Custom(format!("send data error {:?}", e))
+                })?;
+            };
+            log::info!(
+                "end fetch {}({}) {}, task#{}",
+                info.name.as_str(),
+                info.code.as_str(),
+                TAB_OVERSEAS_STOCK_DAILY,
+                self.task_n
+            );
+        }
+
+        Ok(())
+    }
+
+    async fn save(&self, data: HiqSyncData) -> Result<()> {
+        if let HiqSyncData::OverseasStockBar(info) = data {
+            let bar = info.first().unwrap();
+            let len = info.len();
+            log::info!(
+                "start save {}({}) {}, size={}, task#{}",
+                bar.name.as_str(),
+                bar.code.as_str(),
+                TAB_OVERSEAS_STOCK_DAILY,
+                len,
+                self.task_n
+            );
+            insert_many(self.client.clone(), TAB_OVERSEAS_STOCK_DAILY, &info, false).await?;
+            log::info!(
+                "done save {}({}) {}, size={}, task#{}",
+                bar.name.as_str(),
+                bar.code.as_str(),
+                TAB_OVERSEAS_STOCK_DAILY,
+                len,
+                self.task_n
+            );
+        }
+        Ok(())
+    }
+}
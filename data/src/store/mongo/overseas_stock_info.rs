@@ -0,0 +1,76 @@
+use async_trait::async_trait;
+use hiq_fetch::{MarketType, StockFetch};
+use mongodb::Client;
+use std::sync::Arc;
+use tokio::sync::mpsc;
+
+use crate::{
+    store::TAB_OVERSEAS_STOCK_INFO,
+    syncer::{retry, AsyncFunc, Syncer},
+    types::HiqSyncData,
+    Error, Result,
+};
+
+use super::service::insert_many;
+
+struct OverseasStockInfoAsyncFunc {
+    fetch: Arc<dyn StockFetch>,
+}
+
+#[async_trait]
+impl AsyncFunc for OverseasStockInfoAsyncFunc {
+    async fn call(&self) -> Result<Option<HiqSyncData>> {
+        let mut data = self
+            .fetch
+            .fetch_overseas_stock_info(MarketType::HkStock)
+            .await?;
+        data.extend(
+            self.fetch
+                .fetch_overseas_stock_info(MarketType::UsStock)
+                .await?,
+        );
+        if data.is_empty() {
+            Ok(None)
+        } else {
+            Ok(Some(HiqSyncData::OverseasStockInfo(data)))
+        }
+    }
+}
+
+/// 港股、美股基本信息，全量替换
+pub(crate) struct OverseasStockInfoSyncer {
+    fetch: Arc<dyn StockFetch>,
+    client: Client,
+}
+
+impl OverseasStockInfoSyncer {
+    pub fn new(client: Client, fetch: Arc<dyn StockFetch>) -> Self {
+        Self { client, fetch }
+    }
+}
+
+#[async_trait]
+impl Syncer for OverseasStockInfoSyncer {
+    async fn fetch(&self, tx: mpsc::UnboundedSender<HiqSyncData>) -> Result<()> {
+        let func = OverseasStockInfoAsyncFunc {
+            fetch: self.fetch.clone(),
+        };
+        let data = retry(func).await?;
+        if let Some(data) = data {
+            tx.send(data).map_err(|e| {
+                log::error!("send data error {:?}", e);
+                Error::Custom(format!("send data error {:?}", e))
+            })?;
+        }
+        Ok(())
+    }
+    async fn save(&self, data: HiqSyncData) -> Result<()> {
+        if let HiqSyncData::OverseasStockInfo(info) = data {
+            let len = info.len();
+            log::info!("start save {}, size={}", TAB_OVERSEAS_STOCK_INFO, len);
+            insert_many(self.client.clone(), TAB_OVERSEAS_STOCK_INFO, &info, true).await?;
+            log::info!("done save {}, size={}", TAB_OVERSEAS_STOCK_INFO, len);
+        }
+        Ok(())
+    }
+}
@@ -1,7 +1,7 @@
 use futures::TryStreamExt;
 use mongodb::{
-    bson::{doc, Document},
-    options::FindOptions,
+    bson::{doc, Bson, Document},
+    options::{FindOptions, ReplaceOptions},
     Client,
 };
 use serde::de::DeserializeOwned;
@@ -56,6 +56,33 @@ where
     Ok(())
 }
 
+/// 按`key`生成的过滤条件逐条替换，不存在则插入
+pub(crate) async fn replace_many<T, F>(
+    client: Client,
+    collection: &str,
+    info: &[T],
+    key: F,
+) -> Result<()>
+where
+    T: serde::ser::Serialize,
+    F: Fn(&T) -> Result<Document>,
+{
+    let db = client.database(DATABASE);
+    let coll = db.collection::<T>(collection);
+
+    log::info!("replace into {}, {} items", collection, info.len());
+    let opts = ReplaceOptions::builder().upsert(true).build();
+    for item in info.iter() {
+        coll.replace_one(key(item)?, item, opts.clone())
+            .await
+            .map_err(|e| {
+                log::error!("replace collection {} failed: {}", collection, e);
+                Error::Custom(format!("replace collection {} failed: {}", collection, e))
+            })?;
+    }
+    Ok(())
+}
+
 pub async fn query<T>(
     client: Client,
     collection: &str,
@@ -105,3 +132,32 @@ where
     })?;
     Ok(data)
 }
+
+/// 字段去重后的取值
+pub(crate) async fn distinct(
+    client: Client,
+    collection: &str,
+    field: &str,
+    filter: impl Into<Option<Document>>,
+) -> Result<Vec<Bson>> {
+    let db = client.database(DATABASE);
+    let coll = db.collection::<Document>(collection);
+
+    coll.distinct(field, filter, None).await.map_err(|e| {
+        log::error!("distinct {}.{} error: {}", collection, field, e);
+        Error::Custom(format!("distinct {}.{} error: {}", collection, field, e))
+    })
+}
+
+/// 按条件删除，返回删除的条数
+pub(crate) async fn delete_many(client: Client, collection: &str, filter: Document) -> Result<u64> {
+    let db = client.database(DATABASE);
+    let coll = db.collection::<Document>(collection);
+
+    let res = coll.delete_many(filter, None).await.map_err(|e| {
+        log::error!("delete {} error: {}", collection, e);
+        Error::Custom(format!("delete {} error: {}", collection, e))
+    })?;
+    log::info!("delete {}, {} items", collection, res.deleted_count);
+    Ok(res.deleted_count)
+}
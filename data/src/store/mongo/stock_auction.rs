@@ -0,0 +1,182 @@
+use std::sync::{Arc, RwLock};
+
+use async_trait::async_trait;
+use chrono::{Local, NaiveDate};
+use hiq_fetch::{Exchange, SessionPhase, StockFetch};
+use mongodb::{
+    bson::{doc, to_bson},
+    options::FindOptions,
+    Client,
+};
+use tokio::sync::mpsc;
+
+use crate::{
+    store::{mongo::service::query_one, HiqCache, TAB_STOCK_AUCTION},
+    syncer::{retry, AsyncFunc, Syncer},
+    types::HiqSyncData,
+    Error, Result,
+};
+
+use super::service::insert_many;
+
+struct StockAuctionAsyncFunc<'a> {
+    fetch: Arc<dyn StockFetch>,
+    code: &'a str,
+    date: NaiveDate,
+}
+
+#[async_trait]
+impl<'a> AsyncFunc for StockAuctionAsyncFunc<'a> {
+    async fn call(&self) -> Result<Option<HiqSyncData>> {
+        let data = self.fetch.fetch_stock_auction(self.code).await?;
+        // 上游为最新交易日的数据，与待同步的交易日不一致时不保存
+        let data: Vec<_> = data
+            .into_iter()
+            .filter(|item| item.trade_date.date() == self.date)
+            .collect();
+        if data.is_empty() {
+            Ok(None)
+        } else {
+            Ok(Some(HiqSyncData::StockAuction(data)))
+        }
+    }
+}
+
+/// 集合竞价，上游只提供最新交易日的数据，
+/// 集合竞价结束后同步当日，开盘前同步上一交易日，集合竞价中不同步
+pub(crate) struct StockAuctionSyncer {
+    fetch: Arc<dyn StockFetch>,
+    cache: Arc<RwLock<HiqCache>>,
+    client: Client,
+    /// (代码, 简称)
+    codes: Vec<(String, String)>,
+    task_n: usize,
+}
+
+impl StockAuctionSyncer {
+    pub fn new(
+        client: Client,
+        fetch: Arc<dyn StockFetch>,
+        cache: Arc<RwLock<HiqCache>>,
+        codes: Vec<(String, String)>,
+        task_n: usize,
+    ) -> Self {
+        Self {
+            client,
+            fetch,
+            cache,
+            codes,
+            task_n,
+        }
+    }
+
+    /// 可同步的交易日，集合竞价中返回None
+    fn sync_date(&self) -> Option<NaiveDate> {
+        let now = Local::now().naive_local();
+        let clock = hiq_fetch::market_clock(Exchange::SH);
+        match clock.schedule(&now.date()) {
+            Some(schedule) if now.time() >= schedule.open() => {
+                (clock.phase(&now) != SessionPhase::OpeningAuction).then(|| now.date())
+            }
+            _ => {
+                let cache = self.cache.read().unwrap();
+                cache
+                    .trade_date()
+                    .as_ref()
+                    .and_then(|calendar| calendar.prev(&now.date()))
+            }
+        }
+    }
+}
+
+#[async_trait]
+impl Syncer for StockAuctionSyncer {
+    async fn fetch(&self, tx: mpsc::UnboundedSender<HiqSyncData>) -> Result<()> {
+        let date = match self.sync_date() {
+            Some(date) => date,
+            None => {
+                log::info!(
+                    "{} is in auction, skip sync, task#{}",
+                    TAB_STOCK_AUCTION,
+                    self.task_n
+                );
+                return Ok(());
+            }
+        };
+        let trade_date = to_bson(&date.and_hms_opt(0, 0, 0).unwrap())
+            .map_err(|e| Error::Custom(format!("to_bson error: {}", e)))?;
+        for (code, name) in self.codes.iter() {
+            let auction: Option<hiq_fetch::AuctionSnapshot> = query_one(
+                self.client.clone(),
+                TAB_STOCK_AUCTION,
+                doc! {"code": code.as_str(), "trade_date": trade_date.clone()},
+                FindOptions::builder().limit(1).build(),
+            )
+            .await?;
+            if auction.is_some() {
+                log::info!(
+                    "{}({}) {} is the newest, task#{}",
+                    name.as_str(),
+                    code.as_str(),
+                    TAB_STOCK_AUCTION,
+                    self.task_n
+                );
+                continue;
+            }
+
+            log::info!(
+                "start fetch {}({}) {}, date={}, task#{}",
+                name.as_str(),
+                code.as_str(),
+                TAB_STOCK_AUCTION,
+                &date,
+                self.task_n
+            );
+            let func = StockAuctionAsyncFunc {
+                fetch: self.fetch.clone(),
+                code: code.as_str(),
+                date,
+            };
+            let data = retry(func).await?;
+            if let Some(data) = data {
+                tx.send(data).map_err(|e| {
+                    log::error!("send data error {:?}", e);
+                    Error::Custom(format!("send data error {:?}", e))
+                })?;
+            };
+            log::info!(
+                "end fetch {}({}) {}, date={}, task#{}",
+                name.as_str(),
+                code.as_str(),
+                TAB_STOCK_AUCTION,
+                &date,
+                self.task_n
+            );
+        }
+
+        Ok(())
+    }
+
+    async fn save(&self, data: HiqSyncData) -> Result<()> {
+        if let HiqSyncData::StockAuction(info) = data {
+            let auction = info.first().unwrap();
+            let len = info.len();
+            log::info!(
+                "start save {} {}, size={}, task#{}",
+                auction.code.as_str(),
+                TAB_STOCK_AUCTION,
+                len,
+                self.task_n
+            );
+            insert_many(self.client.clone(), TAB_STOCK_AUCTION, &info, false).await?;
+            log::info!(
+                "done save {} {}, size={}, task#{}",
+                auction.code.as_str(),
+                TAB_STOCK_AUCTION,
+                len,
+                self.task_n
+            );
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,137 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use chrono::{Local, NaiveDate};
+use hiq_fetch::{StockCorpAction, StockFetch};
+use mongodb::{
+    bson::{doc, to_bson, Document},
+    options::FindOptions,
+    Client,
+};
+use tokio::sync::mpsc;
+
+use crate::{
+    store::{
+        mongo::service::{query_one, replace_many},
+        TAB_STOCK_CORP_ACTION,
+    },
+    syncer::{retry, AsyncFunc, Syncer},
+    types::HiqSyncData,
+    Error, Result,
+};
+
+struct StockCorpActionAsyncFunc {
+    fetch: Arc<dyn StockFetch>,
+    start: Option<NaiveDate>,
+    end: Option<NaiveDate>,
+}
+
+#[async_trait]
+impl AsyncFunc for StockCorpActionAsyncFunc {
+    async fn call(&self) -> Result<Option<HiqSyncData>> {
+        let data = self
+            .fetch
+            .fetch_stock_corp_action(None, self.start, self.end)
+            .await?;
+        if data.is_empty() {
+            Ok(None)
+        } else {
+            Ok(Some(HiqSyncData::StockCorpAction(data)))
+        }
+    }
+}
+
+/// 除权除息事件的唯一标识: 代码 + 除权除息日 + 各项数值，同一除权日可有多条
+fn corp_action_key(action: &StockCorpAction) -> Result<Document> {
+    let ex_date =
+        to_bson(&action.ex_date).map_err(|e| Error::Custom(format!("to_bson error: {}", e)))?;
+    Ok(doc! {
+        "code": &action.code,
+        "ex_date": ex_date,
+        "cash": action.cash,
+        "bonus": action.bonus,
+        "transfer": action.transfer,
+        "rights": action.rights,
+        "rights_price": action.rights_price,
+    })
+}
+
+/// 全市场除权除息事件，按除权除息日增量同步，只同步已除权的
+///
+/// 从已同步的最后除权除息日(含)开始，同一除权日之后发布的也能同步，已有的更新
+pub(crate) struct StockCorpActionSyncer {
+    fetch: Arc<dyn StockFetch>,
+    client: Client,
+}
+
+impl StockCorpActionSyncer {
+    pub fn new(client: Client, fetch: Arc<dyn StockFetch>) -> Self {
+        Self { client, fetch }
+    }
+}
+
+#[async_trait]
+impl Syncer for StockCorpActionSyncer {
+    async fn fetch(&self, tx: mpsc::UnboundedSender<HiqSyncData>) -> Result<()> {
+        let action: Option<hiq_fetch::StockCorpAction> = query_one(
+            self.client.clone(),
+            TAB_STOCK_CORP_ACTION,
+            doc! {},
+            FindOptions::builder()
+                .sort(doc! {"ex_date": -1})
+                .limit(1)
+                .build(),
+        )
+        .await?;
+
+        let start = action.map(|a| a.ex_date.date());
+        let end = Local::now().date_naive();
+        if start.is_some_and(|s| s > end) {
+            log::info!("{} is the newest", TAB_STOCK_CORP_ACTION);
+            return Ok(());
+        }
+
+        log::info!(
+            "start sync {}, start={:?}, end={:?}",
+            TAB_STOCK_CORP_ACTION,
+            &start,
+            &end
+        );
+        let func = StockCorpActionAsyncFunc {
+            fetch: self.fetch.clone(),
+            start,
+            end: Some(end),
+        };
+        let data = retry(func).await?;
+        if let Some(data) = data {
+            tx.send(data).map_err(|e| {
+                log::error!("send data error {:?}", e);
+                Error::Custom(format!("send data error {:?}", e))
+            })?;
+        }
+        log::info!(
+            "end fetch {}, start={:?}, end={:?}",
+            TAB_STOCK_CORP_ACTION,
+            &start,
+            &end
+        );
+
+        Ok(())
+    }
+
+    async fn save(&self, data: HiqSyncData) -> Result<()> {
+        if let HiqSyncData::StockCorpAction(info) = data {
+            let len = info.len();
+            log::info!("start save {}, size={}", TAB_STOCK_CORP_ACTION, len);
+            replace_many(
+                self.client.clone(),
+                TAB_STOCK_CORP_ACTION,
+                &info,
+                corp_action_key,
+            )
+            .await?;
+            log::info!("done save {}, size={}", TAB_STOCK_CORP_ACTION, len);
+        }
+        Ok(())
+    }
+}
@@ -2,12 +2,22 @@ use std::sync::{Arc, RwLock};
 
 use async_trait::async_trait;
 use chrono::NaiveDate;
-use hiq_fetch::{BarFreq, StockFetch, StockInfo};
-use mongodb::{bson::doc, options::FindOptions, Client};
+use hiq_fetch::{
+    AdjustCalculator, Bar, BarFreq, StockCorpAction, StockFetch, StockInfo, StockStatus,
+    StockStatusType,
+};
+use mongodb::{
+    bson::{doc, to_bson},
+    options::FindOptions,
+    Client,
+};
 use tokio::sync::mpsc;
 
 use crate::{
-    store::{mongo::service::query_one, HiqCache, DATA_DEF_START_DATE, TAB_STOCK_DAILY},
+    store::{
+        mongo::service::{query, query_one},
+        HiqCache, DATA_DEF_START_DATE, TAB_STOCK_DAILY, TAB_STOCK_STATUS,
+    },
     syncer::{need_to_start, retry, AsyncFunc, Syncer},
     types::HiqSyncData,
     Error, Result,
@@ -22,15 +32,22 @@ struct StockDailyAsyncFunc<'a> {
     freq: Option<BarFreq>,
     start: Option<NaiveDate>,
     end: Option<NaiveDate>,
+    /// 已同步的最后一根k线，在其基础上计算复权因子，没有则从第一根k线开始计算
+    base: Option<&'a Bar>,
+    /// 缓存的除权除息事件
+    actions: &'a [StockCorpAction],
 }
 
 #[async_trait]
 impl<'a> AsyncFunc for StockDailyAsyncFunc<'a> {
     async fn call(&self) -> Result<Option<HiqSyncData>> {
-        let data = self
+        let mut data = self
             .fetch
-            .fetch_stock_bar(self.code, Some(self.name), self.freq, self.start, self.end)
+            .fetch_stock_nfq_bar(self.code, Some(self.name), self.freq, self.start, self.end)
             .await?;
+        if let Some(bars) = data.bars.as_mut() {
+            AdjustCalculator::new(self.actions.to_vec()).apply_hfq(bars, self.base);
+        }
         let bar = data.bars;
         if bar.is_none() {
             Ok(None)
@@ -40,11 +57,17 @@ impl<'a> AsyncFunc for StockDailyAsyncFunc<'a> {
     }
 }
 
+/// 股票日线(后复权因子)。
+///
+/// 代码取自当前股票列表，已退市的不在其中，另由`new_delisted`创建的任务按已同步的退市记录
+/// 补齐退市前的日线，退市记录需先由股票状态同步，首次同步时退市股票要到下次同步才有日线。
 pub(crate) struct StockDailySyncer {
     fetch: Arc<dyn StockFetch>,
     cache: Arc<RwLock<HiqCache>>,
     client: Client,
     codes: Vec<StockInfo>,
+    /// 是否为退市股票任务
+    delisted: bool,
     task_n: usize,
 }
 
@@ -61,26 +84,76 @@ impl StockDailySyncer {
             cache,
             fetch,
             codes,
+            delisted: false,
+            task_n,
+        }
+    }
+    /// 已退市股票的日线任务
+    pub fn new_delisted(
+        client: Client,
+        fetch: Arc<dyn StockFetch>,
+        cache: Arc<RwLock<HiqCache>>,
+        task_n: usize,
+    ) -> Self {
+        Self {
+            client,
+            cache,
+            fetch,
+            codes: Vec::new(),
+            delisted: true,
             task_n,
         }
     }
+    /// 待同步的代码、名称及结束日期(退市日期)
+    async fn targets(&self) -> Result<Vec<(String, String, Option<NaiveDate>)>> {
+        if !self.delisted {
+            return Ok(self
+                .codes
+                .iter()
+                .map(|info| (info.code.clone(), info.name.clone(), None))
+                .collect());
+        }
+        let status = to_bson(&StockStatusType::Delist)
+            .map_err(|e| Error::Custom(format!("to_bson error: {}", e)))?;
+        let delist: Vec<StockStatus> = query(
+            self.client.clone(),
+            TAB_STOCK_STATUS,
+            doc! {"status": status},
+            FindOptions::builder().sort(doc! {"code": 1}).build(),
+        )
+        .await?;
+        let def_start = NaiveDate::parse_from_str(DATA_DEF_START_DATE, "%Y-%m-%d").unwrap();
+        let cache = self.cache.read().unwrap();
+        Ok(delist
+            .into_iter()
+            .filter(|status| {
+                // 重新上市的仍在股票列表中
+                !cache
+                    .stock_info()
+                    .as_ref()
+                    .is_some_and(|info| info.contains_key(&status.code))
+                    && status.start_date.date() > def_start
+            })
+            .map(|status| (status.code, status.name, Some(status.start_date.date())))
+            .collect())
+    }
 }
 
 #[async_trait]
 impl Syncer for StockDailySyncer {
     async fn fetch(&self, tx: mpsc::UnboundedSender<HiqSyncData>) -> Result<()> {
-        for info in self.codes.iter() {
+        for (code, name, end) in self.targets().await?.iter() {
             log::info!(
                 "prepare sync {}({}) {}, task#{}",
-                info.name.as_str(),
-                info.code.as_str(),
+                name.as_str(),
+                code.as_str(),
                 TAB_STOCK_DAILY,
                 self.task_n
             );
             let bar: Option<hiq_fetch::Bar> = query_one(
                 self.client.clone(),
                 TAB_STOCK_DAILY,
-                doc! {"code": info.code.as_str()},
+                doc! {"code": code.as_str()},
                 FindOptions::builder()
                     .sort(doc! {"trade_date": -1})
                     .limit(1)
@@ -88,6 +161,7 @@ impl Syncer for StockDailySyncer {
             )
             .await?;
             let start = bar
+                .as_ref()
                 .map(|b| {
                     let cache = self.cache.read().unwrap();
                     Some(cache.next_trade_date(&(b.trade_date.date())))
@@ -95,11 +169,13 @@ impl Syncer for StockDailySyncer {
                 .unwrap_or(Some(
                     NaiveDate::parse_from_str(DATA_DEF_START_DATE, "%Y-%m-%d").unwrap(),
                 ));
-            if !need_to_start(&start) {
+            // 退市股票同步至退市日期为止
+            let delisted = matches!((start, end), (Some(start), Some(end)) if start >= *end);
+            if delisted || !need_to_start(&start) {
                 log::info!(
                     "{}({}) {} is the newest, task#{}",
-                    info.name.as_str(),
-                    info.code.as_str(),
+                    name.as_str(),
+                    code.as_str(),
                     TAB_STOCK_DAILY,
                     self.task_n
                 );
@@ -107,20 +183,32 @@ impl Syncer for StockDailySyncer {
             }
 
             log::info!(
-                "start fetch {}({}) {}, start={:?}, end=None, task#{}",
-                info.name.as_str(),
-                info.code.as_str(),
+                "start fetch {}({}) {}, start={:?}, end={:?}, task#{}",
+                name.as_str(),
+                code.as_str(),
                 TAB_STOCK_DAILY,
                 &start,
+                end,
                 self.task_n
             );
+            let actions = {
+                let cache = self.cache.read().unwrap();
+                cache
+                    .corp_action()
+                    .as_ref()
+                    .and_then(|actions| actions.get(code))
+                    .cloned()
+                    .unwrap_or_default()
+            };
             let func = StockDailyAsyncFunc {
                 fetch: self.fetch.clone(),
-                code: info.code.as_str(),
-                name: info.name.as_str(),
+                code: code.as_str(),
+                name: name.as_str(),
                 freq: Some(BarFreq::Daily),
                 start,
-                end: None,
+                end: *end,
+                base: bar.as_ref(),
+                actions: &actions,
             };
             let data = retry(func).await?;
             if let Some(data) = data {
@@ -130,11 +218,12 @@ impl Syncer for StockDailySyncer {
                 })?;
             };
             log::info!(
-                "end fetch {}({}) {}, start={:?}, end=None, task#{}",
-                info.name.as_str(),
-                info.code.as_str(),
+                "end fetch {}({}) {}, start={:?}, end={:?}, task#{}",
+                name.as_str(),
+                code.as_str(),
                 TAB_STOCK_DAILY,
                 &start,
+                end,
                 self.task_n
             );
         }
@@ -0,0 +1,115 @@
+use std::sync::{Arc, RwLock};
+
+use async_trait::async_trait;
+use chrono::{Local, NaiveDate};
+use hiq_fetch::StockFetch;
+use mongodb::{bson::doc, options::FindOptions, Client};
+use tokio::sync::mpsc;
+
+use crate::{
+    store::{
+        mongo::service::{insert_many, query_one},
+        HiqCache, DATA_DEF_START_DATE, TAB_STOCK_DRAGON_TIGER,
+    },
+    syncer::{need_to_start, retry, AsyncFunc, Syncer},
+    types::HiqSyncData,
+    Error, Result,
+};
+
+struct StockDragonTigerAsyncFunc {
+    fetch: Arc<dyn StockFetch>,
+    date: NaiveDate,
+}
+
+#[async_trait]
+impl AsyncFunc for StockDragonTigerAsyncFunc {
+    async fn call(&self) -> Result<Option<HiqSyncData>> {
+        let data = self.fetch.fetch_stock_dragon_tiger(self.date).await?;
+        if data.is_empty() {
+            Ok(None)
+        } else {
+            Ok(Some(HiqSyncData::StockDragonTiger(data)))
+        }
+    }
+}
+
+/// 龙虎榜，从已保存的最近交易日的下一交易日开始，按交易日增量同步全市场数据
+pub(crate) struct StockDragonTigerSyncer {
+    fetch: Arc<dyn StockFetch>,
+    cache: Arc<RwLock<HiqCache>>,
+    client: Client,
+}
+
+impl StockDragonTigerSyncer {
+    pub fn new(client: Client, fetch: Arc<dyn StockFetch>, cache: Arc<RwLock<HiqCache>>) -> Self {
+        Self {
+            client,
+            fetch,
+            cache,
+        }
+    }
+}
+
+#[async_trait]
+impl Syncer for StockDragonTigerSyncer {
+    async fn fetch(&self, tx: mpsc::UnboundedSender<HiqSyncData>) -> Result<()> {
+        let last: Option<hiq_fetch::StockDragonTiger> = query_one(
+            self.client.clone(),
+            TAB_STOCK_DRAGON_TIGER,
+            doc! {},
+            FindOptions::builder()
+                .sort(doc! {"trade_date": -1})
+                .limit(1)
+                .build(),
+        )
+        .await?;
+        let dates: Vec<_> = {
+            let cache = self.cache.read().unwrap();
+            let start = match last {
+                Some(last) => cache.next_trade_date(&last.trade_date.date()),
+                None => NaiveDate::parse_from_str(DATA_DEF_START_DATE, "%Y-%m-%d").unwrap(),
+            };
+            let end = Local::now().date_naive();
+            cache
+                .trade_date()
+                .as_ref()
+                .map(|cal| cal.range(&start, &end).collect())
+                .unwrap_or_default()
+        };
+        for date in dates.into_iter() {
+            if !need_to_start(&Some(date)) {
+                log::info!("{} is the newest", TAB_STOCK_DRAGON_TIGER);
+                break;
+            }
+            log::info!("start sync {}, trade_date={}", TAB_STOCK_DRAGON_TIGER, date);
+            let func = StockDragonTigerAsyncFunc {
+                fetch: self.fetch.clone(),
+                date,
+            };
+            if let Some(data) = retry(func).await? {
+                tx.send(data).map_err(|e| {
+                    log::error!("send data error {:?}", e);
+                    Error::Custom(format!("send data error {:?}", e))
+                })?;
+            }
+            log::info!("end fetch {}, trade_date={}", TAB_STOCK_DRAGON_TIGER, date);
+        }
+        Ok(())
+    }
+
+    async fn save(&self, data: HiqSyncData) -> Result<()> {
+        if let HiqSyncData::StockDragonTiger(info) = data {
+            let elm = info.first().unwrap();
+            let len = info.len();
+            log::info!(
+                "start save {}, trade_date={}, size={}",
+                TAB_STOCK_DRAGON_TIGER,
+                elm.trade_date,
+                len
+            );
+            insert_many(self.client.clone(), TAB_STOCK_DRAGON_TIGER, &info, false).await?;
+            log::info!("done save {}, size={}", TAB_STOCK_DRAGON_TIGER, len);
+        }
+        Ok(())
+    }
+}
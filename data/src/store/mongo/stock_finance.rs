@@ -0,0 +1,214 @@
+use std::{collections::HashSet, sync::Arc};
+
+use async_trait::async_trait;
+use chrono::{Datelike, Local, NaiveDate, NaiveDateTime};
+use hiq_fetch::StockFetch;
+use mongodb::{
+    bson::{doc, Document},
+    options::FindOptions,
+    Client,
+};
+use serde::{Deserialize, Serialize};
+use tokio::sync::mpsc;
+
+use crate::{
+    store::{
+        mongo::service::{insert_many, query, query_one},
+        DATA_DEF_START_DATE, TAB_STOCK_BALANCE_SHEET, TAB_STOCK_CASH_FLOW, TAB_STOCK_INCOME,
+    },
+    syncer::{retry, AsyncFunc, Syncer},
+    types::{HiqSyncData, HiqSyncDataType},
+    Error, Result,
+};
+
+/// 已保存记录的代码及报告期
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct ReportKey {
+    code: String,
+    report_date: NaiveDateTime,
+}
+
+/// 待同步的报告期，从已保存的最近一期往前再回溯两期(迟于法定期限公告的股票)开始到今年年报，
+/// 已保存的股票在保存前过滤
+pub(crate) async fn report_seasons(client: Client, tab: &str) -> Result<Vec<(u16, u16)>> {
+    let last: Option<ReportKey> = query_one(
+        client,
+        tab,
+        doc! {},
+        FindOptions::builder()
+            .sort(doc! {"report_date": -1})
+            .limit(1)
+            .build(),
+    )
+    .await?;
+    let (year, season) = match last {
+        Some(last) => {
+            let (year, season) = (
+                last.report_date.year() as u16,
+                (last.report_date.month() as u16).div_ceil(3),
+            );
+            if season > 2 {
+                (year, season - 2)
+            } else {
+                (year - 1, season + 2)
+            }
+        }
+        None => {
+            let start = NaiveDate::parse_from_str(DATA_DEF_START_DATE, "%Y-%m-%d")
+                .map_err(|e| Error::Custom(format!("parse date error: {}", e)))?;
+            (start.year() as u16, 1)
+        }
+    };
+    let n_year = Local::now().naive_local().year() as u16;
+    let mut s_vec = Vec::new();
+    for y in year..=n_year {
+        for s in 1..=4 {
+            if y == year && s < season {
+                continue;
+            }
+            s_vec.push((y, s));
+        }
+    }
+    Ok(s_vec)
+}
+
+/// 该报告期已保存的股票代码，`filter`为附加条件
+pub(crate) async fn report_saved_codes(
+    client: Client,
+    tab: &str,
+    report_date: &NaiveDateTime,
+    mut filter: Document,
+) -> Result<HashSet<String>> {
+    let value = serde_json::to_value(report_date)
+        .map_err(|e| Error::Custom(format!("to_value error: {}", e)))?;
+    filter.insert("report_date", value.as_str());
+    let db_data: Vec<ReportKey> = query(client, tab, filter, None).await?;
+    Ok(db_data.into_iter().map(|e| e.code).collect())
+}
+
+struct StockFinanceAsyncFunc {
+    fetch: Arc<dyn StockFetch>,
+    typ: HiqSyncDataType,
+    year: u16,
+    season: u16,
+}
+
+#[async_trait]
+impl AsyncFunc for StockFinanceAsyncFunc {
+    async fn call(&self) -> Result<Option<HiqSyncData>> {
+        let (year, season) = (self.year, self.season);
+        let data = match self.typ {
+            HiqSyncDataType::StockBalanceSheet => {
+                let data = self.fetch.fetch_stock_balance_sheet(year, season).await?;
+                (!data.is_empty()).then_some(HiqSyncData::StockBalanceSheet(data))
+            }
+            HiqSyncDataType::StockIncome => {
+                let data = self.fetch.fetch_stock_income(year, season).await?;
+                (!data.is_empty()).then_some(HiqSyncData::StockIncome(data))
+            }
+            HiqSyncDataType::StockCashFlow => {
+                let data = self.fetch.fetch_stock_cash_flow(year, season).await?;
+                (!data.is_empty()).then_some(HiqSyncData::StockCashFlow(data))
+            }
+            _ => None,
+        };
+        Ok(data)
+    }
+}
+
+/// 财务报表(资产负债表、利润表、现金流量表)，按报告期增量同步。
+/// 同一报告期只保存新公告的股票，公告日期随记录保存，回测时按公告日期过滤
+pub(crate) struct StockFinanceSyncer {
+    fetch: Arc<dyn StockFetch>,
+    client: Client,
+    typ: HiqSyncDataType,
+}
+
+impl StockFinanceSyncer {
+    pub fn new(client: Client, fetch: Arc<dyn StockFetch>, typ: HiqSyncDataType) -> Self {
+        Self { client, fetch, typ }
+    }
+    fn tab(&self) -> &'static str {
+        match self.typ {
+            HiqSyncDataType::StockIncome => TAB_STOCK_INCOME,
+            HiqSyncDataType::StockCashFlow => TAB_STOCK_CASH_FLOW,
+            _ => TAB_STOCK_BALANCE_SHEET,
+        }
+    }
+    /// 过滤掉该报告期已保存的股票
+    async fn filter_saved(&self, data: HiqSyncData) -> Result<Option<HiqSyncData>> {
+        let report_date = match &data {
+            HiqSyncData::StockBalanceSheet(info) => info[0].report_date,
+            HiqSyncData::StockIncome(info) => info[0].report_date,
+            HiqSyncData::StockCashFlow(info) => info[0].report_date,
+            _ => return Ok(None),
+        };
+        let set =
+            report_saved_codes(self.client.clone(), self.tab(), &report_date, doc! {}).await?;
+
+        let data = match data {
+            HiqSyncData::StockBalanceSheet(mut info) => {
+                info.retain(|e| !set.contains(&e.code));
+                (!info.is_empty()).then_some(HiqSyncData::StockBalanceSheet(info))
+            }
+            HiqSyncData::StockIncome(mut info) => {
+                info.retain(|e| !set.contains(&e.code));
+                (!info.is_empty()).then_some(HiqSyncData::StockIncome(info))
+            }
+            HiqSyncData::StockCashFlow(mut info) => {
+                info.retain(|e| !set.contains(&e.code));
+                (!info.is_empty()).then_some(HiqSyncData::StockCashFlow(info))
+            }
+            _ => None,
+        };
+        Ok(data)
+    }
+}
+
+#[async_trait]
+impl Syncer for StockFinanceSyncer {
+    async fn fetch(&self, tx: mpsc::UnboundedSender<HiqSyncData>) -> Result<()> {
+        for (year, season) in report_seasons(self.client.clone(), self.tab())
+            .await?
+            .into_iter()
+        {
+            log::info!("start sync {} year={}, season={}", self.tab(), year, season);
+            let func = StockFinanceAsyncFunc {
+                fetch: self.fetch.clone(),
+                typ: self.typ.clone(),
+                year,
+                season,
+            };
+            if let Some(data) = retry(func).await? {
+                if let Some(data) = self.filter_saved(data).await? {
+                    tx.send(data).map_err(|e| {
+                        log::error!("send data error {:?}", e);
+                        Error::Custom(format!("send data error {:?}", e))
+                    })?;
+                }
+            }
+            log::info!("end fetch {} year={}, season={}", self.tab(), year, season);
+        }
+        Ok(())
+    }
+
+    async fn save(&self, data: HiqSyncData) -> Result<()> {
+        let len = match &data {
+            HiqSyncData::StockBalanceSheet(info) => {
+                insert_many(self.client.clone(), self.tab(), info, false).await?;
+                info.len()
+            }
+            HiqSyncData::StockIncome(info) => {
+                insert_many(self.client.clone(), self.tab(), info, false).await?;
+                info.len()
+            }
+            HiqSyncData::StockCashFlow(info) => {
+                insert_many(self.client.clone(), self.tab(), info, false).await?;
+                info.len()
+            }
+            _ => return Ok(()),
+        };
+        log::info!("done save {}, size={}", self.tab(), len);
+        Ok(())
+    }
+}
@@ -0,0 +1,207 @@
+use std::{collections::HashSet, sync::Arc};
+
+use async_trait::async_trait;
+use hiq_fetch::StockFetch;
+use mongodb::{bson::doc, Client};
+use tokio::sync::mpsc;
+
+use crate::{
+    store::{
+        mongo::{
+            service::insert_many,
+            stock_finance::{report_saved_codes, report_seasons},
+        },
+        TAB_STOCK_HOLDER_NUM, TAB_STOCK_TOP_HOLDER,
+    },
+    syncer::{retry, AsyncFunc, Syncer},
+    types::HiqSyncData,
+    Error, Result,
+};
+
+struct StockHolderNumAsyncFunc {
+    fetch: Arc<dyn StockFetch>,
+    year: u16,
+    season: u16,
+}
+
+#[async_trait]
+impl AsyncFunc for StockHolderNumAsyncFunc {
+    async fn call(&self) -> Result<Option<HiqSyncData>> {
+        let data = self
+            .fetch
+            .fetch_stock_holder_num(self.year, self.season)
+            .await?;
+        if data.is_empty() {
+            Ok(None)
+        } else {
+            Ok(Some(HiqSyncData::StockHolderNum(data)))
+        }
+    }
+}
+
+/// 股东户数，按报告期增量同步，同一截止日期只保存新公告的股票
+pub(crate) struct StockHolderNumSyncer {
+    fetch: Arc<dyn StockFetch>,
+    client: Client,
+}
+
+impl StockHolderNumSyncer {
+    pub fn new(client: Client, fetch: Arc<dyn StockFetch>) -> Self {
+        Self { client, fetch }
+    }
+}
+
+#[async_trait]
+impl Syncer for StockHolderNumSyncer {
+    async fn fetch(&self, tx: mpsc::UnboundedSender<HiqSyncData>) -> Result<()> {
+        for (year, season) in report_seasons(self.client.clone(), TAB_STOCK_HOLDER_NUM)
+            .await?
+            .into_iter()
+        {
+            log::info!(
+                "start sync {} year={}, season={}",
+                TAB_STOCK_HOLDER_NUM,
+                year,
+                season
+            );
+            let func = StockHolderNumAsyncFunc {
+                fetch: self.fetch.clone(),
+                year,
+                season,
+            };
+            if let Some(HiqSyncData::StockHolderNum(mut info)) = retry(func).await? {
+                // 截止日期不一定为季末，按(代码，截止日期)过滤已保存的记录
+                let dates: HashSet<_> = info.iter().map(|e| e.report_date).collect();
+                let mut saved = HashSet::new();
+                for date in dates.iter() {
+                    let set = report_saved_codes(
+                        self.client.clone(),
+                        TAB_STOCK_HOLDER_NUM,
+                        date,
+                        doc! {},
+                    )
+                    .await?;
+                    saved.extend(set.into_iter().map(|code| (code, *date)));
+                }
+                info.retain(|e| !saved.contains(&(e.code.clone(), e.report_date)));
+                if !info.is_empty() {
+                    tx.send(HiqSyncData::StockHolderNum(info)).map_err(|e| {
+                        log::error!("send data error {:?}", e);
+                        Error::Custom(format!("send data error {:?}", e))
+                    })?;
+                }
+            }
+            log::info!(
+                "end fetch {} year={}, season={}",
+                TAB_STOCK_HOLDER_NUM,
+                year,
+                season
+            );
+        }
+        Ok(())
+    }
+
+    async fn save(&self, data: HiqSyncData) -> Result<()> {
+        if let HiqSyncData::StockHolderNum(info) = data {
+            let len = info.len();
+            log::info!("start save {}, size={}", TAB_STOCK_HOLDER_NUM, len);
+            insert_many(self.client.clone(), TAB_STOCK_HOLDER_NUM, &info, false).await?;
+            log::info!("done save {}, size={}", TAB_STOCK_HOLDER_NUM, len);
+        }
+        Ok(())
+    }
+}
+
+struct StockTopHolderAsyncFunc {
+    fetch: Arc<dyn StockFetch>,
+    year: u16,
+    season: u16,
+    is_float: bool,
+}
+
+#[async_trait]
+impl AsyncFunc for StockTopHolderAsyncFunc {
+    async fn call(&self) -> Result<Option<HiqSyncData>> {
+        let data = self
+            .fetch
+            .fetch_stock_top_holders(self.year, self.season, self.is_float)
+            .await?;
+        if data.is_empty() {
+            Ok(None)
+        } else {
+            Ok(Some(HiqSyncData::StockTopHolder(data)))
+        }
+    }
+}
+
+/// 十大股东及十大流通股东，按报告期增量同步，同一报告期只保存新公告的股票
+pub(crate) struct StockTopHolderSyncer {
+    fetch: Arc<dyn StockFetch>,
+    client: Client,
+}
+
+impl StockTopHolderSyncer {
+    pub fn new(client: Client, fetch: Arc<dyn StockFetch>) -> Self {
+        Self { client, fetch }
+    }
+}
+
+#[async_trait]
+impl Syncer for StockTopHolderSyncer {
+    async fn fetch(&self, tx: mpsc::UnboundedSender<HiqSyncData>) -> Result<()> {
+        for (year, season) in report_seasons(self.client.clone(), TAB_STOCK_TOP_HOLDER)
+            .await?
+            .into_iter()
+        {
+            for is_float in [true, false] {
+                log::info!(
+                    "start sync {} year={}, season={}, is_float={}",
+                    TAB_STOCK_TOP_HOLDER,
+                    year,
+                    season,
+                    is_float
+                );
+                let func = StockTopHolderAsyncFunc {
+                    fetch: self.fetch.clone(),
+                    year,
+                    season,
+                    is_float,
+                };
+                if let Some(HiqSyncData::StockTopHolder(mut info)) = retry(func).await? {
+                    let set = report_saved_codes(
+                        self.client.clone(),
+                        TAB_STOCK_TOP_HOLDER,
+                        &info[0].report_date,
+                        doc! {"is_float": is_float},
+                    )
+                    .await?;
+                    info.retain(|e| !set.contains(&e.code));
+                    if !info.is_empty() {
+                        tx.send(HiqSyncData::StockTopHolder(info)).map_err(|e| {
+                            log::error!("send data error {:?}", e);
+                            Error::Custom(format!("send data error {:?}", e))
+                        })?;
+                    }
+                }
+                log::info!(
+                    "end fetch {} year={}, season={}, is_float={}",
+                    TAB_STOCK_TOP_HOLDER,
+                    year,
+                    season,
+                    is_float
+                );
+            }
+        }
+        Ok(())
+    }
+
+    async fn save(&self, data: HiqSyncData) -> Result<()> {
+        if let HiqSyncData::StockTopHolder(info) = data {
+            let len = info.len();
+            log::info!("start save {}, size={}", TAB_STOCK_TOP_HOLDER, len);
+            insert_many(self.client.clone(), TAB_STOCK_TOP_HOLDER, &info, false).await?;
+            log::info!("done save {}, size={}", TAB_STOCK_TOP_HOLDER, len);
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,380 @@
+use std::{
+    collections::HashMap,
+    sync::{Arc, RwLock},
+};
+
+use async_trait::async_trait;
+use chrono::{Duration, Local, NaiveDate};
+use hiq_fetch::{Bar, Board, LimitPool, StockFetch, StockLimitPool, StockStatus, StockStatusType};
+use mongodb::{
+    bson::{doc, to_bson},
+    options::FindOptions,
+    Client,
+};
+use tokio::sync::mpsc;
+
+use crate::{
+    store::{
+        mongo::service::{insert_many, query, query_one},
+        HiqCache, DATA_DEF_START_DATE, TAB_STOCK_DAILY, TAB_STOCK_LIMIT_POOL, TAB_STOCK_STATUS,
+    },
+    syncer::{need_to_start, retry, AsyncFunc, Syncer},
+    types::HiqSyncData,
+    Error, Result,
+};
+
+/// 数据源只保留近期的股池，更早的交易日由日线推算
+const LIMIT_POOL_KEEP_DAYS: i32 = 30;
+
+/// 由前一根k线(可为停牌前)及当日k线推算涨跌停，`pre_limit`为前一交易日的股池及连板数，
+/// `st`为当日是否处于风险警示。
+/// 涨跌停价按当日的板块涨跌幅限制及除权后的昨收计算，不考虑新股上市初期；
+/// 无盘中数据，封板时间为None，开板次数、封单资金为0
+fn limit_pool_from_bar(
+    pre: &Bar,
+    bar: &Bar,
+    pre_limit: Option<(LimitPool, i32)>,
+    st: bool,
+) -> Option<StockLimitPool> {
+    if pre.close <= 0.0 || bar.close <= 0.0 {
+        return None;
+    }
+    let factor = if pre.hfq_factor > 0.0 && bar.hfq_factor > 0.0 {
+        pre.hfq_factor as f64 / bar.hfq_factor as f64
+    } else {
+        1.0
+    };
+    let pre_close = pre.close as f64 * factor;
+    let pct = Board::from_code(&bar.code).limit_pct_on(&bar.trade_date.date(), st) as f64 / 100.0;
+    let limit_up = (pre_close * (1.0 + pct) * 100.0).round() / 100.0;
+    let limit_down = (pre_close * (1.0 - pct) * 100.0).round() / 100.0;
+    const EPS: f64 = 0.005;
+    let pool = if bar.close as f64 >= limit_up - EPS {
+        LimitPool::LimitUp
+    } else if bar.close as f64 <= limit_down + EPS {
+        LimitPool::LimitDown
+    } else if bar.high as f64 >= limit_up - EPS {
+        LimitPool::Broken
+    } else {
+        return None;
+    };
+    let limit_days = match (pool, pre_limit) {
+        (LimitPool::Broken, _) => 0,
+        (pool, Some((pre_pool, days))) if pool == pre_pool => days + 1,
+        _ => 1,
+    };
+    Some(StockLimitPool {
+        code: bar.code.clone(),
+        name: bar.name.clone(),
+        trade_date: bar.trade_date,
+        pool,
+        close: bar.close,
+        chg_pct: bar.chg_pct,
+        amount: bar.amount,
+        turnover: bar.turnover,
+        first_limit_time: None,
+        last_limit_time: None,
+        limit_days,
+        open_times: 0,
+        sealed_amount: 0.0,
+        industry: String::new(),
+    })
+}
+
+struct StockLimitPoolAsyncFunc {
+    fetch: Arc<dyn StockFetch>,
+    date: NaiveDate,
+}
+
+#[async_trait]
+impl AsyncFunc for StockLimitPoolAsyncFunc {
+    async fn call(&self) -> Result<Option<HiqSyncData>> {
+        let mut data = Vec::new();
+        for pool in [LimitPool::LimitUp, LimitPool::LimitDown, LimitPool::Broken] {
+            data.extend(self.fetch.fetch_stock_limit_pool(self.date, pool).await?);
+        }
+        if data.is_empty() {
+            Ok(None)
+        } else {
+            Ok(Some(HiqSyncData::StockLimitPool(data)))
+        }
+    }
+}
+
+/// 涨停、跌停及炸板股池，按交易日增量同步，同一交易日的三个股池一起保存。
+/// 数据源保留范围之前(自DATA_DEF_START_DATE起)的股池由已同步的日线推算，
+/// 需先同步stock_daily及stock_status(风险警示区间)
+pub(crate) struct StockLimitPoolSyncer {
+    fetch: Arc<dyn StockFetch>,
+    cache: Arc<RwLock<HiqCache>>,
+    client: Client,
+}
+
+impl StockLimitPoolSyncer {
+    pub fn new(client: Client, fetch: Arc<dyn StockFetch>, cache: Arc<RwLock<HiqCache>>) -> Self {
+        Self {
+            client,
+            fetch,
+            cache,
+        }
+    }
+}
+
+impl StockLimitPoolSyncer {
+    async fn daily_bars(&self, date: &NaiveDate) -> Result<Vec<Bar>> {
+        let trade_date = to_bson(&date.and_hms_opt(0, 0, 0).unwrap())
+            .map_err(|e| Error::Custom(format!("to_bson error: {}", e)))?;
+        query(
+            self.client.clone(),
+            TAB_STOCK_DAILY,
+            doc! {"trade_date": trade_date},
+            None,
+        )
+        .await
+    }
+
+    /// 各股票的风险警示(ST、*ST)区间
+    async fn st_status(&self) -> Result<HashMap<String, Vec<StockStatus>>> {
+        let status = [StockStatusType::ST, StockStatusType::StarST]
+            .iter()
+            .map(to_bson)
+            .collect::<std::result::Result<Vec<_>, _>>()
+            .map_err(|e| Error::Custom(format!("to_bson error: {}", e)))?;
+        let data: Vec<StockStatus> = query(
+            self.client.clone(),
+            TAB_STOCK_STATUS,
+            doc! {"status": {"$in": status}},
+            None,
+        )
+        .await?;
+        let mut st: HashMap<String, Vec<StockStatus>> = HashMap::new();
+        for status in data.into_iter() {
+            st.entry(status.code.clone()).or_default().push(status);
+        }
+        Ok(st)
+    }
+
+    /// 由日线推算`dates`(升序)的股池，`pre_date`为第一个交易日的前一交易日，
+    /// 日线未同步至最后一个交易日时不推算，返回false
+    async fn backfill(
+        &self,
+        tx: &mpsc::UnboundedSender<HiqSyncData>,
+        pre_date: Option<NaiveDate>,
+        dates: Vec<NaiveDate>,
+    ) -> Result<bool> {
+        let last_date = *dates.last().unwrap();
+        if self.daily_bars(&last_date).await?.is_empty() {
+            log::info!(
+                "{} is not synced to {}, sync stock_daily first",
+                TAB_STOCK_DAILY,
+                last_date
+            );
+            return Ok(false);
+        }
+        let st = self.st_status().await?;
+        // 各股票最近的k线，前一交易日的股池及连板数
+        let mut bars: HashMap<String, Bar> = HashMap::new();
+        let mut pre_limits: HashMap<String, (LimitPool, i32)> = HashMap::new();
+        if let Some(pre_date) = pre_date {
+            for bar in self.daily_bars(&pre_date).await?.into_iter() {
+                bars.insert(bar.code.clone(), bar);
+            }
+            let trade_date = to_bson(&pre_date.and_hms_opt(0, 0, 0).unwrap())
+                .map_err(|e| Error::Custom(format!("to_bson error: {}", e)))?;
+            let pools: Vec<StockLimitPool> = query(
+                self.client.clone(),
+                TAB_STOCK_LIMIT_POOL,
+                doc! {"trade_date": trade_date},
+                None,
+            )
+            .await?;
+            pre_limits = pools
+                .into_iter()
+                .map(|e| (e.code, (e.pool, e.limit_days)))
+                .collect();
+        }
+        for date in dates.into_iter() {
+            log::info!(
+                "start backfill {}, trade_date={}",
+                TAB_STOCK_LIMIT_POOL,
+                date
+            );
+            let mut data = Vec::new();
+            for bar in self.daily_bars(&date).await?.into_iter() {
+                let is_st = st
+                    .get(&bar.code)
+                    .is_some_and(|v| v.iter().any(|s| s.is_active(&bar.trade_date)));
+                if let Some(limit) = bars.get(&bar.code).and_then(|pre| {
+                    limit_pool_from_bar(pre, &bar, pre_limits.get(&bar.code).copied(), is_st)
+                }) {
+                    data.push(limit);
+                }
+                bars.insert(bar.code.clone(), bar);
+            }
+            pre_limits = data
+                .iter()
+                .map(|e| (e.code.clone(), (e.pool, e.limit_days)))
+                .collect();
+            if !data.is_empty() {
+                tx.send(HiqSyncData::StockLimitPool(data)).map_err(|e| {
+                    log::error!("send data error {:?}", e);
+                    Error::Custom(format!("send data error {:?}", e))
+                })?;
+            }
+            log::info!("end backfill {}, trade_date={}", TAB_STOCK_LIMIT_POOL, date);
+        }
+        Ok(true)
+    }
+}
+
+#[async_trait]
+impl Syncer for StockLimitPoolSyncer {
+    async fn fetch(&self, tx: mpsc::UnboundedSender<HiqSyncData>) -> Result<()> {
+        let last: Option<hiq_fetch::StockLimitPool> = query_one(
+            self.client.clone(),
+            TAB_STOCK_LIMIT_POOL,
+            doc! {},
+            FindOptions::builder()
+                .sort(doc! {"trade_date": -1})
+                .limit(1)
+                .build(),
+        )
+        .await?;
+        let (pre_date, backfill, dates): (_, Vec<_>, Vec<_>) = {
+            let cache = self.cache.read().unwrap();
+            let end = Local::now().date_naive();
+            match cache.trade_date().as_ref() {
+                Some(cal) => {
+                    let start = match last {
+                        Some(last) => cache.next_trade_date(&last.trade_date.date()),
+                        None => NaiveDate::parse_from_str(DATA_DEF_START_DATE, "%Y-%m-%d").unwrap(),
+                    };
+                    match cal.offset(&end, -LIMIT_POOL_KEEP_DAYS) {
+                        Some(keep) => (
+                            cal.prev(&start),
+                            cal.range(&start, &(keep - Duration::days(1))).collect(),
+                            cal.range(&start.max(keep), &end).collect(),
+                        ),
+                        None => (None, Vec::new(), cal.range(&start, &end).collect()),
+                    }
+                }
+                None => (None, Vec::new(), Vec::new()),
+            }
+        };
+        if !backfill.is_empty() && !self.backfill(&tx, pre_date, backfill).await? {
+            return Ok(());
+        }
+        for date in dates.into_iter() {
+            if !need_to_start(&Some(date)) {
+                log::info!("{} is the newest", TAB_STOCK_LIMIT_POOL);
+                break;
+            }
+            log::info!("start sync {}, trade_date={}", TAB_STOCK_LIMIT_POOL, date);
+            let func = StockLimitPoolAsyncFunc {
+                fetch: self.fetch.clone(),
+                date,
+            };
+            if let Some(data) = retry(func).await? {
+                tx.send(data).map_err(|e| {
+                    log::error!("send data error {:?}", e);
+                    Error::Custom(format!("send data error {:?}", e))
+                })?;
+            }
+            log::info!("end fetch {}, trade_date={}", TAB_STOCK_LIMIT_POOL, date);
+        }
+        Ok(())
+    }
+
+    async fn save(&self, data: HiqSyncData) -> Result<()> {
+        if let HiqSyncData::StockLimitPool(info) = data {
+            let elm = info.first().unwrap();
+            let len = info.len();
+            log::info!(
+                "start save {}, trade_date={}, size={}",
+                TAB_STOCK_LIMIT_POOL,
+                elm.trade_date,
+                len
+            );
+            insert_many(self.client.clone(), TAB_STOCK_LIMIT_POOL, &info, false).await?;
+            log::info!("done save {}, size={}", TAB_STOCK_LIMIT_POOL, len);
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::NaiveDate;
+    use hiq_fetch::{Bar, LimitPool};
+
+    use super::limit_pool_from_bar;
+
+    fn bar(code: &str, day: u32, close: f32, high: f32, hfq_factor: f32) -> Bar {
+        bar_on(code, 2023, day, close, high, hfq_factor)
+    }
+
+    fn bar_on(code: &str, year: i32, day: u32, close: f32, high: f32, hfq_factor: f32) -> Bar {
+        Bar {
+            code: code.to_owned(),
+            name: String::new(),
+            trade_date: NaiveDate::from_ymd_opt(year, 6, day)
+                .unwrap()
+                .and_hms_opt(0, 0, 0)
+                .unwrap(),
+            open: close,
+            close,
+            high,
+            low: close,
+            volume: 0,
+            amount: 0.0,
+            turnover: 0.0,
+            chg_pct: 0.0,
+            volume_chg_pct: 0.0,
+            amount_chg_pct: 0.0,
+            hfq_factor,
+        }
+    }
+
+    #[test]
+    fn test_limit_pool_from_bar() {
+        let pre = bar("sh600000", 1, 7.33, 7.33, 1.0);
+        // 7.33 * 1.1 = 8.063，涨停价8.06
+        let limit =
+            limit_pool_from_bar(&pre, &bar("sh600000", 2, 8.06, 8.06, 1.0), None, false).unwrap();
+        assert_eq!(limit.pool, LimitPool::LimitUp);
+        assert_eq!(limit.limit_days, 1);
+        let pre_limit = Some((LimitPool::LimitUp, 1));
+        let limit =
+            limit_pool_from_bar(&pre, &bar("sh600000", 2, 8.06, 8.06, 1.0), pre_limit, false)
+                .unwrap();
+        assert_eq!(limit.limit_days, 2);
+        // 创业板20%
+        let pre = bar("sz300750", 1, 10.0, 10.0, 1.0);
+        let limit =
+            limit_pool_from_bar(&pre, &bar("sz300750", 2, 11.5, 12.0, 1.0), None, false).unwrap();
+        assert_eq!(limit.pool, LimitPool::Broken);
+        assert!(
+            limit_pool_from_bar(&pre, &bar("sz300750", 2, 11.0, 11.0, 1.0), None, false).is_none()
+        );
+        // 除权后昨收为5.0，跌停价4.5
+        let pre = bar("sh600519", 1, 10.0, 10.0, 1.0);
+        let limit =
+            limit_pool_from_bar(&pre, &bar("sh600519", 2, 4.5, 4.9, 2.0), None, false).unwrap();
+        assert_eq!(limit.pool, LimitPool::LimitDown);
+    }
+
+    #[test]
+    fn test_limit_pool_from_bar_history() {
+        // 2019年创业板10%，涨停价11.0
+        let pre = bar_on("sz300750", 2019, 3, 10.0, 10.0, 1.0);
+        let cur = bar_on("sz300750", 2019, 4, 11.0, 11.0, 1.0);
+        let limit = limit_pool_from_bar(&pre, &cur, None, false).unwrap();
+        assert_eq!(limit.pool, LimitPool::LimitUp);
+        // 2019年主板ST 5%，跌停价9.5
+        let pre = bar_on("sh600000", 2019, 3, 10.0, 10.0, 1.0);
+        let cur = bar_on("sh600000", 2019, 4, 9.5, 10.0, 1.0);
+        let limit = limit_pool_from_bar(&pre, &cur, None, true).unwrap();
+        assert_eq!(limit.pool, LimitPool::LimitDown);
+        assert!(limit_pool_from_bar(&pre, &cur, None, false).is_none());
+    }
+}
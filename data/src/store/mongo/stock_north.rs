@@ -0,0 +1,203 @@
+use std::sync::{Arc, RwLock};
+
+use async_trait::async_trait;
+use chrono::{Local, NaiveDate};
+use hiq_fetch::StockFetch;
+use mongodb::{bson::doc, options::FindOptions, Client};
+use tokio::sync::mpsc;
+
+use crate::{
+    store::{
+        mongo::service::{insert_many, query_one},
+        HiqCache, TAB_NORTH_FLOW, TAB_STOCK_NORTH_HOLD,
+    },
+    syncer::{need_to_start, retry, AsyncFunc, Syncer},
+    types::HiqSyncData,
+    Error, Result,
+};
+
+/// 北向资金个股持股数据起始日期
+const NORTH_HOLD_START_DATE: &str = "2017-03-17";
+/// 北向资金个股持股数据最后披露日期，之后交易所不再按日披露
+const NORTH_HOLD_END_DATE: &str = "2024-08-16";
+
+struct StockNorthHoldAsyncFunc {
+    fetch: Arc<dyn StockFetch>,
+    date: NaiveDate,
+}
+
+#[async_trait]
+impl AsyncFunc for StockNorthHoldAsyncFunc {
+    async fn call(&self) -> Result<Option<HiqSyncData>> {
+        let data = self.fetch.fetch_stock_north_hold(self.date).await?;
+        if data.is_empty() {
+            Ok(None)
+        } else {
+            Ok(Some(HiqSyncData::StockNorthHold(data)))
+        }
+    }
+}
+
+/// 北向资金个股持股，按交易日增量同步全市场数据，同步至最后披露日期为止
+pub(crate) struct StockNorthHoldSyncer {
+    fetch: Arc<dyn StockFetch>,
+    cache: Arc<RwLock<HiqCache>>,
+    client: Client,
+}
+
+impl StockNorthHoldSyncer {
+    pub fn new(client: Client, fetch: Arc<dyn StockFetch>, cache: Arc<RwLock<HiqCache>>) -> Self {
+        Self {
+            client,
+            fetch,
+            cache,
+        }
+    }
+}
+
+#[async_trait]
+impl Syncer for StockNorthHoldSyncer {
+    async fn fetch(&self, tx: mpsc::UnboundedSender<HiqSyncData>) -> Result<()> {
+        let last: Option<hiq_fetch::StockNorthHold> = query_one(
+            self.client.clone(),
+            TAB_STOCK_NORTH_HOLD,
+            doc! {},
+            FindOptions::builder()
+                .sort(doc! {"trade_date": -1})
+                .limit(1)
+                .build(),
+        )
+        .await?;
+        let dates: Vec<_> = {
+            let cache = self.cache.read().unwrap();
+            let start = match last {
+                Some(last) => cache.next_trade_date(&last.trade_date.date()),
+                None => NaiveDate::parse_from_str(NORTH_HOLD_START_DATE, "%Y-%m-%d").unwrap(),
+            };
+            let end = Local::now()
+                .date_naive()
+                .min(NaiveDate::parse_from_str(NORTH_HOLD_END_DATE, "%Y-%m-%d").unwrap());
+            cache
+                .trade_date()
+                .as_ref()
+                .map(|cal| cal.range(&start, &end).collect())
+                .unwrap_or_default()
+        };
+        for date in dates.into_iter() {
+            if !need_to_start(&Some(date)) {
+                log::info!("{} is the newest", TAB_STOCK_NORTH_HOLD);
+                break;
+            }
+            log::info!("start sync {}, trade_date={}", TAB_STOCK_NORTH_HOLD, date);
+            let func = StockNorthHoldAsyncFunc {
+                fetch: self.fetch.clone(),
+                date,
+            };
+            if let Some(data) = retry(func).await? {
+                tx.send(data).map_err(|e| {
+                    log::error!("send data error {:?}", e);
+                    Error::Custom(format!("send data error {:?}", e))
+                })?;
+            }
+            log::info!("end fetch {}, trade_date={}", TAB_STOCK_NORTH_HOLD, date);
+        }
+        Ok(())
+    }
+
+    async fn save(&self, data: HiqSyncData) -> Result<()> {
+        if let HiqSyncData::StockNorthHold(info) = data {
+            let elm = info.first().unwrap();
+            let len = info.len();
+            log::info!(
+                "start save {}, trade_date={}, size={}",
+                TAB_STOCK_NORTH_HOLD,
+                elm.trade_date,
+                len
+            );
+            insert_many(self.client.clone(), TAB_STOCK_NORTH_HOLD, &info, false).await?;
+            log::info!("done save {}, size={}", TAB_STOCK_NORTH_HOLD, len);
+        }
+        Ok(())
+    }
+}
+
+struct NorthFlowAsyncFunc {
+    fetch: Arc<dyn StockFetch>,
+    start: Option<NaiveDate>,
+}
+
+#[async_trait]
+impl AsyncFunc for NorthFlowAsyncFunc {
+    async fn call(&self) -> Result<Option<HiqSyncData>> {
+        let data = self.fetch.fetch_north_flow(self.start, None).await?;
+        if data.is_empty() {
+            Ok(None)
+        } else {
+            Ok(Some(HiqSyncData::NorthFlow(data)))
+        }
+    }
+}
+
+/// 北向资金每日成交(沪股通、深股通)，按交易日增量同步
+pub(crate) struct NorthFlowSyncer {
+    fetch: Arc<dyn StockFetch>,
+    cache: Arc<RwLock<HiqCache>>,
+    client: Client,
+}
+
+impl NorthFlowSyncer {
+    pub fn new(client: Client, fetch: Arc<dyn StockFetch>, cache: Arc<RwLock<HiqCache>>) -> Self {
+        Self {
+            client,
+            fetch,
+            cache,
+        }
+    }
+}
+
+#[async_trait]
+impl Syncer for NorthFlowSyncer {
+    async fn fetch(&self, tx: mpsc::UnboundedSender<HiqSyncData>) -> Result<()> {
+        let last: Option<hiq_fetch::NorthFlow> = query_one(
+            self.client.clone(),
+            TAB_NORTH_FLOW,
+            doc! {},
+            FindOptions::builder()
+                .sort(doc! {"trade_date": -1})
+                .limit(1)
+                .build(),
+        )
+        .await?;
+        let start = last.map(|last| {
+            let cache = self.cache.read().unwrap();
+            cache.next_trade_date(&last.trade_date.date())
+        });
+        if !need_to_start(&start) {
+            log::info!("{} is the newest", TAB_NORTH_FLOW);
+            return Ok(());
+        }
+        log::info!("start sync {}, start={:?}", TAB_NORTH_FLOW, &start);
+        let func = NorthFlowAsyncFunc {
+            fetch: self.fetch.clone(),
+            start,
+        };
+        if let Some(data) = retry(func).await? {
+            tx.send(data).map_err(|e| {
+                log::error!("send data error {:?}", e);
+                Error::Custom(format!("send data error {:?}", e))
+            })?;
+        }
+        log::info!("end fetch {}, start={:?}", TAB_NORTH_FLOW, &start);
+        Ok(())
+    }
+
+    async fn save(&self, data: HiqSyncData) -> Result<()> {
+        if let HiqSyncData::NorthFlow(info) = data {
+            let len = info.len();
+            log::info!("start save {}, size={}", TAB_NORTH_FLOW, len);
+            insert_many(self.client.clone(), TAB_NORTH_FLOW, &info, false).await?;
+            log::info!("done save {}, size={}", TAB_NORTH_FLOW, len);
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,564 @@
+use std::{
+    collections::{HashMap, HashSet},
+    sync::{Arc, RwLock},
+};
+
+use async_trait::async_trait;
+use chrono::{Duration, Local, NaiveDate, NaiveDateTime};
+use hiq_fetch::{Bar, StockFetch, StockStatus, StockStatusNotice, StockStatusType, TradeCalendar};
+use mongodb::{
+    bson::{doc, to_bson, Document},
+    options::FindOptions,
+    Client,
+};
+use tokio::sync::mpsc;
+
+use crate::{
+    store::{
+        mongo::service::{delete_many, distinct, query, query_one, replace_many},
+        HiqCache, DATA_DEF_START_DATE, TAB_STOCK_DAILY, TAB_STOCK_STATUS, TAB_STOCK_ST_NOTICE,
+    },
+    syncer::{need_to_start, retry, AsyncFunc, Syncer},
+    types::HiqSyncData,
+    Error, Result,
+};
+
+enum StockStatusFunc {
+    ST,
+    STHistory,
+    Suspend(NaiveDate),
+    Delist,
+}
+
+struct StockStatusAsyncFunc {
+    fetch: Arc<dyn StockFetch>,
+    func: StockStatusFunc,
+}
+
+#[async_trait]
+impl AsyncFunc for StockStatusAsyncFunc {
+    async fn call(&self) -> Result<Option<HiqSyncData>> {
+        let data = match self.func {
+            StockStatusFunc::ST => self.fetch.fetch_stock_st().await?,
+            StockStatusFunc::STHistory => self.fetch.fetch_stock_st_history().await?,
+            StockStatusFunc::Suspend(date) => self.fetch.fetch_stock_suspend(date).await?,
+            StockStatusFunc::Delist => self.fetch.fetch_stock_delist().await?,
+        };
+        Ok(Some(HiqSyncData::StockStatus(data)))
+    }
+}
+
+struct StockStatusNoticeAsyncFunc {
+    fetch: Arc<dyn StockFetch>,
+    date: NaiveDate,
+}
+
+#[async_trait]
+impl AsyncFunc for StockStatusNoticeAsyncFunc {
+    async fn call(&self) -> Result<Option<HiqSyncData>> {
+        let data = self.fetch.fetch_stock_st_notice(self.date).await?;
+        Ok(Some(HiqSyncData::StockStatusNotice(data)))
+    }
+}
+
+fn to_bson_err<T: serde::Serialize>(v: &T) -> Result<mongodb::bson::Bson> {
+    to_bson(v).map_err(|e| Error::Custom(format!("to_bson error: {}", e)))
+}
+
+/// 状态区间的唯一标识: 代码 + 状态类型 + 开始日期
+fn status_key(status: &StockStatus) -> Result<Document> {
+    Ok(doc! {
+        "code": &status.code,
+        "status": to_bson_err(&status.status)?,
+        "start_date": to_bson_err(&status.start_date)?,
+    })
+}
+
+/// 公告的唯一标识: 代码 + 公告日期 + 标题
+fn notice_key(notice: &StockStatusNotice) -> Result<Document> {
+    Ok(doc! {
+        "code": &notice.code,
+        "notice_date": to_bson_err(&notice.notice_date)?,
+        "title": &notice.title,
+    })
+}
+
+/// 由风险警示变更公告(按代码、公告日期升序)推算区间，公告标题为reason，未结束的end_date为空。
+/// 公告未给出生效日，按公告后第二个交易日生效(公告次日停牌一天)近似
+fn st_from_notices(notices: &[StockStatusNotice], cal: &TradeCalendar) -> Vec<StockStatus> {
+    let mut data = Vec::new();
+    let mut open: HashMap<&str, StockStatus> = HashMap::new();
+    for notice in notices.iter() {
+        let effective = match cal.offset(&notice.notice_date.date(), 2) {
+            Some(date) => date,
+            None => continue,
+        };
+        if open.get(notice.code.as_str()).map(|s| s.status) == notice.status {
+            continue;
+        }
+        if let Some(mut status) = open.remove(notice.code.as_str()) {
+            let end_date = cal.prev(&effective).unwrap_or(effective);
+            status.end_date = Some(
+                end_date
+                    .and_hms_opt(0, 0, 0)
+                    .unwrap()
+                    .max(status.start_date),
+            );
+            data.push(status);
+        }
+        if let Some(status) = notice.status {
+            open.insert(
+                &notice.code,
+                StockStatus {
+                    code: notice.code.clone(),
+                    name: notice.name.clone(),
+                    status,
+                    start_date: effective.and_hms_opt(0, 0, 0).unwrap(),
+                    end_date: None,
+                    reason: notice.title.clone(),
+                    listing_date: None,
+                },
+            );
+        }
+    }
+    data.extend(open.into_values());
+    data
+}
+
+/// 股票状态历史(ST、*ST、停牌、退市)，以区间保存，同一区间多次同步时更新。
+///
+/// 退市全量同步，北交所未提供退市列表，由日线与当前股票列表比较得出；
+/// 停牌按交易日增量同步，未复牌的在不再停牌时结束；
+/// ST深市以简称变更历史为准；沪市、北交所按日增量同步风险警示变更公告，由公告推算首次快照之前的区间，
+/// 之后与其余一样以当前快照与未结束的ST区间比较，新出现的开始区间，消失的结束区间。
+pub(crate) struct StockStatusSyncer {
+    fetch: Arc<dyn StockFetch>,
+    cache: Arc<RwLock<HiqCache>>,
+    client: Client,
+}
+
+impl StockStatusSyncer {
+    pub fn new(client: Client, fetch: Arc<dyn StockFetch>, cache: Arc<RwLock<HiqCache>>) -> Self {
+        Self {
+            client,
+            fetch,
+            cache,
+        }
+    }
+    async fn call(&self, func: StockStatusFunc) -> Result<Vec<StockStatus>> {
+        let func = StockStatusAsyncFunc {
+            fetch: self.fetch.clone(),
+            func,
+        };
+        match retry(func).await? {
+            Some(HiqSyncData::StockStatus(data)) => Ok(data),
+            _ => Ok(Vec::new()),
+        }
+    }
+    fn send(&self, tx: &mpsc::UnboundedSender<HiqSyncData>, data: Vec<StockStatus>) -> Result<()> {
+        if data.is_empty() {
+            return Ok(());
+        }
+        tx.send(HiqSyncData::StockStatus(data)).map_err(|e| {
+            log::error!("send data error {:?}", e);
+            Error::Custom(format!("send data error {:?}", e))
+        })
+    }
+    /// 未结束的状态区间
+    async fn load_open(&self, status: Vec<StockStatusType>) -> Result<Vec<StockStatus>> {
+        let status = status.iter().map(to_bson_err).collect::<Result<Vec<_>>>()?;
+        query(
+            self.client.clone(),
+            TAB_STOCK_STATUS,
+            doc! {"status": {"$in": status}, "end_date": null},
+            None,
+        )
+        .await
+    }
+    /// `date`之前的最近交易日，作为已结束区间的结束日期
+    fn prev_trade_date(&self, date: &NaiveDate) -> NaiveDateTime {
+        let cache = self.cache.read().unwrap();
+        let prev = cache
+            .trade_date()
+            .as_ref()
+            .and_then(|cal| cal.prev(date))
+            .unwrap_or(*date - Duration::days(1));
+        prev.and_hms_opt(0, 0, 0).unwrap()
+    }
+    async fn fetch_delist(&self, tx: &mpsc::UnboundedSender<HiqSyncData>) -> Result<()> {
+        log::info!("start sync {}, delist", TAB_STOCK_STATUS);
+        let mut data = self.call(StockStatusFunc::Delist).await?;
+        data.extend(self.bj_delist().await?);
+        self.send(tx, data)?;
+        log::info!("end fetch {}, delist", TAB_STOCK_STATUS);
+        Ok(())
+    }
+    /// 北交所退市: 日线中有而当前股票列表中没有的北交所股票，
+    /// 退市日期为最后一根日线的下一交易日，上市日期取首根日线；
+    /// 当前列表中有同名股票的视为代码变更(如切换至920号段)，不算退市
+    async fn bj_delist(&self) -> Result<Vec<StockStatus>> {
+        let (listed, names): (HashSet<_>, HashSet<_>) = {
+            let cache = self.cache.read().unwrap();
+            cache
+                .stock_info()
+                .as_ref()
+                .map(|info| {
+                    info.values()
+                        .filter(|info| info.code.starts_with("bj"))
+                        .map(|info| (info.code.clone(), info.name.clone()))
+                        .unzip()
+                })
+                .unwrap_or_default()
+        };
+        if listed.is_empty() {
+            log::info!(
+                "{} bj stock info is empty, skip bj delist",
+                TAB_STOCK_STATUS
+            );
+            return Ok(Vec::new());
+        }
+        let codes = distinct(
+            self.client.clone(),
+            TAB_STOCK_DAILY,
+            "code",
+            doc! {"code": {"$regex": "^bj"}},
+        )
+        .await?;
+        let mut data = Vec::new();
+        for code in codes.iter().filter_map(|code| code.as_str()) {
+            if listed.contains(code) {
+                continue;
+            }
+            let bar = |sort| {
+                query_one::<Bar>(
+                    self.client.clone(),
+                    TAB_STOCK_DAILY,
+                    doc! {"code": code},
+                    FindOptions::builder()
+                        .sort(doc! {"trade_date": sort})
+                        .limit(1)
+                        .build(),
+                )
+            };
+            let (first, last) = match (bar(1).await?, bar(-1).await?) {
+                (Some(first), Some(last)) => (first, last),
+                _ => continue,
+            };
+            if names.contains(&last.name) {
+                continue;
+            }
+            let delist_date = {
+                let cache = self.cache.read().unwrap();
+                cache.next_trade_date(&last.trade_date.date())
+            };
+            data.push(StockStatus {
+                code: code.to_owned(),
+                name: last.name,
+                status: StockStatusType::Delist,
+                start_date: delist_date.and_hms_opt(0, 0, 0).unwrap(),
+                end_date: None,
+                reason: "北交所股票列表中已移除".to_owned(),
+                listing_date: Some(first.trade_date),
+            });
+        }
+        Ok(data)
+    }
+    async fn fetch_suspend(&self, tx: &mpsc::UnboundedSender<HiqSyncData>) -> Result<()> {
+        let last: Option<StockStatus> = query_one(
+            self.client.clone(),
+            TAB_STOCK_STATUS,
+            doc! {"status": to_bson_err(&StockStatusType::Suspend)?},
+            FindOptions::builder()
+                .sort(doc! {"start_date": -1})
+                .limit(1)
+                .build(),
+        )
+        .await?;
+        let dates: Vec<_> = {
+            let cache = self.cache.read().unwrap();
+            let start = match last {
+                Some(last) => last.start_date.date(),
+                None => NaiveDate::parse_from_str(DATA_DEF_START_DATE, "%Y-%m-%d").unwrap(),
+            };
+            let end = Local::now().date_naive();
+            cache
+                .trade_date()
+                .as_ref()
+                .map(|cal| cal.range(&start, &end).collect())
+                .unwrap_or_default()
+        };
+        let mut open: HashMap<_, _> = self
+            .load_open(vec![StockStatusType::Suspend])
+            .await?
+            .into_iter()
+            .map(|status| ((status.code.clone(), status.start_date), status))
+            .collect();
+        for date in dates.into_iter() {
+            if !need_to_start(&Some(date)) {
+                log::info!("{} suspend is the newest", TAB_STOCK_STATUS);
+                break;
+            }
+            log::info!(
+                "start sync {}, suspend trade_date={}",
+                TAB_STOCK_STATUS,
+                date
+            );
+            let mut data = self.call(StockStatusFunc::Suspend(date)).await?;
+            let mut closed: HashMap<_, _> = std::mem::take(&mut open);
+            for status in data.iter() {
+                let key = (status.code.clone(), status.start_date);
+                closed.remove(&key);
+                if status.end_date.is_none() {
+                    open.insert(key, status.clone());
+                }
+            }
+            // 当日不再停牌的，停牌至上一交易日
+            let end_date = self.prev_trade_date(&date);
+            data.extend(closed.into_values().map(|mut status| {
+                status.end_date = Some(end_date.max(status.start_date));
+                status
+            }));
+            self.send(tx, data)?;
+            log::info!(
+                "end fetch {}, suspend trade_date={}",
+                TAB_STOCK_STATUS,
+                date
+            );
+        }
+        Ok(())
+    }
+    async fn fetch_st_notice(&self, tx: &mpsc::UnboundedSender<HiqSyncData>) -> Result<()> {
+        log::info!("start sync {}, st notice", TAB_STOCK_ST_NOTICE);
+        let mut notices: Vec<StockStatusNotice> = query(
+            self.client.clone(),
+            TAB_STOCK_ST_NOTICE,
+            doc! {},
+            FindOptions::builder().sort(doc! {"notice_date": 1}).build(),
+        )
+        .await?;
+        // 公告日可为非交易日，从最后的公告日(含)起逐日同步
+        let (cal, dates): (_, Vec<_>) = {
+            let cache = self.cache.read().unwrap();
+            let start = notices
+                .last()
+                .map(|notice| notice.notice_date.date())
+                .unwrap_or_else(|| {
+                    NaiveDate::parse_from_str(DATA_DEF_START_DATE, "%Y-%m-%d").unwrap()
+                });
+            let end = Local::now().date_naive();
+            (
+                cache.trade_date().clone().unwrap_or_default(),
+                start.iter_days().take_while(|date| *date <= end).collect(),
+            )
+        };
+        if cal.is_empty() {
+            log::info!("{} trade date is empty, skip", TAB_STOCK_ST_NOTICE);
+            return Ok(());
+        }
+        let mut keys: HashSet<_> = notices
+            .iter()
+            .map(|notice| {
+                (
+                    notice.code.clone(),
+                    notice.notice_date,
+                    notice.title.clone(),
+                )
+            })
+            .collect();
+        for date in dates.into_iter() {
+            if !need_to_start(&Some(date)) {
+                log::info!("{} is the newest", TAB_STOCK_ST_NOTICE);
+                break;
+            }
+            let func = StockStatusNoticeAsyncFunc {
+                fetch: self.fetch.clone(),
+                date,
+            };
+            if let Some(HiqSyncData::StockStatusNotice(data)) = retry(func).await? {
+                let data: Vec<_> = data
+                    .into_iter()
+                    .filter(|notice| {
+                        keys.insert((
+                            notice.code.clone(),
+                            notice.notice_date,
+                            notice.title.clone(),
+                        ))
+                    })
+                    .collect();
+                if !data.is_empty() {
+                    notices.extend(data.iter().cloned());
+                    tx.send(HiqSyncData::StockStatusNotice(data)).map_err(|e| {
+                        log::error!("send data error {:?}", e);
+                        Error::Custom(format!("send data error {:?}", e))
+                    })?;
+                }
+            }
+        }
+
+        // 首次快照之前的区间由公告推算，快照记录的reason为空
+        let st = [StockStatusType::ST, StockStatusType::StarST]
+            .iter()
+            .map(to_bson_err)
+            .collect::<Result<Vec<_>>>()?;
+        let first: Option<StockStatus> = query_one(
+            self.client.clone(),
+            TAB_STOCK_STATUS,
+            doc! {"status": {"$in": st}, "code": {"$regex": "^(sh|bj)"}, "reason": ""},
+            FindOptions::builder()
+                .sort(doc! {"start_date": 1})
+                .limit(1)
+                .build(),
+        )
+        .await?;
+        let boundary = first
+            .map(|status| status.start_date)
+            .unwrap_or_else(|| Local::now().date_naive().and_hms_opt(0, 0, 0).unwrap());
+        let end_date = self.prev_trade_date(&boundary.date());
+        notices.sort_by(|a, b| (&a.code, a.notice_date).cmp(&(&b.code, b.notice_date)));
+        let data = st_from_notices(&notices, &cal)
+            .into_iter()
+            .filter(|status| status.start_date < boundary)
+            .map(|mut status| {
+                if status.end_date.is_none_or(|end| end >= boundary) {
+                    status.end_date = Some(end_date.max(status.start_date));
+                }
+                status
+            })
+            .collect();
+        self.send(tx, data)?;
+        log::info!("end fetch {}, st notice", TAB_STOCK_ST_NOTICE);
+        Ok(())
+    }
+    async fn fetch_st(&self, tx: &mpsc::UnboundedSender<HiqSyncData>) -> Result<()> {
+        log::info!("start sync {}, st", TAB_STOCK_STATUS);
+        let history = self.call(StockStatusFunc::STHistory).await?;
+        let covered: HashSet<_> = history.iter().map(|status| status.code.clone()).collect();
+        if !covered.is_empty() {
+            // 有简称变更历史的以历史为准，删除之前由快照推算的区间
+            let starts: HashSet<_> = history
+                .iter()
+                .map(|status| (status.code.clone(), status.status, status.start_date))
+                .collect();
+            let st = [StockStatusType::ST, StockStatusType::StarST]
+                .iter()
+                .map(to_bson_err)
+                .collect::<Result<Vec<_>>>()?;
+            let saved: Vec<StockStatus> = query(
+                self.client.clone(),
+                TAB_STOCK_STATUS,
+                doc! {"status": {"$in": st}, "code": {"$in": covered.iter().collect::<Vec<_>>()}},
+                None,
+            )
+            .await?;
+            let stale = saved
+                .iter()
+                .filter(|s| !starts.contains(&(s.code.clone(), s.status, s.start_date)))
+                .map(status_key)
+                .collect::<Result<Vec<_>>>()?;
+            if !stale.is_empty() {
+                delete_many(self.client.clone(), TAB_STOCK_STATUS, doc! {"$or": stale}).await?;
+            }
+            self.send(tx, history)?;
+        }
+
+        let current: Vec<_> = self
+            .call(StockStatusFunc::ST)
+            .await?
+            .into_iter()
+            .filter(|status| !covered.contains(&status.code))
+            .collect();
+        if current.is_empty() {
+            log::info!("{} st is empty, skip", TAB_STOCK_STATUS);
+            return Ok(());
+        }
+        let mut open: HashMap<_, _> = self
+            .load_open(vec![StockStatusType::ST, StockStatusType::StarST])
+            .await?
+            .into_iter()
+            .filter(|status| !covered.contains(&status.code))
+            .map(|status| ((status.code.clone(), status.status), status))
+            .collect();
+        let mut data: Vec<_> = current
+            .into_iter()
+            .filter(|status| open.remove(&(status.code.clone(), status.status)).is_none())
+            .collect();
+        let end_date = self.prev_trade_date(&Local::now().date_naive());
+        data.extend(open.into_values().map(|mut status| {
+            status.end_date = Some(end_date.max(status.start_date));
+            status
+        }));
+        self.send(tx, data)?;
+        log::info!("end fetch {}, st", TAB_STOCK_STATUS);
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl Syncer for StockStatusSyncer {
+    async fn fetch(&self, tx: mpsc::UnboundedSender<HiqSyncData>) -> Result<()> {
+        self.fetch_delist(&tx).await?;
+        self.fetch_suspend(&tx).await?;
+        self.fetch_st_notice(&tx).await?;
+        self.fetch_st(&tx).await?;
+        Ok(())
+    }
+
+    async fn save(&self, data: HiqSyncData) -> Result<()> {
+        match data {
+            HiqSyncData::StockStatus(info) => {
+                let len = info.len();
+                log::info!("start save {}, size={}", TAB_STOCK_STATUS, len);
+                replace_many(self.client.clone(), TAB_STOCK_STATUS, &info, status_key).await?;
+                log::info!("done save {}, size={}", TAB_STOCK_STATUS, len);
+            }
+            HiqSyncData::StockStatusNotice(info) => {
+                let len = info.len();
+                log::info!("start save {}, size={}", TAB_STOCK_ST_NOTICE, len);
+                replace_many(self.client.clone(), TAB_STOCK_ST_NOTICE, &info, notice_key).await?;
+                log::info!("done save {}, size={}", TAB_STOCK_ST_NOTICE, len);
+            }
+            _ => {}
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn notice(date: &str, status: Option<StockStatusType>) -> StockStatusNotice {
+        StockStatusNotice {
+            code: "sh600234".to_string(),
+            name: "科新发展".to_string(),
+            notice_date: NaiveDateTime::parse_from_str(date, "%Y%m%d %H:%M:%S").unwrap(),
+            status,
+            title: date.to_string(),
+        }
+    }
+
+    #[test]
+    fn test_st_from_notices() {
+        let cal = TradeCalendar::from_i32(&[
+            20240205, 20240206, 20240207, 20240208, 20240219, 20240220, 20240221,
+        ]);
+        let notices = vec![
+            notice("20240205 00:00:00", Some(StockStatusType::StarST)),
+            notice("20240206 00:00:00", Some(StockStatusType::StarST)),
+            notice("20240208 00:00:00", None),
+            notice("20240219 00:00:00", Some(StockStatusType::ST)),
+        ];
+        let data = st_from_notices(&notices, &cal);
+        assert_eq!(data.len(), 2);
+        assert_eq!(data[0].status, StockStatusType::StarST);
+        assert_eq!(data[0].start_date.date().to_string(), "2024-02-07");
+        assert_eq!(
+            data[0].end_date.map(|d| d.date().to_string()),
+            Some("2024-02-19".to_string())
+        );
+        assert_eq!(data[0].reason, "20240205 00:00:00");
+        assert_eq!(data[1].status, StockStatusType::ST);
+        assert_eq!(data[1].start_date.date().to_string(), "2024-02-21");
+        assert_eq!(data[1].end_date, None);
+    }
+}
@@ -0,0 +1,177 @@
+use std::sync::{Arc, RwLock};
+
+use async_trait::async_trait;
+use chrono::{Local, NaiveDate};
+use hiq_fetch::{Exchange, StockFetch};
+use mongodb::{
+    bson::{doc, to_bson},
+    options::FindOptions,
+    Client,
+};
+use tokio::sync::mpsc;
+
+use crate::{
+    store::{mongo::service::query_one, HiqCache, TAB_STOCK_TICK},
+    syncer::{retry, AsyncFunc, Syncer},
+    types::HiqSyncData,
+    Error, Result,
+};
+
+use super::service::insert_many;
+
+struct StockTickAsyncFunc<'a> {
+    fetch: Arc<dyn StockFetch>,
+    code: &'a str,
+    date: NaiveDate,
+}
+
+#[async_trait]
+impl<'a> AsyncFunc for StockTickAsyncFunc<'a> {
+    async fn call(&self) -> Result<Option<HiqSyncData>> {
+        let data = self.fetch.fetch_stock_ticks(self.code, self.date).await?;
+        if data.is_empty() {
+            Ok(None)
+        } else {
+            Ok(Some(HiqSyncData::StockTick(data)))
+        }
+    }
+}
+
+/// 分笔成交，上游只提供最新交易日的数据，只同步已收盘的交易日，
+/// 收盘后同步当日，开盘前同步上一交易日，交易中不同步
+pub(crate) struct StockTickSyncer {
+    fetch: Arc<dyn StockFetch>,
+    cache: Arc<RwLock<HiqCache>>,
+    client: Client,
+    /// (代码, 简称)，股票或可转债
+    codes: Vec<(String, String)>,
+    task_n: usize,
+}
+
+impl StockTickSyncer {
+    pub fn new(
+        client: Client,
+        fetch: Arc<dyn StockFetch>,
+        cache: Arc<RwLock<HiqCache>>,
+        codes: Vec<(String, String)>,
+        task_n: usize,
+    ) -> Self {
+        Self {
+            client,
+            fetch,
+            cache,
+            codes,
+            task_n,
+        }
+    }
+
+    /// 可同步的交易日，交易中返回None
+    fn sync_date(&self) -> Option<NaiveDate> {
+        let now = Local::now().naive_local();
+        let clock = hiq_fetch::market_clock(Exchange::SH);
+        match clock.schedule(&now.date()) {
+            Some(schedule) if now.time() >= schedule.open() => {
+                clock.is_closed(&now).then(|| now.date())
+            }
+            _ => {
+                let cache = self.cache.read().unwrap();
+                cache
+                    .trade_date()
+                    .as_ref()
+                    .and_then(|calendar| calendar.prev(&now.date()))
+            }
+        }
+    }
+}
+
+#[async_trait]
+impl Syncer for StockTickSyncer {
+    async fn fetch(&self, tx: mpsc::UnboundedSender<HiqSyncData>) -> Result<()> {
+        let date = match self.sync_date() {
+            Some(date) => date,
+            None => {
+                log::info!(
+                    "{} is trading, skip sync, task#{}",
+                    TAB_STOCK_TICK,
+                    self.task_n
+                );
+                return Ok(());
+            }
+        };
+        let trade_date = to_bson(&date.and_hms_opt(0, 0, 0).unwrap())
+            .map_err(|e| Error::Custom(format!("to_bson error: {}", e)))?;
+        for (code, name) in self.codes.iter() {
+            let tick: Option<hiq_fetch::Tick> = query_one(
+                self.client.clone(),
+                TAB_STOCK_TICK,
+                doc! {"code": code.as_str(), "trade_date": trade_date.clone()},
+                FindOptions::builder().limit(1).build(),
+            )
+            .await?;
+            if tick.is_some() {
+                log::info!(
+                    "{}({}) {} is the newest, task#{}",
+                    name.as_str(),
+                    code.as_str(),
+                    TAB_STOCK_TICK,
+                    self.task_n
+                );
+                continue;
+            }
+
+            log::info!(
+                "start fetch {}({}) {}, date={}, task#{}",
+                name.as_str(),
+                code.as_str(),
+                TAB_STOCK_TICK,
+                &date,
+                self.task_n
+            );
+            let func = StockTickAsyncFunc {
+                fetch: self.fetch.clone(),
+                code: code.as_str(),
+                date,
+            };
+            let data = retry(func).await?;
+            if let Some(data) = data {
+                tx.send(data).map_err(|e| {
+                    log::error!("send data error {:?}", e);
+                    Error::Custom(format!("send data error {:?}", e))
+                })?;
+            };
+            log::info!(
+                "end fetch {}({}) {}, date={}, task#{}",
+                name.as_str(),
+                code.as_str(),
+                TAB_STOCK_TICK,
+                &date,
+                self.task_n
+            );
+        }
+
+        Ok(())
+    }
+
+    async fn save(&self, data: HiqSyncData) -> Result<()> {
+        if let HiqSyncData::StockTick(info) = data {
+            let tick = info.first().unwrap();
+            let len = info.len();
+            log::info!(
+                "start save {} {}, size={}, task#{}",
+                tick.code.as_str(),
+                TAB_STOCK_TICK,
+                len,
+                self.task_n
+            );
+            insert_many(self.client.clone(), TAB_STOCK_TICK, &info, false).await?;
+            log::info!(
+                "done save {} {}, size={}, task#{}",
+                tick.code.as_str(),
+                TAB_STOCK_TICK,
+                len,
+                self.task_n
+            );
+        }
+        Ok(())
+    }
+}
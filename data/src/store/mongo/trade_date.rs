@@ -24,7 +24,7 @@ impl AsyncFunc for TradeDateAsyncFunc {
             let mut data = Vec::new();
             let cache_info = self.cache.read().unwrap();
             if let Some(info) = cache_info.trade_date() {
-                for v in info.iter() {
+                for v in info.to_i32().iter() {
                     data.push(hiq_fetch::TradeDate { trade_date: *v });
                 }
             }
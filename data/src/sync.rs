@@ -12,7 +12,7 @@ use crate::{
     store::Store,
     syncer::Syncer,
     types::{HiqSyncData, HiqSyncDest, HiqSyncDestType},
-    Error, Result,
+    Error, FetchConfig, Result,
 };
 
 /// 数据同步
@@ -20,6 +20,7 @@ pub struct HiqSync {
     dest: Vec<HiqSyncDest>,
     shutdown: broadcast::Receiver<()>,
     funcs: Option<Vec<HiqSyncDataType>>,
+    config: FetchConfig,
     store: Option<HashMap<HiqSyncDestType, Arc<Box<dyn Store>>>>,
     is_init: bool,
 }
@@ -28,7 +29,7 @@ impl HiqSync {
     /// 构造对象  
     /// `dest` 数据源，需保证数据源正确，否则后续`init`会报错  
     /// `shutdown·` 停止信号  
-    /// `funcs` 同步的条目类型，如果为`None`，则全部同步
+    /// `funcs` 同步的条目类型，如果为`None`，则全部同步(分笔成交等数据量大的条目需明确指定)
     pub fn new(
         dest: Vec<HiqSyncDest>,
         shutdown: broadcast::Receiver<()>,
@@ -38,10 +39,16 @@ impl HiqSync {
             dest,
             shutdown,
             funcs,
+            config: FetchConfig::default(),
             store: None,
             is_init: false,
         }
     }
+    /// 设置远程获取数据的http配置(代理、超时等)，需在`init`前设置
+    pub fn with_fetch_config(mut self, config: FetchConfig) -> Self {
+        self.config = config;
+        self
+    }
     /// 初始化 
     /// `skip_basic` 初始化数据是否从远程获取，true在从数据库获取, false则从远程获取    
     /// `split_count` 代码切分份数，同一份数据在同一个task里处理  
@@ -49,7 +56,15 @@ impl HiqSync {
         if !self.is_init {
             let mut store = HashMap::new();
             for (i, dest) in self.dest.iter().enumerate() {
-                let (t, s) = get_store(dest, skip_basic, split_count, &self.funcs, true).await?;
+                let (t, s) = get_store(
+                    dest,
+                    skip_basic,
+                    split_count,
+                    &self.funcs,
+                    &self.config,
+                    true,
+                )
+                .await?;
                 store.insert(t.clone(), Arc::new(s));
                 log::debug!("store#{}{:?}-{:?} inited ", i, dest, &t);
             }
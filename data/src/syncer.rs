@@ -1,7 +1,8 @@
 use std::time::Duration;
 
 use async_trait::async_trait;
-use chrono::{Local, NaiveDate, Timelike};
+use chrono::{Local, NaiveDate};
+use hiq_fetch::{market_clock, Exchange};
 use tokio::sync::mpsc;
 
 use crate::{types::HiqSyncData, Result};
@@ -31,20 +32,13 @@ pub async fn retry(func: impl AsyncFunc) -> Result<Option<HiqSyncData>> {
     }
 }
 
-/// 判断是否可以同步
+/// 判断是否可以同步，当日数据需收盘后才同步
 pub fn need_to_start(start: &Option<NaiveDate>) -> bool {
     if let Some(s) = start {
         let now = Local::now().naive_local();
-        let hour = now.hour();
-        let min = now.minute();
         let n = now.date();
-        if s == &n {
-            if hour <= 15 {
-                if hour == 15 && min > 5 {
-                    return true;
-                }
-                return false;
-            }
+        if s == &n && !market_clock(Exchange::SH).is_settled(&now) {
+            return false;
         }
         if s > &n {
             return false;
@@ -2,8 +2,11 @@
 use std::path::PathBuf;
 
 use hiq_fetch::{
-    Bar, BondInfo, FundInfo, FundNet, StockConcept, StockConceptDetail, StockIndex, StockIndustry,
-    StockIndustryDetail, StockInfo, StockMargin, StockYJBB, TradeDate,
+    AuctionSnapshot, Bar, BondInfo, FundInfo, FundNet, IndexConstituent, NorthFlow,
+    StockBalanceSheet, StockCashFlow, StockConcept, StockConceptDetail, StockCorpAction,
+    StockDragonTiger, StockHolderNum, StockIncome, StockIndex, StockIndustry, StockIndustryDetail,
+    StockInfo, StockLimitPool, StockMargin, StockNorthHold, StockStatus, StockStatusNotice,
+    StockTopHolder, StockYJBB, Tick, TradeDate,
 };
 
 use crate::Error;
@@ -78,6 +81,23 @@ pub enum HiqSyncData {
     StockConceptBar(Vec<Bar>),
     StockYJBB(Vec<StockYJBB>),
     StockMargin(Vec<StockMargin>),
+    StockCorpAction(Vec<StockCorpAction>),
+    StockTick(Vec<Tick>),
+    OverseasStockInfo(Vec<StockInfo>),
+    OverseasStockBar(Vec<Bar>),
+    IndexConstituent(Vec<IndexConstituent>),
+    StockBalanceSheet(Vec<StockBalanceSheet>),
+    StockIncome(Vec<StockIncome>),
+    StockCashFlow(Vec<StockCashFlow>),
+    StockHolderNum(Vec<StockHolderNum>),
+    StockTopHolder(Vec<StockTopHolder>),
+    StockNorthHold(Vec<StockNorthHold>),
+    NorthFlow(Vec<NorthFlow>),
+    StockDragonTiger(Vec<StockDragonTiger>),
+    StockLimitPool(Vec<StockLimitPool>),
+    StockStatus(Vec<StockStatus>),
+    StockStatusNotice(Vec<StockStatusNotice>),
+    StockAuction(Vec<AuctionSnapshot>),
 
     // fund
     FundInfo(Vec<FundInfo>),
@@ -118,6 +138,24 @@ pub enum HiqSyncDataType {
     // bond
     BondInfo,
     BondBar,
+
+    // stock
+    StockCorpAction,
+    StockTick,
+    OverseasStockInfo,
+    OverseasStockBar,
+    IndexConstituent,
+    StockBalanceSheet,
+    StockIncome,
+    StockCashFlow,
+    StockHolderNum,
+    StockTopHolder,
+    StockNorthHold,
+    NorthFlow,
+    StockDragonTiger,
+    StockLimitPool,
+    StockStatus,
+    StockAuction,
 }
 
 impl TryFrom<i32> for HiqSyncDataType {
@@ -149,6 +187,24 @@ impl TryFrom<i32> for HiqSyncDataType {
             // bond
             18 => Ok(HiqSyncDataType::BondInfo),
             19 => Ok(HiqSyncDataType::BondBar),
+
+            // stock
+            20 => Ok(HiqSyncDataType::StockCorpAction),
+            21 => Ok(HiqSyncDataType::StockTick),
+            22 => Ok(HiqSyncDataType::OverseasStockInfo),
+            23 => Ok(HiqSyncDataType::OverseasStockBar),
+            24 => Ok(HiqSyncDataType::IndexConstituent),
+            25 => Ok(HiqSyncDataType::StockBalanceSheet),
+            26 => Ok(HiqSyncDataType::StockIncome),
+            27 => Ok(HiqSyncDataType::StockCashFlow),
+            28 => Ok(HiqSyncDataType::StockHolderNum),
+            29 => Ok(HiqSyncDataType::StockTopHolder),
+            30 => Ok(HiqSyncDataType::StockNorthHold),
+            31 => Ok(HiqSyncDataType::NorthFlow),
+            32 => Ok(HiqSyncDataType::StockDragonTiger),
+            33 => Ok(HiqSyncDataType::StockLimitPool),
+            34 => Ok(HiqSyncDataType::StockStatus),
+            35 => Ok(HiqSyncDataType::StockAuction),
             _ => Err(Error::Custom(format!("Invalid HiqSyncDataType: {}", v))),
         }
     }
@@ -176,6 +232,22 @@ impl TryFrom<&str> for HiqSyncDataType {
             "stock_concept_daily" => Ok(HiqSyncDataType::StockConceptBar),
             "stock_yjbb" => Ok(HiqSyncDataType::StockYJBB),
             "stock_margin" => Ok(HiqSyncDataType::StockMargin),
+            "stock_corp_action" => Ok(HiqSyncDataType::StockCorpAction),
+            "stock_tick" => Ok(HiqSyncDataType::StockTick),
+            "overseas_stock_info" => Ok(HiqSyncDataType::OverseasStockInfo),
+            "overseas_stock_daily" => Ok(HiqSyncDataType::OverseasStockBar),
+            "index_constituent" => Ok(HiqSyncDataType::IndexConstituent),
+            "stock_balance_sheet" => Ok(HiqSyncDataType::StockBalanceSheet),
+            "stock_income" => Ok(HiqSyncDataType::StockIncome),
+            "stock_cash_flow" => Ok(HiqSyncDataType::StockCashFlow),
+            "stock_holder_num" => Ok(HiqSyncDataType::StockHolderNum),
+            "stock_top_holder" => Ok(HiqSyncDataType::StockTopHolder),
+            "stock_north_hold" => Ok(HiqSyncDataType::StockNorthHold),
+            "north_flow" => Ok(HiqSyncDataType::NorthFlow),
+            "stock_dragon_tiger" => Ok(HiqSyncDataType::StockDragonTiger),
+            "stock_limit_pool" => Ok(HiqSyncDataType::StockLimitPool),
+            "stock_status" => Ok(HiqSyncDataType::StockStatus),
+            "stock_auction" => Ok(HiqSyncDataType::StockAuction),
 
             // fund
             "fund_info" => Ok(HiqSyncDataType::FundInfo),
@@ -4,8 +4,8 @@ use std::{collections::HashMap, sync::Arc};
 use async_trait::async_trait;
 use bson::doc;
 use hiq_strategy::{
-    stat_result, store::Loader, strategy_to_data_type, Error, Result, Strategy, StrategyResult,
-    StrategyType,
+    stat_result, store::Loader, strategy_to_data_type, AdjustFactor, Error, Result, Strategy,
+    StrategyResult, StrategyType,
 };
 
 #[derive(Debug, Clone)]
@@ -37,6 +37,7 @@ impl Strategy for TestStrategy {
                     doc! {"code": &code},
                     doc! {"trade_date": -1},
                     Some(60),
+                    AdjustFactor::NFQ,
                 )
                 .await
                 .map_err(|e| Error::Custom(format!("load_daily error: {}", e.to_string())))?;
@@ -7,7 +7,8 @@
 use crate::{Error, Result};
 use async_trait::async_trait;
 use chrono::NaiveDate;
-use hiq_common::{BarFreq, BondBar, BondInfo};
+use hiq_common::{BarFreq, BondBar, BondInfo, MinuteBar, OrderBook};
+use std::collections::HashMap;
 
 /// 可转债trait
 #[async_trait]
@@ -34,4 +35,22 @@ pub trait BondFetch: Sync + Send {
     {
         Err(Error::NotImpl("fetch_bond_bar".to_string()))
     }
+    /// 可转债分钟k线，从`end`(默认当日)向前分页获取直至`start`或上游无更早数据，
+    /// 返回实际覆盖的时间范围。*freq* 默认1分钟
+    async fn fetch_bond_minute_bar(
+        &self,
+        code: &str,
+        name: Option<&str>,
+        freq: Option<BarFreq>,
+        start: Option<NaiveDate>,
+        end: Option<NaiveDate>,
+    ) -> Result<MinuteBar>
+    {
+        Err(Error::NotImpl("fetch_bond_minute_bar".to_string()))
+    }
+    /// 可转债五档盘口，含涨跌停价及内外盘
+    async fn fetch_bond_order_book(&self, code: Vec<&str>) -> Result<HashMap<String, OrderBook>>
+    {
+        Err(Error::NotImpl("fetch_bond_order_book".to_string()))
+    }
 }
@@ -0,0 +1,113 @@
+use crate::bond::BondFetch;
+use crate::comm::{CircuitPolicy, Failover, SourceHealth};
+use crate::Result;
+use async_trait::async_trait;
+use chrono::NaiveDate;
+use hiq_common::{BarFreq, BondBar, BondInfo, MinuteBar, OrderBook};
+use std::collections::HashMap;
+use std::future::Future;
+use std::sync::Arc;
+
+/// 多数据源可转债实现，按顺序尝试，失败切换到下一个数据源
+///
+/// 连续失败达到阈值的数据源会被熔断一段时间，熔断期间跳过。
+pub struct FailoverBondFetch {
+    inner: Failover<dyn BondFetch>,
+}
+
+impl FailoverBondFetch {
+    /// `sources`为(数据源名称, 实现)，按优先级排序
+    pub fn new(sources: Vec<(String, Arc<dyn BondFetch>)>) -> Self {
+        Self::with_policy(sources, CircuitPolicy::default())
+    }
+    pub fn with_policy(sources: Vec<(String, Arc<dyn BondFetch>)>, policy: CircuitPolicy) -> Self {
+        Self {
+            inner: Failover::new(sources, policy),
+        }
+    }
+    /// 最近一次成功调用`method`（如: "fetch_bond_bar"）的数据源名称
+    ///
+    /// 并发调用时只是其中一次的数据源，需要每次调用的数据源使用`call_with_source`
+    pub fn served_by(&self, method: &str) -> Option<String> {
+        self.inner.served_by(method)
+    }
+    /// 调用`f`，同时返回本次提供数据的数据源名称
+    ///
+    /// ```ignore
+    /// let (bar, source) = fetch
+    ///     .call_with_source("fetch_bond_bar", |f| async move { f.fetch_bond_bar(..).await })
+    ///     .await?;
+    /// ```
+    pub async fn call_with_source<R, F, Fut>(&self, method: &str, f: F) -> Result<(R, String)>
+    where
+        F: Fn(Arc<dyn BondFetch>) -> Fut,
+        Fut: Future<Output = Result<R>>,
+    {
+        self.inner.call_with_source(method, f).await
+    }
+    /// 各数据源健康状态
+    pub fn health(&self) -> Vec<(String, SourceHealth)> {
+        self.inner.health()
+    }
+}
+
+#[async_trait]
+impl BondFetch for FailoverBondFetch {
+    /// 获取可转债基本信息
+    async fn fetch_bond_info(&self) -> Result<Vec<BondInfo>> {
+        self.inner
+            .call(
+                "fetch_bond_info",
+                |f| async move { f.fetch_bond_info().await },
+            )
+            .await
+    }
+
+    /// 获取可转债基本
+    ///
+    /// *code* 可转债代码，其中11开头的为深市，12开头的为沪市。
+    /// *freq* 为频次。
+    async fn fetch_bond_bar(
+        &self,
+        code: &str,
+        name: &str,
+        stock_code: &str,
+        stock_name: &str,
+        freq: Option<BarFreq>,
+        start: Option<NaiveDate>,
+        end: Option<NaiveDate>,
+    ) -> Result<BondBar> {
+        self.inner
+            .call("fetch_bond_bar", |f| async move {
+                f.fetch_bond_bar(code, name, stock_code, stock_name, freq, start, end)
+                    .await
+            })
+            .await
+    }
+
+    /// 可转债分钟k线，分页获取
+    async fn fetch_bond_minute_bar(
+        &self,
+        code: &str,
+        name: Option<&str>,
+        freq: Option<BarFreq>,
+        start: Option<NaiveDate>,
+        end: Option<NaiveDate>,
+    ) -> Result<MinuteBar> {
+        self.inner
+            .call("fetch_bond_minute_bar", |f| async move {
+                f.fetch_bond_minute_bar(code, name, freq, start, end).await
+            })
+            .await
+    }
+
+    /// 可转债五档盘口
+    async fn fetch_bond_order_book(&self, code: Vec<&str>) -> Result<HashMap<String, OrderBook>> {
+        self.inner
+            .call("fetch_bond_order_book", |f| {
+                let code = code.clone();
+                async move { f.fetch_bond_order_book(code).await }
+            })
+            .await
+    }
+}
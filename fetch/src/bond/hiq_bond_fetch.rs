@@ -1,23 +1,29 @@
 use crate::bond::hiq_bond_info::EastBondInfo;
 use crate::bond::BondFetch;
-use crate::comm::{async_client, fetch_bar};
-use crate::util::to_std_code;
-use crate::{Market, MarketType, Result, HTTP_CMM_HEADER};
+use crate::comm::{
+    default_transport, fetch_bar, fetch_minute_bar, fetch_order_book, to_minute_bar, BarAdjust,
+    Transport, MINUTE_BAR_PAGE_SIZE,
+};
+use crate::util::{to_east_secid, to_std_code};
+use crate::{MarketType, Result, HTTP_CMM_HEADER};
 use async_trait::async_trait;
 use chrono::naive::NaiveDate;
 use chrono::NaiveDateTime;
-use hiq_common::{BarFreq, BondBar, BondInfo};
-use reqwest::Client;
+use hiq_common::{BarFreq, BondBar, BondInfo, MinuteBar, OrderBook};
+use std::collections::HashMap;
+use std::sync::Arc;
 
 pub struct HiqBondFetch {
-    client: Client,
+    transport: Arc<dyn Transport>,
 }
 
 impl HiqBondFetch {
     pub fn new() -> Self {
-        Self {
-            client: async_client(),
-        }
+        Self::with_transport(default_transport())
+    }
+    /// 指定传输层，如录制/回放
+    pub fn with_transport(transport: Arc<dyn Transport>) -> Self {
+        Self { transport }
     }
 }
 
@@ -43,12 +49,8 @@ impl BondFetch for HiqBondFetch {
             );
 
             let resp = self
-                .client
-                .get(req_url)
-                .headers(HTTP_CMM_HEADER.to_owned())
-                .send()
-                .await?
-                .text()
+                .transport
+                .get_text(&req_url, Some(HTTP_CMM_HEADER.to_owned()))
                 .await?;
 
             let json: EastBondInfo = serde_json::from_str(&resp)?;
@@ -101,19 +103,24 @@ impl BondFetch for HiqBondFetch {
         start: Option<NaiveDate>,
         end: Option<NaiveDate>,
     ) -> Result<BondBar> {
-        let market_code = if code.starts_with("sz") {
-            // 深圳市场
-            format!("{}.{}", Market::SZ as i32, &code[2..])
-        } else {
-            format!("{}.{}", Market::SH as i32, &code[2..])
-        };
+        let market_code = to_east_secid(code)?;
         let freq = if freq.is_none() {
             BarFreq::Daily
         } else {
             freq.unwrap()
         };
 
-        let bars = fetch_bar(&self.client, &market_code, code, freq, start, end).await?;
+        let bars = fetch_bar(
+            self.transport.as_ref(),
+            &market_code,
+            code,
+            freq,
+            start,
+            end,
+            // 可转债不复权
+            BarAdjust::None,
+        )
+        .await?;
         let bond_bar = BondBar {
             code: code.to_owned(),
             name: name.to_owned(),
@@ -124,11 +131,39 @@ impl BondFetch for HiqBondFetch {
         };
         Ok(bond_bar)
     }
+    /// 可转债分钟k线，分页获取，不复权
+    async fn fetch_bond_minute_bar(
+        &self,
+        code: &str,
+        name: Option<&str>,
+        freq: Option<BarFreq>,
+        start: Option<NaiveDate>,
+        end: Option<NaiveDate>,
+    ) -> Result<MinuteBar> {
+        let market_code = to_east_secid(code)?;
+        let freq = freq.unwrap_or(BarFreq::Min1);
+        let bars = fetch_minute_bar(
+            self.transport.as_ref(),
+            &market_code,
+            code,
+            freq,
+            start,
+            end,
+            false,
+            MINUTE_BAR_PAGE_SIZE,
+        )
+        .await?;
+        Ok(to_minute_bar(code, name, freq, bars))
+    }
+    /// 可转债五档盘口(腾讯行情)
+    async fn fetch_bond_order_book(&self, code: Vec<&str>) -> Result<HashMap<String, OrderBook>> {
+        fetch_order_book(self.transport.as_ref(), &code).await
+    }
 }
 
 #[cfg(test)]
 mod tests {
-    use crate::{bond_fetch, BondFetch};
+    use crate::{bond_fetch, BondFetch, FetchConfig};
     use hiq_common::BarFreq;
     // use chrono::NaiveDate;
 
@@ -139,7 +174,7 @@ mod tests {
             .build()
             .unwrap()
             .block_on(async {
-                let fetch = bond_fetch();
+                let fetch = bond_fetch(&FetchConfig::default()).unwrap();
                 let res = fetch.fetch_bond_info().await.unwrap();
 
                 assert!(res.len() > 0);
@@ -154,7 +189,7 @@ mod tests {
             .build()
             .unwrap()
             .block_on(async {
-                let fetch = bond_fetch();
+                let fetch = bond_fetch(&FetchConfig::default()).unwrap();
                 let bond_info = fetch.fetch_bond_info().await.unwrap();
 
                 let item = bond_info.get(bond_info.len() - 1).unwrap();
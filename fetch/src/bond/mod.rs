@@ -1,12 +1,18 @@
 mod hiq_bond_info;
 
 mod bond_fetch;
+mod failover_bond_fetch;
 mod hiq_bond_fetch;
 
 pub use bond_fetch::*;
+pub use failover_bond_fetch::*;
 pub use hiq_bond_fetch::*;
 
+use crate::{FetchConfig, Result};
+
 /// 返回默认的可转债实现
-pub fn bond_fetch() -> impl BondFetch {
-    HiqBondFetch::new()
+///
+/// `config`设置代理、超时、user-agent及请求地址等，默认配置用`FetchConfig::default()`
+pub fn bond_fetch(config: &FetchConfig) -> Result<impl BondFetch> {
+    Ok(HiqBondFetch::with_transport(config.transport()?))
 }
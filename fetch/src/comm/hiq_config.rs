@@ -0,0 +1,129 @@
+use crate::comm::{
+    default_transport, HttpTransport, RecordTransport, ReplayTransport, Transport, TransportMode,
+};
+use crate::Result;
+use reqwest::header::HeaderMap;
+use std::collections::HashMap;
+use std::sync::Arc;
+use std::time::Duration;
+
+/// 获取数据的http配置
+///
+/// # Examples
+/// ```
+/// use hiq_fetch::FetchConfig;
+/// use std::time::Duration;
+///
+/// let mut config = FetchConfig::default();
+/// config.proxy = Some("http://127.0.0.1:3128".to_owned());
+/// config.read_timeout = Some(Duration::from_secs(30));
+/// config.base_urls.insert(
+///     "https://push2his.eastmoney.com".to_owned(),
+///     "http://127.0.0.1:8080".to_owned(),
+/// );
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct FetchConfig {
+    /// 代理，如: http://127.0.0.1:3128, socks5需reqwest开启对应feature
+    pub proxy: Option<String>,
+    /// 连接超时
+    pub connect_timeout: Option<Duration>,
+    /// 请求超时（包括读取数据）
+    pub read_timeout: Option<Duration>,
+    /// 轮换使用的user-agent，为空时使用默认
+    pub user_agents: Vec<String>,
+    /// 附加的请求头，覆盖默认请求头
+    pub headers: HeaderMap,
+    /// 替换请求地址前缀，如: https://push2his.eastmoney.com -> http://127.0.0.1:8080
+    pub base_urls: HashMap<String, String>,
+    /// 传输模式，直接请求/录制/回放
+    pub mode: TransportMode,
+}
+
+impl FetchConfig {
+    pub fn new() -> Self {
+        Default::default()
+    }
+    /// 是否没有定制http请求，没有定制时使用`set_default_transport`设置的默认传输层
+    pub fn is_plain(&self) -> bool {
+        self.proxy.is_none()
+            && self.connect_timeout.is_none()
+            && self.read_timeout.is_none()
+            && self.user_agents.is_empty()
+            && self.headers.is_empty()
+            && self.base_urls.is_empty()
+    }
+    /// 根据配置生成传输层
+    pub fn transport(&self) -> Result<Arc<dyn Transport>> {
+        let http = || -> Result<Arc<dyn Transport>> {
+            if self.is_plain() {
+                Ok(default_transport())
+            } else {
+                Ok(Arc::new(HttpTransport::with_config(self)?))
+            }
+        };
+        let transport: Arc<dyn Transport> = match &self.mode {
+            TransportMode::Live => http()?,
+            TransportMode::Record(dir) => Arc::new(RecordTransport::new(http()?, dir)),
+            TransportMode::Replay(dir) => Arc::new(ReplayTransport::new(dir)),
+        };
+        Ok(transport)
+    }
+    /// 替换请求地址前缀
+    pub(crate) fn rewrite_url(&self, url: &str) -> Option<String> {
+        self.base_urls
+            .iter()
+            .filter(|(from, _)| url.starts_with(from.as_str()))
+            .max_by_key(|(from, _)| from.len())
+            .map(|(from, to)| format!("{}{}", to.trim_end_matches('/'), &url[from.len()..]))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::FetchConfig;
+    use crate::comm::default_transport;
+    use std::sync::Arc;
+    use std::time::Duration;
+
+    #[test]
+    fn test_rewrite_url() {
+        let mut config = FetchConfig::new();
+        config.base_urls.insert(
+            "https://push2his.eastmoney.com".to_owned(),
+            "http://127.0.0.1:8080/".to_owned(),
+        );
+        assert_eq!(
+            config
+                .rewrite_url("https://push2his.eastmoney.com/api/qt/stock/kline/get?secid=0.002805")
+                .unwrap(),
+            "http://127.0.0.1:8080/api/qt/stock/kline/get?secid=0.002805"
+        );
+        assert!(config.rewrite_url("https://xueqiu.com/hq").is_none());
+    }
+
+    #[test]
+    fn test_bad_proxy() {
+        let mut config = FetchConfig::new();
+        config.proxy = Some("not a proxy://".to_owned());
+        assert!(config.transport().is_err());
+    }
+
+    #[test]
+    fn test_plain_use_default_transport() {
+        let config = FetchConfig::new();
+        assert!(config.is_plain());
+        assert!(Arc::ptr_eq(
+            &config.transport().unwrap(),
+            &default_transport()
+        ));
+
+        let mut config = FetchConfig::new();
+        config.read_timeout = Some(Duration::from_secs(10));
+        assert!(!config.is_plain());
+        assert!(!Arc::ptr_eq(
+            &config.transport().unwrap(),
+            &default_transport()
+        ));
+    }
+}
@@ -0,0 +1,147 @@
+use crate::{Error, Result};
+use std::collections::HashMap;
+use std::future::Future;
+use std::sync::{Arc, RwLock};
+use std::time::{Duration, Instant};
+
+/// 熔断参数
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CircuitPolicy {
+    /// 连续失败多少次后熔断
+    pub failure_threshold: u32,
+    /// 熔断时长，过后允许再试一次
+    pub cooldown: Duration,
+}
+
+impl Default for CircuitPolicy {
+    fn default() -> Self {
+        Self {
+            failure_threshold: 3,
+            cooldown: Duration::from_secs(60),
+        }
+    }
+}
+
+/// 数据源健康状态
+#[derive(Debug, Clone, Default)]
+pub struct SourceHealth {
+    /// 成功次数
+    pub success: u64,
+    /// 失败次数
+    pub failure: u64,
+    /// 连续失败次数
+    pub consecutive_failures: u32,
+    /// 熔断截止时间
+    pub open_until: Option<Instant>,
+    /// 最近的错误
+    pub last_error: Option<String>,
+}
+
+impl SourceHealth {
+    /// 是否熔断中
+    pub fn is_open(&self) -> bool {
+        self.open_until.is_some_and(|t| Instant::now() < t)
+    }
+}
+
+struct Source<T: ?Sized> {
+    name: String,
+    fetch: Arc<T>,
+    health: RwLock<SourceHealth>,
+}
+
+/// 按顺序尝试多个数据源，失败时切换到下一个
+pub(crate) struct Failover<T: ?Sized> {
+    sources: Vec<Source<T>>,
+    policy: CircuitPolicy,
+    served: RwLock<HashMap<String, String>>,
+}
+
+impl<T: ?Sized> Failover<T> {
+    pub(crate) fn new(sources: Vec<(String, Arc<T>)>, policy: CircuitPolicy) -> Self {
+        Self {
+            sources: sources
+                .into_iter()
+                .map(|(name, fetch)| Source {
+                    name,
+                    fetch,
+                    health: RwLock::new(SourceHealth::default()),
+                })
+                .collect(),
+            policy,
+            served: RwLock::new(HashMap::new()),
+        }
+    }
+
+    /// 最近一次成功调用`method`的数据源，并发调用时只是其中一次的数据源
+    pub(crate) fn served_by(&self, method: &str) -> Option<String> {
+        self.served.read().unwrap().get(method).cloned()
+    }
+
+    /// 各数据源健康状态
+    pub(crate) fn health(&self) -> Vec<(String, SourceHealth)> {
+        self.sources
+            .iter()
+            .map(|s| (s.name.clone(), s.health.read().unwrap().clone()))
+            .collect()
+    }
+
+    /// 依次调用数据源，未实现的数据源跳过，不计入失败
+    pub(crate) async fn call<R, F, Fut>(&self, method: &str, f: F) -> Result<R>
+    where
+        F: Fn(Arc<T>) -> Fut,
+        Fut: Future<Output = Result<R>>,
+    {
+        self.call_with_source(method, f).await.map(|(data, _)| data)
+    }
+
+    /// 依次调用数据源，同时返回本次提供数据的数据源名称
+    pub(crate) async fn call_with_source<R, F, Fut>(
+        &self,
+        method: &str,
+        f: F,
+    ) -> Result<(R, String)>
+    where
+        F: Fn(Arc<T>) -> Fut,
+        Fut: Future<Output = Result<R>>,
+    {
+        let mut last_err = None;
+        for source in self.sources.iter() {
+            if source.health.read().unwrap().is_open() {
+                continue;
+            }
+            match f(source.fetch.clone()).await {
+                Ok(data) => {
+                    {
+                        let mut health = source.health.write().unwrap();
+                        health.success += 1;
+                        health.consecutive_failures = 0;
+                        health.open_until = None;
+                    }
+                    self.served
+                        .write()
+                        .unwrap()
+                        .insert(method.to_owned(), source.name.clone());
+                    return Ok((data, source.name.clone()));
+                }
+                Err(Error::NotImpl(name)) => {
+                    if last_err.is_none() {
+                        last_err = Some(Error::NotImpl(name));
+                    }
+                }
+                Err(e) => {
+                    let mut health = source.health.write().unwrap();
+                    health.failure += 1;
+                    health.consecutive_failures += 1;
+                    health.last_error = Some(e.to_string());
+                    if health.consecutive_failures >= self.policy.failure_threshold {
+                        health.open_until = Some(Instant::now() + self.policy.cooldown);
+                    }
+                    last_err = Some(e);
+                }
+            }
+        }
+        Err(last_err
+            .unwrap_or_else(|| Error::Custom(format!("No available source for \"{}\"", method))))
+    }
+}
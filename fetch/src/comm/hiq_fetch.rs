@@ -1,10 +1,15 @@
-use crate::comm::EastBar;
-use crate::{AdjustFactor, Result, HTTP_CMM_HEADER};
-use chrono::{Datelike, Duration, Local, NaiveDate, NaiveDateTime, Timelike};
-use hiq_common::{Bar, BarFreq};
+use crate::comm::{EastBar, EastTick, EastTrends, FetchConfig, Transport, XueQiuBar};
+use crate::util::to_security_id;
+use crate::{AdjustFactor, Error, Result, HTTP_CMM_HEADER};
+use chrono::{Datelike, Duration, Local, NaiveDate, NaiveDateTime, NaiveTime};
+use hiq_common::{
+    AdjustCalculator, AuctionSnapshot, Bar, BarFreq, Exchange, MinuteBar, OrderBook,
+    OrderBookLevel, SecurityId, Tick, TickDirection, TimeShare, TimeSharePoint,
+};
+use std::collections::HashMap;
 use std::ops::Add;
 
-use super::hiq_trade_date::fetch_prev_trade_date;
+use super::hiq_trade_date::{fetch_prev_trade_date, market_clock};
 
 // pub(crate) fn block_client() -> reqwest::blocking::Client {
 //     reqwest::blocking::ClientBuilder::new()
@@ -14,12 +19,22 @@ use super::hiq_trade_date::fetch_prev_trade_date;
 //         .unwrap()
 // }
 
-pub(crate) fn async_client() -> reqwest::Client {
-    reqwest::ClientBuilder::new()
+pub(crate) fn async_client(config: &FetchConfig) -> Result<reqwest::Client> {
+    let mut headers = HTTP_CMM_HEADER.to_owned();
+    headers.extend(config.headers.clone());
+    let mut builder = reqwest::ClientBuilder::new()
         .cookie_store(true)
-        .default_headers(HTTP_CMM_HEADER.to_owned())
-        .build()
-        .unwrap()
+        .default_headers(headers);
+    if let Some(proxy) = &config.proxy {
+        builder = builder.proxy(reqwest::Proxy::all(proxy)?);
+    }
+    if let Some(timeout) = config.connect_timeout {
+        builder = builder.connect_timeout(timeout);
+    }
+    if let Some(timeout) = config.read_timeout {
+        builder = builder.timeout(timeout);
+    }
+    Ok(builder.build()?)
 }
 
 pub(crate) fn to_bar_ds(name: Option<&str>, bars: Vec<Bar>) -> (String, Option<Vec<Bar>>) {
@@ -36,13 +51,24 @@ pub(crate) fn to_bar_ds(name: Option<&str>, bars: Vec<Bar>) -> (String, Option<V
     (stock_name, bars)
 }
 
+/// k线复权因子的计算方式
+pub(crate) enum BarAdjust<'a> {
+    /// 无需复权(指数、板块、可转债)，复权因子为1
+    None,
+    /// 额外请求后复权k线，复权因子 = 后复权收盘价 / 收盘价
+    Remote,
+    /// 根据除权除息事件本地计算，有开始日期时，区间前的累计复权因子取自前一交易日的后复权k线
+    Local(&'a AdjustCalculator),
+}
+
 pub(crate) async fn fetch_bar(
-    client: &reqwest::Client,
+    transport: &dyn Transport,
     market_code: &str,
     orig_code: &str,
     freq: BarFreq,
     start: Option<NaiveDate>,
     end: Option<NaiveDate>,
+    adjust: BarAdjust<'_>,
 ) -> Result<Vec<Bar>> {
     let mut first_date: Option<i32> = None;
     let mut start_str = "0".to_owned();
@@ -54,7 +80,7 @@ pub(crate) async fn fetch_bar(
 
     let mut n = Local::now().naive_local();
     // 当日的不准
-    if n.hour() < 15 && matches!(freq, BarFreq::Daily) {
+    if !market_clock(Exchange::SH).is_closed(&n) && matches!(freq, BarFreq::Daily) {
         let mut minus_day = true;
         if let Some(e) = end {
             if n.date() > e {
@@ -73,113 +99,594 @@ pub(crate) async fn fetch_bar(
             return Ok(data);
         }
     }
-    let end_str = n.format("%Y%m%d").to_string();
-    let kline_type = if matches!(freq, BarFreq::LooseDaily) {
-        BarFreq::Daily as i32
+    let end_str = end
+        .map_or(n.date(), |e| e.min(n.date()))
+        .format("%Y%m%d")
+        .to_string();
+    let fq_types = if matches!(adjust, BarAdjust::Remote) {
+        vec![AdjustFactor::NFQ, AdjustFactor::HFQ]
     } else {
-        freq as i32
+        vec![AdjustFactor::NFQ]
+    };
+    for fq_type in fq_types {
+        let bars = fetch_kline(
+            transport,
+            market_code,
+            orig_code,
+            freq,
+            fq_type,
+            &start_str,
+            &end_str,
+            None,
+        )
+        .await?;
+        if matches!(fq_type, AdjustFactor::NFQ) {
+            data.extend(bars);
+        } else if matches!(fq_type, AdjustFactor::HFQ) {
+            merge_hfq_factor(&mut data, &bars);
+        }
+    }
+    let date_of = |bar: &Bar| {
+        let (y, m, d) = (
+            bar.trade_date.year(),
+            bar.trade_date.month(),
+            bar.trade_date.day(),
+        );
+        y * 10000 + m as i32 * 100 + d as i32
     };
+    if let BarAdjust::Local(calc) = &adjust {
+        let base = first_date
+            .and_then(|first_date| data.iter().rposition(|bar| date_of(bar) <= first_date));
+        match base {
+            // 只请求前一交易日的后复权k线作为基准，不必从上市开始请求
+            Some(i) => {
+                let hfq_bars = fetch_kline(
+                    transport,
+                    market_code,
+                    orig_code,
+                    freq,
+                    AdjustFactor::HFQ,
+                    &start_str,
+                    &start_str,
+                    None,
+                )
+                .await?;
+                merge_hfq_factor(&mut data[i..=i], &hfq_bars);
+                let (head, tail) = data.split_at_mut(i + 1);
+                calc.apply_hfq(tail, head.last());
+            }
+            None => {
+                calc.apply_hfq(&mut data, None);
+            }
+        }
+    }
+    if let Some(first_date) = first_date {
+        data.retain(|bar| date_of(bar) > first_date);
+    }
+    Ok(data)
+}
 
-    for fq_type in vec![AdjustFactor::NFQ, AdjustFactor::HFQ] {
+/// 雪球日k线每次请求的条数
+const XUEQIU_BAR_PAGE_SIZE: usize = 100;
+
+/// 雪球日k线(港股、美股)，`kind`为normal(不复权)或after(后复权)，从`start`向后分页请求至`end`，
+/// 日期为交易所当地日期，复权因子均为1
+pub(crate) async fn fetch_xueqiu_bar(
+    transport: &dyn Transport,
+    id: &SecurityId,
+    name: &str,
+    kind: &str,
+    start: Option<NaiveDate>,
+    end: Option<NaiveDate>,
+) -> Result<Vec<Bar>> {
+    let exchange = id.exchange();
+    let mut begin = start.unwrap_or_else(|| NaiveDate::from_ymd_opt(1990, 1, 1).unwrap());
+    let end = end.unwrap_or_else(|| exchange.local_time(Local::now().timestamp_millis()).date());
+    let mut data: Vec<Bar> = Vec::new();
+    while begin <= end {
+        // 按UTC零点提前一天，避免时区差异漏掉首日
+        let epoch = NaiveDate::from_ymd_opt(1970, 1, 1).unwrap();
+        let timestamp = (begin - Duration::days(1) - epoch).num_seconds() * 1000;
         let req_url = format!(
-            "https://push2his.eastmoney.com/api/qt/stock/kline/get?\
-        fields1=f1%2Cf2%2Cf3%2Cf4%2Cf5%2Cf6&\
-        fields2=f51%2Cf52%2Cf53%2Cf54%2Cf55%2Cf56%2Cf57%2Cf58%2Cf59%2Cf60%2Cf61&\
-        ut=7eea3edcaed734bea9cbfc24409ed989&klt={kline_type}&fqt={fq_type}&secid={market_code}&\
-        beg={start}&end={end}&_=1667196199286",
-            kline_type = kline_type,
-            fq_type = fq_type as i32,
-            market_code = market_code,
-            start = start_str,
-            end = end_str
+            "https://stock.xueqiu.com/v5/stock/chart/kline.json?\
+        symbol={symbol}&begin={timestamp}&period=day&type={kind}&count={count}&indicator=kline",
+            symbol = id.to_xueqiu(),
+            timestamp = timestamp,
+            kind = kind,
+            count = XUEQIU_BAR_PAGE_SIZE
         );
-
-        let resp = client.get(req_url).send().await?.text().await?;
-
-        let mut pre_item: Option<Bar> = None;
-        let json: EastBar = serde_json::from_str(&resp)?;
-        let tmp_bars: Option<Vec<_>> = if let Some(data) = json.data {
-            let tmp_vec: Vec<_> = data
-                .klines
-                .iter()
-                .map(|item| {
-                    let s: Vec<&str> = item.split(",").collect();
-                    debug_assert!(s.len() == 11);
-                    let trade_date = &s.get(0).unwrap()[..];
-                    let trade_date = if matches!(freq, BarFreq::Daily)
-                        || matches!(freq, BarFreq::LooseDaily)
-                        || matches!(freq, BarFreq::Weekly)
-                        || matches!(freq, BarFreq::Monthly)
-                    {
-                        format!("{} 00:00:00", trade_date)
-                    } else {
-                        format!("{}:00", trade_date)
-                    };
-                    let volume = s.get(5).unwrap().parse().unwrap();
-                    let amount = s.get(6).unwrap().parse().unwrap();
-                    let (volume_chg_pct, amount_chg_pct) = if let Some(item) = &pre_item {
-                        (
-                            (((volume as i64 - item.volume as i64) * 100) as f64
-                                / item.volume as f64) as f32,
-                            ((amount - item.amount) * 100.0 / item.amount) as f32,
-                        )
-                    } else {
-                        (0.0, 0.0)
-                    };
-
-                    let bar = Bar {
-                        code: orig_code.to_owned(),
-                        name: data.name.to_owned(),
-                        trade_date: NaiveDateTime::parse_from_str(&trade_date, "%Y-%m-%d %H:%M:%S")
-                            .unwrap(),
-                        open: s.get(1).unwrap().parse().unwrap(),
-                        close: s.get(2).unwrap().parse().unwrap(),
-                        high: s.get(3).unwrap().parse().unwrap(),
-                        low: s.get(4).unwrap().parse().unwrap(),
-                        volume,
-                        amount,
-                        volume_chg_pct,
-                        amount_chg_pct,
-                        turnover: s.get(10).unwrap().parse().unwrap(),
-                        chg_pct: s.get(8).unwrap().parse().unwrap(),
-                        hfq_factor: 1.0,
-                    };
-                    pre_item = Some(bar.clone());
-                    bar
-                })
-                .collect();
-
-            Some(tmp_vec)
-        } else {
-            None
+        let resp = transport.get_text(&req_url, None).await?;
+        let json: XueQiuBar = serde_json::from_str(&resp)?;
+        let items = match json.data {
+            Some(data) => data.item,
+            None => break,
         };
-        if let Some(bars) = tmp_bars {
-            if matches!(fq_type, AdjustFactor::NFQ) {
-                data.extend(bars.into_iter());
-            } else if matches!(fq_type, AdjustFactor::HFQ) {
-                if data.len() == bars.len() {
-                    data.iter_mut()
-                        .zip(bars.iter())
-                        .for_each(|(t_data, t_bar)| {
-                            t_data.hfq_factor = t_bar.close / t_data.close;
-                        })
-                }
+        // ["timestamp","volume","open","high","low","close","chg","percent","turnoverrate","amount","volume_post","amount_post"]
+        let bars: Vec<_> = items
+            .iter()
+            .map(|item| Bar {
+                code: id.to_std(),
+                name: name.to_owned(),
+                trade_date: exchange
+                    .local_time(item.0)
+                    .date()
+                    .and_hms_opt(0, 0, 0)
+                    .unwrap(),
+                open: item.2.unwrap_or(0.0),
+                close: item.5.unwrap_or(0.0),
+                high: item.3.unwrap_or(0.0),
+                low: item.4.unwrap_or(0.0),
+                volume: item.1.unwrap_or(0),
+                amount: item.9.unwrap_or(0.0),
+                turnover: item.8.unwrap_or(0.0),
+                chg_pct: item.7.unwrap_or(0.0),
+                volume_chg_pct: 0.0,
+                amount_chg_pct: 0.0,
+                hfq_factor: 1.0,
+            })
+            .filter(|bar| bar.trade_date.date() >= begin && bar.trade_date.date() <= end)
+            .collect();
+        match bars.last() {
+            Some(last) => begin = last.trade_date.date() + Duration::days(1),
+            None => break,
+        }
+        data.extend(bars);
+    }
+    fix_chg_pct(&mut data);
+    Ok(data)
+}
+
+/// 腾讯行情每次请求的代码数
+const ORDER_BOOK_BATCH_SIZE: usize = 60;
+
+/// 五档盘口，`codes`为sz/sh/bj + 代码，股票、etf基金、可转债通用，代码较多时分批请求
+pub(crate) async fn fetch_order_book(
+    transport: &dyn Transport,
+    codes: &[&str],
+) -> Result<HashMap<String, OrderBook>> {
+    let mut data = HashMap::new();
+    for batch in codes.chunks(ORDER_BOOK_BATCH_SIZE) {
+        let codes = batch
+            .iter()
+            .map(|s| to_security_id(s).map(|id| id.to_tencent()))
+            .collect::<Result<Vec<_>>>()?
+            .join(",");
+        let req_url = format!("http://qt.gtimg.cn/q={}", codes);
+        let resp = transport.get_text(&req_url, None).await?;
+        data.extend(
+            parse_order_book(&resp)
+                .into_iter()
+                .map(|book| (book.code.clone(), book)),
+        );
+    }
+    Ok(data)
+}
+
+/// 解析腾讯行情，每行形如`v_sz000001="51~平安银行~000001~...";`，字段以~分隔，
+/// 无效代码(v_pv_none_match)或字段不全的忽略
+fn parse_order_book(text: &str) -> Vec<OrderBook> {
+    text.split(';')
+        .filter_map(|line| {
+            let (key, value) = line.trim().split_once('=')?;
+            let code = key.strip_prefix("v_")?.to_lowercase();
+            let s: Vec<&str> = value.trim_matches('"').split('~').collect();
+            if s.len() < 49 {
+                return None;
             }
+            let price = |i: usize| s[i].parse::<f32>().unwrap_or(0.0);
+            let volume = |i: usize| s[i].parse::<f64>().map_or(0, |v| v as u64);
+            let level = |i: usize| OrderBookLevel {
+                price: price(i),
+                volume: volume(i + 1),
+            };
+            let time = NaiveDateTime::parse_from_str(s[30], "%Y%m%d%H%M%S").ok()?;
+            Some(OrderBook {
+                code,
+                name: s[1].to_owned(),
+                time,
+                last: price(3),
+                last_close: price(4),
+                bids: (0..5).map(|i| level(9 + i * 2)).collect(),
+                asks: (0..5).map(|i| level(19 + i * 2)).collect(),
+                limit_up: price(47),
+                limit_down: price(48),
+                volume: volume(6),
+                outer_volume: volume(7),
+                inner_volume: volume(8),
+            })
+        })
+        .collect()
+}
+
+/// `now`时分笔成交所在的交易日，东方财富只提供最新交易日的分笔成交，当日开盘前为上一交易日
+pub(crate) async fn latest_tick_date_at(now: NaiveDateTime) -> Result<NaiveDate> {
+    let schedule = market_clock(Exchange::SH).schedule(&now.date());
+    match schedule {
+        Some(schedule) if now.time() >= schedule.open() => Ok(now.date()),
+        _ => {
+            let prev = fetch_prev_trade_date(&now.date()).await?;
+            Ok(NaiveDate::parse_from_str(&prev.to_string(), "%Y%m%d").unwrap())
         }
     }
-    if let Some(first_date) = first_date {
-        if data.len() > 0 {
-            let first = data.get(0).unwrap();
-            let (y, m, d) = (
-                first.trade_date.year(),
-                first.trade_date.month(),
-                first.trade_date.day(),
-            );
-            let date = y * 10000 + m as i32 * 100 + d as i32;
-            if first_date == date {
-                data = data.into_iter().skip(1).collect();
+}
+
+/// 分笔成交，`market_code`为东方财富secid，只支持最新交易日
+pub(crate) async fn fetch_ticks(
+    transport: &dyn Transport,
+    market_code: &str,
+    orig_code: &str,
+    date: NaiveDate,
+) -> Result<Vec<Tick>> {
+    fetch_ticks_at(
+        transport,
+        market_code,
+        orig_code,
+        date,
+        Local::now().naive_local(),
+    )
+    .await
+}
+
+/// 以`now`为当前时刻获取分笔成交
+pub(crate) async fn fetch_ticks_at(
+    transport: &dyn Transport,
+    market_code: &str,
+    orig_code: &str,
+    date: NaiveDate,
+    now: NaiveDateTime,
+) -> Result<Vec<Tick>> {
+    let latest = latest_tick_date_at(now).await?;
+    if date != latest {
+        return Err(Error::Custom(format!(
+            "ticks of {} is not available, only latest trade date {}",
+            date, latest
+        )));
+    }
+    let req_url = format!(
+        "https://push2.eastmoney.com/api/qt/stock/details/get?\
+    fields1=f1%2Cf2%2Cf3%2Cf4&fields2=f51%2Cf52%2Cf53%2Cf54%2Cf55&\
+    ut=bd1d9ddb04089700cf9c27f6f7426281&fltt=2&pos=-0&secid={market_code}",
+        market_code = market_code
+    );
+    let resp = transport.get_text(&req_url, None).await?;
+    parse_ticks(orig_code, date, &resp)
+}
+
+/// 解析东方财富分笔成交，每条形如`09:25:00,11.50,1234,56,4`，
+/// 依次为时间、价格、成交量(手)、成交笔数、方向(1卖 2买 4中性)
+fn parse_ticks(code: &str, date: NaiveDate, text: &str) -> Result<Vec<Tick>> {
+    let json: EastTick = serde_json::from_str(text)?;
+    let trade_date = date.and_hms_opt(0, 0, 0).unwrap();
+    let details = json.data.map(|data| data.details).unwrap_or_default();
+    Ok(details
+        .iter()
+        .filter_map(|item| {
+            let s: Vec<&str> = item.split(',').collect();
+            if s.len() < 5 {
+                return None;
+            }
+            let time = NaiveTime::parse_from_str(s[0], "%H:%M:%S").ok()?;
+            let direction = match s[4] {
+                "1" => TickDirection::Sell,
+                "2" => TickDirection::Buy,
+                _ => TickDirection::Neutral,
+            };
+            Some(Tick {
+                code: code.to_owned(),
+                trade_date,
+                time: date.and_time(time),
+                price: s[1].parse().ok()?,
+                volume: s[2].parse().ok()?,
+                direction,
+            })
+        })
+        .collect())
+}
+
+/// 最新交易日分时走势，`market_code`为东方财富secid，不含集合竞价
+pub(crate) async fn fetch_time_share(
+    transport: &dyn Transport,
+    market_code: &str,
+    orig_code: &str,
+) -> Result<TimeShare> {
+    let req_url = format!(
+        "https://push2.eastmoney.com/api/qt/stock/trends2/get?\
+    fields1=f1%2Cf2%2Cf3%2Cf4%2Cf5%2Cf6%2Cf7%2Cf8%2Cf9%2Cf10%2Cf11%2Cf12%2Cf13&\
+    fields2=f51%2Cf52%2Cf53%2Cf54%2Cf55%2Cf56%2Cf57%2Cf58&\
+    ut=fa5fd1943c7b386f172d6893dbfba10b&iscr=0&ndays=1&secid={market_code}",
+        market_code = market_code
+    );
+    let resp = transport.get_text(&req_url, None).await?;
+    parse_time_share(orig_code, &resp)
+}
+
+/// 解析东方财富分时，每条形如`2023-06-15 09:31,11.46,11.47,11.48,11.45,12345,14159000.00,11.470`，
+/// 依次为时间、开、收(价格)、高、低、成交量(手)、成交额、均价
+fn parse_time_share(code: &str, text: &str) -> Result<TimeShare> {
+    let json: EastTrends = serde_json::from_str(text)?;
+    let data = json
+        .data
+        .ok_or_else(|| Error::Custom(format!("Error fetch time share of {}", code)))?;
+    let points = data
+        .trends
+        .iter()
+        .filter_map(|item| {
+            let s: Vec<&str> = item.split(',').collect();
+            if s.len() < 8 {
+                return None;
             }
+            Some(TimeSharePoint {
+                time: NaiveDateTime::parse_from_str(s[0], "%Y-%m-%d %H:%M").ok()?,
+                price: s[2].parse().ok()?,
+                avg_price: s[7].parse().ok()?,
+                volume: s[5].parse().ok()?,
+                amount: s[6].parse().ok()?,
+            })
+        })
+        .collect();
+    Ok(TimeShare {
+        code: code.to_owned(),
+        name: data.name.to_owned(),
+        last_close: data.pre_close,
+        points,
+    })
+}
+
+/// 最新交易日集合竞价(9:15-9:25)每分钟的虚拟匹配，`market_code`为东方财富secid
+pub(crate) async fn fetch_auction(
+    transport: &dyn Transport,
+    market_code: &str,
+    orig_code: &str,
+) -> Result<Vec<AuctionSnapshot>> {
+    let req_url = format!(
+        "https://push2.eastmoney.com/api/qt/stock/trends2/get?\
+    fields1=f1%2Cf2%2Cf3%2Cf4%2Cf5%2Cf6%2Cf7%2Cf8%2Cf9%2Cf10%2Cf11%2Cf12%2Cf13&\
+    fields2=f51%2Cf52%2Cf53%2Cf54%2Cf55%2Cf56%2Cf57%2Cf58&\
+    ut=fa5fd1943c7b386f172d6893dbfba10b&iscr=1&ndays=1&secid={market_code}",
+        market_code = market_code
+    );
+    let resp = transport.get_text(&req_url, None).await?;
+    parse_auction(orig_code, &resp)
+}
+
+/// 分时含集合竞价(iscr=1)时，9:15-9:25的数据点为集合竞价每分钟的虚拟匹配价及匹配量，
+/// 分时不含未匹配量
+fn parse_auction(code: &str, text: &str) -> Result<Vec<AuctionSnapshot>> {
+    let (start, end) = (
+        NaiveTime::from_hms_opt(9, 15, 0).unwrap(),
+        NaiveTime::from_hms_opt(9, 25, 0).unwrap(),
+    );
+    let share = parse_time_share(code, text)?;
+    Ok(share
+        .points
+        .into_iter()
+        .filter(|p| p.time.time() >= start && p.time.time() <= end)
+        .map(|p| AuctionSnapshot {
+            code: code.to_owned(),
+            trade_date: p.time.date().and_hms_opt(0, 0, 0).unwrap(),
+            time: p.time,
+            price: p.price,
+            volume: p.volume,
+            amount: p.amount,
+            unmatched_volume: None,
+        })
+        .collect())
+}
+
+/// 分钟k线每页请求条数
+pub(crate) const MINUTE_BAR_PAGE_SIZE: usize = 2000;
+/// 分钟k线最多请求页数
+pub(crate) const MINUTE_BAR_MAX_PAGE: usize = 100;
+
+/// 从`end`开始向前分页获取分钟k线，合并去重后按时间升序返回。
+///
+/// 每页返回截止某日的最后若干条，页中最早的交易日可能不完整，丢弃后以该日作为下一页的截止日，
+/// 直至早于`start`、上游无更早数据或达到最大页数。
+/// 实际覆盖的范围以返回数据的首尾为准，上游只保留近期分钟数据时可能晚于`start`。
+/// `page_size`至少为两个交易日的k线数，保证每页都有完整的交易日。
+#[allow(clippy::too_many_arguments)]
+pub(crate) async fn fetch_minute_bar(
+    transport: &dyn Transport,
+    market_code: &str,
+    orig_code: &str,
+    freq: BarFreq,
+    start: Option<NaiveDate>,
+    end: Option<NaiveDate>,
+    remote_hfq: bool,
+    page_size: usize,
+) -> Result<Vec<Bar>> {
+    if !freq.is_minute() {
+        return Err(Error::Custom(format!("{:?} is not minute freq", freq)));
+    }
+    let page_size = page_size.max(freq.bars_per_day() * 2);
+    let mut page_end = end.unwrap_or_else(|| Local::now().date_naive());
+    let mut data: Vec<Bar> = Vec::new();
+    for _ in 0..MINUTE_BAR_MAX_PAGE {
+        if start.is_some_and(|s| page_end < s) {
+            break;
+        }
+        let end_str = page_end.format("%Y%m%d").to_string();
+        let mut bars = fetch_kline(
+            transport,
+            market_code,
+            orig_code,
+            freq,
+            AdjustFactor::NFQ,
+            "0",
+            &end_str,
+            Some(page_size),
+        )
+        .await?;
+        if remote_hfq && !bars.is_empty() {
+            let hfq_bars = fetch_kline(
+                transport,
+                market_code,
+                orig_code,
+                freq,
+                AdjustFactor::HFQ,
+                "0",
+                &end_str,
+                Some(page_size),
+            )
+            .await?;
+            merge_hfq_factor(&mut bars, &hfq_bars);
+        }
+        let first_date = match bars.first() {
+            Some(bar) => bar.trade_date.date(),
+            None => break,
+        };
+        let exhausted = bars.len() < page_size;
+        if !exhausted {
+            // 最早的交易日可能不完整，由下一页补全
+            bars.retain(|bar| bar.trade_date.date() > first_date);
+        }
+        data.extend(bars);
+        if exhausted || first_date >= page_end {
+            break;
         }
+        page_end = first_date;
     }
+    data.sort_by_key(|bar| bar.trade_date);
+    data.dedup_by_key(|bar| bar.trade_date);
+    data.retain(|bar| {
+        let date = bar.trade_date.date();
+        start.is_none_or(|s| date >= s) && end.is_none_or(|e| date <= e)
+    });
+    fix_chg_pct(&mut data);
     Ok(data)
 }
+
+pub(crate) fn to_minute_bar(
+    code: &str,
+    name: Option<&str>,
+    freq: BarFreq,
+    bars: Vec<Bar>,
+) -> MinuteBar {
+    let start = bars.first().map(|bar| bar.trade_date);
+    let end = bars.last().map(|bar| bar.trade_date);
+    let (name, bars) = to_bar_ds(name, bars);
+    MinuteBar {
+        code: code.to_owned(),
+        name,
+        freq,
+        start,
+        end,
+        bars,
+    }
+}
+
+/// 分页或分段请求时首根k线没有前值，合并后重新计算成交量及成交额的变化
+fn fix_chg_pct(data: &mut [Bar]) {
+    for i in 1..data.len() {
+        let (head, tail) = data.split_at_mut(i);
+        tail[0].calc_chg_pct(&head[i - 1]);
+    }
+}
+
+/// 请求东方财富k线，`lmt`不为None时返回截止`end`的最后`lmt`条
+#[allow(clippy::too_many_arguments)]
+async fn fetch_kline(
+    transport: &dyn Transport,
+    market_code: &str,
+    orig_code: &str,
+    freq: BarFreq,
+    fq_type: AdjustFactor,
+    beg: &str,
+    end: &str,
+    lmt: Option<usize>,
+) -> Result<Vec<Bar>> {
+    let kline_type = if matches!(freq, BarFreq::LooseDaily) {
+        BarFreq::Daily as i32
+    } else {
+        freq as i32
+    };
+    // 返回截止end的最后lmt条
+    let lmt = lmt.map(|l| format!("&lmt={}", l)).unwrap_or_default();
+    let req_url = format!(
+        "https://push2his.eastmoney.com/api/qt/stock/kline/get?\
+    fields1=f1%2Cf2%2Cf3%2Cf4%2Cf5%2Cf6&\
+    fields2=f51%2Cf52%2Cf53%2Cf54%2Cf55%2Cf56%2Cf57%2Cf58%2Cf59%2Cf60%2Cf61&\
+    ut=7eea3edcaed734bea9cbfc24409ed989&klt={kline_type}&fqt={fq_type}&secid={market_code}&\
+    beg={start}&end={end}{lmt}&_=1667196199286",
+        kline_type = kline_type,
+        fq_type = fq_type as i32,
+        market_code = market_code,
+        start = beg,
+        end = end,
+        lmt = lmt
+    );
+
+    let resp = transport.get_text(&req_url, None).await?;
+
+    let mut pre_item: Option<Bar> = None;
+    let json: EastBar = serde_json::from_str(&resp)?;
+    let tmp_bars: Option<Vec<_>> = if let Some(data) = json.data {
+        let tmp_vec: Vec<_> = data
+            .klines
+            .iter()
+            .map(|item| {
+                let s: Vec<&str> = item.split(",").collect();
+                debug_assert!(s.len() == 11);
+                let trade_date = s[0];
+                let trade_date = if matches!(freq, BarFreq::Daily)
+                    || matches!(freq, BarFreq::LooseDaily)
+                    || matches!(freq, BarFreq::Weekly)
+                    || matches!(freq, BarFreq::Monthly)
+                {
+                    format!("{} 00:00:00", trade_date)
+                } else {
+                    format!("{}:00", trade_date)
+                };
+                let volume = s.get(5).unwrap().parse().unwrap();
+                let amount = s.get(6).unwrap().parse().unwrap();
+                let (volume_chg_pct, amount_chg_pct) = if let Some(item) = &pre_item {
+                    (
+                        (((volume as i64 - item.volume as i64) * 100) as f64 / item.volume as f64)
+                            as f32,
+                        ((amount - item.amount) * 100.0 / item.amount) as f32,
+                    )
+                } else {
+                    (0.0, 0.0)
+                };
+
+                let bar = Bar {
+                    code: orig_code.to_owned(),
+                    name: data.name.to_owned(),
+                    trade_date: NaiveDateTime::parse_from_str(&trade_date, "%Y-%m-%d %H:%M:%S")
+                        .unwrap(),
+                    open: s.get(1).unwrap().parse().unwrap(),
+                    close: s.get(2).unwrap().parse().unwrap(),
+                    high: s.get(3).unwrap().parse().unwrap(),
+                    low: s.get(4).unwrap().parse().unwrap(),
+                    volume,
+                    amount,
+                    volume_chg_pct,
+                    amount_chg_pct,
+                    turnover: s.get(10).unwrap().parse().unwrap(),
+                    chg_pct: s.get(8).unwrap().parse().unwrap(),
+                    hfq_factor: 1.0,
+                };
+                pre_item = Some(bar.clone());
+                bar
+            })
+            .collect();
+
+        Some(tmp_vec)
+    } else {
+        None
+    };
+    Ok(tmp_bars.unwrap_or_default())
+}
+
+/// 按交易时间对应，避免两次请求的数据条数不一致时错位
+pub(crate) fn merge_hfq_factor(data: &mut [Bar], hfq_bars: &[Bar]) {
+    let hfq_close: HashMap<_, _> = hfq_bars
+        .iter()
+        .map(|bar| (bar.trade_date, bar.close))
+        .collect();
+    data.iter_mut().for_each(|t_data| {
+        if let Some(close) = hfq_close.get(&t_data.trade_date) {
+            t_data.hfq_factor = close / t_data.close;
+        }
+    });
+}
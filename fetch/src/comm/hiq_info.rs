@@ -16,6 +16,34 @@ pub(crate) struct EastBarData<'a> {
     pub klines: Vec<&'a str>,
 }
 
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub(crate) struct EastTick<'a> {
+    #[serde(borrow)]
+    pub data: Option<EastTickData<'a>>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub(crate) struct EastTickData<'a> {
+    #[serde(borrow)]
+    pub details: Vec<&'a str>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub(crate) struct EastTrends<'a> {
+    #[serde(borrow)]
+    pub data: Option<EastTrendsData<'a>>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub(crate) struct EastTrendsData<'a> {
+    #[serde(borrow)]
+    pub name: &'a str,
+    #[serde(rename = "preClose")]
+    pub pre_close: f32,
+    #[serde(borrow)]
+    pub trends: Vec<&'a str>,
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub(crate) struct XueQiuBar<'a> {
     #[serde(borrow)]
@@ -0,0 +1,237 @@
+use crate::{Error, Result};
+use once_cell::sync::Lazy;
+use std::collections::HashMap;
+use std::sync::{Arc, RwLock};
+use std::time::Duration;
+use tokio::sync::{Mutex, OwnedSemaphorePermit, Semaphore};
+use tokio::time::Instant;
+
+/// 单个host的限流参数
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct RateLimit {
+    /// 每秒请求数
+    pub per_second: f64,
+    /// 最大并发请求数
+    pub max_in_flight: usize,
+}
+
+impl RateLimit {
+    pub fn new(per_second: f64, max_in_flight: usize) -> Self {
+        Self {
+            per_second,
+            max_in_flight,
+        }
+    }
+    /// 校验参数，每秒请求数须为有限正数且换算出的请求间隔可表示，最大并发数须大于0
+    pub fn validate(&self) -> Result<()> {
+        if !self.per_second.is_finite() || self.per_second <= 0.0 {
+            return Err(Error::Custom(format!(
+                "Invalid rate limit per_second: {}",
+                self.per_second
+            )));
+        }
+        Duration::try_from_secs_f64(1.0 / self.per_second).map_err(|e| {
+            Error::Custom(format!(
+                "Invalid rate limit per_second: {}, {}",
+                self.per_second, e
+            ))
+        })?;
+        if self.max_in_flight == 0 {
+            return Err(Error::Custom(
+                "Invalid rate limit max_in_flight: 0".to_string(),
+            ));
+        }
+        Ok(())
+    }
+}
+
+impl Default for RateLimit {
+    fn default() -> Self {
+        Self::new(5.0, 4)
+    }
+}
+
+struct HostLimiter {
+    interval: Duration,
+    next: Mutex<Instant>,
+    in_flight: Arc<Semaphore>,
+}
+
+impl HostLimiter {
+    fn new(limit: RateLimit) -> Self {
+        // 参数已校验，这里不会失败
+        let interval = Duration::try_from_secs_f64(1.0 / limit.per_second).unwrap_or_default();
+        Self {
+            interval,
+            next: Mutex::new(Instant::now()),
+            in_flight: Arc::new(Semaphore::new(limit.max_in_flight.max(1))),
+        }
+    }
+
+    async fn acquire(&self) -> OwnedSemaphorePermit {
+        let permit = self.in_flight.clone().acquire_owned().await.unwrap();
+        let mut next = self.next.lock().await;
+        let now = Instant::now();
+        if *next > now {
+            tokio::time::sleep_until(*next).await;
+        }
+        *next = now.max(*next) + self.interval;
+        permit
+    }
+}
+
+/// 按host限流，限制每秒请求数及并发数
+pub struct RateLimiter {
+    default: RwLock<RateLimit>,
+    limits: RwLock<HashMap<String, RateLimit>>,
+    hosts: RwLock<HashMap<String, Arc<HostLimiter>>>,
+}
+
+/// 限流许可，drop后释放并发数
+pub struct RatePermit {
+    _permit: OwnedSemaphorePermit,
+}
+
+impl RateLimiter {
+    pub fn new(default: RateLimit) -> Self {
+        Self {
+            default: RwLock::new(default),
+            limits: RwLock::new(HashMap::new()),
+            hosts: RwLock::new(HashMap::new()),
+        }
+    }
+    /// 设置host的限流参数，参数不合法时返回错误
+    pub fn set_limit(&self, host: &str, limit: RateLimit) -> Result<()> {
+        limit.validate()?;
+        self.limits.write().unwrap().insert(host.to_owned(), limit);
+        self.hosts.write().unwrap().remove(host);
+        Ok(())
+    }
+    /// 设置未单独配置host的限流参数，参数不合法时返回错误
+    pub fn set_default_limit(&self, limit: RateLimit) -> Result<()> {
+        limit.validate()?;
+        *self.default.write().unwrap() = limit;
+        let limits = self.limits.read().unwrap();
+        self.hosts
+            .write()
+            .unwrap()
+            .retain(|host, _| limits.contains_key(host));
+        Ok(())
+    }
+    /// host的限流参数
+    pub fn limit(&self, host: &str) -> RateLimit {
+        self.limits
+            .read()
+            .unwrap()
+            .get(host)
+            .copied()
+            .unwrap_or_else(|| *self.default.read().unwrap())
+    }
+    /// 请求前获取许可，超出限制时等待
+    pub async fn acquire(&self, host: &str) -> RatePermit {
+        let limiter = self.host_limiter(host);
+        RatePermit {
+            _permit: limiter.acquire().await,
+        }
+    }
+
+    fn host_limiter(&self, host: &str) -> Arc<HostLimiter> {
+        if let Some(limiter) = self.hosts.read().unwrap().get(host) {
+            return limiter.clone();
+        }
+        let limit = self.limit(host);
+        self.hosts
+            .write()
+            .unwrap()
+            .entry(host.to_owned())
+            .or_insert_with(|| Arc::new(HostLimiter::new(limit)))
+            .clone()
+    }
+}
+
+static RATE_LIMITER: Lazy<RateLimiter> = Lazy::new(|| {
+    let limiter = RateLimiter::new(RateLimit::default());
+    for (host, limit) in [
+        ("push2his.eastmoney.com", RateLimit::new(5.0, 4)),
+        ("push2.eastmoney.com", RateLimit::new(5.0, 4)),
+        ("datacenter-web.eastmoney.com", RateLimit::new(3.0, 2)),
+        ("xueqiu.com", RateLimit::new(2.0, 2)),
+        ("stock.xueqiu.com", RateLimit::new(3.0, 2)),
+    ] {
+        limiter.set_limit(host, limit).unwrap();
+    }
+    limiter
+});
+
+/// 全局限流器，所有远程请求共享
+pub fn rate_limiter() -> &'static RateLimiter {
+    &RATE_LIMITER
+}
+
+/// 设置全局限流器中host的限流参数，参数不合法时返回错误
+pub fn set_rate_limit(host: &str, limit: RateLimit) -> Result<()> {
+    RATE_LIMITER.set_limit(host, limit)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    #[test]
+    fn test_rate_limit() {
+        tokio::runtime::Runtime::new().unwrap().block_on(async {
+            let limiter = RateLimiter::new(RateLimit::new(20.0, 10));
+            let start = Instant::now();
+            for _ in 0..5 {
+                limiter.acquire("a.com").await;
+            }
+            // 第一个请求不等待
+            assert!(start.elapsed() >= Duration::from_millis(190));
+
+            let start = Instant::now();
+            limiter.acquire("b.com").await;
+            assert!(start.elapsed() < Duration::from_millis(40));
+        });
+    }
+
+    #[test]
+    fn test_invalid_rate_limit() {
+        let limiter = RateLimiter::new(RateLimit::default());
+        for per_second in [0.0, -1.0, 1e-300, f64::NAN, f64::INFINITY] {
+            assert!(limiter
+                .set_limit("a.com", RateLimit::new(per_second, 1))
+                .is_err());
+        }
+        assert!(limiter.set_limit("a.com", RateLimit::new(1.0, 0)).is_err());
+        assert!(limiter.set_default_limit(RateLimit::new(0.0, 1)).is_err());
+        assert_eq!(limiter.limit("a.com"), RateLimit::default());
+        assert!(limiter.set_limit("a.com", RateLimit::new(0.5, 1)).is_ok());
+    }
+
+    #[test]
+    fn test_max_in_flight() {
+        tokio::runtime::Runtime::new().unwrap().block_on(async {
+            let limiter = Arc::new(RateLimiter::new(RateLimit::new(1000.0, 2)));
+            let running = Arc::new(AtomicUsize::new(0));
+            let max_running = Arc::new(AtomicUsize::new(0));
+            let tasks: Vec<_> = (0..6)
+                .map(|_| {
+                    let (limiter, running, max_running) =
+                        (limiter.clone(), running.clone(), max_running.clone());
+                    tokio::spawn(async move {
+                        let _permit = limiter.acquire("a.com").await;
+                        let n = running.fetch_add(1, Ordering::SeqCst) + 1;
+                        max_running.fetch_max(n, Ordering::SeqCst);
+                        tokio::time::sleep(Duration::from_millis(20)).await;
+                        running.fetch_sub(1, Ordering::SeqCst);
+                    })
+                })
+                .collect();
+            for task in tasks {
+                task.await.unwrap();
+            }
+            assert_eq!(max_running.load(Ordering::SeqCst), 2);
+        });
+    }
+}
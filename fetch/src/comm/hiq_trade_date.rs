@@ -1,411 +1,355 @@
-use crate::comm::async_client;
+use crate::comm::FetchConfig;
 use crate::{Error, Result};
-use chrono::{Duration, NaiveDate};
-use js_sandbox::Script;
+use chrono::{Datelike, Duration, NaiveDate, Weekday};
+use hiq_common::{Exchange, MarketClock, TradeCalendar};
 use once_cell::sync::Lazy;
 use std::collections::BTreeSet;
-use std::ops::Add;
+use std::path::Path;
 use std::sync::RwLock;
 
-static CACHE_TRADE_DATE: Lazy<RwLock<BTreeSet<i32>>> = Lazy::new(|| {
-    let set: BTreeSet<i32> = BTreeSet::new();
-    RwLock::new(set)
-});
+/// 内置的交易日历，无网络时使用
+const BUNDLED_TRADE_DATE: &str = include_str!("../../data/trade_date.txt");
+
+/// 内置的港股、美股休市及半日交易安排
+const BUNDLED_HOLIDAY_HK: &str = include_str!("../../data/holiday_hk.txt");
+const BUNDLED_HOLIDAY_US: &str = include_str!("../../data/holiday_us.txt");
+
+static HOLIDAY_HK: Lazy<(TradeCalendar, BTreeSet<i32>)> =
+    Lazy::new(|| parse_holiday(BUNDLED_HOLIDAY_HK).unwrap());
+static HOLIDAY_US: Lazy<(TradeCalendar, BTreeSet<i32>)> =
+    Lazy::new(|| parse_holiday(BUNDLED_HOLIDAY_US).unwrap());
+
+static CACHE_TRADE_DATE: Lazy<RwLock<TradeCalendar>> =
+    Lazy::new(|| RwLock::new(TradeCalendar::from_i32(&bundled_trade_date())));
+
+/// 内置的交易日历
+pub fn bundled_trade_date() -> BTreeSet<i32> {
+    parse_trade_date(BUNDLED_TRADE_DATE).unwrap()
+}
 
 /// 获取全量交易日数据，获取数据后，进行缓存
 pub async fn fetch_trade_date() -> Result<BTreeSet<i32>> {
-    let client = async_client();
+    fetch_trade_date_with(&FetchConfig::default()).await
+}
 
-    let resp = client
-        .get("https://finance.sina.com.cn/realstock/company/klc_td_sh.txt")
-        .send()
-        .await?
-        .text()
+/// 按配置(代理、超时等)获取全量交易日数据，获取数据后，进行缓存
+pub async fn fetch_trade_date_with(config: &FetchConfig) -> Result<BTreeSet<i32>> {
+    let resp = config
+        .transport()?
+        .get_text(
+            "https://finance.sina.com.cn/realstock/company/klc_td_sh.txt",
+            None,
+        )
         .await?;
-    let js_code = format!("{}{}", resp, JS_CODE);
-    let mut js_script = Script::from_string(&js_code)
-        .map_err(|e| Error::Custom(format!("Load js code error: {}!", e.to_string())))?;
-    let data: BTreeSet<i32> = js_script
-        .call("get_trade_date", &())
-        .map_err(|e| Error::Custom(format!("Call js function error:{}!", e.to_string())))?;
+    let data = decode_trade_date(&resp)?;
+
+    {
+        let mut cache = CACHE_TRADE_DATE.write().unwrap();
+        *cache = TradeCalendar::from_i32(&data);
+    }
+
+    Ok(data)
+}
+
+/// 从文件加载交易日历，并替换缓存
+pub fn load_trade_date_file<P: AsRef<Path>>(path: P) -> Result<BTreeSet<i32>> {
+    let path = path.as_ref();
+    let text = std::fs::read_to_string(path).map_err(|e| {
+        Error::Custom(format!(
+            "Read trade date file {} error: {}!",
+            path.display(),
+            e
+        ))
+    })?;
+    let data = parse_trade_date(&text)?;
 
     {
         let mut cache = CACHE_TRADE_DATE.write().unwrap();
-        *cache = data.clone();
+        *cache = TradeCalendar::from_i32(&data);
     }
 
     Ok(data)
 }
 
-/// 获取某交易日后的第一个交易日
+/// 获取最新的交易日历，并写入文件，之后可用`load_trade_date_file`离线加载
+pub async fn update_trade_date_file<P: AsRef<Path>>(path: P) -> Result<BTreeSet<i32>> {
+    let path = path.as_ref();
+    let data = fetch_trade_date().await?;
+    let text: String = data.iter().map(|d| format!("{}\n", d)).collect();
+    std::fs::write(path, text).map_err(|e| {
+        Error::Custom(format!(
+            "Write trade date file {} error: {}!",
+            path.display(),
+            e
+        ))
+    })?;
+    Ok(data)
+}
+
+/// 缓存的交易日历，未获取远程数据时为内置或文件加载的交易日历
+pub fn trade_calendar() -> TradeCalendar {
+    CACHE_TRADE_DATE.read().unwrap().clone()
+}
+
+/// 获取某交易日后的第一个交易日，缓存中没有时才请求远程，
+/// 远程也没有(交易所未公布休市安排)时与`market_clock`一样按工作日推算
 pub async fn fetch_next_trade_date(date: &NaiveDate) -> Result<i32> {
-    let mut mx_date = {
-        let cache = CACHE_TRADE_DATE.read().unwrap();
-        let elm = cache.iter().next_back();
-        elm.map_or(0, |e| *e)
+    if let Some(next) = trade_calendar().next(date) {
+        return Ok(date_to_i32(&next));
+    }
+    if let Err(e) = fetch_trade_date().await {
+        log::warn!("fetch trade date error: {}, use weekday instead", e);
+    }
+    let next = trade_calendar().next(date).unwrap_or_else(|| {
+        let mut next = date.succ_opt().unwrap();
+        while matches!(next.weekday(), Weekday::Sat | Weekday::Sun) {
+            next = next.succ_opt().unwrap();
+        }
+        next
+    });
+    Ok(date_to_i32(&next))
+}
+
+/// 获取某交易日前的第一个交易日，缓存中没有时才请求远程，开市日(1990-12-19)及之前没有交易日
+pub async fn fetch_prev_trade_date(date: &NaiveDate) -> Result<i32> {
+    if let Some(prev) = trade_calendar().prev(date) {
+        return Ok(date_to_i32(&prev));
+    }
+    if *date <= NaiveDate::from_ymd_opt(1990, 12, 19).unwrap() {
+        return Err(Error::Custom("date is to old ...".to_string()));
+    }
+    fetch_trade_date().await?;
+    trade_calendar()
+        .prev(date)
+        .map(|d| date_to_i32(&d))
+        .ok_or_else(|| Error::Custom("date is to old ...".to_string()))
+}
+
+/// 交易时钟，A股使用缓存的交易日历，港股、美股使用内置的休市及半日交易安排，日历之后按工作日判断
+pub fn market_clock(exchange: Exchange) -> MarketClock {
+    let clock = MarketClock::new(exchange);
+    match exchange {
+        Exchange::SH | Exchange::SZ | Exchange::BJ => clock.with_calendar(trade_calendar()),
+        Exchange::HK => clock
+            .with_calendar(HOLIDAY_HK.0.clone())
+            .with_half_days(HOLIDAY_HK.1.clone()),
+        Exchange::US => clock
+            .with_calendar(HOLIDAY_US.0.clone())
+            .with_half_days(HOLIDAY_US.1.clone()),
+    }
+}
+
+fn date_to_i32(date: &NaiveDate) -> i32 {
+    date.year() * 10000 + date.month() as i32 * 100 + date.day() as i32
+}
+
+/// 解析交易日历文件，每行一个交易日(YYYYMMDD)，忽略空行及#开头的注释
+fn parse_trade_date(text: &str) -> Result<BTreeSet<i32>> {
+    text.lines()
+        .map(|line| line.trim())
+        .filter(|line| !line.is_empty() && !line.starts_with('#'))
+        .map(|line| {
+            NaiveDate::parse_from_str(line, "%Y%m%d")
+                .map(|d| date_to_i32(&d))
+                .map_err(|e| Error::Custom(format!("Invalid trade date {}: {}!", line, e)))
+        })
+        .collect()
+}
+
+/// 解析休市安排文件，每行一个工作日(YYYYMMDD)，其后为half表示半日交易，否则为休市，
+/// 返回所覆盖年份内的交易日历及半日交易日
+fn parse_holiday(text: &str) -> Result<(TradeCalendar, BTreeSet<i32>)> {
+    let mut closed = BTreeSet::new();
+    let mut half_days = BTreeSet::new();
+    for line in text
+        .lines()
+        .map(|line| line.trim())
+        .filter(|line| !line.is_empty() && !line.starts_with('#'))
+    {
+        let mut fields = line.split_whitespace();
+        let date = fields.next().unwrap_or_default();
+        let date = NaiveDate::parse_from_str(date, "%Y%m%d")
+            .map_err(|e| Error::Custom(format!("Invalid holiday {}: {}!", line, e)))?;
+        match fields.next() {
+            Some("half") => half_days.insert(date_to_i32(&date)),
+            None => closed.insert(date),
+            Some(_) => return Err(Error::Custom(format!("Invalid holiday {}!", line))),
+        };
+    }
+    let (first, last) = match (closed.first(), closed.last()) {
+        (Some(first), Some(last)) => (
+            NaiveDate::from_ymd_opt(first.year(), 1, 1).unwrap(),
+            NaiveDate::from_ymd_opt(last.year(), 12, 31).unwrap(),
+        ),
+        _ => return Err(Error::Custom("Empty holiday data!".to_string())),
     };
+    let days = first
+        .iter_days()
+        .take_while(|d| *d <= last)
+        .filter(|d| !matches!(d.weekday(), Weekday::Sat | Weekday::Sun) && !closed.contains(d));
+    Ok((TradeCalendar::new(days), half_days))
+}
 
-    if mx_date == 0 {
-        let cache = fetch_trade_date().await?;
-        let elm = cache.iter().next_back();
-        mx_date = elm.map_or(0, |e| *e);
+/// 解码新浪交易日数据，格式: var datelist="...";
+fn decode_trade_date(text: &str) -> Result<BTreeSet<i32>> {
+    let encoded = text
+        .split(['"', '\''])
+        .nth(1)
+        .ok_or_else(|| Error::Custom("Invalid trade date data!".to_string()))?;
+    let epoch = NaiveDate::from_ymd_opt(1970, 1, 1).unwrap();
+    let data: BTreeSet<i32> = TradeDateDecoder::new(encoded)
+        .decode()
+        .into_iter()
+        .map(|days| date_to_i32(&(epoch + Duration::days(days))))
+        .collect();
+    if data.is_empty() {
+        return Err(Error::Custom("Decode trade date data error!".to_string()));
     }
+    Ok(data)
+}
+
+/// 1990-12-19距1970-01-01的天数
+const DECODE_BASE_DAYS: i64 = 7657;
 
-    let mut test_date = date.clone();
-    loop {
-        test_date = test_date.add(Duration::days(1));
-        let d: i32 = test_date.format("%Y%m%d").to_string().parse().unwrap();
-        if d > mx_date {
-            break;
+/// 新浪交易日数据解码，数据为base64字符表示的位流(低位在前)，
+/// 记录的是相对1990-12-19的工作日中哪些为交易日
+struct TradeDateDecoder {
+    data: Vec<i32>,
+    pos: usize,
+    bit: u32,
+}
+
+impl TradeDateDecoder {
+    fn new(text: &str) -> Self {
+        const ALPHABET: &[u8] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/";
+        let data = text
+            .bytes()
+            .map(|b| {
+                ALPHABET
+                    .iter()
+                    .position(|a| *a == b)
+                    .map_or(-1, |p| p as i32)
+            })
+            .collect();
+        Self {
+            data,
+            pos: 0,
+            bit: 0,
         }
-        {
-            let cache = CACHE_TRADE_DATE.read().unwrap();
-            if cache.contains(&d) {
-                return Ok(*(cache.get(&d).unwrap()));
-            }
+    }
+
+    fn is_end(&self) -> bool {
+        self.pos >= self.data.len()
+    }
+
+    fn advance(&mut self, bits: u32) {
+        self.bit += bits;
+        if self.bit >= 6 {
+            self.bit -= 6;
+            self.pos += 1;
         }
     }
-    Err(Error::Custom("date is to far ...".to_string()))
-}
 
-/// 获取某交易日前的第一个交易日
-pub async fn fetch_prev_trade_date(date: &NaiveDate) -> Result<i32> {
-    let mut min_date = {
-        let cache = CACHE_TRADE_DATE.read().unwrap();
-        let elm = cache.iter().next();
-        elm.map_or(0, |e| *e)
-    };
+    /// 读1位
+    fn read_bit(&mut self) -> bool {
+        if self.is_end() {
+            return false;
+        }
+        let set = self.data[self.pos] & (1 << self.bit) != 0;
+        self.advance(1);
+        set
+    }
 
-    if min_date == 0 {
-        let cache = fetch_trade_date().await?;
-        let elm = cache.iter().next();
-        min_date = elm.map_or(0, |e| *e);
+    /// 读变长整数: 符号位 + 一元编码的长度
+    fn read_var(&mut self) -> i64 {
+        let sign = self.read_bit() as i64;
+        let mut n = 1;
+        while self.read_bit() {
+            n += 1;
+        }
+        n * (2 * sign - 1)
     }
 
-    let mut test_date = date.clone();
-    loop {
-        test_date = test_date.add(Duration::days(-1));
-        let d: i32 = test_date.format("%Y%m%d").to_string().parse().unwrap();
-        if d < min_date {
-            break;
+    /// 读`bits`位无符号整数，数据不足时返回None
+    fn read_bits(&mut self, bits: i64) -> Option<i64> {
+        if bits == 0 {
+            return Some(0);
         }
-        {
-            let cache = CACHE_TRADE_DATE.read().unwrap();
-            if cache.contains(&d) {
-                return Ok(*(cache.get(&d).unwrap()));
-            }
+        if self.is_end() {
+            return None;
+        }
+        if bits < 0 {
+            return Some(0);
         }
+        if bits > 30 {
+            let low = self.read_bits(30)?;
+            let high = self.read_bits(bits - 30)?;
+            return Some(low + high * (1 << 30));
+        }
+        let mut value: i64 = 0;
+        let mut left = bits;
+        while left > 0 {
+            let n = (6 - self.bit as i64).min(left);
+            let v = *self.data.get(self.pos).unwrap_or(&0) as i64;
+            value |= ((v >> self.bit) & ((1 << n) - 1)) << (bits - left);
+            self.advance(n as u32);
+            left -= n;
+        }
+        Some(value)
     }
-    Err(Error::Custom("date is to old ...".to_string()))
-}
 
-const JS_CODE: &'static str = r###"
-function d(t) {
-    var e, i, n, r, a, o, s, l = (arguments,
-            864e5), u = 7657, c = [], h = [], d = ~(3 << 30), f = 1 << 30,
-        p = [0, 3, 5, 6, 9, 10, 12, 15, 17, 18, 20, 23, 24, 27, 29, 30], m = Math, g = function () {
-            var l, u;
-            for (l = 0; 64 > l; l++)
-                h[l] = m.pow(2, l),
-                26 > l && (c[l] = v(l + 65),
-                    c[l + 26] = v(l + 97),
-                10 > l && (c[l + 52] = v(l + 48)));
-            for (c.push("+", "/"),
-                     c = c.join(""),
-                     i = t.split(""),
-                     n = i.length,
-                     l = 0; n > l; l++)
-                i[l] = c.indexOf(i[l]);
-            return r = {},
-                e = o = 0,
-                a = {},
-                u = w([12, 6]),
-                s = 63 ^ u[1],
-            {
-                _1479: T,
-                _136: _,
-                _200: S,
-                _139: k,
-                _197: _mi_run
-            }["_" + u[0]] || function () {
-                return []
-            }
-        }, v = String.fromCharCode, b = function (t) {
-            return t === {}._
-        }, N = function () {
-            var t, e;
-            for (t = y(),
-                     e = 1; ;) {
-                if (!y())
-                    return e * (2 * t - 1);
-                e++
-            }
-        }, y = function () {
-            var t;
-            return e >= n ? 0 : (t = i[e] & 1 << o,
-                o++,
-            o >= 6 && (o -= 6,
-                e++),
-                !!t)
-        }, w = function (t, r, a) {
-            var s, l, u, c, d;
-            for (l = [],
-                     u = 0,
-                 r || (r = []),
-                 a || (a = []),
-                     s = 0; s < t.length; s++)
-                if (c = t[s],
-                    u = 0,
-                    c) {
-                    if (e >= n)
-                        return l;
-                    if (t[s] <= 0)
-                        u = 0;
-                    else if (t[s] <= 30) {
-                        for (; d = 6 - o,
-                                   d = c > d ? d : c,
-                                   u |= (i[e] >> o & (1 << d) - 1) << t[s] - c,
-                                   o += d,
-                               o >= 6 && (o -= 6,
-                                   e++),
-                                   c -= d,
-                                   !(0 >= c);)
-                            ;
-                        r[s] && u >= h[t[s] - 1] && (u -= h[t[s]])
-                    } else
-                        u = w([30, t[s] - 30], [0, r[s]]),
-                        a[s] || (u = u[0] + u[1] * h[30]);
-                    l[s] = u
-                } else
-                    l[s] = 0;
-            return l
-        }, x = function (t) {
-            var e, i, n;
-            for (t > 1 && (e = 0),
-                     e = 0; t > e; e++)
-                r.d++,
-                    n = r.d % 7,
-                (3 == n || 4 == n) && (r.d += 5 - n);
-            return i = new Date,
-                i.setTime((u + r.d) * l),
-                i
-        }, S = function () {
-            var t, i, a, o, l;
-            if (s >= 1)
-                return [];
-            for (r.d = w([18], [1])[0] - 1,
-                     a = w([3, 3, 30, 6]),
-                     r.p = a[0],
-                     r.ld = a[1],
-                     r.cd = a[2],
-                     r.c = a[3],
-                     r.m = m.pow(10, r.p),
-                     r.pc = r.cd / r.m,
-                     i = [],
-                     t = 0; o = {
-                d: 1
-            },
-                 y() && (a = w([3])[0],
-                     0 == a ? o.d = w([6])[0] : 1 == a ? (r.d = w([18])[0],
-                         o.d = 0) : o.d = a),
-                     l = {
-                         day: x(o.d)
-                     },
-                 y() && (r.ld += N()),
-                     a = w([3 * r.ld], [1]),
-                     r.cd += a[0],
-                     l.close = r.cd / r.m,
-                     i.push(l),
-                 !(e >= n) && (e != n - 1 || 63 & (r.c ^ t + 1)); t++)
-                ;
-            return i[0].prevclose = r.pc,
-                i
-        }, _ = function () {
-            var t, i, a, o, l, u, c, h, d, f, p;
-            if (s > 2)
-                return [];
-            for (c = [],
-                     d = {
-                         v: "volume",
-                         p: "price",
-                         a: "avg_price"
-                     },
-                     r.d = w([18], [1])[0] - 1,
-                     h = {
-                         day: x(1)
-                     },
-                     a = w(1 > s ? [3, 3, 4, 1, 1, 1, 5] : [4, 4, 4, 1, 1, 1, 3]),
-                     t = 0; 7 > t; t++)
-                r[["la", "lp", "lv", "tv", "rv", "zv", "pp"][t]] = a[t];
-            for (r.m = m.pow(10, r.pp),
-                     s >= 1 ? (a = w([3, 3]),
-                         r.c = a[0],
-                         a = a[1]) : (a = 5,
-                         r.c = 2),
-                     r.pc = w([6 * a])[0],
-                     h.pc = r.pc / r.m,
-                     r.cp = r.pc,
-                     r.da = 0,
-                     r.sa = r.sv = 0,
-                     t = 0; !(e >= n) && (e != n - 1 || 7 & (r.c ^ t)); t++) {
-                for (l = {},
-                         o = {},
-                         f = r.tv ? y() : 1,
-                         i = 0; 3 > i; i++)
-                    if (p = ["v", "p", "a"][i],
-                    (f ? y() : 0) && (a = N(),
-                        r["l" + p] += a),
-                        u = "v" == p && r.rv ? y() : 1,
-                        a = w([3 * r["l" + p] + ("v" == p ? 7 * u : 0)], [!!i])[0] * (u ? 1 : 100),
-                        o[p] = a,
-                    "v" == p) {
-                        if (!(l[d[p]] = a) && (s > 1 || 241 > t) && (r.zv ? !y() : 1)) {
-                            o.p = 0;
-                            break
-                        }
-                    } else
-                        "a" == p && (r.da = (1 > s ? 0 : r.da) + o.a);
-                r.sv += o.v,
-                    l[d.p] = (r.cp += o.p) / r.m,
-                    r.sa += o.v * r.cp,
-                    l[d.a] = b(o.a) ? t ? c[t - 1][d.a] : l[d.p] : r.sv ? ((m.floor((r.sa * (2e3 / r.m) + r.sv) / r.sv) >> 1) + r.da) / 1e3 : l[d.p] + r.da / 1e3,
-                    c.push(l)
-            }
-            return c[0].date = h.day,
-                c[0].prevclose = h.pc,
-                c
-        }, T = function () {
-            var t, e, i, n, a, o, l;
-            if (s >= 1)
-                return [];
-            for (r.lv = 0,
-                     r.ld = 0,
-                     r.cd = 0,
-                     r.cv = [0, 0],
-                     r.p = w([6])[0],
-                     r.d = w([18], [1])[0] - 1,
-                     r.m = m.pow(10, r.p),
-                     a = w([3, 3]),
-                     r.md = a[0],
-                     r.mv = a[1],
-                     t = []; a = w([6]),
-                     a.length;) {
-                if (i = {
-                    c: a[0]
-                },
-                    n = {},
-                    i.d = 1,
-                32 & i.c)
-                    for (; ;) {
-                        if (a = w([6])[0],
-                        63 == (16 | a)) {
-                            l = 16 & a ? "x" : "u",
-                                a = w([3, 3]),
-                                i[l + "_d"] = a[0] + r.md,
-                                i[l + "_v"] = a[1] + r.mv;
-                            break
-                        }
-                        if (32 & a) {
-                            o = 8 & a ? "d" : "v",
-                                l = 16 & a ? "x" : "u",
-                                i[l + "_" + o] = (7 & a) + r["m" + o];
-                            break
-                        }
-                        if (o = 15 & a,
-                            0 == o ? i.d = w([6])[0] : 1 == o ? (r.d = o = w([18])[0],
-                                i.d = 0) : i.d = o,
-                            !(16 & a))
-                            break
+    /// 下一个工作日，跳过周末
+    fn next_day(day: &mut i64) -> i64 {
+        *day += 1;
+        let n = *day % 7;
+        if n == 3 || n == 4 {
+            *day += 5 - n;
+        }
+        *day
+    }
+
+    /// 解码，返回距1970-01-01的天数
+    fn decode(&mut self) -> Vec<i64> {
+        let kind = self.read_bits(12).unwrap_or(0);
+        let flag = 63 ^ self.read_bits(6).unwrap_or(0);
+        if kind != 139 || flag > 1 {
+            return Vec::new();
+        }
+        let mut data = Vec::new();
+        let mut width: i64 = 0;
+        // 连续交易日的数量，数据读完后为None，此后的工作日都是交易日
+        let mut run: Option<i64> = Some(-1);
+        let mut day = self.read_bits(18).unwrap_or(0) - 1;
+        let end = self.read_bits(18).unwrap_or(0);
+        while day < end {
+            let d = Self::next_day(&mut day);
+            match run {
+                Some(n) if n <= 0 => {
+                    if self.read_bit() {
+                        width += self.read_var();
+                    }
+                    run = self.read_bits(3 * width).map(|n| n + 1);
+                    if data.is_empty() {
+                        data.push(d);
+                        run = run.map(|n| n - 1);
                     }
-                n.date = x(i.d);
-                for (o in {
-                    v: 0,
-                    d: 0
-                })
-                    b(i["x_" + o]) || (r["l" + o] = i["x_" + o]),
-                    b(i["u_" + o]) && (i["u_" + o] = r["l" + o]);
-                for (i.l_l = [i.u_d, i.u_d, i.u_d, i.u_d, i.u_v],
-                         l = p[15 & i.c],
-                     1 & i.u_v && (l = 31 - l),
-                     16 & i.c && (i.l_l[4] += 2),
-                         e = 0; 5 > e; e++)
-                    l & 1 << 4 - e && i.l_l[e]++,
-                        i.l_l[e] *= 3;
-                i.d_v = w(i.l_l, [1, 0, 0, 1, 1], [0, 0, 0, 0, 1]),
-                    o = r.cd + i.d_v[0],
-                    n.open = o / r.m,
-                    n.high = (o + i.d_v[1]) / r.m,
-                    n.low = (o - i.d_v[2]) / r.m,
-                    n.close = (o + i.d_v[3]) / r.m,
-                    a = i.d_v[4],
-                "number" == typeof a && (a = [a, a >= 0 ? 0 : -1]),
-                    r.cd = o + i.d_v[3],
-                    l = r.cv[0] + a[0],
-                    r.cv = [l & d, r.cv[1] + a[1] + !!((r.cv[0] & d) + (a[0] & d) & f)],
-                    n.volume = (r.cv[0] & f - 1) + r.cv[1] * f,
-                    t.push(n)
+                }
+                _ => data.push(d),
             }
-            return t
-        }, k = function () {
-            var t, e, i, n;
-            if (s > 1)
-                return [];
-            for (r.l = 0,
-                     n = -1,
-                     r.d = w([18])[0] - 1,
-                     i = w([18])[0]; r.d < i;)
-                e = x(1),
-                    0 >= n ? (y() && (r.l += N()),
-                        n = w([3 * r.l], [0])[0] + 1,
-                    t || (t = [e],
-                        n--)) : t.push(e),
-                    n--;
-            return t
-        };
-    return _mi_run = function () {
-        var t, i, a, o;
-        if (s >= 1)
-            return [];
-        for (r.f = w([6])[0],
-                 r.c = w([6])[0],
-                 a = [],
-                 r.dv = [],
-                 r.dl = [],
-                 t = 0; t < r.f; t++)
-            r.dv[t] = 0,
-                r.dl[t] = 0;
-        for (t = 0; !(e >= n) && (e != n - 1 || 7 & (r.c ^ t)); t++) {
-            for (o = [],
-                     i = 0; i < r.f; i++)
-                y() && (r.dl[i] += N()),
-                    r.dv[i] += w([3 * r.dl[i]], [1])[0],
-                    o[i] = r.dv[i];
-            a.push(o)
+            run = run.map(|n| n - 1);
         }
-        return a
+        data.into_iter().map(|d| d + DECODE_BASE_DAYS).collect()
     }
-        ,
-        g()()
 }
-function normal_day(day) {
-    var y = day.getFullYear();
-    var m = day.getMonth() + 1;
-    var d = day.getDate();
-
-    return y*10000 + m*100 +d;
-}
-function get_trade_date() {
-    var list = d(datelist);
-    return list.map(normal_day);
-}
-"###;
 
 #[cfg(test)]
 mod tests {
-    use chrono::NaiveDate;
+    use chrono::{NaiveDate, NaiveDateTime};
+    use hiq_common::Exchange;
 
     use crate::comm::hiq_trade_date::{
-        fetch_next_trade_date, fetch_prev_trade_date, fetch_trade_date,
+        bundled_trade_date, decode_trade_date, fetch_next_trade_date, fetch_prev_trade_date,
+        fetch_trade_date, market_clock,
     };
 
     #[test]
@@ -431,4 +375,57 @@ mod tests {
                 println!("next: {}, prev: {}", next, prev);
             })
     }
+
+    #[test]
+    fn test_decode_trade_date() {
+        // 1990-12-19至1991-01-01的交易日，结果与新浪js解码一致
+        let data = decode_trade_date("var datelist=\"LC+AAANAAjAB\";").unwrap();
+        assert_eq!(
+            data.into_iter().collect::<Vec<_>>(),
+            vec![19901219, 19901220, 19901221, 19901224, 19901227, 19901228]
+        );
+
+        assert!(decode_trade_date("var datelist=\"\";").is_err());
+        assert!(decode_trade_date("<html></html>").is_err());
+    }
+
+    #[test]
+    fn test_overseas_market_clock() {
+        let at = |d: &str| NaiveDateTime::parse_from_str(d, "%Y%m%d %H:%M").unwrap();
+        let clock = market_clock(Exchange::HK);
+        // 农历新年、圣诞
+        assert!(!clock.is_trading(&at("20250129 10:00")));
+        assert!(clock.is_trading(&at("20250203 10:00")));
+        assert!(clock.is_closed(&at("20241224 12:10")));
+        assert!(!clock.is_closed(&at("20241223 12:10")));
+
+        let clock = market_clock(Exchange::US);
+        // 感恩节及次日提前收市
+        assert!(!clock.is_trading(&at("20241128 10:00")));
+        assert!(clock.is_trading(&at("20241129 12:30")));
+        assert!(clock.is_closed(&at("20241129 13:00")));
+        assert!(!clock.is_closed(&at("20241127 13:00")));
+    }
+
+    #[test]
+    fn test_offline_trade_date() {
+        let data = bundled_trade_date();
+        assert!(data.contains(&20221114));
+        assert!(!data.contains(&20240209));
+        assert_eq!(data.first(), Some(&19901219));
+        tokio::runtime::Runtime::new().unwrap().block_on(async {
+            // 春节前后，无需请求远程
+            let date = NaiveDate::from_ymd_opt(2024, 2, 8).unwrap();
+            assert_eq!(fetch_next_trade_date(&date).await.unwrap(), 20240219);
+            let date = NaiveDate::from_ymd_opt(2024, 2, 19).unwrap();
+            assert_eq!(fetch_prev_trade_date(&date).await.unwrap(), 20240208);
+            // 内置日历自开市日起
+            let date = NaiveDate::from_ymd_opt(2008, 2, 13).unwrap();
+            assert_eq!(fetch_prev_trade_date(&date).await.unwrap(), 20080205);
+            let date = NaiveDate::from_ymd_opt(1990, 12, 20).unwrap();
+            assert_eq!(fetch_prev_trade_date(&date).await.unwrap(), 19901219);
+            let date = NaiveDate::from_ymd_opt(1990, 12, 19).unwrap();
+            assert!(fetch_prev_trade_date(&date).await.is_err());
+        });
+    }
 }
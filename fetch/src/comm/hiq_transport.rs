@@ -0,0 +1,251 @@
+use crate::comm::{async_client, rate_limiter, FetchConfig};
+use crate::{Error, Result};
+use async_trait::async_trait;
+use once_cell::sync::Lazy;
+use reqwest::header::{HeaderMap, HeaderValue, USER_AGENT};
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::{Arc, RwLock};
+
+/// 请求缓存参数，生成fixture文件名时忽略
+const VOLATILE_QUERY_KEYS: [&str; 2] = ["_", "random"];
+
+/// http传输层，所有远程数据都通过此接口获取，方便录制/回放
+#[async_trait]
+pub trait Transport: Sync + Send {
+    /// GET请求，返回原始数据
+    async fn get(&self, url: &str, headers: Option<HeaderMap>) -> Result<Vec<u8>>;
+
+    /// GET请求，返回文本
+    async fn get_text(&self, url: &str, headers: Option<HeaderMap>) -> Result<String> {
+        let data = self.get(url, headers).await?;
+        String::from_utf8(data)
+            .map_err(|e| Error::Custom(format!("Response of {} is not utf8: {}!", url, e)))
+    }
+}
+
+/// 传输模式
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub enum TransportMode {
+    /// 直接请求远程
+    #[default]
+    Live,
+    /// 请求远程，并把原始数据保存到目录
+    Record(PathBuf),
+    /// 从目录读取之前录制的数据，不请求远程
+    Replay(PathBuf),
+}
+
+static DEFAULT_TRANSPORT: Lazy<RwLock<Arc<dyn Transport>>> =
+    Lazy::new(|| RwLock::new(Arc::new(HttpTransport::new())));
+
+/// 设置默认传输层，`HiqXxxFetch::new()`及交易日获取使用
+pub fn set_default_transport(transport: Arc<dyn Transport>) {
+    let mut default = DEFAULT_TRANSPORT.write().unwrap();
+    *default = transport;
+}
+
+/// 默认传输层
+pub fn default_transport() -> Arc<dyn Transport> {
+    DEFAULT_TRANSPORT.read().unwrap().clone()
+}
+
+/// 根据模式生成传输层
+#[deprecated(note = "使用`FetchConfig::transport`, 可同时配置代理、超时等")]
+pub fn transport(mode: TransportMode) -> Arc<dyn Transport> {
+    let config = FetchConfig {
+        mode,
+        ..Default::default()
+    };
+    config.transport().unwrap_or_else(|_| default_transport())
+}
+
+/// 远程请求，按host限流
+pub struct HttpTransport {
+    client: reqwest::Client,
+    config: FetchConfig,
+    user_agents: Vec<HeaderValue>,
+    ua_index: AtomicUsize,
+}
+
+impl HttpTransport {
+    pub fn new() -> Self {
+        Self::with_config(&FetchConfig::default()).unwrap()
+    }
+    /// 按配置生成，代理等配置错误时返回错误
+    pub fn with_config(config: &FetchConfig) -> Result<Self> {
+        let user_agents = config
+            .user_agents
+            .iter()
+            .map(|ua| {
+                HeaderValue::from_str(ua)
+                    .map_err(|e| Error::Custom(format!("Invalid user agent {}: {}!", ua, e)))
+            })
+            .collect::<Result<Vec<_>>>()?;
+        Ok(Self {
+            client: async_client(config)?,
+            config: config.clone(),
+            user_agents,
+            ua_index: AtomicUsize::new(0),
+        })
+    }
+}
+
+impl Default for HttpTransport {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[async_trait]
+impl Transport for HttpTransport {
+    async fn get(&self, url: &str, headers: Option<HeaderMap>) -> Result<Vec<u8>> {
+        let _permit = rate_limiter().acquire(url_host(url)).await;
+        let mut req = match self.config.rewrite_url(url) {
+            Some(url) => self.client.get(url),
+            None => self.client.get(url),
+        };
+        if let Some(headers) = headers {
+            req = req.headers(headers);
+        }
+        if !self.user_agents.is_empty() {
+            let index = self.ua_index.fetch_add(1, Ordering::Relaxed) % self.user_agents.len();
+            req = req.header(USER_AGENT, self.user_agents[index].clone());
+        }
+        let resp = req.send().await?.bytes().await?;
+        Ok(resp.to_vec())
+    }
+}
+
+/// 录制，请求后把原始数据写到目录
+pub struct RecordTransport {
+    inner: Arc<dyn Transport>,
+    dir: PathBuf,
+}
+
+impl RecordTransport {
+    pub fn new<P: AsRef<Path>>(inner: Arc<dyn Transport>, dir: P) -> Self {
+        Self {
+            inner,
+            dir: dir.as_ref().to_path_buf(),
+        }
+    }
+}
+
+#[async_trait]
+impl Transport for RecordTransport {
+    async fn get(&self, url: &str, headers: Option<HeaderMap>) -> Result<Vec<u8>> {
+        let data = self.inner.get(url, headers).await?;
+        std::fs::create_dir_all(&self.dir)
+            .map_err(|e| Error::Custom(format!("Create fixture dir error: {}!", e)))?;
+        let path = self.dir.join(fixture_name(url));
+        std::fs::write(&path, &data).map_err(|e| {
+            Error::Custom(format!("Write fixture {} error: {}!", path.display(), e))
+        })?;
+        Ok(data)
+    }
+}
+
+/// 回放，从目录读取录制的数据
+pub struct ReplayTransport {
+    dir: PathBuf,
+}
+
+impl ReplayTransport {
+    pub fn new<P: AsRef<Path>>(dir: P) -> Self {
+        Self {
+            dir: dir.as_ref().to_path_buf(),
+        }
+    }
+}
+
+#[async_trait]
+impl Transport for ReplayTransport {
+    async fn get(&self, url: &str, _headers: Option<HeaderMap>) -> Result<Vec<u8>> {
+        let path = self.dir.join(fixture_name(url));
+        std::fs::read(&path).map_err(|e| {
+            Error::Custom(format!(
+                "Read fixture {} of {} error: {}!",
+                path.display(),
+                url,
+                e
+            ))
+        })
+    }
+}
+
+/// url对应的fixture文件名，格式: host-hash.txt，hash忽略缓存参数
+pub fn fixture_name(url: &str) -> String {
+    let url = normalize_url(url);
+    let host = url_host(&url);
+    // FNV-1a，保证不同版本的编译器下文件名一致
+    let hash = url.bytes().fold(0xcbf29ce484222325_u64, |hash, b| {
+        (hash ^ b as u64).wrapping_mul(0x100000001b3)
+    });
+    format!("{}-{:016x}.txt", host, hash)
+}
+
+/// url中的host
+pub(crate) fn url_host(url: &str) -> &str {
+    let url = url.split_once("://").map_or(url, |(_, rest)| rest);
+    url.split(['/', '?', ':']).next().unwrap_or("")
+}
+
+fn normalize_url(url: &str) -> String {
+    let url = url.replace(' ', "");
+    match url.split_once('?') {
+        Some((path, query)) => {
+            let query: Vec<_> = query
+                .split('&')
+                .filter(|kv| {
+                    let key = kv.split('=').next().unwrap_or("");
+                    !kv.is_empty() && !VOLATILE_QUERY_KEYS.contains(&key)
+                })
+                .collect();
+            format!("{}?{}", path, query.join("&"))
+        }
+        None => url,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    struct StaticTransport;
+
+    #[async_trait]
+    impl Transport for StaticTransport {
+        async fn get(&self, url: &str, _headers: Option<HeaderMap>) -> Result<Vec<u8>> {
+            Ok(format!("body of {}", url).into_bytes())
+        }
+    }
+
+    #[test]
+    fn test_fixture_name() {
+        let a = fixture_name("https://xueqiu.com/hq?a=1&_=1667196199286");
+        let b = fixture_name("https://xueqiu.com/hq?a=1&_=1667196199999");
+        let c = fixture_name("https://xueqiu.com/hq?a=2");
+        assert_eq!(a, b);
+        assert_ne!(a, c);
+        assert!(a.starts_with("xueqiu.com-"));
+    }
+
+    #[test]
+    fn test_record_replay() {
+        let dir = std::env::temp_dir().join(format!("hiq_fixture_{}", std::process::id()));
+        let url = "https://push2.eastmoney.com/api/qt/clist/get?pn=1&_=1";
+        tokio::runtime::Runtime::new().unwrap().block_on(async {
+            let record = RecordTransport::new(Arc::new(StaticTransport), &dir);
+            let recorded = record.get_text(url, None).await.unwrap();
+
+            let replay = ReplayTransport::new(&dir);
+            let replayed = replay.get_text(url, None).await.unwrap();
+            assert_eq!(recorded, replayed);
+
+            let missing = replay.get_text("https://xueqiu.com/none", None).await;
+            assert!(missing.is_err());
+        });
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+}
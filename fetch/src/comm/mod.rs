@@ -1,8 +1,17 @@
+mod hiq_config;
+mod hiq_failover;
 mod hiq_fetch;
 mod hiq_info;
+mod hiq_limiter;
 mod hiq_trade_date;
+mod hiq_transport;
 
 pub(crate) use self::hiq_fetch::*;
 pub(crate) use hiq_info::*;
 
+pub use hiq_config::*;
+pub(crate) use hiq_failover::Failover;
+pub use hiq_failover::{CircuitPolicy, SourceHealth};
+pub use hiq_limiter::*;
 pub use hiq_trade_date::*;
+pub use hiq_transport::*;
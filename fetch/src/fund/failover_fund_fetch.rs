@@ -0,0 +1,122 @@
+use crate::comm::{CircuitPolicy, Failover, SourceHealth};
+use crate::fund::FundFetch;
+use crate::Result;
+use async_trait::async_trait;
+use chrono::NaiveDate;
+use hiq_common::{BarFreq, FundBar, FundInfo, FundNet, MinuteBar, OrderBook};
+use std::collections::HashMap;
+use std::future::Future;
+use std::sync::Arc;
+
+/// 多数据源etf基金实现，按顺序尝试，失败切换到下一个数据源
+///
+/// 连续失败达到阈值的数据源会被熔断一段时间，熔断期间跳过。
+pub struct FailoverFundFetch {
+    inner: Failover<dyn FundFetch>,
+}
+
+impl FailoverFundFetch {
+    /// `sources`为(数据源名称, 实现)，按优先级排序
+    pub fn new(sources: Vec<(String, Arc<dyn FundFetch>)>) -> Self {
+        Self::with_policy(sources, CircuitPolicy::default())
+    }
+    pub fn with_policy(sources: Vec<(String, Arc<dyn FundFetch>)>, policy: CircuitPolicy) -> Self {
+        Self {
+            inner: Failover::new(sources, policy),
+        }
+    }
+    /// 最近一次成功调用`method`（如: "fetch_fund_bar"）的数据源名称
+    ///
+    /// 并发调用时只是其中一次的数据源，需要每次调用的数据源使用`call_with_source`
+    pub fn served_by(&self, method: &str) -> Option<String> {
+        self.inner.served_by(method)
+    }
+    /// 调用`f`，同时返回本次提供数据的数据源名称
+    ///
+    /// ```ignore
+    /// let (bar, source) = fetch
+    ///     .call_with_source("fetch_fund_bar", |f| async move { f.fetch_fund_bar(..).await })
+    ///     .await?;
+    /// ```
+    pub async fn call_with_source<R, F, Fut>(&self, method: &str, f: F) -> Result<(R, String)>
+    where
+        F: Fn(Arc<dyn FundFetch>) -> Fut,
+        Fut: Future<Output = Result<R>>,
+    {
+        self.inner.call_with_source(method, f).await
+    }
+    /// 各数据源健康状态
+    pub fn health(&self) -> Vec<(String, SourceHealth)> {
+        self.inner.health()
+    }
+}
+
+#[async_trait]
+impl FundFetch for FailoverFundFetch {
+    /// etf基金基本信息
+    async fn fetch_fund_info(&self) -> Result<Vec<FundInfo>> {
+        self.inner
+            .call(
+                "fetch_fund_info",
+                |f| async move { f.fetch_fund_info().await },
+            )
+            .await
+    }
+
+    /// etf基金净值
+    async fn fetch_fund_net(
+        &self,
+        code: &str,
+        name: Option<&str>,
+        start: Option<NaiveDate>,
+        end: Option<NaiveDate>,
+    ) -> Result<Vec<FundNet>> {
+        self.inner
+            .call("fetch_fund_net", |f| async move {
+                f.fetch_fund_net(code, name, start, end).await
+            })
+            .await
+    }
+
+    /// etf基金k线数据
+    async fn fetch_fund_bar(
+        &self,
+        code: &str,
+        name: Option<&str>,
+        freq: Option<BarFreq>,
+        start: Option<NaiveDate>,
+        end: Option<NaiveDate>,
+    ) -> Result<FundBar> {
+        self.inner
+            .call("fetch_fund_bar", |f| async move {
+                f.fetch_fund_bar(code, name, freq, start, end).await
+            })
+            .await
+    }
+
+    /// etf基金分钟k线，分页获取
+    async fn fetch_fund_minute_bar(
+        &self,
+        code: &str,
+        name: Option<&str>,
+        freq: Option<BarFreq>,
+        start: Option<NaiveDate>,
+        end: Option<NaiveDate>,
+    ) -> Result<MinuteBar> {
+        self.inner
+            .call("fetch_fund_minute_bar", |f| async move {
+                f.fetch_fund_minute_bar(code, name, freq, start, end).await
+            })
+            .await
+    }
+
+    /// etf基金五档盘口
+    async fn fetch_fund_order_book(&self, code: Vec<&str>) -> Result<HashMap<String, OrderBook>> {
+        self.inner
+            .call("fetch_fund_order_book", |f| {
+                let code = code.clone();
+                async move { f.fetch_fund_order_book(code).await }
+            })
+            .await
+    }
+}
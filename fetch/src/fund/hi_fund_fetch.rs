@@ -1,24 +1,30 @@
-use crate::comm::{async_client, fetch_bar, fetch_prev_trade_date, XueQiuBar};
+use crate::comm::{
+    default_transport, fetch_bar, fetch_minute_bar, fetch_order_book, fetch_prev_trade_date,
+    to_minute_bar, BarAdjust, Transport, XueQiuBar, MINUTE_BAR_PAGE_SIZE,
+};
 use crate::fund::hi_fund_info::EastFundNet;
 use crate::fund::FundFetch;
-use crate::util::to_std_code;
-use crate::{Error, HeaderValue, Market, MarketType, Result, HTTP_CMM_HEADER};
+use crate::util::{to_east_secid, to_security_id, to_std_code};
+use crate::{Error, HeaderValue, MarketType, Result, HTTP_CMM_HEADER};
 use async_trait::async_trait;
 use chrono::{Datelike, Duration, Local, NaiveDate, NaiveDateTime, NaiveTime, TimeZone};
-use hiq_common::{Bar, BarFreq, FundBar, FundInfo, FundNet};
+use hiq_common::{Bar, BarFreq, FundBar, FundInfo, FundNet, MinuteBar, OrderBook};
 use reqwest::header::REFERER;
-use reqwest::Client;
+use std::collections::HashMap;
 use std::ops::Add;
+use std::sync::Arc;
 
 pub struct HiqFundFetch {
-    client: Client,
+    transport: Arc<dyn Transport>,
 }
 
 impl HiqFundFetch {
     pub fn new() -> Self {
-        Self {
-            client: async_client(),
-        }
+        Self::with_transport(default_transport())
+    }
+    /// 指定传输层，如录制/回放
+    pub fn with_transport(transport: Arc<dyn Transport>) -> Self {
+        Self { transport }
     }
     pub async fn fetch_fund_bar_xq(
         &self,
@@ -47,7 +53,7 @@ impl HiqFundFetch {
 
         let mut pre_item: Option<Bar> = None;
         // prepare cookie
-        self.client.get("https://xueqiu.com/hq").send().await?;
+        self.transport.get("https://xueqiu.com/hq", None).await?;
         while start <= end {
             let timestamp = start.timestamp() * 1000;
             let req_url = format!(
@@ -57,7 +63,7 @@ impl HiqFundFetch {
                 timestamp = timestamp
             );
 
-            let resp = self.client.get(req_url).send().await?.text().await?;
+            let resp = self.transport.get_text(&req_url, None).await?;
             let json: XueQiuBar = serde_json::from_str(&resp)?;
 
             if let Some(result) = json.data {
@@ -143,7 +149,7 @@ impl FundFetch for HiqFundFetch {
     async fn fetch_fund_info(&self) -> Result<Vec<FundInfo>> {
         let req_url = format!("http://fund.eastmoney.com/js/fundcode_search.js?v=20130718.js");
 
-        let resp = self.client.get(req_url).send().await?.text().await?;
+        let resp = self.transport.get_text(&req_url, None).await?;
 
         let index = resp
             .find("[")
@@ -177,13 +183,14 @@ impl FundFetch for HiqFundFetch {
         end: Option<NaiveDate>,
     ) -> Result<Vec<FundNet>> {
         let name = name.unwrap_or("");
+        let id = to_security_id(code)?;
         let start = start.unwrap_or(NaiveDate::parse_from_str("2010-01-01", "%Y-%m-%d").unwrap());
         let now = Local::now();
         let end = end.unwrap_or(now.date_naive());
         let req_url = format!(
             "https://api.fund.eastmoney.com/f10/lsjz?\
         fundCode={code}&pageIndex=1&pageSize=10000&startDate={start}&endDate={end}&_={timestamp}",
-            code = &code[2..],
+            code = id.code(),
             start = start,
             end = end,
             timestamp = now.timestamp()
@@ -192,14 +199,7 @@ impl FundFetch for HiqFundFetch {
         let mut headers = HTTP_CMM_HEADER.to_owned();
         let referer = format!("http://fundf10.eastmoney.com/jjjz_{code}.html", code = code);
         headers.insert(REFERER, HeaderValue::from_str(&referer).unwrap());
-        let resp = self
-            .client
-            .get(req_url)
-            .headers(headers)
-            .send()
-            .await?
-            .text()
-            .await?;
+        let resp = self.transport.get_text(&req_url, Some(headers)).await?;
 
         let json: EastFundNet = serde_json::from_str(&resp)?;
 
@@ -238,18 +238,24 @@ impl FundFetch for HiqFundFetch {
         start: Option<NaiveDate>,
         end: Option<NaiveDate>,
     ) -> Result<FundBar> {
-        let market_code = if code.starts_with("sz") {
-            format!("{}.{}", Market::SZ as i32, &code[2..])
-        } else {
-            format!("{}.{}", Market::SH as i32, &code[2..])
-        };
+        let market_code = to_east_secid(code)?;
         let freq = if freq.is_none() {
             BarFreq::Daily
         } else {
             freq.unwrap()
         };
 
-        let bars = fetch_bar(&self.client, &market_code, code, freq, start, end).await?;
+        let bars = fetch_bar(
+            self.transport.as_ref(),
+            &market_code,
+            code,
+            freq,
+            start,
+            end,
+            // 基金暂无分红数据源，请求后复权k线计算
+            BarAdjust::Remote,
+        )
+        .await?;
         let bond_bar = FundBar {
             code: code.to_owned(),
             name: name.unwrap_or("").to_owned(),
@@ -258,6 +264,34 @@ impl FundFetch for HiqFundFetch {
         };
         Ok(bond_bar)
     }
+    /// etf基金分钟k线，分页获取，请求后复权k线计算复权因子
+    async fn fetch_fund_minute_bar(
+        &self,
+        code: &str,
+        name: Option<&str>,
+        freq: Option<BarFreq>,
+        start: Option<NaiveDate>,
+        end: Option<NaiveDate>,
+    ) -> Result<MinuteBar> {
+        let market_code = to_east_secid(code)?;
+        let freq = freq.unwrap_or(BarFreq::Min1);
+        let bars = fetch_minute_bar(
+            self.transport.as_ref(),
+            &market_code,
+            code,
+            freq,
+            start,
+            end,
+            true,
+            MINUTE_BAR_PAGE_SIZE,
+        )
+        .await?;
+        Ok(to_minute_bar(code, name, freq, bars))
+    }
+    /// etf基金五档盘口(腾讯行情)
+    async fn fetch_fund_order_book(&self, code: Vec<&str>) -> Result<HashMap<String, OrderBook>> {
+        fetch_order_book(self.transport.as_ref(), &code).await
+    }
 }
 
 #[cfg(test)]
@@ -1,11 +1,17 @@
+mod failover_fund_fetch;
 mod fund_fetch;
 mod hi_fund_fetch;
 mod hi_fund_info;
 
+pub use failover_fund_fetch::*;
 pub use fund_fetch::*;
 pub use hi_fund_fetch::*;
 
+use crate::{FetchConfig, Result};
+
 /// 返回默认的etf基金实现
-pub fn fund_fetch() -> impl FundFetch {
-    HiqFundFetch::new()
+///
+/// `config`设置代理、超时、user-agent及请求地址等，默认配置用`FetchConfig::default()`
+pub fn fund_fetch(config: &FetchConfig) -> Result<impl FundFetch> {
+    Ok(HiqFundFetch::with_transport(config.transport()?))
 }
@@ -1,7 +1,10 @@
 //! 网上获取数据，数据类型包括可转债，ETF基金，股票。  
 //! 这里获取的数据是最基本的数据，不排除以后会新增其他类型的数据。  
 //! 数据的来源不一定是固定一个地方。  
-//! 需要注意的是，获取数据时，如果并发获取，需要要限制并发数量，否则可能会被封ip  
+//! 需要注意的是，获取数据时，如果并发获取，需要要限制并发数量，否则可能会被封ip。  
+//! 所有远程请求都经过全局限流器（按host限制每秒请求数及并发数），可通过`set_rate_limit`调整。  
+//! 交易日历内置离线数据(`data/trade_date.txt`)，`update_trade_date_file`可更新，`load_trade_date_file`加载。  
+//! 实时行情可通过`subscribe_stock_rt_quot`订阅，按间隔轮询并只推送有变化的行情。  
 
 use once_cell::sync::Lazy;
 use reqwest::header::{
@@ -21,6 +24,9 @@ pub use fund::*;
 mod comm;
 pub use comm::*;
 
+mod tencent;
+pub use tencent::*;
+
 mod util;
 
 pub use hiq_common::*;
@@ -66,18 +72,21 @@ pub(crate) static HTTP_CMM_HEADER: Lazy<HeaderMap> = Lazy::new(|| {
 });
 
 /// 股票市场： 深圳或上海
+#[deprecated(note = "使用`Exchange`及`SecurityId`")]
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub enum Market {
     SZ = 0,
     SH = 1,
 }
 
-/// 市场交易类型： 可转债，etf基金，股票
+/// 市场交易类型： 可转债，etf基金，股票，港股，美股
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub enum MarketType {
     Bond = 0,
     Fund = 1,
     Stock = 2,
+    HkStock = 3,
+    UsStock = 4,
 }
 
 impl From<i32> for MarketType {
@@ -86,15 +95,9 @@ impl From<i32> for MarketType {
             0 => MarketType::Bond,
             1 => MarketType::Fund,
             2 => MarketType::Stock,
+            3 => MarketType::HkStock,
+            4 => MarketType::UsStock,
             _ => MarketType::Stock,
         }
     }
 }
-
-/// 复权方式
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
-pub enum AdjustFactor {
-    NFQ = 0,
-    QFQ = 1,
-    HFQ = 2,
-}
@@ -0,0 +1,602 @@
+use crate::comm::{CircuitPolicy, Failover, SourceHealth};
+use crate::stock::StockFetch;
+use crate::{MarketType, Result};
+use async_trait::async_trait;
+use chrono::NaiveDate;
+use hiq_common::stock::*;
+use hiq_common::{AuctionSnapshot, BarFreq, MinuteBar, OrderBook, Tick, TimeShare};
+use std::collections::{HashMap, HashSet};
+use std::future::Future;
+use std::sync::Arc;
+
+/// 多数据源股票实现，按顺序尝试，失败切换到下一个数据源
+///
+/// 连续失败达到阈值的数据源会被熔断一段时间，熔断期间跳过。
+pub struct FailoverStockFetch {
+    inner: Failover<dyn StockFetch>,
+}
+
+impl FailoverStockFetch {
+    /// `sources`为(数据源名称, 实现)，按优先级排序
+    pub fn new(sources: Vec<(String, Arc<dyn StockFetch>)>) -> Self {
+        Self::with_policy(sources, CircuitPolicy::default())
+    }
+    pub fn with_policy(sources: Vec<(String, Arc<dyn StockFetch>)>, policy: CircuitPolicy) -> Self {
+        Self {
+            inner: Failover::new(sources, policy),
+        }
+    }
+    /// 最近一次成功调用`method`（如: "fetch_stock_bar"）的数据源名称
+    ///
+    /// 并发调用时只是其中一次的数据源，需要每次调用的数据源使用`call_with_source`
+    pub fn served_by(&self, method: &str) -> Option<String> {
+        self.inner.served_by(method)
+    }
+    /// 调用`f`，同时返回本次提供数据的数据源名称
+    ///
+    /// ```ignore
+    /// let (bar, source) = fetch
+    ///     .call_with_source("fetch_stock_bar", |f| async move { f.fetch_stock_bar(..).await })
+    ///     .await?;
+    /// ```
+    pub async fn call_with_source<R, F, Fut>(&self, method: &str, f: F) -> Result<(R, String)>
+    where
+        F: Fn(Arc<dyn StockFetch>) -> Fut,
+        Fut: Future<Output = Result<R>>,
+    {
+        self.inner.call_with_source(method, f).await
+    }
+    /// 各数据源健康状态
+    pub fn health(&self) -> Vec<(String, SourceHealth)> {
+        self.inner.health()
+    }
+}
+
+#[async_trait]
+impl StockFetch for FailoverStockFetch {
+    /// 上证、深证及中证系列指数
+    async fn fetch_index_info(&self) -> Result<Vec<StockInfo>> {
+        self.inner
+            .call(
+                "fetch_index_info",
+                |f| async move { f.fetch_index_info().await },
+            )
+            .await
+    }
+
+    /// 指数成分股及权重
+    async fn fetch_index_constituents(
+        &self,
+        code: &str,
+        date: Option<NaiveDate>,
+    ) -> Result<Vec<IndexConstituent>> {
+        self.inner
+            .call("fetch_index_constituents", |f| async move {
+                f.fetch_index_constituents(code, date).await
+            })
+            .await
+    }
+
+    /// 指数k线数据
+    async fn fetch_index_bar(
+        &self,
+        code: &str,
+        name: Option<&str>,
+        freq: Option<BarFreq>,
+        start: Option<NaiveDate>,
+        end: Option<NaiveDate>,
+    ) -> Result<StockBar> {
+        self.inner
+            .call("fetch_index_bar", |f| async move {
+                f.fetch_index_bar(code, name, freq, start, end).await
+            })
+            .await
+    }
+
+    /// 获取股票基本信息
+    async fn fetch_stock_info(&self) -> Result<Vec<StockInfo>> {
+        self.inner
+            .call(
+                "fetch_stock_info",
+                |f| async move { f.fetch_stock_info().await },
+            )
+            .await
+    }
+
+    /// 获取港股或美股基本信息
+    async fn fetch_overseas_stock_info(&self, market: MarketType) -> Result<Vec<StockInfo>> {
+        self.inner
+            .call("fetch_overseas_stock_info", |f| async move {
+                f.fetch_overseas_stock_info(market).await
+            })
+            .await
+    }
+
+    /// 获取融资融券股票代码
+    async fn fetch_stock_is_margin(&self) -> Result<HashSet<String>> {
+        self.inner
+            .call("fetch_stock_is_margin", |f| async move {
+                f.fetch_stock_is_margin().await
+            })
+            .await
+    }
+
+    /// 股票/指数k线数据
+    async fn fetch_stock_bar(
+        &self,
+        code: &str,
+        name: Option<&str>,
+        freq: Option<BarFreq>,
+        start: Option<NaiveDate>,
+        end: Option<NaiveDate>,
+    ) -> Result<StockBar> {
+        self.inner
+            .call("fetch_stock_bar", |f| async move {
+                f.fetch_stock_bar(code, name, freq, start, end).await
+            })
+            .await
+    }
+
+    /// 股票不复权k线数据，复权因子均为1，可结合除权除息事件在本地计算复权因子
+    async fn fetch_stock_nfq_bar(
+        &self,
+        code: &str,
+        name: Option<&str>,
+        freq: Option<BarFreq>,
+        start: Option<NaiveDate>,
+        end: Option<NaiveDate>,
+    ) -> Result<StockBar> {
+        self.inner
+            .call("fetch_stock_nfq_bar", |f| async move {
+                f.fetch_stock_nfq_bar(code, name, freq, start, end).await
+            })
+            .await
+    }
+
+    /// 股票分钟k线，分页获取
+    async fn fetch_stock_minute_bar(
+        &self,
+        code: &str,
+        name: Option<&str>,
+        freq: Option<BarFreq>,
+        start: Option<NaiveDate>,
+        end: Option<NaiveDate>,
+    ) -> Result<MinuteBar> {
+        self.inner
+            .call("fetch_stock_minute_bar", |f| async move {
+                f.fetch_stock_minute_bar(code, name, freq, start, end).await
+            })
+            .await
+    }
+
+    /// 股票最新指标
+    async fn fetch_stock_index(
+        &self,
+        date: Option<NaiveDate>,
+    ) -> Result<HashMap<String, StockIndex>> {
+        self.inner
+            .call("fetch_stock_index", |f| async move {
+                f.fetch_stock_index(date).await
+            })
+            .await
+    }
+
+    /// 股票行业
+    async fn fetch_stock_industry(&self) -> Result<Vec<StockIndustry>> {
+        self.inner
+            .call("fetch_stock_industry", |f| async move {
+                f.fetch_stock_industry().await
+            })
+            .await
+    }
+
+    /// 股票行业详情
+    async fn fetch_stock_industry_detail(
+        &self,
+        code: Option<&str>,
+        name: Option<&str>,
+    ) -> Result<Vec<StockIndustryDetail>> {
+        self.inner
+            .call("fetch_stock_industry_detail", |f| async move {
+                f.fetch_stock_industry_detail(code, name).await
+            })
+            .await
+    }
+
+    /// 股票行业k线数据，只有日频率
+    async fn fetch_stock_industry_daily(
+        &self,
+        code: &str,
+        name: Option<&str>,
+        start: Option<NaiveDate>,
+        end: Option<NaiveDate>,
+    ) -> Result<StockIndustryBar> {
+        self.inner
+            .call("fetch_stock_industry_daily", |f| async move {
+                f.fetch_stock_industry_daily(code, name, start, end).await
+            })
+            .await
+    }
+
+    /// 股票概念
+    async fn fetch_stock_concept(&self) -> Result<Vec<StockConcept>> {
+        self.inner
+            .call("fetch_stock_concept", |f| async move {
+                f.fetch_stock_concept().await
+            })
+            .await
+    }
+
+    /// 股票概念详情
+    async fn fetch_stock_concept_detail(
+        &self,
+        code: Option<&str>,
+        name: Option<&str>,
+    ) -> Result<Vec<StockConceptDetail>> {
+        self.inner
+            .call("fetch_stock_concept_detail", |f| async move {
+                f.fetch_stock_concept_detail(code, name).await
+            })
+            .await
+    }
+
+    /// 股票概念k线数据
+    async fn fetch_stock_concept_daily(
+        &self,
+        code: &str,
+        name: Option<&str>,
+        start: Option<NaiveDate>,
+        end: Option<NaiveDate>,
+    ) -> Result<StockConceptBar> {
+        self.inner
+            .call("fetch_stock_concept_daily", |f| async move {
+                f.fetch_stock_concept_daily(code, name, start, end).await
+            })
+            .await
+    }
+
+    /// 股票业绩报表
+    async fn fetch_stock_yjbb(&self, year: u16, season: u16) -> Result<Vec<StockYJBB>> {
+        self.inner
+            .call("fetch_stock_yjbb", |f| async move {
+                f.fetch_stock_yjbb(year, season).await
+            })
+            .await
+    }
+
+    /// 资产负债表
+    async fn fetch_stock_balance_sheet(
+        &self,
+        year: u16,
+        season: u16,
+    ) -> Result<Vec<StockBalanceSheet>> {
+        self.inner
+            .call("fetch_stock_balance_sheet", |f| async move {
+                f.fetch_stock_balance_sheet(year, season).await
+            })
+            .await
+    }
+
+    /// 利润表
+    async fn fetch_stock_income(&self, year: u16, season: u16) -> Result<Vec<StockIncome>> {
+        self.inner
+            .call("fetch_stock_income", |f| async move {
+                f.fetch_stock_income(year, season).await
+            })
+            .await
+    }
+
+    /// 现金流量表
+    async fn fetch_stock_cash_flow(&self, year: u16, season: u16) -> Result<Vec<StockCashFlow>> {
+        self.inner
+            .call("fetch_stock_cash_flow", |f| async move {
+                f.fetch_stock_cash_flow(year, season).await
+            })
+            .await
+    }
+
+    /// 股东户数
+    async fn fetch_stock_holder_num(&self, year: u16, season: u16) -> Result<Vec<StockHolderNum>> {
+        self.inner
+            .call("fetch_stock_holder_num", |f| async move {
+                f.fetch_stock_holder_num(year, season).await
+            })
+            .await
+    }
+
+    /// 十大股东/十大流通股东
+    async fn fetch_stock_top_holders(
+        &self,
+        year: u16,
+        season: u16,
+        is_float: bool,
+    ) -> Result<Vec<StockTopHolder>> {
+        self.inner
+            .call("fetch_stock_top_holders", |f| async move {
+                f.fetch_stock_top_holders(year, season, is_float).await
+            })
+            .await
+    }
+
+    /// 融资融券
+    async fn fetch_stock_margin(
+        &self,
+        code: &str,
+        start: Option<NaiveDate>,
+        end: Option<NaiveDate>,
+    ) -> Result<Vec<StockMargin>> {
+        self.inner
+            .call("fetch_stock_margin", |f| async move {
+                f.fetch_stock_margin(code, start, end).await
+            })
+            .await
+    }
+
+    /// 北向资金个股持股
+    async fn fetch_stock_north_hold(&self, date: NaiveDate) -> Result<Vec<StockNorthHold>> {
+        self.inner
+            .call("fetch_stock_north_hold", |f| async move {
+                f.fetch_stock_north_hold(date).await
+            })
+            .await
+    }
+
+    /// 北向资金每日成交
+    async fn fetch_north_flow(
+        &self,
+        start: Option<NaiveDate>,
+        end: Option<NaiveDate>,
+    ) -> Result<Vec<NorthFlow>> {
+        self.inner
+            .call("fetch_north_flow", |f| async move {
+                f.fetch_north_flow(start, end).await
+            })
+            .await
+    }
+
+    /// 龙虎榜
+    async fn fetch_stock_dragon_tiger(&self, date: NaiveDate) -> Result<Vec<StockDragonTiger>> {
+        self.inner
+            .call("fetch_stock_dragon_tiger", |f| async move {
+                f.fetch_stock_dragon_tiger(date).await
+            })
+            .await
+    }
+
+    /// 涨停、跌停或炸板股池
+    async fn fetch_stock_limit_pool(
+        &self,
+        date: NaiveDate,
+        pool: LimitPool,
+    ) -> Result<Vec<StockLimitPool>> {
+        self.inner
+            .call("fetch_stock_limit_pool", |f| async move {
+                f.fetch_stock_limit_pool(date, pool).await
+            })
+            .await
+    }
+
+    /// 当前风险警示(ST、*ST)股票
+    async fn fetch_stock_st(&self) -> Result<Vec<StockStatus>> {
+        self.inner
+            .call(
+                "fetch_stock_st",
+                |f| async move { f.fetch_stock_st().await },
+            )
+            .await
+    }
+
+    /// 风险警示变更公告
+    async fn fetch_stock_st_notice(&self, date: NaiveDate) -> Result<Vec<StockStatusNotice>> {
+        self.inner
+            .call("fetch_stock_st_notice", |f| async move {
+                f.fetch_stock_st_notice(date).await
+            })
+            .await
+    }
+
+    /// 风险警示历史区间
+    async fn fetch_stock_st_history(&self) -> Result<Vec<StockStatus>> {
+        self.inner
+            .call("fetch_stock_st_history", |f| async move {
+                f.fetch_stock_st_history().await
+            })
+            .await
+    }
+
+    /// 停牌股票
+    async fn fetch_stock_suspend(&self, date: NaiveDate) -> Result<Vec<StockStatus>> {
+        self.inner
+            .call("fetch_stock_suspend", |f| async move {
+                f.fetch_stock_suspend(date).await
+            })
+            .await
+    }
+
+    /// 终止上市股票
+    async fn fetch_stock_delist(&self) -> Result<Vec<StockStatus>> {
+        self.inner
+            .call("fetch_stock_delist", |f| async move {
+                f.fetch_stock_delist().await
+            })
+            .await
+    }
+
+    /// 除权除息事件(分红送转及配股)，code为None时为全市场，start/end为除权除息日
+    async fn fetch_stock_corp_action(
+        &self,
+        code: Option<&str>,
+        start: Option<NaiveDate>,
+        end: Option<NaiveDate>,
+    ) -> Result<Vec<StockCorpAction>> {
+        self.inner
+            .call("fetch_stock_corp_action", |f| async move {
+                f.fetch_stock_corp_action(code, start, end).await
+            })
+            .await
+    }
+
+    /// 实时行情
+    async fn fetch_stock_rt_quot(&self, code: Vec<&str>) -> Result<HashMap<String, StockRtQuot>> {
+        self.inner
+            .call("fetch_stock_rt_quot", |f| {
+                let code = code.clone();
+                async move { f.fetch_stock_rt_quot(code).await }
+            })
+            .await
+    }
+
+    /// 股票五档盘口
+    async fn fetch_stock_order_book(&self, code: Vec<&str>) -> Result<HashMap<String, OrderBook>> {
+        self.inner
+            .call("fetch_stock_order_book", |f| {
+                let code = code.clone();
+                async move { f.fetch_stock_order_book(code).await }
+            })
+            .await
+    }
+
+    /// 分笔成交
+    async fn fetch_stock_ticks(&self, code: &str, date: NaiveDate) -> Result<Vec<Tick>> {
+        self.inner
+            .call("fetch_stock_ticks", |f| async move {
+                f.fetch_stock_ticks(code, date).await
+            })
+            .await
+    }
+
+    /// 分时走势
+    async fn fetch_stock_time_share(&self, code: &str) -> Result<TimeShare> {
+        self.inner
+            .call("fetch_stock_time_share", |f| async move {
+                f.fetch_stock_time_share(code).await
+            })
+            .await
+    }
+
+    /// 集合竞价
+    async fn fetch_stock_auction(&self, code: &str) -> Result<Vec<AuctionSnapshot>> {
+        self.inner
+            .call("fetch_stock_auction", |f| async move {
+                f.fetch_stock_auction(code).await
+            })
+            .await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::FailoverStockFetch;
+    use crate::{CircuitPolicy, Error, Result, StockFetch};
+    use async_trait::async_trait;
+    use hiq_common::StockInfo;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::sync::Arc;
+    use std::time::Duration;
+
+    struct DownFetch {
+        calls: AtomicUsize,
+    }
+
+    #[async_trait]
+    impl StockFetch for DownFetch {
+        async fn fetch_stock_info(&self) -> Result<Vec<StockInfo>> {
+            self.calls.fetch_add(1, Ordering::SeqCst);
+            Err(Error::Custom("upstream down".to_owned()))
+        }
+    }
+
+    struct UpFetch;
+
+    #[async_trait]
+    impl StockFetch for UpFetch {
+        async fn fetch_stock_info(&self) -> Result<Vec<StockInfo>> {
+            Ok(vec![StockInfo {
+                code: "sz002805".to_owned(),
+                name: "丰元股份".to_owned(),
+                block: "主板".to_owned(),
+                is_margin: false,
+                listing_date: Default::default(),
+                board: Default::default(),
+                currency: Default::default(),
+                lot_size: Some(100),
+            }])
+        }
+    }
+
+    #[test]
+    fn test_failover() {
+        tokio::runtime::Runtime::new().unwrap().block_on(async {
+            let down = Arc::new(DownFetch {
+                calls: AtomicUsize::new(0),
+            });
+            let policy = CircuitPolicy {
+                failure_threshold: 2,
+                cooldown: Duration::from_secs(60),
+            };
+            let fetch = FailoverStockFetch::with_policy(
+                vec![
+                    ("down".to_owned(), down.clone()),
+                    ("up".to_owned(), Arc::new(UpFetch)),
+                ],
+                policy,
+            );
+
+            for _ in 0..3 {
+                let data = fetch.fetch_stock_info().await.unwrap();
+                assert_eq!(data.len(), 1);
+                assert_eq!(fetch.served_by("fetch_stock_info").unwrap(), "up");
+            }
+            // 熔断后不再请求
+            assert_eq!(down.calls.load(Ordering::SeqCst), 2);
+
+            let health = fetch.health();
+            assert!(health[0].1.is_open());
+            assert_eq!(health[1].1.success, 3);
+
+            // 都未实现
+            let data = fetch.fetch_stock_yjbb(2022, 4).await;
+            assert!(matches!(data, Err(Error::NotImpl(_))));
+            assert!(fetch.served_by("fetch_stock_yjbb").is_none());
+        });
+    }
+
+    #[test]
+    fn test_call_with_source() {
+        tokio::runtime::Runtime::new().unwrap().block_on(async {
+            let fetch = Arc::new(FailoverStockFetch::new(vec![
+                ("up".to_owned(), Arc::new(UpFetch) as Arc<dyn StockFetch>),
+                (
+                    "down".to_owned(),
+                    Arc::new(DownFetch {
+                        calls: AtomicUsize::new(0),
+                    }),
+                ),
+            ]));
+            let tasks: Vec<_> = (0..4)
+                .map(|_| {
+                    let fetch = fetch.clone();
+                    tokio::spawn(async move {
+                        fetch
+                            .call_with_source("fetch_stock_info", |f| async move {
+                                f.fetch_stock_info().await
+                            })
+                            .await
+                    })
+                })
+                .collect();
+            for task in tasks {
+                let (data, source) = task.await.unwrap().unwrap();
+                assert_eq!(data.len(), 1);
+                assert_eq!(source, "up");
+            }
+
+            let data = fetch
+                .call_with_source("fetch_stock_yjbb", |f| async move {
+                    f.fetch_stock_yjbb(2022, 4).await
+                })
+                .await;
+            assert!(matches!(data, Err(Error::NotImpl(_))));
+        });
+    }
+}
@@ -1,33 +1,160 @@
-use crate::comm::{async_client, fetch_bar, to_bar_ds};
+use crate::comm::{
+    default_transport, fetch_auction, fetch_bar, fetch_minute_bar, fetch_order_book, fetch_ticks,
+    fetch_time_share, fetch_xueqiu_bar, market_clock, merge_hfq_factor, to_bar_ds, to_minute_bar,
+    BarAdjust, Transport, MINUTE_BAR_PAGE_SIZE,
+};
 use crate::stock::hiq_stock_info::{
-    EastStockIndex, EastStockIndexDataDetailValue, EastStockIndustry, EastStockInfoMargin,
-    EastStockMargin, EastStockYJBB, ExchStockInfo, XuQiuStockRtQuot,
+    BseStockInfo, EastDataCenter, EastDragonTigerData, EastDragonTigerSeatData,
+    EastIndexConstituent, EastIndexList, EastLimitPool, EastNorthFlowData, EastNotice,
+    EastStockAllotment, EastStockBalanceData, EastStockBonus, EastStockCashFlowData,
+    EastStockHolderNumData, EastStockIncomeData, EastStockIndex, EastStockIndexDataDetailValue,
+    EastStockIndustry, EastStockInfoMargin, EastStockMargin, EastStockNorthHoldData,
+    EastStockSuspendData, EastStockTopHolderData, EastStockYJBB, ExchStockInfo, XuQiuStockRtQuot,
+    XueQiuStockList,
 };
-use crate::util::to_std_code;
-use crate::{fetch_trade_date, Error, Market, MarketType, Result, StockFetch, HTTP_CMM_HEADER};
+use crate::util::{to_east_secid, to_overseas_id, to_security_id, to_std_code};
+use crate::{fetch_trade_date, Error, MarketType, Result, StockFetch, HTTP_CMM_HEADER};
 use async_trait::async_trait;
 use calamine::{open_workbook_auto_from_rs, DataType, Reader};
-use chrono::{Datelike, Duration, Local, NaiveDate, NaiveDateTime, NaiveTime, TimeZone};
+use chrono::{Datelike, Duration, Local, NaiveDate, NaiveDateTime, NaiveTime, Timelike};
 use hiq_common::{
-    BarFreq, StockBar, StockConcept, StockConceptBar, StockConceptDetail, StockIndex,
-    StockIndustry, StockIndustryBar, StockIndustryDetail, StockInfo, StockMargin, StockRtQuot,
-    StockYJBB,
+    AdjustCalculator, AuctionSnapshot, BarFreq, Board, Currency, DragonTigerSeat, Exchange,
+    IndexConstituent, LimitPool, MinuteBar, NorthFlow, OrderBook, SecurityId, StockBalanceSheet,
+    StockBar, StockCashFlow, StockConcept, StockConceptBar, StockConceptDetail, StockCorpAction,
+    StockDragonTiger, StockHolderNum, StockIncome, StockIndex, StockIndustry, StockIndustryBar,
+    StockIndustryDetail, StockInfo, StockLimitPool, StockMargin, StockNorthHold, StockRtQuot,
+    StockStatus, StockStatusNotice, StockStatusType, StockTopHolder, StockYJBB, Tick, TimeShare,
 };
 use reqwest::header::*;
-use reqwest::Client;
+use serde::de::DeserializeOwned;
 use std::collections::{HashMap, HashSet};
 use std::io::Cursor;
 use std::ops::Add;
+use std::sync::Arc;
+
+/// 雪球实时行情每次请求的代码数
+const RT_QUOT_BATCH_SIZE: usize = 50;
 
 pub struct HiqStockFetch {
-    client: Client,
+    transport: Arc<dyn Transport>,
 }
 
 impl HiqStockFetch {
     pub fn new() -> Self {
-        Self {
-            client: async_client(),
+        Self::with_transport(default_transport())
+    }
+    /// 指定传输层，如录制/回放
+    pub fn with_transport(transport: Arc<dyn Transport>) -> Self {
+        Self { transport }
+    }
+    /// 北交所上市股票，分页请求，返回内容为`[{...}]`形式的jsonp
+    async fn fetch_bj_stock_info(&self, margin_codes: &HashSet<String>) -> Result<Vec<StockInfo>> {
+        let mut data = Vec::new();
+        let mut page = 0;
+        loop {
+            let req_url = format!(
+                "https://www.bse.cn/nqxxController/nqxxCnzq.do?\
+            page={page}&typejb=T&xxfcbj%5B%5D=2&xxzqdm=&sortfield=xxzqdm&sorttype=asc",
+                page = page
+            );
+            let resp = self.transport.get_text(&req_url, None).await?;
+            let (beg, end) = match (resp.find('['), resp.rfind(']')) {
+                (Some(beg), Some(end)) if beg < end => (beg, end),
+                _ => return Err(Error::Custom("Invalid bse stock info".to_string())),
+            };
+            let json: Vec<BseStockInfo> = serde_json::from_str(&resp[beg..=end])?;
+            let mut total_pages = 0;
+            for info in json.into_iter() {
+                total_pages = info.total_pages;
+                data.extend(info.content.into_iter().map(|item| {
+                    let code = format!("bj{}", item.code);
+                    let listing_date = NaiveDate::parse_from_str(&item.list_date, "%Y%m%d")
+                        .map(|d| NaiveDateTime::new(d, NaiveTime::from_hms_opt(0, 0, 0).unwrap()))
+                        .unwrap_or_default();
+                    StockInfo {
+                        code: code.clone(),
+                        name: item.name,
+                        block: "北交所".to_owned(),
+                        is_margin: margin_codes.contains(&code),
+                        listing_date,
+                        board: Board::Bse,
+                        currency: Currency::from_code(&code),
+                        lot_size: StockInfo::lot_size_of(&code),
+                    }
+                }));
+            }
+            page += 1;
+            if page >= total_pages {
+                break;
+            }
+        }
+        Ok(data)
+    }
+    async fn fetch_bar_adjust(
+        &self,
+        code: &str,
+        name: Option<&str>,
+        freq: Option<BarFreq>,
+        start: Option<NaiveDate>,
+        end: Option<NaiveDate>,
+        adjust: BarAdjust<'_>,
+    ) -> Result<StockBar> {
+        let market_code = to_east_secid(code)?;
+
+        let freq = freq.unwrap_or(BarFreq::Daily);
+
+        let bars = fetch_bar(
+            self.transport.as_ref(),
+            &market_code,
+            code,
+            freq,
+            start,
+            end,
+            adjust,
+        )
+        .await?;
+        let (stock_name, bars) = to_bar_ds(name, bars);
+        let stock_bar = StockBar {
+            code: code.to_owned(),
+            name: stock_name,
+            freq,
+            bars,
+        };
+        Ok(stock_bar)
+    }
+    /// 港股、美股日k线(雪球)，复权因子 = 后复权收盘价 / 收盘价
+    async fn fetch_overseas_bar(
+        &self,
+        id: &SecurityId,
+        name: Option<&str>,
+        freq: Option<BarFreq>,
+        start: Option<NaiveDate>,
+        end: Option<NaiveDate>,
+        adjust: bool,
+    ) -> Result<StockBar> {
+        let freq = freq.unwrap_or(BarFreq::Daily);
+        if !matches!(freq, BarFreq::Daily | BarFreq::LooseDaily) {
+            return Err(Error::Custom(format!(
+                "Overseas stock {} only support daily bar!",
+                id
+            )));
         }
+        // prepare cookie
+        self.transport.get("https://xueqiu.com/hq", None).await?;
+        let transport = self.transport.as_ref();
+        let bar_name = name.unwrap_or("");
+        let mut bars = fetch_xueqiu_bar(transport, id, bar_name, "normal", start, end).await?;
+        if adjust {
+            let hfq_bars = fetch_xueqiu_bar(transport, id, bar_name, "after", start, end).await?;
+            merge_hfq_factor(&mut bars, &hfq_bars);
+        }
+        let (stock_name, bars) = to_bar_ds(name, bars);
+        Ok(StockBar {
+            code: id.to_std(),
+            name: stock_name,
+            freq,
+            bars,
+        })
     }
     fn get_cell(&self, cell: &DataType) -> String {
         match cell {
@@ -41,36 +168,311 @@ impl HiqStockFetch {
             _ => "".into(),
         }
     }
+    /// 东方财富数据中心某报告期全市场的数据，`date_column`为报告期字段，按`sort_columns`升序分页请求
+    async fn fetch_season_report<T>(
+        &self,
+        report: &str,
+        date_column: &str,
+        sort_columns: &[&str],
+        year: u16,
+        season: u16,
+    ) -> Result<Vec<T>>
+    where
+        T: DeserializeOwned + Send,
+    {
+        let report_date = finance_report_date(year, season)?;
+        let filter = format!("%28{}%3D%27{}%27%29", date_column, report_date);
+        self.fetch_data_center(report, sort_columns, &filter).await
+    }
+    /// 东方财富数据中心报表，`filter`为已编码的过滤条件，按`sort_columns`升序分页请求
+    async fn fetch_data_center<T>(
+        &self,
+        report: &str,
+        sort_columns: &[&str],
+        filter: &str,
+    ) -> Result<Vec<T>>
+    where
+        T: DeserializeOwned + Send,
+    {
+        const PAGE_SIZE: usize = 500;
+        let sort_types = vec!["1"; sort_columns.len()].join("%2C");
+        let sort_columns = sort_columns.join("%2C");
+        let mut data = Vec::new();
+        let mut page = 1;
+        loop {
+            let req_url = format!(
+                "https://datacenter-web.eastmoney.com/api/data/v1/get?\
+            reportName={report}&columns=ALL&source=WEB&sortColumns={sort_columns}&sortTypes={sort_types}&\
+            pageNumber={page}&pageSize={page_size}&filter={filter}",
+                report = report,
+                sort_columns = sort_columns,
+                sort_types = sort_types,
+                page = page,
+                page_size = PAGE_SIZE,
+                filter = filter
+            );
+            let resp = self.transport.get_text(&req_url, None).await?;
+            let json = serde_json::from_str::<EastDataCenter<T>>(&resp)?;
+            let result = match json.result {
+                Some(result) => result,
+                None => break,
+            };
+            data.extend(result.data);
+            if page >= result.pages {
+                break;
+            }
+            page += 1;
+        }
+        Ok(data)
+    }
+}
+
+/// 报告期日期，如2022年3季度为`2022-09-30`
+fn finance_report_date(year: u16, season: u16) -> Result<String> {
+    if !(1991..=2050).contains(&year) {
+        return Err(Error::Custom(format!("Invalid year: {}", year)));
+    }
+    let season_date = match season {
+        1 => "03-31",
+        2 => "06-30",
+        3 => "09-30",
+        4 => "12-31",
+        _ => return Err(Error::Custom(format!("Invalid season: {}", season))),
+    };
+    Ok(format!("{}-{}", year, season_date))
+}
+
+/// 龙虎榜席位按(代码，上榜原因)分组，按`amount`降序取前五
+fn dragon_tiger_seats(
+    data: Vec<EastDragonTigerSeatData>,
+    amount: fn(&DragonTigerSeat) -> f64,
+) -> HashMap<(String, String), Vec<DragonTigerSeat>> {
+    let mut seats: HashMap<(String, String), Vec<DragonTigerSeat>> = HashMap::new();
+    for item in data.into_iter() {
+        let buy_amount = item.buy_amount.unwrap_or(0.0);
+        let sell_amount = item.sell_amount.unwrap_or(0.0);
+        seats
+            .entry((item.code, item.reason))
+            .or_default()
+            .push(DragonTigerSeat {
+                name: item.name,
+                buy_amount,
+                sell_amount,
+                net_buy: item.net_buy.unwrap_or(buy_amount - sell_amount),
+            });
+    }
+    for v in seats.values_mut() {
+        v.sort_by(|a, b| amount(b).total_cmp(&amount(a)));
+        v.truncate(5);
+    }
+    seats
+}
+
+/// 股池中`HHMMSS`形式的封板时间，0或无效时为`None`
+fn limit_pool_time(date: &NaiveDate, time: Option<u32>) -> Option<NaiveDateTime> {
+    let time = time.filter(|t| *t > 0)?;
+    let time = NaiveTime::from_hms_opt(time / 10000, time / 100 % 100, time % 100)?;
+    Some(NaiveDateTime::new(*date, time))
+}
+
+/// 财务报表的报告期及公告日期，未公告的返回`None`
+fn finance_report_dates(
+    report_date: &str,
+    notice_date: Option<&str>,
+) -> Option<(NaiveDateTime, NaiveDateTime)> {
+    let report_date = NaiveDateTime::parse_from_str(report_date, "%Y-%m-%d %H:%M:%S").ok()?;
+    let notice_date = NaiveDateTime::parse_from_str(notice_date?, "%Y-%m-%d %H:%M:%S").ok()?;
+    Some((report_date, notice_date))
+}
+
+/// 由简称判断风险警示类型，`*ST`(含股改前的`S*ST`)为退市风险警示
+fn st_status_of(name: &str) -> Option<StockStatusType> {
+    let name = name.trim();
+    if name.starts_with("*ST") || name.starts_with("S*ST") {
+        Some(StockStatusType::StarST)
+    } else if name.starts_with("ST") || name.starts_with("SST") {
+        Some(StockStatusType::ST)
+    } else {
+        None
+    }
+}
+
+/// 交易所未提供退市原因，按退市时的简称推断并注明:
+/// 简称带`退`或处于风险警示的为强制退市，否则为主动退市(吸收合并、私有化等)
+fn delist_reason(name: &str) -> String {
+    if name.contains('退') || st_status_of(name).is_some() || name.starts_with("PT") {
+        "强制退市(按简称推断)".to_owned()
+    } else {
+        "主动退市(按简称推断)".to_owned()
+    }
+}
+
+/// 由公告标题推断风险警示变更后的状态，内层None为撤销风险警示，
+/// 与变更无关的(含申请撤销、可能被实施等提示)返回None。
+/// `撤销`之后到`并`、`及`、`同时`为撤销的部分，之后为同时实施的部分
+fn st_notice_status(title: &str) -> Option<Option<StockStatusType>> {
+    const IGNORES: [&str; 6] = ["可能", "申请", "拟", "叠加", "继续", "进展"];
+    if IGNORES.iter().any(|s| title.contains(s)) {
+        return None;
+    }
+    let (implemented, revoked) = match title.split_once("撤销") {
+        Some((_, revoked)) => {
+            let implemented = ["并", "及", "同时"]
+                .iter()
+                .find_map(|sep| revoked.split_once(sep).map(|(_, rest)| rest))
+                .unwrap_or("");
+            (implemented, revoked.contains("风险警示"))
+        }
+        None => (title, false),
+    };
+    let set = |kind: &str| {
+        ["实施", "实行"]
+            .iter()
+            .any(|verb| implemented.contains(&format!("{}{}风险警示", verb, kind)))
+    };
+    if set("退市") {
+        Some(Some(StockStatusType::StarST))
+    } else if set("其他") {
+        Some(Some(StockStatusType::ST))
+    } else if revoked {
+        Some(None)
+    } else {
+        None
+    }
 }
 
 #[async_trait]
 impl StockFetch for HiqStockFetch {
-    /// 股票大盘指数（列举几个而已）
+    /// 上证、深证及中证系列指数，中证系列无沪深行情代码(000、399开头)的指数以csi为前缀，如csi930050
     async fn fetch_index_info(&self) -> Result<Vec<StockInfo>> {
-        let data = vec![
-            ("sh000001", "上证综指"),
-            ("sz399001", "深证成指"),
-            ("sz399006", "创业板指"),
-            ("sz399102", "创业板综指"),
-            ("sz399005", "中小板指"),
-            ("sh000300", "沪深300"),
-            ("sh000688", "科创50"),
-            ("sz399673", "创业板50"),
-            ("sz399550", "央视50"),
-            ("sz399678", "深次新股"),
-            ("sz399007", "深证300"),
-            ("sz399008", "中小300"),
+        const PAGE_SIZE: usize = 500;
+        // 上证系列指数，深证系列指数，中证系列指数
+        let series = [
+            ("m%3A1+s%3A2", "上证指数"),
+            ("m%3A0+t%3A5", "深证指数"),
+            ("m%3A2", "中证指数"),
         ];
-        Ok(data
-            .into_iter()
-            .map(|(code, name)| StockInfo {
-                code: code.to_owned(),
-                name: name.to_owned(),
-                block: "指数".to_owned(),
-                is_margin: false,
-                listing_date: Default::default(),
-            })
-            .collect())
+        let mut codes = HashSet::new();
+        let mut data = Vec::new();
+        for (fs, block) in series.into_iter() {
+            let mut page = 1;
+            let mut count = 0;
+            loop {
+                let req_url = format!(
+                    "https://push2.eastmoney.com/api/qt/clist/get?\
+                pn={page}&pz={page_size}&po=0&np=1&ut=bd1d9ddb04089700cf9c27f6f7426281&fltt=2&invt=2&\
+                fid=f12&fs={fs}&fields=f12%2Cf13%2Cf14",
+                    page = page,
+                    page_size = PAGE_SIZE,
+                    fs = fs
+                );
+                let resp = self.transport.get_text(&req_url, None).await?;
+                let json: EastIndexList = serde_json::from_str(&resp)?;
+                let json = match json.data {
+                    Some(data) if !data.diff.is_empty() => data,
+                    _ => break,
+                };
+                count += json.diff.len();
+                for item in json.diff.iter() {
+                    let prefix = match item.market {
+                        1 => "sh",
+                        0 => "sz",
+                        _ if item.code.starts_with("000") => "sh",
+                        _ if item.code.starts_with("399") => "sz",
+                        // 中证指数公司独立发布的指数(如930050、H30269)
+                        _ => "csi",
+                    };
+                    let code = format!("{}{}", prefix, item.code);
+                    if !codes.insert(code.clone()) {
+                        continue;
+                    }
+                    data.push(StockInfo {
+                        code,
+                        name: item.name.to_owned(),
+                        block: block.to_owned(),
+                        is_margin: false,
+                        listing_date: Default::default(),
+                        board: Default::default(),
+                        currency: Default::default(),
+                        lot_size: Some(100),
+                    });
+                }
+                if count >= json.total {
+                    break;
+                }
+                page += 1;
+            }
+        }
+        Ok(data)
+    }
+    /// 指数成分股及权重，先取不晚于`date`的最近一期权重日期，再取该期全部成分股
+    async fn fetch_index_constituents(
+        &self,
+        code: &str,
+        date: Option<NaiveDate>,
+    ) -> Result<Vec<IndexConstituent>> {
+        const PAGE_SIZE: usize = 500;
+        let index_code = code
+            .strip_prefix("csi")
+            .or_else(|| code.get(2..))
+            .unwrap_or(code);
+        let mut filter = format!("(INDEX_CODE%3D%22{}%22)", index_code);
+        if let Some(date) = date {
+            filter.push_str(&format!(
+                "(END_DATE%3C%3D%27{}%27)",
+                date.format("%Y-%m-%d")
+            ));
+        }
+        let req_url = format!(
+            "https://datacenter-web.eastmoney.com/api/data/v1/get?\
+        reportName=RPT_INDEX_TS_COMPONENT&columns=ALL&source=WEB&sortColumns=END_DATE&sortTypes=-1&\
+        pageNumber=1&pageSize=1&filter={filter}",
+            filter = filter
+        );
+        let resp = self.transport.get_text(&req_url, None).await?;
+        let json = serde_json::from_str::<EastIndexConstituent>(&resp)?;
+        let trade_date = match json.result.as_ref().and_then(|r| r.data.first()) {
+            Some(item) => NaiveDateTime::parse_from_str(item.end_date, "%Y-%m-%d %H:%M:%S")
+                .map_err(|e| Error::Custom(format!("Invalid index weight date: {}", e)))?,
+            None => return Ok(Vec::new()),
+        };
+        let filter = format!(
+            "(INDEX_CODE%3D%22{}%22)(END_DATE%3D%27{}%27)",
+            index_code,
+            trade_date.format("%Y-%m-%d")
+        );
+
+        let mut data = Vec::new();
+        let mut page = 1;
+        loop {
+            let req_url = format!(
+                "https://datacenter-web.eastmoney.com/api/data/v1/get?\
+            reportName=RPT_INDEX_TS_COMPONENT&columns=ALL&source=WEB&sortColumns=WEIGHT&sortTypes=-1&\
+            pageNumber={page}&pageSize={page_size}&filter={filter}",
+                page = page,
+                page_size = PAGE_SIZE,
+                filter = filter
+            );
+            let resp = self.transport.get_text(&req_url, None).await?;
+            let json = serde_json::from_str::<EastIndexConstituent>(&resp)?;
+            let result = match json.result {
+                Some(result) => result,
+                None => break,
+            };
+            data.extend(result.data.iter().map(|item| IndexConstituent {
+                index_code: code.to_owned(),
+                index_name: item.index_name.to_owned(),
+                code: to_std_code(MarketType::Stock, item.code),
+                name: item.name.to_owned(),
+                weight: item.weight.unwrap_or(0.0),
+                trade_date,
+            }));
+            if page >= result.pages {
+                break;
+            }
+            page += 1;
+        }
+        Ok(data)
     }
     /// 指数k线数据
     async fn fetch_index_bar(
@@ -81,10 +483,11 @@ impl StockFetch for HiqStockFetch {
         start: Option<NaiveDate>,
         end: Option<NaiveDate>,
     ) -> Result<StockBar> {
-        self.fetch_stock_bar(code, name, freq, start, end).await
+        self.fetch_bar_adjust(code, name, freq, start, end, BarAdjust::None)
+            .await
     }
 
-    /// 获取股票基本信息
+    /// 获取股票基本信息，北交所接口失败时只记录日志，返回的列表中没有北交所股票
     async fn fetch_stock_info(&self) -> Result<Vec<StockInfo>> {
         let mut data = Vec::new();
 
@@ -112,12 +515,8 @@ impl StockFetch for HiqStockFetch {
             );
 
             let resp = self
-                .client
-                .get(req_url)
-                .headers(header.clone())
-                .send()
-                .await?
-                .text()
+                .transport
+                .get_text(&req_url, Some(header.clone()))
                 .await?;
 
             let json: ExchStockInfo = serde_json::from_str(&resp)?;
@@ -137,6 +536,9 @@ impl StockFetch for HiqStockFetch {
                         block: block_name.to_owned(),
                         is_margin: margin_codes.contains(&code),
                         listing_date,
+                        board: Board::from_code(&code),
+                        currency: Currency::from_code(&code),
+                        lot_size: StockInfo::lot_size_of(&code),
                     }
                 })
                 .collect();
@@ -148,7 +550,7 @@ impl StockFetch for HiqStockFetch {
         let req_url = "http://www.szse.cn/api/report/ShowReport?SHOWTYPE=xlsx&CATALOGID=1110&\
         TABKEY=tab1&random=0.6935816432433362";
 
-        let resp = self.client.get(req_url).send().await?.bytes().await?;
+        let resp = self.transport.get(req_url, None).await?;
         // 板块	公司全称	英文名称	注册地址	A股代码	A股简称	A股上市日期	A股总股本	A股流通股本	B股代码
         // 	B股简称	B股上市日期	B股总股本	B股流通股本	地 区	省    份	城     市	所属行业	公司网址
         // 2712
@@ -183,12 +585,78 @@ impl StockFetch for HiqStockFetch {
                         block: self.get_cell(&row[0]),
                         is_margin: margin_codes.contains(&code),
                         listing_date,
+                        board: Board::from_code(&code),
+                        currency: Currency::from_code(&code),
+                        lot_size: StockInfo::lot_size_of(&code),
                     }
                 })
                 .collect();
             data.extend(tmp_vec.into_iter());
         }
 
+        // 北京，北交所接口失败时不影响沪深
+        match self.fetch_bj_stock_info(&margin_codes).await {
+            Ok(bj) => data.extend(bj),
+            Err(e) => log::error!("fetch bj stock info error: {}, skip", e),
+        }
+
+        Ok(data)
+    }
+    /// 港股、美股基本信息(雪球)，分页请求
+    async fn fetch_overseas_stock_info(&self, market: MarketType) -> Result<Vec<StockInfo>> {
+        let (exchange, block, market_name) = match market {
+            MarketType::HkStock => (Exchange::HK, "港股", "HK"),
+            MarketType::UsStock => (Exchange::US, "美股", "US"),
+            _ => {
+                return Err(Error::Custom(format!(
+                    "Invalid overseas market: {:?}!",
+                    market
+                )))
+            }
+        };
+        const PAGE_SIZE: usize = 90;
+        // prepare cookie
+        self.transport.get("https://xueqiu.com/hq", None).await?;
+        let mut data = Vec::new();
+        let mut page = 1;
+        loop {
+            let req_url = format!(
+                "https://stock.xueqiu.com/v5/stock/screener/quote/list.json?\
+            page={page}&size={page_size}&order=asc&order_by=symbol&market={market}&type={typ}",
+                page = page,
+                page_size = PAGE_SIZE,
+                market = market_name,
+                typ = market_name.to_lowercase()
+            );
+            let resp = self.transport.get_text(&req_url, None).await?;
+            let json: XueQiuStockList = serde_json::from_str(&resp)?;
+            let list = json
+                .data
+                .ok_or(Error::Custom("Error fetch overseas stock info".to_string()))?;
+            let count = list.count;
+            let is_empty = list.list.is_empty();
+            data.extend(list.list.into_iter().map(|item| {
+                let code = to_std_code(market, &item.symbol);
+                let listing_date = item
+                    .issue_date_ts
+                    .map(|ts| exchange.local_time(ts).date().and_hms_opt(0, 0, 0).unwrap())
+                    .unwrap_or_default();
+                StockInfo {
+                    lot_size: item.lot_size.or_else(|| StockInfo::lot_size_of(&code)),
+                    code,
+                    name: item.name,
+                    block: block.to_owned(),
+                    is_margin: false,
+                    listing_date,
+                    board: Default::default(),
+                    currency: exchange.currency(),
+                }
+            }));
+            if is_empty || data.len() >= count {
+                break;
+            }
+            page += 1;
+        }
         Ok(data)
     }
     /// 获取融资融券股票代码
@@ -206,7 +674,7 @@ impl StockFetch for HiqStockFetch {
                 page_size = PAGE_SIZE
             );
 
-            let resp = self.client.get(req_url).send().await?.text().await?;
+            let resp = self.transport.get_text(&req_url, None).await?;
 
             let js_text = &resp[43..resp.len() - 2];
             let json: EastStockInfoMargin = serde_json::from_str(js_text)?;
@@ -239,28 +707,65 @@ impl StockFetch for HiqStockFetch {
         start: Option<NaiveDate>,
         end: Option<NaiveDate>,
     ) -> Result<StockBar> {
-        let market_code = if code.starts_with("sh") {
-            // 上海市场
-            format!("{}.{}", Market::SH as i32, &code[2..])
-        } else {
-            format!("{}.{}", Market::SZ as i32, &code[2..])
-        };
-
-        let freq = if freq.is_none() {
-            BarFreq::Daily
+        if let Some(id) = to_overseas_id(code) {
+            return self
+                .fetch_overseas_bar(&id, name, freq, start, end, true)
+                .await;
+        }
+        // 日线根据除权除息事件本地计算复权因子，其他频率请求后复权k线计算
+        if matches!(
+            freq,
+            None | Some(BarFreq::Daily) | Some(BarFreq::LooseDaily)
+        ) {
+            let actions = self.fetch_stock_corp_action(Some(code), start, end).await?;
+            let calc = AdjustCalculator::new(actions);
+            self.fetch_bar_adjust(code, name, freq, start, end, BarAdjust::Local(&calc))
+                .await
         } else {
-            freq.unwrap()
-        };
-
-        let bars = fetch_bar(&self.client, &market_code, code, freq, start, end).await?;
-        let (stock_name, bars) = to_bar_ds(name, bars);
-        let stock_bar = StockBar {
-            code: code.to_owned(),
-            name: stock_name,
+            self.fetch_bar_adjust(code, name, freq, start, end, BarAdjust::Remote)
+                .await
+        }
+    }
+    /// 股票不复权k线数据，复权因子均为1
+    async fn fetch_stock_nfq_bar(
+        &self,
+        code: &str,
+        name: Option<&str>,
+        freq: Option<BarFreq>,
+        start: Option<NaiveDate>,
+        end: Option<NaiveDate>,
+    ) -> Result<StockBar> {
+        if let Some(id) = to_overseas_id(code) {
+            return self
+                .fetch_overseas_bar(&id, name, freq, start, end, false)
+                .await;
+        }
+        self.fetch_bar_adjust(code, name, freq, start, end, BarAdjust::None)
+            .await
+    }
+    /// 股票分钟k线，分页获取，请求后复权k线计算复权因子
+    async fn fetch_stock_minute_bar(
+        &self,
+        code: &str,
+        name: Option<&str>,
+        freq: Option<BarFreq>,
+        start: Option<NaiveDate>,
+        end: Option<NaiveDate>,
+    ) -> Result<MinuteBar> {
+        let market_code = to_east_secid(code)?;
+        let freq = freq.unwrap_or(BarFreq::Min1);
+        let bars = fetch_minute_bar(
+            self.transport.as_ref(),
+            &market_code,
+            code,
             freq,
-            bars,
-        };
-        Ok(stock_bar)
+            start,
+            end,
+            true,
+            MINUTE_BAR_PAGE_SIZE,
+        )
+        .await?;
+        Ok(to_minute_bar(code, name, freq, bars))
     }
 
     /// 股票最新指标
@@ -291,7 +796,7 @@ impl StockFetch for HiqStockFetch {
             fid=f3&fs=m:0+t:6,m:0+t:13,m:0+t:80,m:1+t:2,m:1+t:23&fields=f2,f9,f12,f14,f20,f21,f23&\
             _=1626075887768", page_num = page_num, page_size = PAGE_SIZE);
 
-            let resp = self.client.get(req_url).send().await?.text().await?;
+            let resp = self.transport.get_text(&req_url, None).await?;
 
             let json = serde_json::from_str::<EastStockIndex>(&resp)?;
             if json.data.is_none() {
@@ -364,7 +869,7 @@ impl StockFetch for HiqStockFetch {
             %2Cf207%2Cf208%2Cf209%2Cf222&_=1626075887768"
         );
 
-        let resp = self.client.get(req_url).send().await?.text().await?;
+        let resp = self.transport.get_text(&req_url, None).await?;
 
         let json: EastStockIndustry = serde_json::from_str(&resp)?;
 
@@ -411,7 +916,7 @@ impl StockFetch for HiqStockFetch {
                 code = &industry.code
             );
 
-            let resp = self.client.get(req_url).send().await?.text().await?;
+            let resp = self.transport.get_text(&req_url, None).await?;
 
             let json: EastStockIndustry = serde_json::from_str(&resp)?;
 
@@ -443,7 +948,16 @@ impl StockFetch for HiqStockFetch {
         let market_code = format!("90.{}", code);
         let freq = BarFreq::Daily;
 
-        let bars = fetch_bar(&self.client, &market_code, code, freq, start, end).await?;
+        let bars = fetch_bar(
+            self.transport.as_ref(),
+            &market_code,
+            code,
+            freq,
+            start,
+            end,
+            BarAdjust::None,
+        )
+        .await?;
         let (stock_name, bars) = to_bar_ds(name, bars);
         let industry_bar = StockIndustryBar {
             code: code.to_owned(),
@@ -464,7 +978,7 @@ impl StockFetch for HiqStockFetch {
         &_=1626075887768"
         );
 
-        let resp = self.client.get(req_url).send().await?.text().await?;
+        let resp = self.transport.get_text(&req_url, None).await?;
 
         let json: EastStockIndustry = serde_json::from_str(&resp)?;
 
@@ -512,7 +1026,7 @@ impl StockFetch for HiqStockFetch {
                 code = &concept.code
             );
 
-            let resp = self.client.get(req_url).send().await?.text().await?;
+            let resp = self.transport.get_text(&req_url, None).await?;
 
             let json: EastStockIndustry = serde_json::from_str(&resp)?;
 
@@ -545,7 +1059,16 @@ impl StockFetch for HiqStockFetch {
         let market_code = format!("90.{}", code);
         let freq = BarFreq::Daily;
 
-        let bars = fetch_bar(&self.client, &market_code, code, freq, start, end).await?;
+        let bars = fetch_bar(
+            self.transport.as_ref(),
+            &market_code,
+            code,
+            freq,
+            start,
+            end,
+            BarAdjust::None,
+        )
+        .await?;
         let (stock_name, bars) = to_bar_ds(name, bars);
         let industry_bar = StockConceptBar {
             code: code.to_owned(),
@@ -583,7 +1106,7 @@ impl StockFetch for HiqStockFetch {
             token=894050c76af8597a853f5b408b759f5d&filter=%28REPORTDATE%3D%27{season_date}%27%29",
                                   page_size = PAGE_SIZE, page = page, season_date = season_date);
 
-            let resp = self.client.get(req_url).send().await?.text().await?;
+            let resp = self.transport.get_text(&req_url, None).await?;
 
             let json: EastStockYJBB = serde_json::from_str(&resp)?;
 
@@ -675,6 +1198,199 @@ impl StockFetch for HiqStockFetch {
         }
         Ok(data)
     }
+    /// 资产负债表，只保留已公告的记录
+    async fn fetch_stock_balance_sheet(
+        &self,
+        year: u16,
+        season: u16,
+    ) -> Result<Vec<StockBalanceSheet>> {
+        let data: Vec<EastStockBalanceData> = self
+            .fetch_season_report(
+                "RPT_DMSK_FN_BALANCE",
+                "REPORT_DATE",
+                &["NOTICE_DATE", "SECURITY_CODE"],
+                year,
+                season,
+            )
+            .await?;
+        Ok(data
+            .into_iter()
+            .filter_map(|item| {
+                let (report_date, notice_date) =
+                    finance_report_dates(&item.report_date, item.notice_date.as_deref())?;
+                Some(StockBalanceSheet {
+                    code: to_std_code(MarketType::Stock, &item.code),
+                    name: item.name,
+                    report_date,
+                    notice_date,
+                    monetary_funds: item.monetary_funds.unwrap_or(0.0),
+                    accounts_rece: item.accounts_rece.unwrap_or(0.0),
+                    inventory: item.inventory.unwrap_or(0.0),
+                    total_assets: item.total_assets.unwrap_or(0.0),
+                    accounts_payable: item.accounts_payable.unwrap_or(0.0),
+                    advance_receivables: item.advance_receivables.unwrap_or(0.0),
+                    total_liab: item.total_liab.unwrap_or(0.0),
+                    total_equity: item.total_equity.unwrap_or(0.0),
+                    debt_asset_ratio: item.debt_asset_ratio.unwrap_or(0.0),
+                })
+            })
+            .collect())
+    }
+    /// 利润表，只保留已公告的记录
+    async fn fetch_stock_income(&self, year: u16, season: u16) -> Result<Vec<StockIncome>> {
+        let data: Vec<EastStockIncomeData> = self
+            .fetch_season_report(
+                "RPT_DMSK_FN_INCOME",
+                "REPORT_DATE",
+                &["NOTICE_DATE", "SECURITY_CODE"],
+                year,
+                season,
+            )
+            .await?;
+        Ok(data
+            .into_iter()
+            .filter_map(|item| {
+                let (report_date, notice_date) =
+                    finance_report_dates(&item.report_date, item.notice_date.as_deref())?;
+                Some(StockIncome {
+                    code: to_std_code(MarketType::Stock, &item.code),
+                    name: item.name,
+                    report_date,
+                    notice_date,
+                    total_operate_income: item.total_operate_income.unwrap_or(0.0),
+                    operate_cost: item.operate_cost.unwrap_or(0.0),
+                    sale_expense: item.sale_expense.unwrap_or(0.0),
+                    manage_expense: item.manage_expense.unwrap_or(0.0),
+                    finance_expense: item.finance_expense.unwrap_or(0.0),
+                    total_operate_cost: item.total_operate_cost.unwrap_or(0.0),
+                    operate_profit: item.operate_profit.unwrap_or(0.0),
+                    total_profit: item.total_profit.unwrap_or(0.0),
+                    parent_netprofit: item.parent_netprofit.unwrap_or(0.0),
+                })
+            })
+            .collect())
+    }
+    /// 现金流量表，只保留已公告的记录
+    async fn fetch_stock_cash_flow(&self, year: u16, season: u16) -> Result<Vec<StockCashFlow>> {
+        let data: Vec<EastStockCashFlowData> = self
+            .fetch_season_report(
+                "RPT_DMSK_FN_CASHFLOW",
+                "REPORT_DATE",
+                &["NOTICE_DATE", "SECURITY_CODE"],
+                year,
+                season,
+            )
+            .await?;
+        Ok(data
+            .into_iter()
+            .filter_map(|item| {
+                let (report_date, notice_date) =
+                    finance_report_dates(&item.report_date, item.notice_date.as_deref())?;
+                Some(StockCashFlow {
+                    code: to_std_code(MarketType::Stock, &item.code),
+                    name: item.name,
+                    report_date,
+                    notice_date,
+                    netcash_operate: item.netcash_operate.unwrap_or(0.0),
+                    netcash_invest: item.netcash_invest.unwrap_or(0.0),
+                    netcash_finance: item.netcash_finance.unwrap_or(0.0),
+                    construct_long_asset: item.construct_long_asset.unwrap_or(0.0),
+                    cce_add: item.cce_add.unwrap_or(0.0),
+                })
+            })
+            .collect())
+    }
+    /// 股东户数，只保留已公告的记录，
+    /// 公司可在非季末日披露股东户数，取截止日期在上一报告期之后、该报告期及之前的全部记录
+    async fn fetch_stock_holder_num(&self, year: u16, season: u16) -> Result<Vec<StockHolderNum>> {
+        let (pre_year, pre_season) = if season == 1 {
+            (year - 1, 4)
+        } else {
+            (year, season - 1)
+        };
+        let filter = format!(
+            "%28END_DATE%3E%27{}%27%29%28END_DATE%3C%3D%27{}%27%29",
+            finance_report_date(pre_year, pre_season)?,
+            finance_report_date(year, season)?
+        );
+        let data: Vec<EastStockHolderNumData> = self
+            .fetch_data_center(
+                "RPT_HOLDERNUM_DET",
+                &["HOLD_NOTICE_DATE", "SECURITY_CODE"],
+                &filter,
+            )
+            .await?;
+        Ok(data
+            .into_iter()
+            .filter_map(|item| {
+                let (report_date, notice_date) =
+                    finance_report_dates(&item.report_date, item.notice_date.as_deref())?;
+                Some(StockHolderNum {
+                    code: to_std_code(MarketType::Stock, &item.code),
+                    name: item.name,
+                    report_date,
+                    notice_date,
+                    holder_num: item.holder_num.unwrap_or(0),
+                    pre_holder_num: item.pre_holder_num.unwrap_or(0),
+                    holder_num_change: item.holder_num_change.unwrap_or(0),
+                    holder_num_ratio: item.holder_num_ratio.unwrap_or(0.0),
+                    avg_free_shares: item.avg_free_shares.unwrap_or(0.0),
+                })
+            })
+            .collect())
+    }
+    /// 十大股东/十大流通股东，按代码、排名排序，只保留已公告的记录
+    async fn fetch_stock_top_holders(
+        &self,
+        year: u16,
+        season: u16,
+        is_float: bool,
+    ) -> Result<Vec<StockTopHolder>> {
+        let report = if is_float {
+            "RPT_F10_EH_FREEHOLDERS"
+        } else {
+            "RPT_F10_EH_HOLDERS"
+        };
+        let data: Vec<EastStockTopHolderData> = self
+            .fetch_season_report(
+                report,
+                "END_DATE",
+                &["SECURITY_CODE", "HOLDER_RANK"],
+                year,
+                season,
+            )
+            .await?;
+        Ok(data
+            .into_iter()
+            .filter_map(|item| {
+                let (report_date, notice_date) =
+                    finance_report_dates(&item.report_date, item.notice_date.as_deref())?;
+                let hold_num = item.hold_num.unwrap_or(0.0);
+                let (hold_change, change_type) = match item.hold_change.as_deref() {
+                    Some("新进") => (hold_num, "新进"),
+                    Some(change) => match change.parse::<f64>() {
+                        Ok(change) if change > 0.0 => (change, "增加"),
+                        Ok(change) if change < 0.0 => (change, "减少"),
+                        _ => (0.0, "不变"),
+                    },
+                    None => (0.0, "不变"),
+                };
+                Some(StockTopHolder {
+                    code: to_std_code(MarketType::Stock, &item.code),
+                    report_date,
+                    notice_date,
+                    is_float,
+                    rank: item.rank,
+                    holder_name: item.holder_name,
+                    holder_type: item.holder_type.unwrap_or_default(),
+                    hold_num,
+                    hold_ratio: item.hold_ratio.unwrap_or(0.0),
+                    hold_change,
+                    change_type: change_type.to_string(),
+                })
+            })
+            .collect())
+    }
     /// 融资融券
     async fn fetch_stock_margin(
         &self,
@@ -682,6 +1398,7 @@ impl StockFetch for HiqStockFetch {
         start: Option<NaiveDate>,
         end: Option<NaiveDate>,
     ) -> Result<Vec<StockMargin>> {
+        let id = to_security_id(code)?;
         let mut page = 1;
         const PAGE_SIZE: i32 = 500;
         let mut data = Vec::new();
@@ -699,10 +1416,10 @@ impl StockFetch for HiqStockFetch {
             _=1668232304568",
                 page_size = PAGE_SIZE,
                 page = page,
-                code = &code[2..]
+                code = id.code()
             );
 
-            let resp = self.client.get(req_url).send().await?.text().await?;
+            let resp = self.transport.get_text(&req_url, None).await?;
 
             let json = serde_json::from_str::<EastStockMargin>(&resp)?;
 
@@ -760,76 +1477,646 @@ impl StockFetch for HiqStockFetch {
         Ok(data)
     }
 
-    /// 实时行情
-    async fn fetch_stock_rt_quot(&self, code: Vec<&str>) -> Result<HashMap<String, StockRtQuot>> {
-        let codes = code
-            .iter()
-            .map(|s| s.to_uppercase())
-            .collect::<Vec<_>>()
-            .join("%2C");
-
-        let req_url = format!(
-            "https://stock.xueqiu.com/v5/stock/realtime/quotec.json?\
-        symbol={codes}",
-            codes = codes
+    /// 北向资金个股持股，全市场某交易日
+    async fn fetch_stock_north_hold(&self, date: NaiveDate) -> Result<Vec<StockNorthHold>> {
+        let filter = format!(
+            "%28TRADE_DATE%3D%27{}%27%29%28INTERVAL_TYPE%3D%221%22%29",
+            date.format("%Y-%m-%d")
         );
-
-        let resp = self.client.get(req_url).send().await?.text().await?;
-
-        let json: XuQiuStockRtQuot = serde_json::from_str(&resp)?;
-        let data = json
-            .data
-            .ok_or(Error::Custom("Error fetch quotation".to_string()))?;
-
-        let data: Vec<_> = data
-            .iter()
+        let data: Vec<EastStockNorthHoldData> = self
+            .fetch_data_center("RPT_MUTUAL_HOLDSTOCKNORTH_STA", &["SECURITY_CODE"], &filter)
+            .await?;
+        data.into_iter()
             .map(|item| {
-                let code = item.symbol.to_lowercase();
-                let time = Local
-                    .timestamp_opt(item.timestamp / 1000, 0)
-                    .unwrap()
-                    .naive_local();
-                let mut is_trading = false;
-                let t = time.time();
-                let ms = NaiveTime::from_hms_opt(9, 30, 0).unwrap();
-                let me = NaiveTime::from_hms_opt(11, 30, 0).unwrap();
-                let ns = NaiveTime::from_hms_opt(13, 0, 0).unwrap();
-                let ne = NaiveTime::from_hms_opt(15, 0, 0).unwrap();
-                if (t > ms && t < me) || (t > ns && t < ne) {
-                    is_trading = true;
-                }
-                (
-                    code.clone(),
-                    StockRtQuot {
-                        code,
-                        time,
-                        last_close: item.last_close,
-                        open: item.open,
-                        high: item.high,
-                        low: item.low,
-                        last: item.last,
-                        chg: item.chg,
-                        chg_pct: item.percent,
-                        volume: item.volume,
-                        amount: item.amount,
-                        turnover: item.turnover_rate,
-                        total_value: item.market_capital,
-                        currency_value: item.float_market_capital,
-                        is_trading: is_trading,
-                    },
-                )
+                let trade_date =
+                    NaiveDateTime::parse_from_str(&item.trade_date, "%Y-%m-%d %H:%M:%S")
+                        .map_err(|e| Error::Custom(format!("Invalid north hold date: {}", e)))?;
+                Ok(StockNorthHold {
+                    code: to_std_code(MarketType::Stock, &item.code),
+                    name: item.name,
+                    trade_date,
+                    hold_shares: item.hold_shares,
+                    hold_market_cap: item.hold_market_cap,
+                    hold_ratio: item.hold_ratio,
+                    hold_shares_change: item.hold_shares_change,
+                })
             })
-            .collect();
-
-        Ok(data.into_iter().collect())
+            .collect()
+    }
+    /// 北向资金每日成交，默认从沪股通开通(2014-11-17)至今，金额由百万元转换为元，缺失的金额为None
+    async fn fetch_north_flow(
+        &self,
+        start: Option<NaiveDate>,
+        end: Option<NaiveDate>,
+    ) -> Result<Vec<NorthFlow>> {
+        let start = start.unwrap_or(NaiveDate::from_ymd_opt(2014, 11, 17).unwrap());
+        let end = end.unwrap_or(Local::now().date_naive());
+        let filter = format!(
+            "%28MUTUAL_TYPE+in+%28%22001%22%2C%22003%22%29%29\
+            %28TRADE_DATE%3E%3D%27{}%27%29%28TRADE_DATE%3C%3D%27{}%27%29",
+            start.format("%Y-%m-%d"),
+            end.format("%Y-%m-%d")
+        );
+        let data: Vec<EastNorthFlowData> = self
+            .fetch_data_center(
+                "RPT_MUTUAL_DEAL_HISTORY",
+                &["TRADE_DATE", "MUTUAL_TYPE"],
+                &filter,
+            )
+            .await?;
+        const UNIT: f64 = 1_000_000.0;
+        let mut flows = Vec::new();
+        for item in data.into_iter() {
+            let exchange = match item.mutual_type.as_str() {
+                "001" => Exchange::SH,
+                "003" => Exchange::SZ,
+                _ => continue,
+            };
+            let trade_date =
+                NaiveDateTime::parse_from_str(&item.trade_date, "%Y-%m-%d %H:%M:%S")
+                    .map_err(|e| Error::Custom(format!("Invalid north flow date: {}", e)))?;
+            flows.push(NorthFlow {
+                exchange,
+                trade_date,
+                buy_amount: item.buy_amount.map(|v| v * UNIT),
+                sell_amount: item.sell_amount.map(|v| v * UNIT),
+                net_buy: item.net_buy.map(|v| v * UNIT),
+            });
+        }
+        Ok(flows)
+    }
+    /// 龙虎榜，全市场某交易日，席位按上榜原因归并，买入、卖出各取金额最大的前五
+    async fn fetch_stock_dragon_tiger(&self, date: NaiveDate) -> Result<Vec<StockDragonTiger>> {
+        let filter = format!("%28TRADE_DATE%3D%27{}%27%29", date.format("%Y-%m-%d"));
+        let data: Vec<EastDragonTigerData> = self
+            .fetch_data_center("RPT_DAILYBILLBOARD_DETAILSNEW", &["SECURITY_CODE"], &filter)
+            .await?;
+        if data.is_empty() {
+            return Ok(Vec::new());
+        }
+        let buys: Vec<EastDragonTigerSeatData> = self
+            .fetch_data_center("RPT_BILLBOARD_DAILYDETAILSBUY", &["SECURITY_CODE"], &filter)
+            .await?;
+        let sells: Vec<EastDragonTigerSeatData> = self
+            .fetch_data_center(
+                "RPT_BILLBOARD_DAILYDETAILSSELL",
+                &["SECURITY_CODE"],
+                &filter,
+            )
+            .await?;
+        let mut buy_seats = dragon_tiger_seats(buys, |seat| seat.buy_amount);
+        let mut sell_seats = dragon_tiger_seats(sells, |seat| seat.sell_amount);
+
+        data.into_iter()
+            .map(|item| {
+                let trade_date =
+                    NaiveDateTime::parse_from_str(&item.trade_date, "%Y-%m-%d %H:%M:%S")
+                        .map_err(|e| Error::Custom(format!("Invalid dragon tiger date: {}", e)))?;
+                let key = (item.code, item.reason);
+                Ok(StockDragonTiger {
+                    code: to_std_code(MarketType::Stock, &key.0),
+                    name: item.name,
+                    trade_date,
+                    close: item.close.unwrap_or(0.0),
+                    chg_pct: item.chg_pct.unwrap_or(0.0),
+                    turnover: item.turnover.unwrap_or(0.0),
+                    amount: item.amount.unwrap_or(0.0),
+                    buy_amount: item.buy_amount.unwrap_or(0.0),
+                    sell_amount: item.sell_amount.unwrap_or(0.0),
+                    net_buy: item.net_buy.unwrap_or(0.0),
+                    buy_seats: buy_seats.remove(&key).unwrap_or_default(),
+                    sell_seats: sell_seats.remove(&key).unwrap_or_default(),
+                    reason: key.1,
+                })
+            })
+            .collect()
+    }
+    /// 涨停、跌停或炸板股池，东方财富只保留近期约一个月的数据
+    async fn fetch_stock_limit_pool(
+        &self,
+        date: NaiveDate,
+        pool: LimitPool,
+    ) -> Result<Vec<StockLimitPool>> {
+        let (topic, sort) = match pool {
+            LimitPool::LimitUp => ("getTopicZTPool", "fbt%3Aasc"),
+            LimitPool::LimitDown => ("getTopicDTPool", "fund%3Aasc"),
+            LimitPool::Broken => ("getTopicZBPool", "fbt%3Aasc"),
+        };
+        let req_url = format!(
+            "https://push2ex.eastmoney.com/{topic}?ut=7eea3edcaed734bea9cbfc24409ed989&\
+            dpt=wz.ztzt&Pageindex=0&pagesize=10000&sort={sort}&date={date}",
+            topic = topic,
+            sort = sort,
+            date = date.format("%Y%m%d")
+        );
+        let resp = self.transport.get_text(&req_url, None).await?;
+        let json: EastLimitPool = serde_json::from_str(&resp)?;
+        let items = match json.data {
+            Some(data) => data.pool,
+            None => return Ok(Vec::new()),
+        };
+        let trade_date = NaiveDateTime::new(date, NaiveTime::from_hms_opt(0, 0, 0).unwrap());
+        Ok(items
+            .into_iter()
+            .map(|item| {
+                let limit_days = match pool {
+                    LimitPool::LimitUp => item.lbc,
+                    LimitPool::LimitDown => item.days,
+                    LimitPool::Broken => None,
+                };
+                StockLimitPool {
+                    code: to_std_code(MarketType::Stock, &item.code),
+                    name: item.name,
+                    trade_date,
+                    pool,
+                    close: (item.price / 1000.0) as f32,
+                    chg_pct: item.chg_pct,
+                    amount: item.amount,
+                    turnover: item.turnover,
+                    first_limit_time: limit_pool_time(&date, item.fbt),
+                    last_limit_time: limit_pool_time(&date, item.lbt),
+                    limit_days: limit_days.unwrap_or(0),
+                    open_times: item.zbc.or(item.oc).unwrap_or(0),
+                    sealed_amount: item.fund.unwrap_or(0.0),
+                    industry: item.hybk,
+                }
+            })
+            .collect())
+    }
+    /// 当前风险警示股票，名称以`*ST`开头的为退市风险警示
+    async fn fetch_stock_st(&self) -> Result<Vec<StockStatus>> {
+        let req_url = "http://push2.eastmoney.com/api/qt/clist/get?\
+            pn=1&pz=5000&po=1&np=1&ut=bd1d9ddb04089700cf9c27f6f7426281&fltt=2&invt=2&fid=f12&\
+            fs=m%3A0+f%3A4%2Cm%3A1+f%3A4&fields=f12%2Cf14";
+        let resp = self.transport.get_text(req_url, None).await?;
+        let json: EastStockIndustry = serde_json::from_str(&resp)?;
+        let start_date = Local::now().date_naive().and_hms_opt(0, 0, 0).unwrap();
+        Ok(json
+            .data
+            .diff
+            .iter()
+            .map(|item| StockStatus {
+                code: to_std_code(MarketType::Stock, item.code),
+                name: item.name.to_owned(),
+                status: st_status_of(item.name).unwrap_or(StockStatusType::ST),
+                start_date,
+                end_date: None,
+                reason: String::new(),
+                listing_date: None,
+            })
+            .collect())
+    }
+    /// 沪市、北交所某日发布的风险警示变更公告(东方财富公告，风险提示类)，变更后的状态由标题推断。
+    /// 交易所未提供沪市、北交所批量的简称变更记录，深市以简称变更记录为准，不在其中
+    async fn fetch_stock_st_notice(&self, date: NaiveDate) -> Result<Vec<StockStatusNotice>> {
+        const PAGE_SIZE: usize = 100;
+        let mut data = Vec::new();
+        let mut page = 1;
+        loop {
+            let req_url = format!(
+                "https://np-anotice-stock.eastmoney.com/api/security/ann?\
+            sr=-1&page_size={page_size}&page_index={page}&ann_type=SHA%2CBJA&client_source=web&\
+            f_node=3&s_node=0&begin_time={date}&end_time={date}",
+                page_size = PAGE_SIZE,
+                page = page,
+                date = date.format("%Y-%m-%d")
+            );
+            let resp = self.transport.get_text(&req_url, None).await?;
+            let json: EastNotice = serde_json::from_str(&resp)?;
+            let notice = match json.data {
+                Some(notice) => notice,
+                None => break,
+            };
+            let (len, total) = (notice.list.len(), notice.total_hits);
+            for item in notice.list.into_iter() {
+                let status = match st_notice_status(&item.title) {
+                    Some(status) => status,
+                    None => continue,
+                };
+                let notice_date =
+                    NaiveDateTime::parse_from_str(&item.notice_date, "%Y-%m-%d %H:%M:%S")
+                        .map_err(|e| Error::Custom(format!("Invalid notice date: {}", e)))?;
+                for code in item.codes.iter() {
+                    let std_code = to_std_code(MarketType::Stock, &code.stock_code);
+                    if std_code.starts_with("sh") || std_code.starts_with("bj") {
+                        data.push(StockStatusNotice {
+                            code: std_code,
+                            name: code.short_name.clone(),
+                            notice_date,
+                            status,
+                            title: item.title.clone(),
+                        });
+                    }
+                }
+            }
+            if len == 0 || page * PAGE_SIZE >= total {
+                break;
+            }
+            page += 1;
+        }
+        Ok(data)
+    }
+    /// 风险警示历史区间，由深交所证券简称变更记录推算(上交所未提供批量的简称变更记录)，
+    /// 简称变为ST、*ST的当日开始，变回其他简称(或ST、*ST互转)的前一日结束，未结束的end_date为空
+    async fn fetch_stock_st_history(&self) -> Result<Vec<StockStatus>> {
+        let req_url = "http://www.szse.cn/api/report/ShowReport?SHOWTYPE=xlsx&CATALOGID=SSGSGMXX&\
+        TABKEY=tab2&random=0.5379373345285255";
+        let resp = self.transport.get(req_url, None).await?;
+        // 变更日期	证券代码	证券简称	变更前简称	变更后简称
+        let mut workbook = open_workbook_auto_from_rs(Cursor::new(&*resp)).map_err(|e| {
+            Error::Custom(format!(
+                "Open shenzhen stock name change xlsx stream error: {}!",
+                e
+            ))
+        })?;
+        let mut changes = Vec::new();
+        if let Some(Ok(range)) = workbook.worksheet_range("简称变更") {
+            for row in range.rows().skip(1) {
+                let date = match NaiveDate::parse_from_str(&self.get_cell(&row[0]), "%Y-%m-%d") {
+                    Ok(date) => date,
+                    Err(_) => continue,
+                };
+                changes.push((
+                    to_std_code(MarketType::Stock, &self.get_cell(&row[1])),
+                    date,
+                    self.get_cell(&row[3]),
+                    self.get_cell(&row[4]),
+                ));
+            }
+        }
+        changes.sort_by(|a, b| (&a.0, a.1).cmp(&(&b.0, b.1)));
+
+        let mut data = Vec::new();
+        let mut open: HashMap<String, StockStatus> = HashMap::new();
+        for (code, date, before, after) in changes.into_iter() {
+            let (from, to) = (st_status_of(&before), st_status_of(&after));
+            if from == to {
+                continue;
+            }
+            // 变更前的区间开始于数据范围之前的，无法确定开始日期，不收录
+            if let Some(mut status) = open.remove(&code) {
+                status.end_date = Some((date - Duration::days(1)).and_hms_opt(0, 0, 0).unwrap());
+                data.push(status);
+            }
+            if let Some(status) = to {
+                open.insert(
+                    code.clone(),
+                    StockStatus {
+                        code,
+                        name: after,
+                        status,
+                        start_date: date.and_hms_opt(0, 0, 0).unwrap(),
+                        end_date: None,
+                        reason: String::new(),
+                        listing_date: None,
+                    },
+                );
+            }
+        }
+        data.extend(open.into_values());
+        data.sort_by(|a, b| (&a.code, a.start_date).cmp(&(&b.code, b.start_date)));
+        Ok(data)
+    }
+    /// 停牌股票，全市场某交易日，未公告复牌日期的end_date为空
+    async fn fetch_stock_suspend(&self, date: NaiveDate) -> Result<Vec<StockStatus>> {
+        let filter = format!(
+            "%28MARKET%3D%22%E5%85%A8%E9%83%A8%22%29%28DATETIME%3D%27{}%27%29",
+            date.format("%Y-%m-%d")
+        );
+        let data: Vec<EastStockSuspendData> = self
+            .fetch_data_center(
+                "RPT_CUSTOM_SUSPEND_DATA_INTERFACE",
+                &["SUSPEND_START_DATE"],
+                &filter,
+            )
+            .await?;
+        let parse_date = |s: &str| {
+            NaiveDateTime::parse_from_str(s, "%Y-%m-%d %H:%M:%S")
+                .map(|d| d.date().and_hms_opt(0, 0, 0).unwrap())
+        };
+        data.into_iter()
+            .map(|item| {
+                let start_date = parse_date(&item.start_time)
+                    .map_err(|e| Error::Custom(format!("Invalid suspend date: {}", e)))?;
+                Ok(StockStatus {
+                    code: to_std_code(MarketType::Stock, &item.code),
+                    name: item.name,
+                    status: StockStatusType::Suspend,
+                    start_date,
+                    end_date: item.end_time.and_then(|s| parse_date(&s).ok()),
+                    reason: item.reason.unwrap_or_default(),
+                    listing_date: None,
+                })
+            })
+            .collect()
+    }
+    /// 终止上市股票(沪深)，含上市日期，退市原因按退市时的简称推断并注明
+    async fn fetch_stock_delist(&self) -> Result<Vec<StockStatus>> {
+        let mut data = Vec::new();
+        let to_status = |code: String,
+                         name: String,
+                         listing_date: Option<NaiveDate>,
+                         delist_date: NaiveDate| StockStatus {
+            code,
+            reason: delist_reason(&name),
+            name,
+            status: StockStatusType::Delist,
+            start_date: delist_date.and_hms_opt(0, 0, 0).unwrap(),
+            end_date: None,
+            listing_date: listing_date.map(|d| d.and_hms_opt(0, 0, 0).unwrap()),
+        };
+
+        // 上海，"主板A股": "1", "科创板": "8"
+        let mut header = HTTP_CMM_HEADER.to_owned();
+        header.insert(HOST, HeaderValue::from_static("query.sse.com.cn"));
+        header.insert(
+            REFERER,
+            HeaderValue::from_static("http://www.sse.com.cn/assortment/stock/list/delisting/"),
+        );
+        for stock_type in ["1", "8"] {
+            let req_url = format!(
+                "http://query.sse.com.cn/sseQuery/commonQuery.do?\
+            STOCK_TYPE={stock_type}&REG_PROVINCE=&CSRC_CODE=&STOCK_CODE=&\
+            sqlId=COMMON_SSE_CP_GPJCTPZ_GPLB_GP_L&COMPANY_STATUS=3&type=inParams&\
+            isPagination=true&pageHelp.cacheSize=1&\
+            pageHelp.beginPage=1&pageHelp.pageSize=10000&pageHelp.pageNo=1&pageHelp.endPage=1",
+                stock_type = stock_type
+            );
+            let resp = self
+                .transport
+                .get_text(&req_url, Some(header.clone()))
+                .await?;
+            let json: ExchStockInfo = serde_json::from_str(&resp)?;
+            data.extend(json.page_help.data.iter().filter_map(|item| {
+                let delist_date = NaiveDate::parse_from_str(item.de_list, "%Y%m%d").ok()?;
+                Some(to_status(
+                    to_std_code(MarketType::Stock, item.code),
+                    item.name.to_owned(),
+                    NaiveDate::parse_from_str(item.list_date, "%Y%m%d").ok(),
+                    delist_date,
+                ))
+            }));
+        }
+
+        // 深圳，"暂停上市公司": "tab1", "终止上市公司": "tab2"
+        let req_url = "http://www.szse.cn/api/report/ShowReport?SHOWTYPE=xlsx&CATALOGID=1793_ssgs&\
+        TABKEY=tab2&random=0.6935816432433362";
+        let resp = self.transport.get(req_url, None).await?;
+        // 证券代码	证券简称	上市日期	终止上市日期
+        let mut workbook = open_workbook_auto_from_rs(Cursor::new(&*resp)).map_err(|e| {
+            Error::Custom(format!(
+                "Open shenzhen stock delist xlsx stream error: {}!",
+                e
+            ))
+        })?;
+        if let Some(Ok(range)) = workbook.worksheet_range("终止上市公司") {
+            data.extend(range.rows().skip(1).filter_map(|row| {
+                let code = self.get_cell(&row[0]);
+                let delist_date =
+                    NaiveDate::parse_from_str(&self.get_cell(&row[3]), "%Y-%m-%d").ok()?;
+                Some(to_status(
+                    to_std_code(MarketType::Stock, &code),
+                    self.get_cell(&row[1]),
+                    NaiveDate::parse_from_str(&self.get_cell(&row[2]), "%Y-%m-%d").ok(),
+                    delist_date,
+                ))
+            }));
+        }
+        Ok(data)
+    }
+    /// 除权除息事件(分红送转及配股)
+    async fn fetch_stock_corp_action(
+        &self,
+        code: Option<&str>,
+        start: Option<NaiveDate>,
+        end: Option<NaiveDate>,
+    ) -> Result<Vec<StockCorpAction>> {
+        let mut filter = String::new();
+        if let Some(code) = code {
+            let id = to_security_id(code)?;
+            filter.push_str(&format!("(SECURITY_CODE%3D%22{}%22)", id.code()));
+        }
+        if let Some(s) = start {
+            filter.push_str(&format!(
+                "(EX_DIVIDEND_DATE%3E%3D%27{}%27)",
+                s.format("%Y-%m-%d")
+            ));
+        }
+        if let Some(e) = end {
+            filter.push_str(&format!(
+                "(EX_DIVIDEND_DATE%3C%3D%27{}%27)",
+                e.format("%Y-%m-%d")
+            ));
+        }
+        let parse_date = |s: &str| NaiveDateTime::parse_from_str(s, "%Y-%m-%d %H:%M:%S").ok();
+
+        const PAGE_SIZE: i32 = 500;
+        let mut data = Vec::new();
+
+        // 分红送转，只取已实施的
+        let mut page = 1;
+        loop {
+            let req_url = format!(
+                "http://datacenter-web.eastmoney.com/api/data/v1/get?\
+            reportName=RPT_SHAREBONUS_DET&columns=ALL&source=WEB&sortColumns=EX_DIVIDEND_DATE&sortTypes=-1&\
+            pageNumber={page}&pageSize={page_size}&\
+            filter={filter}(ASSIGN_PROGRESS%3D%22%E5%AE%9E%E6%96%BD%E5%88%86%E9%85%8D%22)",
+                page_size = PAGE_SIZE,
+                page = page,
+                filter = filter
+            );
+            let resp = self.transport.get_text(&req_url, None).await?;
+            let json = serde_json::from_str::<EastStockBonus>(&resp)?;
+            if json.result.is_none() {
+                break;
+            }
+            let result = json.result.unwrap();
+            data.extend(result.data.iter().filter_map(|item| {
+                Some(StockCorpAction {
+                    code: to_std_code(MarketType::Stock, item.code),
+                    name: item.name.to_owned(),
+                    notice_date: item.notice_date.and_then(parse_date),
+                    ex_date: item.ex_date.and_then(parse_date)?,
+                    cash: item.cash.unwrap_or(0.0) / 10.0,
+                    bonus: item.bonus.unwrap_or(0.0) / 10.0,
+                    transfer: item.transfer.unwrap_or(0.0) / 10.0,
+                    rights: 0.0,
+                    rights_price: 0.0,
+                })
+            }));
+            if page >= result.pages {
+                break;
+            }
+            page += 1;
+        }
+
+        // 配股
+        let mut page = 1;
+        loop {
+            let req_url = format!(
+                "http://datacenter-web.eastmoney.com/api/data/v1/get?\
+            reportName=RPT_IPO_ALLOTMENT&columns=ALL&source=WEB&sortColumns=EX_DIVIDEND_DATE&sortTypes=-1&\
+            pageNumber={page}&pageSize={page_size}&filter={filter}",
+                page_size = PAGE_SIZE,
+                page = page,
+                filter = filter
+            );
+            let resp = self.transport.get_text(&req_url, None).await?;
+            let json = serde_json::from_str::<EastStockAllotment>(&resp)?;
+            if json.result.is_none() {
+                break;
+            }
+            let result = json.result.unwrap();
+            data.extend(result.data.iter().filter_map(|item| {
+                // 每10股配股，如: 3 或 "10配3"
+                let rights = match item.rights.as_ref()? {
+                    serde_json::Value::Number(n) => n.as_f64()? as f32,
+                    serde_json::Value::String(s) => s.rsplit('配').next()?.parse().ok()?,
+                    _ => return None,
+                };
+                Some(StockCorpAction {
+                    code: to_std_code(MarketType::Stock, item.code),
+                    name: item.name.to_owned(),
+                    notice_date: item.notice_date.and_then(parse_date),
+                    ex_date: item.ex_date.and_then(parse_date)?,
+                    cash: 0.0,
+                    bonus: 0.0,
+                    transfer: 0.0,
+                    rights: rights / 10.0,
+                    rights_price: item.rights_price.unwrap_or(0.0),
+                })
+            }));
+            if page >= result.pages {
+                break;
+            }
+            page += 1;
+        }
+
+        data.sort_by(|a, b| a.code.cmp(&b.code).then(a.ex_date.cmp(&b.ex_date)));
+        Ok(data)
+    }
+
+    /// 实时行情，代码较多时分批请求
+    async fn fetch_stock_rt_quot(&self, code: Vec<&str>) -> Result<HashMap<String, StockRtQuot>> {
+        let mut data = HashMap::new();
+        for batch in code.chunks(RT_QUOT_BATCH_SIZE) {
+            // 雪球代码 -> (内部代码, 交易所)，港股美股的雪球代码不带市场前缀
+            let symbols: Vec<_> = batch
+                .iter()
+                .map(|s| match SecurityId::parse(s) {
+                    Ok(id) => (id.to_xueqiu(), (id.to_std(), id.exchange())),
+                    Err(_) => (s.to_uppercase(), (s.to_lowercase(), Exchange::SH)),
+                })
+                .collect();
+            let codes = symbols
+                .iter()
+                .map(|(symbol, _)| symbol.as_str())
+                .collect::<Vec<_>>()
+                .join("%2C");
+            let symbols: HashMap<_, _> = symbols.into_iter().collect();
+
+            let req_url = format!(
+                "https://stock.xueqiu.com/v5/stock/realtime/quotec.json?\
+            symbol={codes}",
+                codes = codes
+            );
+
+            let resp = self.transport.get_text(&req_url, None).await?;
+
+            let json: XuQiuStockRtQuot = serde_json::from_str(&resp)?;
+            let items = json
+                .data
+                .ok_or(Error::Custom("Error fetch quotation".to_string()))?;
+
+            data.extend(items.iter().map(|item| {
+                let (code, exchange) = symbols
+                    .get(item.symbol)
+                    .cloned()
+                    .unwrap_or_else(|| (item.symbol.to_lowercase(), Exchange::SH));
+                let time = exchange.local_time(item.timestamp);
+                let is_trading = market_clock(exchange).is_trading(&time);
+                let currency = Currency::from_code(&code);
+                (
+                    code.clone(),
+                    StockRtQuot {
+                        code,
+                        time,
+                        last_close: item.last_close,
+                        open: item.open,
+                        high: item.high,
+                        low: item.low,
+                        last: item.last,
+                        chg: item.chg,
+                        chg_pct: item.percent,
+                        volume: item.volume,
+                        amount: item.amount,
+                        turnover: item.turnover_rate,
+                        total_value: item.market_capital,
+                        currency_value: item.float_market_capital,
+                        is_trading,
+                        currency,
+                    },
+                )
+            }));
+        }
+
+        Ok(data)
+    }
+
+    /// 股票五档盘口(腾讯行情)
+    async fn fetch_stock_order_book(&self, code: Vec<&str>) -> Result<HashMap<String, OrderBook>> {
+        fetch_order_book(self.transport.as_ref(), &code).await
     }
-}
+
+    /// 分笔成交(东方财富)，只支持最新交易日
+    async fn fetch_stock_ticks(&self, code: &str, date: NaiveDate) -> Result<Vec<Tick>> {
+        let market_code = to_east_secid(code)?;
+        fetch_ticks(self.transport.as_ref(), &market_code, code, date).await
+    }
+
+    /// 最新交易日分时走势(东方财富)
+    async fn fetch_stock_time_share(&self, code: &str) -> Result<TimeShare> {
+        let market_code = to_east_secid(code)?;
+        fetch_time_share(self.transport.as_ref(), &market_code, code).await
+    }
+
+    /// 最新交易日集合竞价(东方财富分时，含集合竞价)，
+    /// 分时没有未匹配量，集合竞价中以当前盘口(腾讯)补充最新一分钟的未匹配量
+    async fn fetch_stock_auction(&self, code: &str) -> Result<Vec<AuctionSnapshot>> {
+        let market_code = to_east_secid(code)?;
+        let mut data = fetch_auction(self.transport.as_ref(), &market_code, code).await?;
+        let book = fetch_order_book(self.transport.as_ref(), &[code])
+            .await?
+            .remove(&code.to_lowercase());
+        if let Some(snapshot) = book.as_ref().and_then(AuctionSnapshot::from_order_book) {
+            let minute = snapshot.time.with_second(0).unwrap();
+            match data.last_mut() {
+                Some(last) if last.time == minute => {
+                    last.unmatched_volume = snapshot.unmatched_volume
+                }
+                _ => data.push(AuctionSnapshot {
+                    time: minute,
+                    ..snapshot
+                }),
+            }
+        }
+        Ok(data)
+    }
+}
 
 #[cfg(test)]
 mod tests {
     use super::HiqStockFetch;
-    use crate::StockFetch;
-    use chrono::NaiveDate;
+    use crate::comm::{fetch_minute_bar, fetch_ticks_at, latest_tick_date_at, to_minute_bar};
+    use crate::util::to_std_code;
+    use crate::MarketType;
+    use crate::{ReplayTransport, StockFetch};
+    use chrono::{NaiveDate, NaiveTime};
+    use hiq_common::{
+        BarFreq, Board, Currency, Exchange, LimitPool, StockStatusType, TickDirection,
+    };
+    use std::collections::HashSet;
+    use std::sync::Arc;
 
     #[test]
     fn test_fetch_stock_info() {
@@ -1126,4 +2413,595 @@ mod tests {
                 });
             })
     }
+
+    #[test]
+    fn test_replay_fetch_stock_industry() {
+        tokio::runtime::Builder::new_multi_thread()
+            .enable_all()
+            .build()
+            .unwrap()
+            .block_on(async {
+                let fetch = replay_fetch();
+                let data = fetch.fetch_stock_industry().await.unwrap();
+                assert_eq!(data.len(), 2);
+                assert_eq!(data[0].code, "BK0478");
+                assert_eq!(data[1].name, "银行");
+            })
+    }
+
+    #[test]
+    fn test_replay_fetch_stock_rt_quot() {
+        tokio::runtime::Builder::new_multi_thread()
+            .enable_all()
+            .build()
+            .unwrap()
+            .block_on(async {
+                let fetch = replay_fetch();
+                let data = fetch
+                    .fetch_stock_rt_quot(vec!["sz002805", "sh600000"])
+                    .await
+                    .unwrap();
+                assert_eq!(data.len(), 2);
+                let quot = data.get("sz002805").unwrap();
+                assert_eq!(quot.last, 9.87);
+                assert_eq!(quot.volume, 3456700);
+                assert_eq!(data.get("sh600000").unwrap().last_close, 7.24);
+            })
+    }
+
+    #[test]
+    fn test_replay_fetch_overseas_stock() {
+        tokio::runtime::Builder::new_multi_thread()
+            .enable_all()
+            .build()
+            .unwrap()
+            .block_on(async {
+                let fetch = replay_fetch();
+                let info = fetch
+                    .fetch_overseas_stock_info(MarketType::HkStock)
+                    .await
+                    .unwrap();
+                assert_eq!(info.len(), 2);
+                assert_eq!(info[0].code, "hk00700");
+                assert_eq!(info[0].currency, Currency::HKD);
+                assert_eq!(info[0].lot_size, Some(100));
+                assert_eq!(
+                    info[0].listing_date.date(),
+                    NaiveDate::from_ymd_opt(2004, 6, 16).unwrap()
+                );
+
+                let start = NaiveDate::from_ymd_opt(2023, 1, 3);
+                let end = NaiveDate::from_ymd_opt(2023, 1, 5);
+                let bar = fetch
+                    .fetch_stock_bar("hk00700", None, None, start, end)
+                    .await
+                    .unwrap();
+                assert_eq!(bar.code, "hk00700");
+                let bars = bar.bars.unwrap();
+                assert_eq!(bars.len(), 3);
+                assert_eq!(bars[0].trade_date.date(), start.unwrap());
+                assert_eq!(bars[2].close, 320.0);
+                assert!((bars[2].hfq_factor - 1.5).abs() < 1e-6);
+
+                let quot = fetch
+                    .fetch_stock_rt_quot(vec!["hk00700", "usAAPL"])
+                    .await
+                    .unwrap();
+                let hk = quot.get("hk00700").unwrap();
+                assert_eq!(hk.currency, Currency::HKD);
+                assert!(hk.is_trading);
+                let us = quot.get("usAAPL").unwrap();
+                assert_eq!(us.currency, Currency::USD);
+                assert!(!us.is_trading);
+            })
+    }
+
+    #[test]
+    fn test_replay_fetch_index_constituents() {
+        tokio::runtime::Builder::new_multi_thread()
+            .enable_all()
+            .build()
+            .unwrap()
+            .block_on(async {
+                let fetch = replay_fetch();
+                let info = fetch.fetch_index_info().await.unwrap();
+                let codes: Vec<_> = info.iter().map(|i| i.code.as_str()).collect();
+                // 中证系列与上证系列重复的去重，无沪深行情代码的以csi为前缀
+                assert_eq!(
+                    codes,
+                    vec![
+                        "sh000001",
+                        "sh000016",
+                        "sh000300",
+                        "sz399001",
+                        "sz399006",
+                        "sh000905",
+                        "csi930050"
+                    ]
+                );
+                assert_eq!(info[5].block, "中证指数");
+                assert_eq!(info[6].name, "中证A50");
+
+                let date = NaiveDate::from_ymd_opt(2023, 7, 15);
+                let data = fetch
+                    .fetch_index_constituents("sh000300", date)
+                    .await
+                    .unwrap();
+                assert_eq!(data.len(), 3);
+                assert_eq!(data[0].index_code, "sh000300");
+                assert_eq!(data[0].code, "sh600519");
+                assert_eq!(data[1].code, "sz300750");
+                assert_eq!(data[2].weight, 0.0);
+                assert_eq!(data[0].trade_date.date().to_string(), "2023-06-30");
+            })
+    }
+
+    #[test]
+    fn test_replay_fetch_stock_finance() {
+        tokio::runtime::Builder::new_multi_thread()
+            .enable_all()
+            .build()
+            .unwrap()
+            .block_on(async {
+                let fetch = replay_fetch();
+                assert!(fetch.fetch_stock_balance_sheet(2023, 5).await.is_err());
+
+                // 未公告的记录不收录
+                let data = fetch.fetch_stock_balance_sheet(2023, 2).await.unwrap();
+                assert_eq!(data.len(), 2);
+                assert_eq!(data[0].code, "sh600519");
+                assert_eq!(data[0].report_date.date().to_string(), "2023-06-30");
+                assert_eq!(data[0].notice_date.date().to_string(), "2023-08-03");
+                assert_eq!(data[0].accounts_rece, 0.0);
+                assert_eq!(data[1].code, "sz000001");
+
+                let data = fetch.fetch_stock_income(2023, 2).await.unwrap();
+                assert_eq!(data.len(), 1);
+                assert_eq!(data[0].parent_netprofit, 35979760000.0);
+
+                let data = fetch.fetch_stock_cash_flow(2023, 2).await.unwrap();
+                assert_eq!(data.len(), 1);
+                assert_eq!(data[0].netcash_finance, -59642070000.0);
+                assert_eq!(data[0].construct_long_asset, 1056420000.0);
+            })
+    }
+
+    #[test]
+    fn test_replay_fetch_stock_holders() {
+        tokio::runtime::Builder::new_multi_thread()
+            .enable_all()
+            .build()
+            .unwrap()
+            .block_on(async {
+                let fetch = replay_fetch();
+                // 未公告的记录不收录
+                let data = fetch.fetch_stock_holder_num(2023, 2).await.unwrap();
+                assert_eq!(data.len(), 2);
+                // 非季末的截止日期
+                assert_eq!(data[0].code, "sz300750");
+                assert_eq!(data[0].report_date.date().to_string(), "2023-05-10");
+                assert_eq!(data[1].code, "sh600519");
+                assert_eq!(data[1].holder_num, 162307);
+                assert_eq!(data[1].holder_num_change, -20957);
+                assert_eq!(data[1].notice_date.date().to_string(), "2023-08-03");
+
+                let data = fetch.fetch_stock_top_holders(2023, 2, true).await.unwrap();
+                assert_eq!(data.len(), 3);
+                assert!(data[0].is_float);
+                assert_eq!(data[0].hold_ratio, 53.99);
+                assert_eq!(data[0].change_type, "不变");
+                assert_eq!(data[1].hold_change, -1930577.0);
+                assert_eq!(data[1].change_type, "减少");
+                assert_eq!(data[2].hold_change, data[2].hold_num);
+                assert_eq!(data[2].change_type, "新进");
+
+                let data = fetch.fetch_stock_top_holders(2023, 2, false).await.unwrap();
+                assert!(!data[0].is_float);
+                assert_eq!(data[0].rank, 3);
+                assert_eq!(data[0].hold_ratio, 4.54);
+                assert_eq!(data[0].change_type, "增加");
+            })
+    }
+
+    #[test]
+    fn test_replay_fetch_north() {
+        tokio::runtime::Builder::new_multi_thread()
+            .enable_all()
+            .build()
+            .unwrap()
+            .block_on(async {
+                let fetch = replay_fetch();
+                let date = NaiveDate::from_ymd_opt(2023, 7, 14).unwrap();
+                let data = fetch.fetch_stock_north_hold(date).await.unwrap();
+                assert_eq!(data.len(), 2);
+                assert_eq!(data[0].code, "sz000001");
+                assert_eq!(data[0].hold_shares_change, Some(-3456200.0));
+                assert_eq!(data[1].code, "sh600519");
+                assert_eq!(data[1].hold_ratio, Some(7.32));
+                assert_eq!(data[1].trade_date.date(), date);
+
+                let start = NaiveDate::from_ymd_opt(2023, 7, 13);
+                let data = fetch.fetch_north_flow(start, Some(date)).await.unwrap();
+                assert_eq!(data.len(), 4);
+                assert_eq!(data[0].exchange, Exchange::SH);
+                assert_eq!(data[1].exchange, Exchange::SZ);
+                assert!((data[2].net_buy.unwrap() + 1115730000.0).abs() < 1.0);
+                // 缺失的净买入额不以0填充
+                assert_eq!(data[3].net_buy, None);
+            })
+    }
+
+    #[test]
+    fn test_replay_fetch_stock_dragon_tiger() {
+        tokio::runtime::Builder::new_multi_thread()
+            .enable_all()
+            .build()
+            .unwrap()
+            .block_on(async {
+                let fetch = replay_fetch();
+                let date = NaiveDate::from_ymd_opt(2023, 7, 14).unwrap();
+                let data = fetch.fetch_stock_dragon_tiger(date).await.unwrap();
+                assert_eq!(data.len(), 3);
+                assert_eq!(data[0].code, "sz002456");
+                assert_eq!(data[0].trade_date.date(), date);
+                // 买入席位按买入额降序，只取前五
+                let seats = &data[0].buy_seats;
+                assert_eq!(seats.len(), 5);
+                assert_eq!(seats[0].name, "深股通专用");
+                assert_eq!(seats[1].name, "机构专用");
+                assert_eq!(seats[4].buy_amount, 21000000.0);
+                assert_eq!(data[0].sell_seats.len(), 2);
+                assert_eq!(data[0].sell_seats[0].sell_amount, 80000000.0);
+                // 同一股票不同上榜原因分别记录
+                assert_eq!(data[1].code, "sh603019");
+                assert_eq!(data[2].code, "sh603019");
+                assert_ne!(data[1].reason, data[2].reason);
+                assert_eq!(data[1].buy_seats[1].net_buy, -20000000.0);
+                assert!(data[2].buy_seats.is_empty());
+                assert_eq!(data[2].net_buy, 0.0);
+            })
+    }
+
+    #[test]
+    fn test_replay_fetch_stock_limit_pool() {
+        tokio::runtime::Builder::new_multi_thread()
+            .enable_all()
+            .build()
+            .unwrap()
+            .block_on(async {
+                let fetch = replay_fetch();
+                let date = NaiveDate::from_ymd_opt(2023, 7, 14).unwrap();
+                let data = fetch
+                    .fetch_stock_limit_pool(date, LimitPool::LimitUp)
+                    .await
+                    .unwrap();
+                assert_eq!(data.len(), 2);
+                assert_eq!(data[0].code, "sh603019");
+                assert_eq!(data[0].pool, LimitPool::LimitUp);
+                assert_eq!(data[0].limit_days, 3);
+                assert_eq!(data[0].open_times, 2);
+                assert!((data[0].close - 48.8).abs() < 1e-4);
+                assert_eq!(
+                    data[0].first_limit_time.unwrap().time(),
+                    NaiveTime::from_hms_opt(9, 35, 12).unwrap()
+                );
+                assert_eq!(data[0].first_limit_time.unwrap().date(), date);
+                assert_eq!(data[1].code, "sz002456");
+                assert_eq!(data[1].limit_days, 1);
+                assert_eq!(data[1].sealed_amount, 201234567.0);
+
+                let data = fetch
+                    .fetch_stock_limit_pool(date, LimitPool::LimitDown)
+                    .await
+                    .unwrap();
+                assert_eq!(data.len(), 1);
+                assert_eq!(data[0].code, "sz300313");
+                assert_eq!(data[0].limit_days, 2);
+                assert_eq!(data[0].open_times, 1);
+                assert!(data[0].first_limit_time.is_none());
+
+                let data = fetch
+                    .fetch_stock_limit_pool(date, LimitPool::Broken)
+                    .await
+                    .unwrap();
+                assert_eq!(data.len(), 1);
+                assert_eq!(data[0].code, "sh600580");
+                assert_eq!(data[0].limit_days, 0);
+                assert_eq!(data[0].open_times, 4);
+                assert_eq!(data[0].sealed_amount, 0.0);
+                assert!(data[0].last_limit_time.is_none());
+
+                // 非交易日无数据
+                let date = NaiveDate::from_ymd_opt(2023, 7, 15).unwrap();
+                let data = fetch
+                    .fetch_stock_limit_pool(date, LimitPool::LimitUp)
+                    .await
+                    .unwrap();
+                assert!(data.is_empty());
+            })
+    }
+
+    #[test]
+    fn test_replay_fetch_stock_status() {
+        tokio::runtime::Builder::new_multi_thread()
+            .enable_all()
+            .build()
+            .unwrap()
+            .block_on(async {
+                let fetch = replay_fetch();
+                let data = fetch.fetch_stock_st().await.unwrap();
+                assert_eq!(data.len(), 3);
+                assert_eq!(data[0].code, "sz000004");
+                assert_eq!(data[0].status, StockStatusType::ST);
+                assert_eq!(data[1].status, StockStatusType::StarST);
+                assert!(data.iter().all(|status| status.end_date.is_none()));
+
+                let data = fetch.fetch_stock_st_history().await.unwrap();
+                assert_eq!(data.len(), 3);
+                assert_eq!(data[0].code, "sz000004");
+                assert_eq!(data[0].status, StockStatusType::StarST);
+                assert_eq!(data[0].start_date.to_string(), "2019-05-06 00:00:00");
+                assert_eq!(data[0].end_date.unwrap().to_string(), "2021-05-09 00:00:00");
+                assert_eq!(data[1].status, StockStatusType::ST);
+                assert_eq!(data[1].end_date.unwrap().to_string(), "2022-02-28 00:00:00");
+                assert_eq!(data[2].code, "sz000005");
+                assert_eq!(data[2].name, "ST星源");
+                assert!(data[2].end_date.is_none());
+
+                let date = NaiveDate::from_ymd_opt(2023, 5, 5).unwrap();
+                let data = fetch.fetch_stock_st_notice(date).await.unwrap();
+                // 可能被实施的提示、深市及无关公告不返回
+                assert_eq!(data.len(), 3);
+                assert_eq!(data[0].code, "sh600234");
+                assert_eq!(data[0].status, Some(StockStatusType::StarST));
+                assert_eq!(data[0].notice_date.date(), date);
+                // 撤销退市风险警示并实施其他风险警示
+                assert_eq!(data[1].status, Some(StockStatusType::ST));
+                assert_eq!(data[2].code, "bj830799");
+                assert_eq!(data[2].status, None);
+
+                let date = NaiveDate::from_ymd_opt(2023, 7, 14).unwrap();
+                let data = fetch.fetch_stock_suspend(date).await.unwrap();
+                assert_eq!(data.len(), 2);
+                assert_eq!(data[0].code, "sh600247");
+                assert_eq!(data[0].start_date.to_string(), "2021-04-29 00:00:00");
+                assert!(data[0].end_date.is_none());
+                assert_eq!(data[1].code, "sz002496");
+                assert_eq!(data[1].end_date.unwrap().date(), date);
+                assert_eq!(data[1].reason, "刊登重要公告");
+
+                let data = fetch.fetch_stock_delist().await.unwrap();
+                assert_eq!(data.len(), 5);
+                assert!(data
+                    .iter()
+                    .all(|status| status.status == StockStatusType::Delist));
+                let status = data.iter().find(|s| s.code == "sh688086").unwrap();
+                assert_eq!(status.start_date.to_string(), "2023-08-11 00:00:00");
+                let status = data.iter().find(|s| s.code == "sz300028").unwrap();
+                assert_eq!(status.name, "金亚退");
+                assert_eq!(status.start_date.to_string(), "2019-06-28 00:00:00");
+                assert_eq!(
+                    status.listing_date.unwrap().to_string(),
+                    "2009-10-30 00:00:00"
+                );
+                assert_eq!(status.reason, "强制退市(按简称推断)");
+                let status = data.iter().find(|s| s.code == "sh688086").unwrap();
+                assert!(status.listing_date.is_some());
+            })
+    }
+
+    #[test]
+    fn test_replay_fetch_stock_corp_action() {
+        tokio::runtime::Builder::new_multi_thread()
+            .enable_all()
+            .build()
+            .unwrap()
+            .block_on(async {
+                let fetch = replay_fetch();
+                let end = NaiveDate::from_ymd_opt(2023, 12, 31).unwrap();
+                let data = fetch
+                    .fetch_stock_corp_action(Some("sz000001"), None, Some(end))
+                    .await
+                    .unwrap();
+                assert_eq!(data.len(), 3);
+                // 按除权除息日升序，每10股转为每股
+                assert_eq!(data[0].code, "sz000001");
+                assert_eq!(data[0].ex_date.date().to_string(), "2016-06-16");
+                assert!((data[0].cash - 0.153).abs() < 1e-6);
+                assert!((data[0].transfer - 0.2).abs() < 1e-6);
+                assert!((data[2].cash - 0.285).abs() < 1e-6);
+            })
+    }
+
+    #[test]
+    fn test_replay_fetch_stock_bar_seed_factor() {
+        tokio::runtime::Builder::new_multi_thread()
+            .enable_all()
+            .build()
+            .unwrap()
+            .block_on(async {
+                let fetch = replay_fetch();
+                let start = NaiveDate::from_ymd_opt(2023, 6, 13).unwrap();
+                let end = NaiveDate::from_ymd_opt(2023, 6, 15).unwrap();
+                let data = fetch
+                    .fetch_stock_bar("sz000001", None, None, Some(start), Some(end))
+                    .await
+                    .unwrap();
+                let bars = data.bars.unwrap();
+                assert_eq!(bars.len(), 3);
+                // 前一交易日的后复权因子为基准，6月14日除息后累乘
+                let base = 1482.30 / 11.25;
+                assert!((bars[0].hfq_factor - base).abs() < 1e-3);
+                let factor = base * 11.38 / (11.38 - 0.285);
+                assert!((bars[1].hfq_factor - factor).abs() < 1e-3);
+                assert!((bars[2].hfq_factor - factor).abs() < 1e-3);
+            })
+    }
+
+    #[test]
+    fn test_replay_fetch_minute_bar_paged() {
+        tokio::runtime::Builder::new_multi_thread()
+            .enable_all()
+            .build()
+            .unwrap()
+            .block_on(async {
+                let dir = concat!(env!("CARGO_MANIFEST_DIR"), "/fixtures");
+                let transport = ReplayTransport::new(dir);
+                let start = NaiveDate::from_ymd_opt(2023, 6, 13).unwrap();
+                let end = NaiveDate::from_ymd_opt(2023, 6, 15).unwrap();
+                // 每页8根60分钟线，首页最早的06-13不完整，由第二页补全
+                let bars = fetch_minute_bar(
+                    &transport,
+                    "0.000001",
+                    "sz000001",
+                    BarFreq::Min60,
+                    Some(start),
+                    Some(end),
+                    false,
+                    8,
+                )
+                .await
+                .unwrap();
+                assert_eq!(bars.len(), 10);
+                assert!(bars.windows(2).all(|w| w[0].trade_date < w[1].trade_date));
+                assert_eq!(bars[0].trade_date.to_string(), "2023-06-13 10:30:00");
+                assert_eq!(bars[9].trade_date.to_string(), "2023-06-15 11:30:00");
+                assert_eq!(bars[3].volume, 107000);
+                assert!((bars[3].volume_chg_pct - 100.0 / 106.0).abs() < 1e-4);
+
+                let data = to_minute_bar("sz000001", None, BarFreq::Min60, bars);
+                assert_eq!(data.name, "平安银行");
+                assert_eq!(data.start.unwrap().to_string(), "2023-06-13 10:30:00");
+                assert_eq!(data.end.unwrap().to_string(), "2023-06-15 11:30:00");
+            })
+    }
+
+    #[test]
+    fn test_replay_fetch_stock_order_book() {
+        tokio::runtime::Builder::new_multi_thread()
+            .enable_all()
+            .build()
+            .unwrap()
+            .block_on(async {
+                let fetch = replay_fetch();
+                let data = fetch
+                    .fetch_stock_order_book(vec!["sz000001", "SH113052"])
+                    .await
+                    .unwrap();
+                assert_eq!(data.len(), 2);
+                let book = data.get("sz000001").unwrap();
+                assert_eq!(book.name, "平安银行");
+                assert_eq!(book.bids.len(), 5);
+                assert_eq!(book.bids[0].price, 11.49);
+                assert_eq!(book.bids[0].volume, 1820);
+                assert_eq!(book.asks[4].price, 11.54);
+                assert_eq!(book.limit_up, 12.62);
+                assert_eq!(book.limit_down, 10.32);
+                assert_eq!(book.outer_volume + book.inner_volume, 1013131);
+                // 可转债同样适用
+                assert_eq!(data.get("sh113052").unwrap().limit_up, 124.86);
+            })
+    }
+
+    #[test]
+    fn test_replay_fetch_stock_ticks() {
+        tokio::runtime::Builder::new_multi_thread()
+            .enable_all()
+            .build()
+            .unwrap()
+            .block_on(async {
+                let transport = replay_transport();
+                let date = NaiveDate::from_ymd_opt(2023, 6, 15).unwrap();
+                // 开盘前为上一交易日
+                let before_open = date.and_hms_opt(9, 0, 0).unwrap();
+                let prev = latest_tick_date_at(before_open).await.unwrap();
+                assert_eq!(prev, NaiveDate::from_ymd_opt(2023, 6, 14).unwrap());
+
+                let now = date.and_hms_opt(15, 0, 0).unwrap();
+                assert_eq!(latest_tick_date_at(now).await.unwrap(), date);
+                let data = fetch_ticks_at(transport.as_ref(), "0.000001", "sz000001", date, now)
+                    .await
+                    .unwrap();
+                assert_eq!(data.len(), 6);
+                assert_eq!(data[1].time, date.and_hms_opt(9, 25, 0).unwrap());
+                assert_eq!(data[1].volume, 5624);
+                assert_eq!(data[1].direction, TickDirection::Neutral);
+                assert_eq!(data[2].direction, TickDirection::Buy);
+                assert_eq!(data[3].price, 11.47);
+                assert_eq!(data[3].direction, TickDirection::Sell);
+                // 只支持最新交易日
+                let res = fetch_ticks_at(transport.as_ref(), "0.000001", "sz000001", prev, now);
+                assert!(res.await.is_err());
+            })
+    }
+
+    #[test]
+    fn test_replay_fetch_stock_time_share() {
+        tokio::runtime::Builder::new_multi_thread()
+            .enable_all()
+            .build()
+            .unwrap()
+            .block_on(async {
+                let fetch = replay_fetch();
+                let data = fetch.fetch_stock_time_share("sz000001").await.unwrap();
+                assert_eq!(data.name, "平安银行");
+                assert_eq!(data.last_close, 11.47);
+                assert_eq!(data.points.len(), 4);
+                assert_eq!(data.points[1].time.to_string(), "2023-06-15 09:31:00");
+                assert_eq!(data.points[1].price, 11.48);
+                assert_eq!(data.points[1].avg_price, 11.458);
+                assert_eq!(data.points[1].volume, 18213);
+            })
+    }
+
+    #[test]
+    fn test_replay_fetch_stock_auction() {
+        tokio::runtime::Builder::new_multi_thread()
+            .enable_all()
+            .build()
+            .unwrap()
+            .block_on(async {
+                let fetch = replay_fetch();
+                let data = fetch.fetch_stock_auction("sz000001").await.unwrap();
+                // 9:25后的不返回
+                assert_eq!(data.len(), 3);
+                assert_eq!(data[0].time.to_string(), "2023-06-16 09:15:00");
+                assert_eq!(data[0].trade_date.to_string(), "2023-06-16 00:00:00");
+                assert_eq!(data[2].time.to_string(), "2023-06-16 09:25:00");
+                assert_eq!(data[2].price, 11.52);
+                assert_eq!(data[2].volume, 8520);
+                assert!(data[0].unmatched_volume.is_none());
+                // 集合竞价中的盘口，买二为买方未匹配量
+                assert_eq!(data[2].unmatched_volume, Some(1260));
+            })
+    }
+
+    #[test]
+    fn test_replay_fetch_bj_stock_info() {
+        tokio::runtime::Builder::new_multi_thread()
+            .enable_all()
+            .build()
+            .unwrap()
+            .block_on(async {
+                let fetch = replay_fetch();
+                let margin_codes = HashSet::from(["bj832000".to_string()]);
+                let data = fetch.fetch_bj_stock_info(&margin_codes).await.unwrap();
+                assert_eq!(data.len(), 3);
+                assert_eq!(data[0].code, "bj430017");
+                assert_eq!(data[0].name, "星昊医药");
+                assert_eq!(data[0].listing_date.to_string(), "2023-05-18 00:00:00");
+                assert!(data[1].is_margin);
+                assert!(data.iter().all(|info| info.board == Board::Bse));
+                assert_eq!(to_std_code(MarketType::Stock, "920002"), data[2].code);
+            })
+    }
+
+    fn replay_transport() -> Arc<ReplayTransport> {
+        let dir = concat!(env!("CARGO_MANIFEST_DIR"), "/fixtures");
+        Arc::new(ReplayTransport::new(dir))
+    }
+
+    fn replay_fetch() -> HiqStockFetch {
+        HiqStockFetch::with_transport(replay_transport())
+    }
 }
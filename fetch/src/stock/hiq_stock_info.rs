@@ -33,6 +33,27 @@ pub(crate) struct ExchStockInfoData<'a> {
     pub list_date: &'a str,
 }
 
+/// 北交所股票列表，每页一个对象
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub(crate) struct BseStockInfo {
+    pub content: Vec<BseStockInfoData>,
+    #[serde(rename(deserialize = "totalPages"))]
+    pub total_pages: usize,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub(crate) struct BseStockInfoData {
+    /// 证券代码
+    #[serde(rename(deserialize = "xxzqdm"))]
+    pub code: String,
+    /// 证券简称
+    #[serde(rename(deserialize = "xxzqjc"))]
+    pub name: String,
+    /// 上市日期
+    #[serde(rename(deserialize = "fxssrq"))]
+    pub list_date: String,
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub(crate) struct EastStockInfoMargin<'a> {
     #[serde(borrow)]
@@ -264,6 +285,92 @@ pub(crate) struct EastStockMarginData<'a> {
     pub rz_rq_ye_cz: Option<f64>,
 }
 
+/// stock_corp_action 分红送转
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub(crate) struct EastStockBonus<'a> {
+    #[serde(borrow)]
+    pub result: Option<EastStockBonusResult<'a>>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub(crate) struct EastStockBonusResult<'a> {
+    pub pages: usize,
+    #[serde(borrow)]
+    pub data: Vec<EastStockBonusData<'a>>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub(crate) struct EastStockBonusData<'a> {
+    #[serde(borrow)]
+    #[serde(rename(deserialize = "SECURITY_CODE"))]
+    pub code: &'a str,
+
+    #[serde(borrow)]
+    #[serde(rename(deserialize = "SECURITY_NAME_ABBR"))]
+    pub name: &'a str,
+
+    #[serde(borrow)]
+    #[serde(rename(deserialize = "NOTICE_DATE"))]
+    pub notice_date: Option<&'a str>,
+
+    #[serde(borrow)]
+    #[serde(rename(deserialize = "EX_DIVIDEND_DATE"))]
+    pub ex_date: Option<&'a str>,
+
+    /// 每10股派息(税前)
+    #[serde(rename(deserialize = "PRETAX_BONUS_RMB"))]
+    pub cash: Option<f32>,
+
+    /// 每10股送股
+    #[serde(rename(deserialize = "BONUS_RATIO"))]
+    pub bonus: Option<f32>,
+
+    /// 每10股转增
+    #[serde(rename(deserialize = "IT_RATIO"))]
+    pub transfer: Option<f32>,
+}
+
+/// stock_corp_action 配股
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub(crate) struct EastStockAllotment<'a> {
+    #[serde(borrow)]
+    pub result: Option<EastStockAllotmentResult<'a>>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub(crate) struct EastStockAllotmentResult<'a> {
+    pub pages: usize,
+    #[serde(borrow)]
+    pub data: Vec<EastStockAllotmentData<'a>>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub(crate) struct EastStockAllotmentData<'a> {
+    #[serde(borrow)]
+    #[serde(rename(deserialize = "SECURITY_CODE"))]
+    pub code: &'a str,
+
+    #[serde(borrow)]
+    #[serde(rename(deserialize = "SECURITY_NAME_ABBR"))]
+    pub name: &'a str,
+
+    #[serde(borrow)]
+    #[serde(default, rename(deserialize = "NOTICE_DATE"))]
+    pub notice_date: Option<&'a str>,
+
+    #[serde(borrow)]
+    #[serde(rename(deserialize = "EX_DIVIDEND_DATE"))]
+    pub ex_date: Option<&'a str>,
+
+    /// 每10股配股，部分数据为"10配3"的形式
+    #[serde(rename(deserialize = "PLACING_RATIO"))]
+    pub rights: Option<serde_json::Value>,
+
+    /// 配股价
+    #[serde(rename(deserialize = "ISSUE_PRICE"))]
+    pub rights_price: Option<f32>,
+}
+
 /// stock_rt_quot
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub(crate) struct XuQiuStockRtQuot<'a> {
@@ -290,3 +397,398 @@ pub(crate) struct XuQiuStockRtQuotData<'a> {
     pub float_market_capital: f64,
     pub is_trade: bool,
 }
+
+/// 雪球港股、美股列表
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub(crate) struct XueQiuStockList {
+    pub data: Option<XueQiuStockListData>,
+}
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub(crate) struct XueQiuStockListData {
+    pub count: usize,
+    pub list: Vec<XueQiuStockListItem>,
+}
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub(crate) struct XueQiuStockListItem {
+    pub symbol: String,
+    pub name: String,
+    /// 每手股数
+    #[serde(default)]
+    pub lot_size: Option<u32>,
+    /// 上市日期时间戳(毫秒)
+    #[serde(default)]
+    pub issue_date_ts: Option<i64>,
+}
+
+/// index_info 指数列表
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub(crate) struct EastIndexList<'a> {
+    #[serde(borrow)]
+    pub data: Option<EastIndexListData<'a>>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub(crate) struct EastIndexListData<'a> {
+    pub total: usize,
+    #[serde(borrow)]
+    pub diff: Vec<EastIndexListDataDetail<'a>>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub(crate) struct EastIndexListDataDetail<'a> {
+    #[serde(borrow)]
+    #[serde(rename(deserialize = "f12"))]
+    pub code: &'a str,
+
+    /// 市场: 0 深圳，1 上海，2 中证
+    #[serde(rename(deserialize = "f13"))]
+    pub market: i32,
+
+    #[serde(borrow)]
+    #[serde(rename(deserialize = "f14"))]
+    pub name: &'a str,
+}
+
+/// index_constituent 指数成分股权重
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub(crate) struct EastIndexConstituent<'a> {
+    #[serde(borrow)]
+    pub result: Option<EastIndexConstituentResult<'a>>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub(crate) struct EastIndexConstituentResult<'a> {
+    pub pages: usize,
+    #[serde(borrow)]
+    pub data: Vec<EastIndexConstituentData<'a>>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub(crate) struct EastIndexConstituentData<'a> {
+    #[serde(borrow)]
+    #[serde(rename(deserialize = "INDEX_CODE"))]
+    pub index_code: &'a str,
+
+    #[serde(borrow)]
+    #[serde(rename(deserialize = "INDEX_NAME"))]
+    pub index_name: &'a str,
+
+    #[serde(borrow)]
+    #[serde(rename(deserialize = "SECURITY_CODE"))]
+    pub code: &'a str,
+
+    #[serde(borrow)]
+    #[serde(rename(deserialize = "SECURITY_NAME_ABBR"))]
+    pub name: &'a str,
+
+    /// 权重(%)
+    #[serde(rename(deserialize = "WEIGHT"))]
+    pub weight: Option<f32>,
+
+    #[serde(borrow)]
+    #[serde(rename(deserialize = "END_DATE"))]
+    pub end_date: &'a str,
+}
+
+/// 数据中心报表通用格式，如财务报表、股东数据、北向资金等
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub(crate) struct EastDataCenter<T> {
+    pub result: Option<EastDataCenterResult<T>>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub(crate) struct EastDataCenterResult<T> {
+    pub pages: usize,
+    pub data: Vec<T>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub(crate) struct EastStockBalanceData {
+    #[serde(rename(deserialize = "SECURITY_CODE"))]
+    pub code: String,
+    #[serde(rename(deserialize = "SECURITY_NAME_ABBR"))]
+    pub name: String,
+    #[serde(rename(deserialize = "REPORT_DATE"))]
+    pub report_date: String,
+    #[serde(rename(deserialize = "NOTICE_DATE"))]
+    pub notice_date: Option<String>,
+    #[serde(rename(deserialize = "MONETARYFUNDS"))]
+    pub monetary_funds: Option<f64>,
+    #[serde(rename(deserialize = "ACCOUNTS_RECE"))]
+    pub accounts_rece: Option<f64>,
+    #[serde(rename(deserialize = "INVENTORY"))]
+    pub inventory: Option<f64>,
+    #[serde(rename(deserialize = "TOTAL_ASSETS"))]
+    pub total_assets: Option<f64>,
+    #[serde(rename(deserialize = "ACCOUNTS_PAYABLE"))]
+    pub accounts_payable: Option<f64>,
+    #[serde(rename(deserialize = "ADVANCE_RECEIVABLES"))]
+    pub advance_receivables: Option<f64>,
+    #[serde(rename(deserialize = "TOTAL_LIABILITIES"))]
+    pub total_liab: Option<f64>,
+    #[serde(rename(deserialize = "TOTAL_EQUITY"))]
+    pub total_equity: Option<f64>,
+    #[serde(rename(deserialize = "DEBT_ASSET_RATIO"))]
+    pub debt_asset_ratio: Option<f32>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub(crate) struct EastStockIncomeData {
+    #[serde(rename(deserialize = "SECURITY_CODE"))]
+    pub code: String,
+    #[serde(rename(deserialize = "SECURITY_NAME_ABBR"))]
+    pub name: String,
+    #[serde(rename(deserialize = "REPORT_DATE"))]
+    pub report_date: String,
+    #[serde(rename(deserialize = "NOTICE_DATE"))]
+    pub notice_date: Option<String>,
+    #[serde(rename(deserialize = "TOTAL_OPERATE_INCOME"))]
+    pub total_operate_income: Option<f64>,
+    #[serde(rename(deserialize = "OPERATE_COST"))]
+    pub operate_cost: Option<f64>,
+    #[serde(rename(deserialize = "SALE_EXPENSE"))]
+    pub sale_expense: Option<f64>,
+    #[serde(rename(deserialize = "MANAGE_EXPENSE"))]
+    pub manage_expense: Option<f64>,
+    #[serde(rename(deserialize = "FINANCE_EXPENSE"))]
+    pub finance_expense: Option<f64>,
+    #[serde(rename(deserialize = "TOTAL_OPERATE_COST"))]
+    pub total_operate_cost: Option<f64>,
+    #[serde(rename(deserialize = "OPERATE_PROFIT"))]
+    pub operate_profit: Option<f64>,
+    #[serde(rename(deserialize = "TOTAL_PROFIT"))]
+    pub total_profit: Option<f64>,
+    #[serde(rename(deserialize = "PARENT_NETPROFIT"))]
+    pub parent_netprofit: Option<f64>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub(crate) struct EastStockCashFlowData {
+    #[serde(rename(deserialize = "SECURITY_CODE"))]
+    pub code: String,
+    #[serde(rename(deserialize = "SECURITY_NAME_ABBR"))]
+    pub name: String,
+    #[serde(rename(deserialize = "REPORT_DATE"))]
+    pub report_date: String,
+    #[serde(rename(deserialize = "NOTICE_DATE"))]
+    pub notice_date: Option<String>,
+    #[serde(rename(deserialize = "NETCASH_OPERATE"))]
+    pub netcash_operate: Option<f64>,
+    #[serde(rename(deserialize = "NETCASH_INVEST"))]
+    pub netcash_invest: Option<f64>,
+    #[serde(rename(deserialize = "NETCASH_FINANCE"))]
+    pub netcash_finance: Option<f64>,
+    #[serde(rename(deserialize = "CONSTRUCT_LONG_ASSET"))]
+    pub construct_long_asset: Option<f64>,
+    #[serde(rename(deserialize = "CCE_ADD"))]
+    pub cce_add: Option<f64>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub(crate) struct EastStockHolderNumData {
+    #[serde(rename(deserialize = "SECURITY_CODE"))]
+    pub code: String,
+    #[serde(rename(deserialize = "SECURITY_NAME_ABBR"))]
+    pub name: String,
+    #[serde(rename(deserialize = "END_DATE"))]
+    pub report_date: String,
+    #[serde(rename(deserialize = "HOLD_NOTICE_DATE"))]
+    pub notice_date: Option<String>,
+    #[serde(rename(deserialize = "HOLDER_NUM"))]
+    pub holder_num: Option<i64>,
+    #[serde(rename(deserialize = "PRE_HOLDER_NUM"))]
+    pub pre_holder_num: Option<i64>,
+    #[serde(rename(deserialize = "HOLDER_NUM_CHANGE"))]
+    pub holder_num_change: Option<i64>,
+    #[serde(rename(deserialize = "HOLDER_NUM_RATIO"))]
+    pub holder_num_ratio: Option<f32>,
+    #[serde(rename(deserialize = "AVG_FREE_SHARES"))]
+    pub avg_free_shares: Option<f64>,
+}
+
+/// 十大股东及十大流通股东，持股比例字段名不同
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub(crate) struct EastStockTopHolderData {
+    #[serde(rename(deserialize = "SECURITY_CODE"))]
+    pub code: String,
+    #[serde(rename(deserialize = "END_DATE"))]
+    pub report_date: String,
+    #[serde(rename(deserialize = "NOTICE_DATE"))]
+    pub notice_date: Option<String>,
+    #[serde(rename(deserialize = "HOLDER_RANK"))]
+    pub rank: i32,
+    #[serde(rename(deserialize = "HOLDER_NAME"))]
+    pub holder_name: String,
+    #[serde(rename(deserialize = "HOLDER_TYPE"))]
+    pub holder_type: Option<String>,
+    #[serde(rename(deserialize = "HOLD_NUM"))]
+    pub hold_num: Option<f64>,
+    #[serde(rename(deserialize = "HOLD_NUM_RATIO"), alias = "FREE_HOLDNUM_RATIO")]
+    pub hold_ratio: Option<f32>,
+    /// 增减股数，或`新进`、`不变`
+    #[serde(rename(deserialize = "HOLD_NUM_CHANGE"))]
+    pub hold_change: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub(crate) struct EastStockNorthHoldData {
+    #[serde(rename(deserialize = "SECURITY_CODE"))]
+    pub code: String,
+    #[serde(rename(deserialize = "SECURITY_NAME"))]
+    pub name: String,
+    #[serde(rename(deserialize = "TRADE_DATE"))]
+    pub trade_date: String,
+    #[serde(rename(deserialize = "HOLD_SHARES"))]
+    pub hold_shares: Option<f64>,
+    #[serde(rename(deserialize = "HOLD_MARKET_CAP"))]
+    pub hold_market_cap: Option<f64>,
+    #[serde(rename(deserialize = "FREE_SHARES_RATIO"))]
+    pub hold_ratio: Option<f32>,
+    #[serde(rename(deserialize = "ADD_SHARES_REPAIR"))]
+    pub hold_shares_change: Option<f64>,
+}
+
+/// 北向资金每日成交，金额单位为百万元
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub(crate) struct EastNorthFlowData {
+    /// 001: 沪股通，003: 深股通
+    #[serde(rename(deserialize = "MUTUAL_TYPE"))]
+    pub mutual_type: String,
+    #[serde(rename(deserialize = "TRADE_DATE"))]
+    pub trade_date: String,
+    #[serde(rename(deserialize = "BUY_AMT"))]
+    pub buy_amount: Option<f64>,
+    #[serde(rename(deserialize = "SELL_AMT"))]
+    pub sell_amount: Option<f64>,
+    #[serde(rename(deserialize = "NET_DEAL_AMT"))]
+    pub net_buy: Option<f64>,
+}
+
+/// 龙虎榜上榜记录
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub(crate) struct EastDragonTigerData {
+    #[serde(rename(deserialize = "SECURITY_CODE"))]
+    pub code: String,
+    #[serde(rename(deserialize = "SECURITY_NAME_ABBR"))]
+    pub name: String,
+    #[serde(rename(deserialize = "TRADE_DATE"))]
+    pub trade_date: String,
+    #[serde(rename(deserialize = "EXPLANATION"))]
+    pub reason: String,
+    #[serde(rename(deserialize = "CLOSE_PRICE"))]
+    pub close: Option<f32>,
+    #[serde(rename(deserialize = "CHANGE_RATE"))]
+    pub chg_pct: Option<f32>,
+    #[serde(rename(deserialize = "TURNOVERRATE"))]
+    pub turnover: Option<f32>,
+    #[serde(rename(deserialize = "ACCUM_AMOUNT"))]
+    pub amount: Option<f64>,
+    #[serde(rename(deserialize = "BILLBOARD_BUY_AMT"))]
+    pub buy_amount: Option<f64>,
+    #[serde(rename(deserialize = "BILLBOARD_SELL_AMT"))]
+    pub sell_amount: Option<f64>,
+    #[serde(rename(deserialize = "BILLBOARD_NET_AMT"))]
+    pub net_buy: Option<f64>,
+}
+
+/// 龙虎榜买入或卖出席位明细
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub(crate) struct EastDragonTigerSeatData {
+    #[serde(rename(deserialize = "SECURITY_CODE"))]
+    pub code: String,
+    #[serde(rename(deserialize = "EXPLANATION"))]
+    pub reason: String,
+    #[serde(rename(deserialize = "OPERATEDEPT_NAME"))]
+    pub name: String,
+    #[serde(rename(deserialize = "BUY"))]
+    pub buy_amount: Option<f64>,
+    #[serde(rename(deserialize = "SELL"))]
+    pub sell_amount: Option<f64>,
+    #[serde(rename(deserialize = "NET"))]
+    pub net_buy: Option<f64>,
+}
+
+/// 涨跌停股池
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub(crate) struct EastLimitPool {
+    pub data: Option<EastLimitPoolData>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub(crate) struct EastLimitPoolData {
+    pub pool: Vec<EastLimitPoolItem>,
+}
+
+/// 涨跌停股池明细，价格为实际价格的1000倍，时间为`HHMMSS`形式的整数
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub(crate) struct EastLimitPoolItem {
+    #[serde(rename(deserialize = "c"))]
+    pub code: String,
+    #[serde(rename(deserialize = "n"))]
+    pub name: String,
+    #[serde(rename(deserialize = "p"))]
+    pub price: f64,
+    #[serde(rename(deserialize = "zdp"))]
+    pub chg_pct: f32,
+    pub amount: f64,
+    #[serde(rename(deserialize = "hs"))]
+    pub turnover: f32,
+    /// 首次封板时间(涨停、炸板)
+    pub fbt: Option<u32>,
+    /// 最后封板时间(涨停、跌停)
+    pub lbt: Option<u32>,
+    /// 连板数(涨停)
+    pub lbc: Option<i32>,
+    /// 连续跌停天数(跌停)
+    pub days: Option<i32>,
+    /// 炸板次数(涨停、炸板)
+    pub zbc: Option<i32>,
+    /// 开板次数(跌停)
+    pub oc: Option<i32>,
+    /// 封单资金(涨停、跌停)
+    pub fund: Option<f64>,
+    #[serde(default)]
+    pub hybk: String,
+}
+
+/// 停复牌信息，时间为`%Y-%m-%d %H:%M:%S`形式
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub(crate) struct EastStockSuspendData {
+    #[serde(rename(deserialize = "SECURITY_CODE"))]
+    pub code: String,
+    #[serde(rename(deserialize = "SECURITY_NAME_ABBR"))]
+    pub name: String,
+    #[serde(rename(deserialize = "SUSPEND_START_TIME"))]
+    pub start_time: String,
+    /// 未公告复牌的为空
+    #[serde(rename(deserialize = "SUSPEND_END_TIME"))]
+    pub end_time: Option<String>,
+    #[serde(rename(deserialize = "SUSPEND_REASON"))]
+    pub reason: Option<String>,
+}
+
+/// 公告列表(东方财富)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub(crate) struct EastNotice {
+    pub data: Option<EastNoticeData>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub(crate) struct EastNoticeData {
+    pub list: Vec<EastNoticeItem>,
+    pub total_hits: usize,
+}
+
+/// 公告，公告日期为`%Y-%m-%d %H:%M:%S`形式
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub(crate) struct EastNoticeItem {
+    pub codes: Vec<EastNoticeCode>,
+    pub notice_date: String,
+    pub title: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub(crate) struct EastNoticeCode {
+    pub stock_code: String,
+    pub short_name: String,
+}
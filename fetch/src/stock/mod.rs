@@ -1,11 +1,19 @@
+mod failover_stock_fetch;
 mod hiq_stock_fetch;
 mod hiq_stock_info;
 mod stock_fetch;
+mod stock_rt_quot_sub;
 
+pub use failover_stock_fetch::*;
 pub use hiq_stock_fetch::*;
 pub use stock_fetch::*;
+pub use stock_rt_quot_sub::*;
+
+use crate::{FetchConfig, Result};
 
 /// 返回默认的股票实现
-pub fn stock_fetch() -> impl StockFetch {
-    HiqStockFetch::new()
+///
+/// `config`设置代理、超时、user-agent及请求地址等，默认配置用`FetchConfig::default()`
+pub fn stock_fetch(config: &FetchConfig) -> Result<impl StockFetch> {
+    Ok(HiqStockFetch::with_transport(config.transport()?))
 }
@@ -4,20 +4,28 @@
 //!
 #![allow(unused_variables)]
 
-use crate::{Error, Result};
+use crate::{Error, MarketType, Result};
 use async_trait::async_trait;
 use chrono::NaiveDate;
 use hiq_common::stock::*;
-use hiq_common::BarFreq;
+use hiq_common::{AuctionSnapshot, BarFreq, MinuteBar, OrderBook, Tick, TimeShare};
 use std::collections::{HashMap, HashSet};
 
 /// 获取股票数据接口
 #[async_trait]
 pub trait StockFetch: Sync + Send {
-    /// 股票大盘指数（列举几个而已）
+    /// 上证、深证及中证系列指数
     async fn fetch_index_info(&self) -> Result<Vec<StockInfo>> {
         Err(Error::NotImpl("fetch_index_info".to_string()))
     }
+    /// 指数成分股及权重，`code`为内部代码如sh000300，`date`为None时取最新一期，否则取该日或之前最近一期
+    async fn fetch_index_constituents(
+        &self,
+        code: &str,
+        date: Option<NaiveDate>,
+    ) -> Result<Vec<IndexConstituent>> {
+        Err(Error::NotImpl("fetch_index_constituents".to_string()))
+    }
     /// 指数k线数据
     async fn fetch_index_bar(
         &self,
@@ -33,11 +41,15 @@ pub trait StockFetch: Sync + Send {
     async fn fetch_stock_info(&self) -> Result<Vec<StockInfo>> {
         Err(Error::NotImpl("fetch_stock_info".to_string()))
     }
+    /// 获取港股(`MarketType::HkStock`)或美股(`MarketType::UsStock`)基本信息，含交易货币及每手股数
+    async fn fetch_overseas_stock_info(&self, market: MarketType) -> Result<Vec<StockInfo>> {
+        Err(Error::NotImpl("fetch_overseas_stock_info".to_string()))
+    }
     /// 获取融资融券股票代码
     async fn fetch_stock_is_margin(&self) -> Result<HashSet<String>> {
         Err(Error::NotImpl("fetch_stock_is_margin".to_string()))
     }
-    /// 股票/指数k线数据
+    /// 股票/指数k线数据，港股(hk + 代码)、美股(us + 代码)只支持日线
     async fn fetch_stock_bar(
         &self,
         code: &str,
@@ -48,6 +60,29 @@ pub trait StockFetch: Sync + Send {
     ) -> Result<StockBar> {
         Err(Error::NotImpl("fetch_stock_bar".to_string()))
     }
+    /// 股票不复权k线数据，复权因子均为1，可结合除权除息事件在本地计算复权因子
+    async fn fetch_stock_nfq_bar(
+        &self,
+        code: &str,
+        name: Option<&str>,
+        freq: Option<BarFreq>,
+        start: Option<NaiveDate>,
+        end: Option<NaiveDate>,
+    ) -> Result<StockBar> {
+        Err(Error::NotImpl("fetch_stock_nfq_bar".to_string()))
+    }
+    /// 股票分钟k线(后复权因子)，从`end`(默认当日)向前分页获取直至`start`或上游无更早数据，
+    /// 返回实际覆盖的时间范围。*freq* 默认1分钟
+    async fn fetch_stock_minute_bar(
+        &self,
+        code: &str,
+        name: Option<&str>,
+        freq: Option<BarFreq>,
+        start: Option<NaiveDate>,
+        end: Option<NaiveDate>,
+    ) -> Result<MinuteBar> {
+        Err(Error::NotImpl("fetch_stock_minute_bar".to_string()))
+    }
 
     /// 股票最新指标
     async fn fetch_stock_index(
@@ -111,6 +146,35 @@ pub trait StockFetch: Sync + Send {
     async fn fetch_stock_yjbb(&self, year: u16, season: u16) -> Result<Vec<StockYJBB>> {
         Err(Error::NotImpl("fetch_stock_yjbb".to_string()))
     }
+    /// 资产负债表，全市场某报告期，`season`为1~4
+    async fn fetch_stock_balance_sheet(
+        &self,
+        year: u16,
+        season: u16,
+    ) -> Result<Vec<StockBalanceSheet>> {
+        Err(Error::NotImpl("fetch_stock_balance_sheet".to_string()))
+    }
+    /// 利润表，全市场某报告期，`season`为1~4
+    async fn fetch_stock_income(&self, year: u16, season: u16) -> Result<Vec<StockIncome>> {
+        Err(Error::NotImpl("fetch_stock_income".to_string()))
+    }
+    /// 现金流量表，全市场某报告期，`season`为1~4
+    async fn fetch_stock_cash_flow(&self, year: u16, season: u16) -> Result<Vec<StockCashFlow>> {
+        Err(Error::NotImpl("fetch_stock_cash_flow".to_string()))
+    }
+    /// 股东户数，全市场截止日期在上一报告期之后、该报告期及之前的记录，`season`为1~4
+    async fn fetch_stock_holder_num(&self, year: u16, season: u16) -> Result<Vec<StockHolderNum>> {
+        Err(Error::NotImpl("fetch_stock_holder_num".to_string()))
+    }
+    /// 十大股东，全市场某报告期，`is_float`为`true`时为十大流通股东
+    async fn fetch_stock_top_holders(
+        &self,
+        year: u16,
+        season: u16,
+        is_float: bool,
+    ) -> Result<Vec<StockTopHolder>> {
+        Err(Error::NotImpl("fetch_stock_top_holders".to_string()))
+    }
     /// 融资融券
     async fn fetch_stock_margin(
         &self,
@@ -120,8 +184,77 @@ pub trait StockFetch: Sync + Send {
     ) -> Result<Vec<StockMargin>> {
         Err(Error::NotImpl("fetch_stock_margin".to_string()))
     }
+    /// 北向资金个股持股，全市场某交易日，2024-08-16之后交易所不再按日披露
+    async fn fetch_stock_north_hold(&self, date: NaiveDate) -> Result<Vec<StockNorthHold>> {
+        Err(Error::NotImpl("fetch_stock_north_hold".to_string()))
+    }
+    /// 北向资金每日成交(沪股通、深股通)，start/end为交易日期
+    async fn fetch_north_flow(
+        &self,
+        start: Option<NaiveDate>,
+        end: Option<NaiveDate>,
+    ) -> Result<Vec<NorthFlow>> {
+        Err(Error::NotImpl("fetch_north_flow".to_string()))
+    }
+    /// 龙虎榜，全市场某交易日的上榜记录及买卖前五席位
+    async fn fetch_stock_dragon_tiger(&self, date: NaiveDate) -> Result<Vec<StockDragonTiger>> {
+        Err(Error::NotImpl("fetch_stock_dragon_tiger".to_string()))
+    }
+    /// 涨停、跌停或炸板股池，全市场某交易日
+    async fn fetch_stock_limit_pool(
+        &self,
+        date: NaiveDate,
+        pool: LimitPool,
+    ) -> Result<Vec<StockLimitPool>> {
+        Err(Error::NotImpl("fetch_stock_limit_pool".to_string()))
+    }
+    /// 当前风险警示(ST、*ST)股票，start_date为当日
+    async fn fetch_stock_st(&self) -> Result<Vec<StockStatus>> {
+        Err(Error::NotImpl("fetch_stock_st".to_string()))
+    }
+    /// 某日发布的风险警示变更公告
+    async fn fetch_stock_st_notice(&self, date: NaiveDate) -> Result<Vec<StockStatusNotice>> {
+        Err(Error::NotImpl("fetch_stock_st_notice".to_string()))
+    }
+    /// 风险警示(ST、*ST)历史区间，end_date为空表示尚未结束
+    async fn fetch_stock_st_history(&self) -> Result<Vec<StockStatus>> {
+        Err(Error::NotImpl("fetch_stock_st_history".to_string()))
+    }
+    /// 停牌股票，全市场某交易日
+    async fn fetch_stock_suspend(&self, date: NaiveDate) -> Result<Vec<StockStatus>> {
+        Err(Error::NotImpl("fetch_stock_suspend".to_string()))
+    }
+    /// 终止上市股票(沪深)，start_date为退市日期
+    async fn fetch_stock_delist(&self) -> Result<Vec<StockStatus>> {
+        Err(Error::NotImpl("fetch_stock_delist".to_string()))
+    }
+    /// 除权除息事件(分红送转及配股)，code为None时为全市场，start/end为除权除息日
+    async fn fetch_stock_corp_action(
+        &self,
+        code: Option<&str>,
+        start: Option<NaiveDate>,
+        end: Option<NaiveDate>,
+    ) -> Result<Vec<StockCorpAction>> {
+        Err(Error::NotImpl("fetch_stock_corp_action".to_string()))
+    }
     /// 实时行情
     async fn fetch_stock_rt_quot(&self, code: Vec<&str>) -> Result<HashMap<String, StockRtQuot>> {
         Err(Error::NotImpl("fetch_stock_rt_quot".to_string()))
     }
+    /// 股票五档盘口，含涨跌停价及内外盘
+    async fn fetch_stock_order_book(&self, code: Vec<&str>) -> Result<HashMap<String, OrderBook>> {
+        Err(Error::NotImpl("fetch_stock_order_book".to_string()))
+    }
+    /// 分笔成交，`code`为sz/sh + 代码，可转债同样适用
+    async fn fetch_stock_ticks(&self, code: &str, date: NaiveDate) -> Result<Vec<Tick>> {
+        Err(Error::NotImpl("fetch_stock_ticks".to_string()))
+    }
+    /// 最新交易日分时走势(价格、均价、成交量)
+    async fn fetch_stock_time_share(&self, code: &str) -> Result<TimeShare> {
+        Err(Error::NotImpl("fetch_stock_time_share".to_string()))
+    }
+    /// 最新交易日集合竞价(9:15-9:25)每分钟的虚拟匹配价及匹配量
+    async fn fetch_stock_auction(&self, code: &str) -> Result<Vec<AuctionSnapshot>> {
+        Err(Error::NotImpl("fetch_stock_auction".to_string()))
+    }
 }
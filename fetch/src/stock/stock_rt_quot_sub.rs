@@ -0,0 +1,188 @@
+//! 实时行情订阅
+//!
+//! 按固定间隔轮询实时行情，只推送有变化的行情，非交易时段暂停轮询。
+
+use crate::comm::market_clock;
+use crate::stock::StockFetch;
+use crate::Result;
+use chrono::Local;
+use futures::stream::{self, BoxStream, StreamExt};
+use hiq_common::{Exchange, MarketClock, StockRtQuot};
+use std::collections::{HashMap, VecDeque};
+use std::sync::Arc;
+use std::time::Duration;
+
+/// 实时行情订阅配置
+#[derive(Debug, Clone)]
+pub struct RtQuotSubscribeConfig {
+    /// 轮询间隔
+    pub interval: Duration,
+    /// 只在最新价或成交量变化时推送
+    pub only_changed: bool,
+    /// 非交易时段暂停轮询，直至下一交易时段
+    pub pause_when_closed: bool,
+}
+
+impl Default for RtQuotSubscribeConfig {
+    fn default() -> Self {
+        Self {
+            interval: Duration::from_secs(3),
+            only_changed: true,
+            pause_when_closed: true,
+        }
+    }
+}
+
+struct RtQuotSubscriber {
+    fetch: Arc<dyn StockFetch>,
+    codes: Vec<String>,
+    config: RtQuotSubscribeConfig,
+    clock: MarketClock,
+    /// 已推送的(最新价, 成交量)
+    last: HashMap<String, (f32, i64)>,
+    pending: VecDeque<Result<StockRtQuot>>,
+    polled: bool,
+}
+
+impl RtQuotSubscriber {
+    async fn poll(&mut self) {
+        if self.polled {
+            let mut wait = self.config.interval;
+            if self.config.pause_when_closed {
+                let now = Local::now().naive_local();
+                if let Some(next) = self.clock.next_trading(&now) {
+                    if let Ok(d) = (next - now).to_std() {
+                        wait = wait.max(d);
+                    }
+                }
+            }
+            tokio::time::sleep(wait).await;
+        }
+        self.polled = true;
+
+        let codes: Vec<_> = self.codes.iter().map(String::as_str).collect();
+        match self.fetch.fetch_stock_rt_quot(codes).await {
+            Ok(mut data) => {
+                for code in self.codes.iter() {
+                    if let Some(quot) = data.remove(code) {
+                        let cur = (quot.last, quot.volume);
+                        let prev = self.last.insert(code.clone(), cur);
+                        if !self.config.only_changed || prev != Some(cur) {
+                            self.pending.push_back(Ok(quot));
+                        }
+                    }
+                }
+            }
+            Err(e) => self.pending.push_back(Err(e)),
+        }
+    }
+}
+
+/// 订阅实时行情，返回行情更新流，流不会主动结束，drop即取消订阅。
+///
+/// 首次立即请求全部代码的行情，之后按`interval`轮询，推送顺序与`codes`一致；
+/// 请求失败时推送错误并继续轮询。
+pub fn subscribe_stock_rt_quot(
+    fetch: Arc<dyn StockFetch>,
+    codes: Vec<String>,
+    config: RtQuotSubscribeConfig,
+) -> BoxStream<'static, Result<StockRtQuot>> {
+    let subscriber = RtQuotSubscriber {
+        fetch,
+        codes: codes.into_iter().map(|code| code.to_lowercase()).collect(),
+        config,
+        clock: market_clock(Exchange::SH),
+        last: HashMap::new(),
+        pending: VecDeque::new(),
+        polled: false,
+    };
+    stream::unfold(subscriber, |mut subscriber| async move {
+        while subscriber.pending.is_empty() {
+            subscriber.poll().await;
+        }
+        subscriber
+            .pending
+            .pop_front()
+            .map(|item| (item, subscriber))
+    })
+    .boxed()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use async_trait::async_trait;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    /// 每请求两次成交量变化一次
+    struct MockFetch {
+        count: AtomicUsize,
+    }
+
+    #[async_trait]
+    impl StockFetch for MockFetch {
+        async fn fetch_stock_rt_quot(
+            &self,
+            code: Vec<&str>,
+        ) -> Result<HashMap<String, StockRtQuot>> {
+            let n = self.count.fetch_add(1, Ordering::SeqCst);
+            Ok(code
+                .into_iter()
+                .map(|code| {
+                    let quot = StockRtQuot {
+                        code: code.to_owned(),
+                        time: Local::now().naive_local(),
+                        last_close: 10.0,
+                        open: 10.0,
+                        high: 10.0,
+                        low: 10.0,
+                        last: 10.0,
+                        chg: 0.0,
+                        chg_pct: 0.0,
+                        volume: (n / 2) as i64,
+                        amount: 0.0,
+                        turnover: 0.0,
+                        total_value: 0.0,
+                        currency_value: 0.0,
+                        is_trading: true,
+                        currency: Default::default(),
+                    };
+                    (code.to_owned(), quot)
+                })
+                .collect())
+        }
+    }
+
+    #[test]
+    fn test_subscribe_stock_rt_quot() {
+        tokio::runtime::Builder::new_multi_thread()
+            .enable_all()
+            .build()
+            .unwrap()
+            .block_on(async {
+                let fetch = Arc::new(MockFetch {
+                    count: AtomicUsize::new(0),
+                });
+                let config = RtQuotSubscribeConfig {
+                    interval: Duration::from_millis(1),
+                    only_changed: true,
+                    pause_when_closed: false,
+                };
+                let data: Vec<_> = subscribe_stock_rt_quot(
+                    fetch.clone(),
+                    vec!["SZ000001".to_string(), "sh600000".to_string()],
+                    config,
+                )
+                .take(4)
+                .collect()
+                .await;
+                let data: Vec<_> = data.into_iter().map(|q| q.unwrap()).collect();
+                assert_eq!(data[0].code, "sz000001");
+                assert_eq!(data[1].code, "sh600000");
+                // 成交量未变化的不推送
+                assert_eq!(data[2].volume, 1);
+                assert_eq!(data[3].volume, 1);
+                assert_eq!(fetch.count.load(Ordering::SeqCst), 3);
+            })
+    }
+}
@@ -0,0 +1,6 @@
+//! 腾讯k线数据源，可作为东方财富的备用数据源或交叉验证
+
+mod tencent_fetch;
+mod tencent_info;
+
+pub use tencent_fetch::*;
@@ -0,0 +1,470 @@
+use crate::comm::{default_transport, fetch_prev_trade_date, market_clock, Transport};
+use crate::tencent::tencent_info::TencentKline;
+use crate::{BondFetch, Error, FundFetch, Result, StockFetch};
+use async_trait::async_trait;
+use chrono::{Duration, Local, NaiveDate, NaiveDateTime};
+use hiq_common::{Bar, BarFreq, BondBar, Exchange, FundBar, StockBar};
+use std::collections::{BTreeMap, HashMap};
+use std::sync::Arc;
+
+/// 每次请求的最大k线条数
+const KLINE_COUNT: usize = 800;
+/// 日线每次请求的时间跨度(自然日)，约600个交易日，不超过KLINE_COUNT
+const KLINE_SPAN_DAYS: i64 = 900;
+/// 最早的交易日
+const KLINE_FIRST_DATE: &str = "1990-12-19";
+
+/// 腾讯k线原始数据
+#[derive(Debug, Clone)]
+struct RawBar {
+    trade_date: NaiveDateTime,
+    open: f32,
+    close: f32,
+    high: f32,
+    low: f32,
+    volume: u64,
+    amount: f64,
+    turnover: f32,
+}
+
+/// 腾讯k线数据源，股票、指数、etf基金、可转债通用
+///
+/// 成交量单位和东方财富一致(手)，成交额及换手率取自日/周/月线，
+/// 分钟线及较早的(腾讯未提供的)k线没有这两项，为0，需要时应使用其他数据源。
+/// `hfq_factor`由后复权收盘价/不复权收盘价计算。
+pub struct TencentFetch {
+    transport: Arc<dyn Transport>,
+}
+
+impl TencentFetch {
+    pub fn new() -> Self {
+        Self::with_transport(default_transport())
+    }
+    /// 指定传输层，如录制/回放
+    pub fn with_transport(transport: Arc<dyn Transport>) -> Self {
+        Self { transport }
+    }
+
+    /// k线数据，`code`为sz/sh + 代码，返回(名称, k线)
+    pub async fn fetch_bar(
+        &self,
+        code: &str,
+        freq: BarFreq,
+        start: Option<NaiveDate>,
+        end: Option<NaiveDate>,
+    ) -> Result<(String, Vec<Bar>)> {
+        let now = Local::now().naive_local();
+        let mut end = end.map_or(now.date(), |e| e.min(now.date()));
+        // 当日的不准
+        if matches!(freq, BarFreq::Daily)
+            && !market_clock(Exchange::SH).is_closed(&now)
+            && end >= now.date()
+        {
+            end = now.date() - Duration::days(1);
+        }
+        // 多取一个交易日，用于计算涨跌幅
+        let first = match &start {
+            Some(s) => {
+                let prev = fetch_prev_trade_date(s).await?;
+                NaiveDate::parse_from_str(&prev.to_string(), "%Y%m%d").unwrap()
+            }
+            None => NaiveDate::parse_from_str(KLINE_FIRST_DATE, "%Y-%m-%d").unwrap(),
+        };
+        if first > end {
+            return Ok(("".to_owned(), Vec::new()));
+        }
+
+        let (name, raw, factors) = match Self::period(freq) {
+            Some(period) => {
+                let (name, raw) = self.fetch_kline(code, period, "", first, end).await?;
+                let (_, hfq) = self.fetch_kline(code, period, "hfq", first, end).await?;
+                let hfq: HashMap<_, _> =
+                    hfq.iter().map(|bar| (bar.trade_date, bar.close)).collect();
+                let factors: HashMap<_, _> = raw
+                    .iter()
+                    .map(|bar| {
+                        let factor = hfq.get(&bar.trade_date).map_or(1.0, |hfq_close| {
+                            if bar.close > 0.0 {
+                                hfq_close / bar.close
+                            } else {
+                                1.0
+                            }
+                        });
+                        (bar.trade_date.date(), factor)
+                    })
+                    .collect();
+                (name, raw, factors)
+            }
+            None => {
+                let (name, raw) = self.fetch_mkline(code, freq).await?;
+                let raw: Vec<_> = raw
+                    .into_iter()
+                    .filter(|bar| bar.trade_date.date() >= first && bar.trade_date.date() <= end)
+                    .collect();
+                // 分钟线的复权因子同当日的日线
+                let mut factors = HashMap::new();
+                if let (Some(s), Some(e)) = (raw.first(), raw.last()) {
+                    let (s, e) = (s.trade_date.date(), e.trade_date.date());
+                    let (_, day) = self.fetch_kline(code, "day", "", s, e).await?;
+                    let (_, hfq) = self.fetch_kline(code, "day", "hfq", s, e).await?;
+                    let hfq: HashMap<_, _> =
+                        hfq.iter().map(|bar| (bar.trade_date, bar.close)).collect();
+                    day.iter().for_each(|bar| {
+                        if let Some(hfq_close) = hfq.get(&bar.trade_date) {
+                            if bar.close > 0.0 {
+                                factors.insert(bar.trade_date.date(), hfq_close / bar.close);
+                            }
+                        }
+                    });
+                }
+                (name, raw, factors)
+            }
+        };
+
+        let mut pre_item: Option<&RawBar> = None;
+        let mut bars = Vec::new();
+        for item in raw.iter() {
+            let (chg_pct, volume_chg_pct, amount_chg_pct) = if let Some(pre) = pre_item {
+                (
+                    if pre.close > 0.0 {
+                        (item.close - pre.close) * 100.0 / pre.close
+                    } else {
+                        0.0
+                    },
+                    if pre.volume > 0 {
+                        ((item.volume as f64 - pre.volume as f64) * 100.0 / pre.volume as f64)
+                            as f32
+                    } else {
+                        0.0
+                    },
+                    if pre.amount > 0.0 {
+                        ((item.amount - pre.amount) * 100.0 / pre.amount) as f32
+                    } else {
+                        0.0
+                    },
+                )
+            } else {
+                (0.0, 0.0, 0.0)
+            };
+            pre_item = Some(item);
+            if let Some(s) = &start {
+                if item.trade_date.date() < *s {
+                    continue;
+                }
+            }
+            bars.push(Bar {
+                code: code.to_owned(),
+                name: name.clone().unwrap_or_default(),
+                trade_date: item.trade_date,
+                open: item.open,
+                close: item.close,
+                high: item.high,
+                low: item.low,
+                volume: item.volume,
+                amount: item.amount,
+                turnover: item.turnover,
+                chg_pct,
+                volume_chg_pct,
+                amount_chg_pct,
+                hfq_factor: *factors.get(&item.trade_date.date()).unwrap_or(&1.0),
+            });
+        }
+        Ok((name.unwrap_or_default(), bars))
+    }
+
+    fn period(freq: BarFreq) -> Option<&'static str> {
+        match freq {
+            BarFreq::Daily | BarFreq::LooseDaily => Some("day"),
+            BarFreq::Weekly => Some("week"),
+            BarFreq::Monthly => Some("month"),
+            _ => None,
+        }
+    }
+
+    /// 日/周/月线，按时间分段请求
+    async fn fetch_kline(
+        &self,
+        code: &str,
+        period: &str,
+        fq: &str,
+        start: NaiveDate,
+        end: NaiveDate,
+    ) -> Result<(Option<String>, Vec<RawBar>)> {
+        let mut name = None;
+        let mut data = BTreeMap::new();
+        let mut s = start;
+        while s <= end {
+            let e = end.min(s + Duration::days(KLINE_SPAN_DAYS));
+            let req_url = format!(
+                "https://web.ifzq.gtimg.cn/appstock/app/fqkline/get?\
+                param={code},{period},{start},{end},{count},{fq}",
+                code = code,
+                period = period,
+                start = s.format("%Y-%m-%d"),
+                end = e.format("%Y-%m-%d"),
+                count = KLINE_COUNT,
+                fq = fq
+            );
+            let resp = self.transport.get_text(&req_url, None).await?;
+            let json: TencentKline = serde_json::from_str(&resp)?;
+            let (t_name, bars) = parse_kline(&json, code, &format!("{}{}", fq, period))?;
+            if name.is_none() {
+                name = t_name;
+            }
+            bars.into_iter().for_each(|bar| {
+                data.insert(bar.trade_date, bar);
+            });
+            s = e + Duration::days(1);
+        }
+        Ok((name, data.into_values().collect()))
+    }
+
+    /// 分钟线，只能获取最近的数据
+    async fn fetch_mkline(
+        &self,
+        code: &str,
+        freq: BarFreq,
+    ) -> Result<(Option<String>, Vec<RawBar>)> {
+        let period = format!("m{}", freq as i32);
+        let req_url = format!(
+            "https://ifzq.gtimg.cn/appstock/app/kline/mkline?\
+            param={code},{period},,{count}",
+            code = code,
+            period = period,
+            count = KLINE_COUNT
+        );
+        let resp = self.transport.get_text(&req_url, None).await?;
+        let json: TencentKline = serde_json::from_str(&resp)?;
+        parse_kline(&json, code, &period)
+    }
+}
+
+impl Default for TencentFetch {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+fn parse_kline(
+    json: &TencentKline,
+    code: &str,
+    key: &str,
+) -> Result<(Option<String>, Vec<RawBar>)> {
+    if json.code != 0 {
+        return Err(Error::Custom(format!(
+            "Fetch tencent kline of {} error: {}!",
+            code, json.msg
+        )));
+    }
+    let data = match json.data.get(code) {
+        Some(data) => data,
+        None => return Ok((None, Vec::new())),
+    };
+    let name = data
+        .get("qt")
+        .and_then(|qt| qt.get(code))
+        .and_then(|qt| qt.get(1))
+        .and_then(|name| name.as_str())
+        .map(String::from);
+
+    let items = match data.get(key).and_then(|items| items.as_array()) {
+        Some(items) => items,
+        None => return Ok((name, Vec::new())),
+    };
+    let field = |item: &serde_json::Value, i: usize| -> Result<f64> {
+        item.get(i)
+            .and_then(|v| v.as_str())
+            .and_then(|v| v.parse::<f64>().ok())
+            .ok_or_else(|| Error::Custom(format!("Invalid tencent kline item: {}", item)))
+    };
+    // 日/周/月线: 换手率(百分比)、成交额(万元)，分钟线没有
+    let minute = key.starts_with('m');
+    let optional = |item: &serde_json::Value, i: usize| -> f64 {
+        if minute {
+            return 0.0;
+        }
+        item.get(i)
+            .and_then(|v| v.as_str())
+            .and_then(|v| v.parse::<f64>().ok())
+            .unwrap_or(0.0)
+    };
+    let bars = items
+        .iter()
+        .map(|item| {
+            let date = item.get(0).and_then(|v| v.as_str()).unwrap_or("");
+            let trade_date = if date.len() == 10 {
+                NaiveDate::parse_from_str(date, "%Y-%m-%d").map(|d| d.and_hms_opt(0, 0, 0).unwrap())
+            } else {
+                NaiveDateTime::parse_from_str(date, "%Y%m%d%H%M")
+            }
+            .map_err(|_| Error::Custom(format!("Invalid tencent kline date: {}", date)))?;
+            Ok(RawBar {
+                trade_date,
+                open: field(item, 1)? as f32,
+                close: field(item, 2)? as f32,
+                high: field(item, 3)? as f32,
+                low: field(item, 4)? as f32,
+                volume: field(item, 5)? as u64,
+                amount: optional(item, 8) * 10000.0,
+                turnover: optional(item, 7) as f32,
+            })
+        })
+        .collect::<Result<Vec<_>>>()?;
+    Ok((name, bars))
+}
+
+#[async_trait]
+impl StockFetch for TencentFetch {
+    /// 指数k线数据
+    async fn fetch_index_bar(
+        &self,
+        code: &str,
+        name: Option<&str>,
+        freq: Option<BarFreq>,
+        start: Option<NaiveDate>,
+        end: Option<NaiveDate>,
+    ) -> Result<StockBar> {
+        self.fetch_stock_bar(code, name, freq, start, end).await
+    }
+    /// 股票k线数据
+    async fn fetch_stock_bar(
+        &self,
+        code: &str,
+        name: Option<&str>,
+        freq: Option<BarFreq>,
+        start: Option<NaiveDate>,
+        end: Option<NaiveDate>,
+    ) -> Result<StockBar> {
+        let freq = freq.unwrap_or(BarFreq::Daily);
+        let (t_name, bars) = self.fetch_bar(code, freq, start, end).await?;
+        Ok(StockBar {
+            code: code.to_owned(),
+            name: name.map_or(t_name, String::from),
+            freq,
+            bars: if bars.is_empty() { None } else { Some(bars) },
+        })
+    }
+}
+
+#[async_trait]
+impl FundFetch for TencentFetch {
+    /// etf基金k线数据
+    async fn fetch_fund_bar(
+        &self,
+        code: &str,
+        name: Option<&str>,
+        freq: Option<BarFreq>,
+        start: Option<NaiveDate>,
+        end: Option<NaiveDate>,
+    ) -> Result<FundBar> {
+        let freq = freq.unwrap_or(BarFreq::Daily);
+        let (t_name, bars) = self.fetch_bar(code, freq, start, end).await?;
+        Ok(FundBar {
+            code: code.to_owned(),
+            name: name.map_or(t_name, String::from),
+            freq,
+            bars: if bars.is_empty() { None } else { Some(bars) },
+        })
+    }
+}
+
+#[async_trait]
+impl BondFetch for TencentFetch {
+    /// 可转债k线数据
+    async fn fetch_bond_bar(
+        &self,
+        code: &str,
+        name: &str,
+        stock_code: &str,
+        stock_name: &str,
+        freq: Option<BarFreq>,
+        start: Option<NaiveDate>,
+        end: Option<NaiveDate>,
+    ) -> Result<BondBar> {
+        let freq = freq.unwrap_or(BarFreq::Daily);
+        let (_, bars) = self.fetch_bar(code, freq, start, end).await?;
+        Ok(BondBar {
+            code: code.to_owned(),
+            name: name.to_owned(),
+            stock_code: stock_code.to_owned(),
+            stock_name: stock_name.to_owned(),
+            freq,
+            bars: if bars.is_empty() { None } else { Some(bars) },
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::TencentFetch;
+    use crate::{ReplayTransport, StockFetch, Transport};
+    use async_trait::async_trait;
+    use chrono::NaiveDate;
+    use reqwest::header::HeaderMap;
+    use std::sync::Arc;
+
+    #[test]
+    fn test_fetch_stock_bar() {
+        tokio::runtime::Runtime::new().unwrap().block_on(async {
+            let dir = concat!(env!("CARGO_MANIFEST_DIR"), "/fixtures");
+            let fetch = TencentFetch::with_transport(Arc::new(ReplayTransport::new(dir)));
+            let start = NaiveDate::from_ymd_opt(2023, 1, 4).unwrap();
+            let end = NaiveDate::from_ymd_opt(2023, 1, 6).unwrap();
+            let data = fetch
+                .fetch_stock_bar("sz002805", None, None, Some(start), Some(end))
+                .await
+                .unwrap();
+            assert_eq!(data.name, "丰元股份");
+            let bars = data.bars.unwrap();
+            assert_eq!(bars.len(), 3);
+            assert_eq!(bars[0].trade_date.date(), start);
+            assert_eq!(bars[0].close, 32.05);
+            assert!((bars[0].chg_pct - 2.4616).abs() < 0.001);
+            assert_eq!(bars[0].amount, 163428150.0);
+            assert_eq!(bars[0].turnover, 2.628);
+            assert!((bars[0].amount_chg_pct - 25.3814).abs() < 0.001);
+            assert_eq!(bars[0].hfq_factor, 2.0);
+        })
+    }
+
+    /// 固定返回同一内容
+    struct FixedTransport(&'static str);
+
+    #[async_trait]
+    impl Transport for FixedTransport {
+        async fn get(&self, url: &str, _headers: Option<HeaderMap>) -> crate::Result<Vec<u8>> {
+            let body = if url.ends_with(",hfq") {
+                self.0
+                    .replace("\"day\"", "\"hfqday\"")
+                    .replace("9.87", "19.74")
+            } else {
+                self.0.to_owned()
+            };
+            Ok(body.into_bytes())
+        }
+    }
+
+    #[test]
+    fn test_parse_kline() {
+        let body = r#"{"code":0,"msg":"","data":{"sz002805":{"day":[
+            ["2023-01-03","9.750","9.870","9.950","9.700","34567.000"],
+            ["2023-01-04","9.870","9.990","10.100","9.800","40000.000",{"nd":"2022"}]],
+            "qt":{"sz002805":["51","丰元股份","002805"]}}}}"#;
+        tokio::runtime::Runtime::new().unwrap().block_on(async {
+            let fetch = TencentFetch::with_transport(Arc::new(FixedTransport(body)));
+            let end = NaiveDate::from_ymd_opt(2023, 1, 4).unwrap();
+            let data = fetch
+                .fetch_stock_bar("sz002805", None, None, None, Some(end))
+                .await
+                .unwrap();
+            assert_eq!(data.name, "丰元股份");
+            let bars = data.bars.unwrap();
+            assert_eq!(bars.len(), 2);
+            assert_eq!(bars[1].close, 9.99);
+            assert_eq!(bars[1].volume, 40000);
+            assert!((bars[1].chg_pct - 1.2158).abs() < 0.001);
+            assert_eq!(bars[0].hfq_factor, 2.0);
+            assert_eq!(bars[1].hfq_factor, 1.0);
+        });
+    }
+}
@@ -0,0 +1,10 @@
+use serde::{Deserialize, Serialize};
+
+/// 腾讯k线，data按代码索引，失败时为空数组
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub(crate) struct TencentKline {
+    pub code: i32,
+    #[serde(default)]
+    pub msg: String,
+    pub data: serde_json::Value,
+}
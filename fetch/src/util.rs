@@ -1,19 +1,25 @@
-use crate::MarketType;
+use crate::{Error, Exchange, MarketType, Result, SecurityId};
 
-/// 原始的代码表示为内部的代码格式，内部格式为sz/sh + 代码
-/// 
+/// 原始的代码表示为内部的代码格式，内部格式为sz/sh/bj + 代码，港股为hk + 5位代码，美股为us + 代码
+///
+/// 股票: 6开头及900开头的B股为上海，4、8、92开头为北京，其余(含200开头的B股)为深圳
+///
 /// # Examples
 /// ```
 /// use hiq_fetch::{to_std_code, MarketType};
 /// let code = to_std_code(MarketType::Stock, "002805");
 /// assert_eq!(code.as_str(), "sz002805");
+/// let code = to_std_code(MarketType::Stock, "920002");
+/// assert_eq!(code.as_str(), "bj920002");
+/// let code = to_std_code(MarketType::HkStock, "700");
+/// assert_eq!(code.as_str(), "hk00700");
 /// ```
 #[inline]
 pub fn to_std_code(typ: MarketType, code: &str) -> String {
-    if code.len() != 6 {
-        return code.to_owned();
-    }
     match typ {
+        MarketType::HkStock => format!("hk{:0>5}", code),
+        MarketType::UsStock => format!("us{}", code.to_uppercase()),
+        _ if code.len() != 6 => code.to_owned(),
         MarketType::Bond => {
             if code.starts_with("12") {
                 format!("sz{}", code)
@@ -29,11 +35,54 @@ pub fn to_std_code(typ: MarketType, code: &str) -> String {
             }
         }
         MarketType::Stock => {
-            if code.starts_with("6") {
+            if code.starts_with('6') || code.starts_with("900") {
                 format!("sh{}", code)
+            } else if code.starts_with('4') || code.starts_with('8') || code.starts_with("92") {
+                format!("bj{}", code)
             } else {
                 format!("sz{}", code)
             }
         }
     }
 }
+
+/// 解析内部代码(sz/sh/bj/hk/us + 代码)，不支持的代码返回错误
+#[inline]
+pub(crate) fn to_security_id(code: &str) -> Result<SecurityId> {
+    SecurityId::parse(code).map_err(|e| Error::Custom(format!("Invalid code {}: {}", code, e)))
+}
+
+/// 内部代码转换为东方财富secid，如sh600000为1.600000，东方财富不支持的代码(如美股)返回错误，
+/// 中证指数(csi + 代码，如csi930050)为2.930050
+#[inline]
+pub(crate) fn to_east_secid(code: &str) -> Result<String> {
+    if let Some(index_code) = code.strip_prefix("csi") {
+        return Ok(format!("2.{}", index_code));
+    }
+    to_security_id(code)?
+        .to_east()
+        .ok_or_else(|| Error::Custom(format!("Code {} is not supported by eastmoney", code)))
+}
+
+/// 港股、美股代码，其他返回None
+#[inline]
+pub(crate) fn to_overseas_id(code: &str) -> Option<SecurityId> {
+    SecurityId::parse(code)
+        .ok()
+        .filter(|id| matches!(id.exchange(), Exchange::HK | Exchange::US))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::to_east_secid;
+
+    #[test]
+    fn test_to_east_secid() {
+        assert_eq!(to_east_secid("sh600000").unwrap(), "1.600000");
+        assert_eq!(to_east_secid("bj920002").unwrap(), "0.920002");
+        assert_eq!(to_east_secid("hk00700").unwrap(), "116.00700");
+        assert_eq!(to_east_secid("csiH30269").unwrap(), "2.H30269");
+        assert!(to_east_secid("usAAPL").is_err());
+        assert!(to_east_secid("600000").is_err());
+    }
+}
@@ -1,5 +1,6 @@
+use pyo3::exceptions::PyValueError;
 use pyo3::types::PyDict;
-use pyo3::{IntoPy, PyObject, Python};
+use pyo3::{FromPyObject, IntoPy, PyAny, PyObject, PyResult, Python};
 use serde::{Deserialize, Serialize};
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -35,3 +36,220 @@ impl IntoPy<PyObject> for Bar {
         dict.into()
     }
 }
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MinuteBar {
+    pub(crate) inner: hiq_common::MinuteBar,
+}
+
+impl From<hiq_common::MinuteBar> for MinuteBar {
+    fn from(inner: hiq_common::MinuteBar) -> Self {
+        Self { inner }
+    }
+}
+
+impl IntoPy<PyObject> for MinuteBar {
+    fn into_py(self, py: Python<'_>) -> PyObject {
+        let dict = PyDict::new(py);
+        dict.set_item("code", self.inner.code).unwrap();
+        dict.set_item("name", self.inner.name).unwrap();
+        dict.set_item("freq", self.inner.freq as i32).unwrap();
+        dict.set_item("start", self.inner.start).unwrap();
+        dict.set_item("end", self.inner.end).unwrap();
+        let bars: Vec<_> = self
+            .inner
+            .bars
+            .unwrap_or_default()
+            .into_iter()
+            .map(|bar| Bar::from(bar).into_py(py))
+            .collect();
+        dict.set_item("bars", bars).unwrap();
+        dict.into()
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct OrderBook {
+    pub(crate) inner: hiq_common::OrderBook,
+}
+
+impl From<hiq_common::OrderBook> for OrderBook {
+    fn from(inner: hiq_common::OrderBook) -> Self {
+        Self { inner }
+    }
+}
+
+fn levels_into_py(py: Python<'_>, levels: Vec<hiq_common::OrderBookLevel>) -> Vec<PyObject> {
+    levels
+        .into_iter()
+        .map(|level| {
+            let dict = PyDict::new(py);
+            dict.set_item("price", level.price).unwrap();
+            dict.set_item("volume", level.volume).unwrap();
+            dict.into()
+        })
+        .collect()
+}
+
+impl IntoPy<PyObject> for OrderBook {
+    fn into_py(self, py: Python<'_>) -> PyObject {
+        let dict = PyDict::new(py);
+        dict.set_item("code", self.inner.code).unwrap();
+        dict.set_item("name", self.inner.name).unwrap();
+        dict.set_item("time", self.inner.time).unwrap();
+        dict.set_item("last", self.inner.last).unwrap();
+        dict.set_item("last_close", self.inner.last_close).unwrap();
+        dict.set_item("bids", levels_into_py(py, self.inner.bids))
+            .unwrap();
+        dict.set_item("asks", levels_into_py(py, self.inner.asks))
+            .unwrap();
+        dict.set_item("limit_up", self.inner.limit_up).unwrap();
+        dict.set_item("limit_down", self.inner.limit_down).unwrap();
+        dict.set_item("volume", self.inner.volume).unwrap();
+        dict.set_item("outer_volume", self.inner.outer_volume)
+            .unwrap();
+        dict.set_item("inner_volume", self.inner.inner_volume)
+            .unwrap();
+        dict.into()
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Tick {
+    pub(crate) inner: hiq_common::Tick,
+}
+
+impl From<hiq_common::Tick> for Tick {
+    fn from(inner: hiq_common::Tick) -> Self {
+        Self { inner }
+    }
+}
+
+impl IntoPy<PyObject> for Tick {
+    fn into_py(self, py: Python<'_>) -> PyObject {
+        let direction = match self.inner.direction {
+            hiq_common::TickDirection::Buy => "buy",
+            hiq_common::TickDirection::Sell => "sell",
+            hiq_common::TickDirection::Neutral => "neutral",
+        };
+        let dict = PyDict::new(py);
+        dict.set_item("code", self.inner.code).unwrap();
+        dict.set_item("trade_date", self.inner.trade_date).unwrap();
+        dict.set_item("time", self.inner.time).unwrap();
+        dict.set_item("price", self.inner.price).unwrap();
+        dict.set_item("volume", self.inner.volume).unwrap();
+        dict.set_item("direction", direction).unwrap();
+        dict.into()
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TimeShare {
+    pub(crate) inner: hiq_common::TimeShare,
+}
+
+impl From<hiq_common::TimeShare> for TimeShare {
+    fn from(inner: hiq_common::TimeShare) -> Self {
+        Self { inner }
+    }
+}
+
+impl IntoPy<PyObject> for TimeShare {
+    fn into_py(self, py: Python<'_>) -> PyObject {
+        let points: Vec<PyObject> = self
+            .inner
+            .points
+            .into_iter()
+            .map(|point| {
+                let dict = PyDict::new(py);
+                dict.set_item("time", point.time).unwrap();
+                dict.set_item("price", point.price).unwrap();
+                dict.set_item("avg_price", point.avg_price).unwrap();
+                dict.set_item("volume", point.volume).unwrap();
+                dict.set_item("amount", point.amount).unwrap();
+                dict.into()
+            })
+            .collect();
+        let dict = PyDict::new(py);
+        dict.set_item("code", self.inner.code).unwrap();
+        dict.set_item("name", self.inner.name).unwrap();
+        dict.set_item("last_close", self.inner.last_close).unwrap();
+        dict.set_item("points", points).unwrap();
+        dict.into()
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AuctionSnapshot {
+    pub(crate) inner: hiq_common::AuctionSnapshot,
+}
+
+impl From<hiq_common::AuctionSnapshot> for AuctionSnapshot {
+    fn from(inner: hiq_common::AuctionSnapshot) -> Self {
+        Self { inner }
+    }
+}
+
+impl IntoPy<PyObject> for AuctionSnapshot {
+    fn into_py(self, py: Python<'_>) -> PyObject {
+        let dict = PyDict::new(py);
+        dict.set_item("code", self.inner.code).unwrap();
+        dict.set_item("trade_date", self.inner.trade_date).unwrap();
+        dict.set_item("time", self.inner.time).unwrap();
+        dict.set_item("price", self.inner.price).unwrap();
+        dict.set_item("volume", self.inner.volume).unwrap();
+        dict.set_item("amount", self.inner.amount).unwrap();
+        dict.set_item("unmatched_volume", self.inner.unmatched_volume)
+            .unwrap();
+        dict.into()
+    }
+}
+
+/// python侧以字符串传入，支持的格式同hiq_common::SecurityId::parse
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SecurityId {
+    pub(crate) inner: hiq_common::SecurityId,
+}
+
+impl From<hiq_common::SecurityId> for SecurityId {
+    fn from(inner: hiq_common::SecurityId) -> Self {
+        Self { inner }
+    }
+}
+
+impl From<SecurityId> for hiq_common::SecurityId {
+    fn from(id: SecurityId) -> Self {
+        id.inner
+    }
+}
+
+impl<'source> FromPyObject<'source> for SecurityId {
+    fn extract(ob: &'source PyAny) -> PyResult<Self> {
+        let code: &str = ob.extract()?;
+        let inner = hiq_common::SecurityId::parse(code)
+            .map_err(|e| PyValueError::new_err(e.to_string()))?;
+        Ok(Self { inner })
+    }
+}
+
+impl IntoPy<PyObject> for SecurityId {
+    fn into_py(self, py: Python<'_>) -> PyObject {
+        let asset = match self.inner.asset() {
+            hiq_common::AssetClass::Stock => "stock",
+            hiq_common::AssetClass::Index => "index",
+            hiq_common::AssetClass::Fund => "fund",
+            hiq_common::AssetClass::Bond => "bond",
+        };
+        let dict = PyDict::new(py);
+        dict.set_item("code", self.inner.to_std()).unwrap();
+        dict.set_item("exchange", format!("{:?}", self.inner.exchange()))
+            .unwrap();
+        dict.set_item("symbol", self.inner.code()).unwrap();
+        dict.set_item("asset", asset).unwrap();
+        dict.set_item("east", self.inner.to_east()).unwrap();
+        dict.set_item("xueqiu", self.inner.to_xueqiu()).unwrap();
+        dict.set_item("tencent", self.inner.to_tencent()).unwrap();
+        dict.set_item("suffix", self.inner.to_suffix()).unwrap();
+        dict.into()
+    }
+}
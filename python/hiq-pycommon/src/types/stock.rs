@@ -23,6 +23,10 @@ impl IntoPy<PyObject> for StockInfo {
         dict.set_item("is_margin", self.inner.is_margin).unwrap();
         dict.set_item("listing_date", self.inner.listing_date)
             .unwrap();
+        dict.set_item("board", self.inner.board.name()).unwrap();
+        dict.set_item("currency", format!("{:?}", self.inner.currency))
+            .unwrap();
+        dict.set_item("lot_size", self.inner.lot_size).unwrap();
         dict.into()
     }
 }
@@ -268,6 +272,189 @@ impl IntoPy<PyObject> for StockYJBB {
     }
 }
 
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct StockBalanceSheet {
+    pub(crate) inner: hiq_common::StockBalanceSheet,
+}
+
+impl From<hiq_common::StockBalanceSheet> for StockBalanceSheet {
+    fn from(inner: hiq_common::StockBalanceSheet) -> Self {
+        Self { inner }
+    }
+}
+
+impl IntoPy<PyObject> for StockBalanceSheet {
+    fn into_py(self, py: Python<'_>) -> PyObject {
+        let dict = PyDict::new(py);
+        dict.set_item("code", self.inner.code).unwrap();
+        dict.set_item("name", self.inner.name).unwrap();
+        dict.set_item("report_date", self.inner.report_date)
+            .unwrap();
+        dict.set_item("notice_date", self.inner.notice_date)
+            .unwrap();
+        dict.set_item("monetary_funds", self.inner.monetary_funds)
+            .unwrap();
+        dict.set_item("accounts_rece", self.inner.accounts_rece)
+            .unwrap();
+        dict.set_item("inventory", self.inner.inventory).unwrap();
+        dict.set_item("total_assets", self.inner.total_assets)
+            .unwrap();
+        dict.set_item("accounts_payable", self.inner.accounts_payable)
+            .unwrap();
+        dict.set_item("advance_receivables", self.inner.advance_receivables)
+            .unwrap();
+        dict.set_item("total_liab", self.inner.total_liab).unwrap();
+        dict.set_item("total_equity", self.inner.total_equity)
+            .unwrap();
+        dict.set_item("debt_asset_ratio", self.inner.debt_asset_ratio)
+            .unwrap();
+        dict.into()
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct StockIncome {
+    pub(crate) inner: hiq_common::StockIncome,
+}
+
+impl From<hiq_common::StockIncome> for StockIncome {
+    fn from(inner: hiq_common::StockIncome) -> Self {
+        Self { inner }
+    }
+}
+
+impl IntoPy<PyObject> for StockIncome {
+    fn into_py(self, py: Python<'_>) -> PyObject {
+        let dict = PyDict::new(py);
+        dict.set_item("code", self.inner.code).unwrap();
+        dict.set_item("name", self.inner.name).unwrap();
+        dict.set_item("report_date", self.inner.report_date)
+            .unwrap();
+        dict.set_item("notice_date", self.inner.notice_date)
+            .unwrap();
+        dict.set_item("total_operate_income", self.inner.total_operate_income)
+            .unwrap();
+        dict.set_item("operate_cost", self.inner.operate_cost)
+            .unwrap();
+        dict.set_item("sale_expense", self.inner.sale_expense)
+            .unwrap();
+        dict.set_item("manage_expense", self.inner.manage_expense)
+            .unwrap();
+        dict.set_item("finance_expense", self.inner.finance_expense)
+            .unwrap();
+        dict.set_item("total_operate_cost", self.inner.total_operate_cost)
+            .unwrap();
+        dict.set_item("operate_profit", self.inner.operate_profit)
+            .unwrap();
+        dict.set_item("total_profit", self.inner.total_profit)
+            .unwrap();
+        dict.set_item("parent_netprofit", self.inner.parent_netprofit)
+            .unwrap();
+        dict.into()
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct StockCashFlow {
+    pub(crate) inner: hiq_common::StockCashFlow,
+}
+
+impl From<hiq_common::StockCashFlow> for StockCashFlow {
+    fn from(inner: hiq_common::StockCashFlow) -> Self {
+        Self { inner }
+    }
+}
+
+impl IntoPy<PyObject> for StockCashFlow {
+    fn into_py(self, py: Python<'_>) -> PyObject {
+        let dict = PyDict::new(py);
+        dict.set_item("code", self.inner.code).unwrap();
+        dict.set_item("name", self.inner.name).unwrap();
+        dict.set_item("report_date", self.inner.report_date)
+            .unwrap();
+        dict.set_item("notice_date", self.inner.notice_date)
+            .unwrap();
+        dict.set_item("netcash_operate", self.inner.netcash_operate)
+            .unwrap();
+        dict.set_item("netcash_invest", self.inner.netcash_invest)
+            .unwrap();
+        dict.set_item("netcash_finance", self.inner.netcash_finance)
+            .unwrap();
+        dict.set_item("construct_long_asset", self.inner.construct_long_asset)
+            .unwrap();
+        dict.set_item("cce_add", self.inner.cce_add).unwrap();
+        dict.into()
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct StockHolderNum {
+    pub(crate) inner: hiq_common::StockHolderNum,
+}
+
+impl From<hiq_common::StockHolderNum> for StockHolderNum {
+    fn from(inner: hiq_common::StockHolderNum) -> Self {
+        Self { inner }
+    }
+}
+
+impl IntoPy<PyObject> for StockHolderNum {
+    fn into_py(self, py: Python<'_>) -> PyObject {
+        let dict = PyDict::new(py);
+        dict.set_item("code", self.inner.code).unwrap();
+        dict.set_item("name", self.inner.name).unwrap();
+        dict.set_item("report_date", self.inner.report_date)
+            .unwrap();
+        dict.set_item("notice_date", self.inner.notice_date)
+            .unwrap();
+        dict.set_item("holder_num", self.inner.holder_num).unwrap();
+        dict.set_item("pre_holder_num", self.inner.pre_holder_num)
+            .unwrap();
+        dict.set_item("holder_num_change", self.inner.holder_num_change)
+            .unwrap();
+        dict.set_item("holder_num_ratio", self.inner.holder_num_ratio)
+            .unwrap();
+        dict.set_item("avg_free_shares", self.inner.avg_free_shares)
+            .unwrap();
+        dict.into()
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct StockTopHolder {
+    pub(crate) inner: hiq_common::StockTopHolder,
+}
+
+impl From<hiq_common::StockTopHolder> for StockTopHolder {
+    fn from(inner: hiq_common::StockTopHolder) -> Self {
+        Self { inner }
+    }
+}
+
+impl IntoPy<PyObject> for StockTopHolder {
+    fn into_py(self, py: Python<'_>) -> PyObject {
+        let dict = PyDict::new(py);
+        dict.set_item("code", self.inner.code).unwrap();
+        dict.set_item("report_date", self.inner.report_date)
+            .unwrap();
+        dict.set_item("notice_date", self.inner.notice_date)
+            .unwrap();
+        dict.set_item("is_float", self.inner.is_float).unwrap();
+        dict.set_item("rank", self.inner.rank).unwrap();
+        dict.set_item("holder_name", self.inner.holder_name)
+            .unwrap();
+        dict.set_item("holder_type", self.inner.holder_type)
+            .unwrap();
+        dict.set_item("hold_num", self.inner.hold_num).unwrap();
+        dict.set_item("hold_ratio", self.inner.hold_ratio).unwrap();
+        dict.set_item("hold_change", self.inner.hold_change)
+            .unwrap();
+        dict.set_item("change_type", self.inner.change_type)
+            .unwrap();
+        dict.into()
+    }
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct StockMargin {
     pub(crate) inner: hiq_common::StockMargin,
@@ -304,6 +491,248 @@ impl IntoPy<PyObject> for StockMargin {
     }
 }
 
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct IndexConstituent {
+    pub(crate) inner: hiq_common::IndexConstituent,
+}
+
+impl From<hiq_common::IndexConstituent> for IndexConstituent {
+    fn from(inner: hiq_common::IndexConstituent) -> Self {
+        Self { inner }
+    }
+}
+
+impl IntoPy<PyObject> for IndexConstituent {
+    fn into_py(self, py: Python<'_>) -> PyObject {
+        let dict = PyDict::new(py);
+        dict.set_item("index_code", self.inner.index_code).unwrap();
+        dict.set_item("index_name", self.inner.index_name).unwrap();
+        dict.set_item("code", self.inner.code).unwrap();
+        dict.set_item("name", self.inner.name).unwrap();
+        dict.set_item("weight", self.inner.weight).unwrap();
+        dict.set_item("trade_date", self.inner.trade_date).unwrap();
+        dict.into()
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct StockCorpAction {
+    pub(crate) inner: hiq_common::StockCorpAction,
+}
+
+impl From<hiq_common::StockCorpAction> for StockCorpAction {
+    fn from(inner: hiq_common::StockCorpAction) -> Self {
+        Self { inner }
+    }
+}
+
+impl IntoPy<PyObject> for StockCorpAction {
+    fn into_py(self, py: Python<'_>) -> PyObject {
+        let dict = PyDict::new(py);
+        dict.set_item("code", self.inner.code).unwrap();
+        dict.set_item("name", self.inner.name).unwrap();
+        dict.set_item("notice_date", self.inner.notice_date)
+            .unwrap();
+        dict.set_item("ex_date", self.inner.ex_date).unwrap();
+        dict.set_item("cash", self.inner.cash).unwrap();
+        dict.set_item("bonus", self.inner.bonus).unwrap();
+        dict.set_item("transfer", self.inner.transfer).unwrap();
+        dict.set_item("rights", self.inner.rights).unwrap();
+        dict.set_item("rights_price", self.inner.rights_price)
+            .unwrap();
+        dict.into()
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct StockNorthHold {
+    pub(crate) inner: hiq_common::StockNorthHold,
+}
+
+impl From<hiq_common::StockNorthHold> for StockNorthHold {
+    fn from(inner: hiq_common::StockNorthHold) -> Self {
+        Self { inner }
+    }
+}
+
+impl IntoPy<PyObject> for StockNorthHold {
+    fn into_py(self, py: Python<'_>) -> PyObject {
+        let dict = PyDict::new(py);
+        dict.set_item("code", self.inner.code).unwrap();
+        dict.set_item("name", self.inner.name).unwrap();
+        dict.set_item("trade_date", self.inner.trade_date).unwrap();
+        dict.set_item("hold_shares", self.inner.hold_shares)
+            .unwrap();
+        dict.set_item("hold_market_cap", self.inner.hold_market_cap)
+            .unwrap();
+        dict.set_item("hold_ratio", self.inner.hold_ratio).unwrap();
+        dict.set_item("hold_shares_change", self.inner.hold_shares_change)
+            .unwrap();
+        dict.into()
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct NorthFlow {
+    pub(crate) inner: hiq_common::NorthFlow,
+}
+
+impl From<hiq_common::NorthFlow> for NorthFlow {
+    fn from(inner: hiq_common::NorthFlow) -> Self {
+        Self { inner }
+    }
+}
+
+impl IntoPy<PyObject> for NorthFlow {
+    fn into_py(self, py: Python<'_>) -> PyObject {
+        let dict = PyDict::new(py);
+        dict.set_item("exchange", format!("{:?}", self.inner.exchange))
+            .unwrap();
+        dict.set_item("trade_date", self.inner.trade_date).unwrap();
+        dict.set_item("buy_amount", self.inner.buy_amount).unwrap();
+        dict.set_item("sell_amount", self.inner.sell_amount)
+            .unwrap();
+        dict.set_item("net_buy", self.inner.net_buy).unwrap();
+        dict.into()
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct StockDragonTiger {
+    pub(crate) inner: hiq_common::StockDragonTiger,
+}
+
+impl From<hiq_common::StockDragonTiger> for StockDragonTiger {
+    fn from(inner: hiq_common::StockDragonTiger) -> Self {
+        Self { inner }
+    }
+}
+
+fn seats_into_py(py: Python<'_>, seats: Vec<hiq_common::DragonTigerSeat>) -> Vec<PyObject> {
+    seats
+        .into_iter()
+        .map(|seat| {
+            let dict = PyDict::new(py);
+            dict.set_item("name", seat.name).unwrap();
+            dict.set_item("buy_amount", seat.buy_amount).unwrap();
+            dict.set_item("sell_amount", seat.sell_amount).unwrap();
+            dict.set_item("net_buy", seat.net_buy).unwrap();
+            dict.into()
+        })
+        .collect()
+}
+
+impl IntoPy<PyObject> for StockDragonTiger {
+    fn into_py(self, py: Python<'_>) -> PyObject {
+        let dict = PyDict::new(py);
+        dict.set_item("code", self.inner.code).unwrap();
+        dict.set_item("name", self.inner.name).unwrap();
+        dict.set_item("trade_date", self.inner.trade_date).unwrap();
+        dict.set_item("reason", self.inner.reason).unwrap();
+        dict.set_item("close", self.inner.close).unwrap();
+        dict.set_item("chg_pct", self.inner.chg_pct).unwrap();
+        dict.set_item("turnover", self.inner.turnover).unwrap();
+        dict.set_item("amount", self.inner.amount).unwrap();
+        dict.set_item("buy_amount", self.inner.buy_amount).unwrap();
+        dict.set_item("sell_amount", self.inner.sell_amount)
+            .unwrap();
+        dict.set_item("net_buy", self.inner.net_buy).unwrap();
+        dict.set_item("buy_seats", seats_into_py(py, self.inner.buy_seats))
+            .unwrap();
+        dict.set_item("sell_seats", seats_into_py(py, self.inner.sell_seats))
+            .unwrap();
+        dict.into()
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct StockLimitPool {
+    pub(crate) inner: hiq_common::StockLimitPool,
+}
+
+impl From<hiq_common::StockLimitPool> for StockLimitPool {
+    fn from(inner: hiq_common::StockLimitPool) -> Self {
+        Self { inner }
+    }
+}
+
+impl IntoPy<PyObject> for StockLimitPool {
+    fn into_py(self, py: Python<'_>) -> PyObject {
+        let dict = PyDict::new(py);
+        dict.set_item("code", self.inner.code).unwrap();
+        dict.set_item("name", self.inner.name).unwrap();
+        dict.set_item("trade_date", self.inner.trade_date).unwrap();
+        dict.set_item("pool", format!("{:?}", self.inner.pool))
+            .unwrap();
+        dict.set_item("close", self.inner.close).unwrap();
+        dict.set_item("chg_pct", self.inner.chg_pct).unwrap();
+        dict.set_item("amount", self.inner.amount).unwrap();
+        dict.set_item("turnover", self.inner.turnover).unwrap();
+        dict.set_item("first_limit_time", self.inner.first_limit_time)
+            .unwrap();
+        dict.set_item("last_limit_time", self.inner.last_limit_time)
+            .unwrap();
+        dict.set_item("limit_days", self.inner.limit_days).unwrap();
+        dict.set_item("open_times", self.inner.open_times).unwrap();
+        dict.set_item("sealed_amount", self.inner.sealed_amount)
+            .unwrap();
+        dict.set_item("industry", self.inner.industry).unwrap();
+        dict.into()
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct StockStatus {
+    pub(crate) inner: hiq_common::StockStatus,
+}
+
+impl From<hiq_common::StockStatus> for StockStatus {
+    fn from(inner: hiq_common::StockStatus) -> Self {
+        Self { inner }
+    }
+}
+
+impl IntoPy<PyObject> for StockStatus {
+    fn into_py(self, py: Python<'_>) -> PyObject {
+        let dict = PyDict::new(py);
+        dict.set_item("code", self.inner.code).unwrap();
+        dict.set_item("name", self.inner.name).unwrap();
+        dict.set_item("status", format!("{:?}", self.inner.status))
+            .unwrap();
+        dict.set_item("start_date", self.inner.start_date).unwrap();
+        dict.set_item("end_date", self.inner.end_date).unwrap();
+        dict.set_item("reason", self.inner.reason).unwrap();
+        dict.set_item("listing_date", self.inner.listing_date)
+            .unwrap();
+        dict.into()
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct StockStatusNotice {
+    pub(crate) inner: hiq_common::StockStatusNotice,
+}
+
+impl From<hiq_common::StockStatusNotice> for StockStatusNotice {
+    fn from(inner: hiq_common::StockStatusNotice) -> Self {
+        Self { inner }
+    }
+}
+
+impl IntoPy<PyObject> for StockStatusNotice {
+    fn into_py(self, py: Python<'_>) -> PyObject {
+        let dict = PyDict::new(py);
+        dict.set_item("code", self.inner.code).unwrap();
+        dict.set_item("name", self.inner.name).unwrap();
+        dict.set_item("notice_date", self.inner.notice_date)
+            .unwrap();
+        dict.set_item("status", self.inner.status.map(|s| format!("{:?}", s)))
+            .unwrap();
+        dict.set_item("title", self.inner.title).unwrap();
+        dict.into()
+    }
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct StockRtQuot {
     pub(crate) inner: hiq_common::StockRtQuot,
@@ -335,6 +764,8 @@ impl IntoPy<PyObject> for StockRtQuot {
         dict.set_item("currency_value", self.inner.currency_value)
             .unwrap();
         dict.set_item("is_trading", self.inner.is_trading).unwrap();
+        dict.set_item("currency", format!("{:?}", self.inner.currency))
+            .unwrap();
         dict.into()
     }
 }
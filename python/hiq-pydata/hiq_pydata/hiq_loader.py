@@ -39,7 +39,7 @@ class HiqLoader(ABC):
         self, *,
         filter: Optional[Dict] = {},
         sort: Optional[Dict] = {},
-        limit: Optional[int] = None, to_frame=True
+        limit: Optional[int] = None, adjust: int = 0, to_frame=True
     ) -> Union[List[Dict], pd.DataFrame]:
         pass
 
@@ -55,7 +55,7 @@ class HiqLoader(ABC):
         self, *,
         filter: Optional[Dict] = {},
         sort: Optional[Dict] = {},
-        limit: Optional[int] = None, to_frame=True
+        limit: Optional[int] = None, adjust: int = 0, to_frame=True
     ) -> Union[List[Dict], pd.DataFrame]:
         pass
 
@@ -79,7 +79,7 @@ class HiqLoader(ABC):
         self, *,
         filter: Optional[Dict] = {},
         sort: Optional[Dict] = {},
-        limit: Optional[int] = None, to_frame=True
+        limit: Optional[int] = None, adjust: int = 0, to_frame=True
     ) -> Union[List[Dict], pd.DataFrame]:
         pass
 
@@ -95,7 +95,7 @@ class HiqLoader(ABC):
         self, *,
         filter: Optional[Dict] = {},
         sort: Optional[Dict] = {},
-        limit: Optional[int] = None, to_frame=True
+        limit: Optional[int] = None, adjust: int = 0, to_frame=True
     ) -> Union[List[Dict], pd.DataFrame]:
         pass
 
@@ -119,7 +119,7 @@ class HiqLoader(ABC):
         self, *,
         filter: Optional[Dict] = {},
         sort: Optional[Dict] = {},
-        limit: Optional[int] = None, to_frame=True
+        limit: Optional[int] = None, adjust: int = 0, to_frame=True
     ) -> Union[List[Dict], pd.DataFrame]:
         pass
 
@@ -143,7 +143,7 @@ class HiqLoader(ABC):
         self, *,
         filter: Optional[Dict] = {},
         sort: Optional[Dict] = {},
-        limit: Optional[int] = None, to_frame=True
+        limit: Optional[int] = None, adjust: int = 0, to_frame=True
     ) -> Union[List[Dict], pd.DataFrame]:
         pass
 
@@ -171,6 +171,134 @@ class HiqLoader(ABC):
     ) -> Union[List[Dict], pd.DataFrame]:
         pass
 
+    async def load_stock_corp_action(
+        self, *,
+        filter: Optional[Dict] = {},
+        sort: Optional[Dict] = {},
+        limit: Optional[int] = None, to_frame=True
+    ) -> Union[List[Dict], pd.DataFrame]:
+        pass
+
+    async def load_stock_tick(
+        self, *,
+        filter: Optional[Dict] = {},
+        sort: Optional[Dict] = {},
+        limit: Optional[int] = None, to_frame=True
+    ) -> Union[List[Dict], pd.DataFrame]:
+        pass
+
+    async def load_stock_auction(
+        self, *,
+        filter: Optional[Dict] = {},
+        sort: Optional[Dict] = {},
+        limit: Optional[int] = None, to_frame=True
+    ) -> Union[List[Dict], pd.DataFrame]:
+        pass
+
+    async def load_overseas_stock_info(
+        self, *,
+        filter: Optional[Dict] = {},
+        sort: Optional[Dict] = {},
+        limit: Optional[int] = None, to_frame=True
+    ) -> Union[List[Dict], pd.DataFrame]:
+        pass
+
+    async def load_overseas_stock_daily(
+        self, *,
+        filter: Optional[Dict] = {},
+        sort: Optional[Dict] = {},
+        limit: Optional[int] = None, adjust: int = 0, to_frame=True
+    ) -> Union[List[Dict], pd.DataFrame]:
+        pass
+
+    async def load_index_constituent(
+        self, *,
+        filter: Optional[Dict] = {},
+        sort: Optional[Dict] = {},
+        limit: Optional[int] = None, to_frame=True
+    ) -> Union[List[Dict], pd.DataFrame]:
+        pass
+
+    async def load_stock_balance_sheet(
+        self, *,
+        filter: Optional[Dict] = {},
+        sort: Optional[Dict] = {},
+        limit: Optional[int] = None, to_frame=True
+    ) -> Union[List[Dict], pd.DataFrame]:
+        pass
+
+    async def load_stock_income(
+        self, *,
+        filter: Optional[Dict] = {},
+        sort: Optional[Dict] = {},
+        limit: Optional[int] = None, to_frame=True
+    ) -> Union[List[Dict], pd.DataFrame]:
+        pass
+
+    async def load_stock_cash_flow(
+        self, *,
+        filter: Optional[Dict] = {},
+        sort: Optional[Dict] = {},
+        limit: Optional[int] = None, to_frame=True
+    ) -> Union[List[Dict], pd.DataFrame]:
+        pass
+
+    async def load_stock_holder_num(
+        self, *,
+        filter: Optional[Dict] = {},
+        sort: Optional[Dict] = {},
+        limit: Optional[int] = None, to_frame=True
+    ) -> Union[List[Dict], pd.DataFrame]:
+        pass
+
+    async def load_stock_top_holder(
+        self, *,
+        filter: Optional[Dict] = {},
+        sort: Optional[Dict] = {},
+        limit: Optional[int] = None, to_frame=True
+    ) -> Union[List[Dict], pd.DataFrame]:
+        pass
+
+    async def load_stock_north_hold(
+        self, *,
+        filter: Optional[Dict] = {},
+        sort: Optional[Dict] = {},
+        limit: Optional[int] = None, to_frame=True
+    ) -> Union[List[Dict], pd.DataFrame]:
+        pass
+
+    async def load_north_flow(
+        self, *,
+        filter: Optional[Dict] = {},
+        sort: Optional[Dict] = {},
+        limit: Optional[int] = None, to_frame=True
+    ) -> Union[List[Dict], pd.DataFrame]:
+        pass
+
+    async def load_stock_dragon_tiger(
+        self, *,
+        filter: Optional[Dict] = {},
+        sort: Optional[Dict] = {},
+        limit: Optional[int] = None, to_frame=True
+    ) -> Union[List[Dict], pd.DataFrame]:
+        pass
+
+    async def load_stock_limit_pool(
+        self, *,
+        filter: Optional[Dict] = {},
+        sort: Optional[Dict] = {},
+        limit: Optional[int] = None, to_frame=True
+    ) -> Union[List[Dict], pd.DataFrame]:
+        pass
+
+    async def load_stock_status(
+        self, *,
+        filter: Optional[Dict] = {},
+        sort: Optional[Dict] = {},
+        limit: Optional[int] = None, to_frame=True
+    ) -> Union[List[Dict], pd.DataFrame]:
+        pass
+
 
 class BlockHiqLoader(ABC):
     def __init__(self, url: str):
@@ -192,7 +320,7 @@ class BlockHiqLoader(ABC):
         self, *,
         filter: Optional[Dict] = {},
         sort: Optional[Dict] = {},
-        limit: Optional[int] = None, to_frame=True
+        limit: Optional[int] = None, adjust: int = 0, to_frame=True
     ) -> Union[List[Dict], pd.DataFrame]:
         pass
 
@@ -208,7 +336,7 @@ class BlockHiqLoader(ABC):
         self, *,
         filter: Optional[Dict] = {},
         sort: Optional[Dict] = {},
-        limit: Optional[int] = None, to_frame=True
+        limit: Optional[int] = None, adjust: int = 0, to_frame=True
     ) -> Union[List[Dict], pd.DataFrame]:
         pass
 
@@ -232,7 +360,7 @@ class BlockHiqLoader(ABC):
         self, *,
         filter: Optional[Dict] = {},
         sort: Optional[Dict] = {},
-        limit: Optional[int] = None, to_frame=True
+        limit: Optional[int] = None, adjust: int = 0, to_frame=True
     ) -> Union[List[Dict], pd.DataFrame]:
         pass
 
@@ -248,7 +376,7 @@ class BlockHiqLoader(ABC):
         self, *,
         filter: Optional[Dict] = {},
         sort: Optional[Dict] = {},
-        limit: Optional[int] = None, to_frame=True
+        limit: Optional[int] = None, adjust: int = 0, to_frame=True
     ) -> Union[List[Dict], pd.DataFrame]:
         pass
 
@@ -272,7 +400,7 @@ class BlockHiqLoader(ABC):
         self, *,
         filter: Optional[Dict] = {},
         sort: Optional[Dict] = {},
-        limit: Optional[int] = None, to_frame=True
+        limit: Optional[int] = None, adjust: int = 0, to_frame=True
     ) -> Union[List[Dict], pd.DataFrame]:
         pass
 
@@ -296,7 +424,7 @@ class BlockHiqLoader(ABC):
         self, *,
         filter: Optional[Dict] = {},
         sort: Optional[Dict] = {},
-        limit: Optional[int] = None, to_frame=True
+        limit: Optional[int] = None, adjust: int = 0, to_frame=True
     ) -> Union[List[Dict], pd.DataFrame]:
         pass
 
@@ -323,3 +451,131 @@ class BlockHiqLoader(ABC):
         limit: Optional[int] = None, to_frame=True
     ) -> Union[List[Dict], pd.DataFrame]:
         pass
+
+    def load_stock_corp_action(
+        self, *,
+        filter: Optional[Dict] = {},
+        sort: Optional[Dict] = {},
+        limit: Optional[int] = None, to_frame=True
+    ) -> Union[List[Dict], pd.DataFrame]:
+        pass
+
+    def load_stock_tick(
+        self, *,
+        filter: Optional[Dict] = {},
+        sort: Optional[Dict] = {},
+        limit: Optional[int] = None, to_frame=True
+    ) -> Union[List[Dict], pd.DataFrame]:
+        pass
+
+    def load_stock_auction(
+        self, *,
+        filter: Optional[Dict] = {},
+        sort: Optional[Dict] = {},
+        limit: Optional[int] = None, to_frame=True
+    ) -> Union[List[Dict], pd.DataFrame]:
+        pass
+
+    def load_overseas_stock_info(
+        self, *,
+        filter: Optional[Dict] = {},
+        sort: Optional[Dict] = {},
+        limit: Optional[int] = None, to_frame=True
+    ) -> Union[List[Dict], pd.DataFrame]:
+        pass
+
+    def load_overseas_stock_daily(
+        self, *,
+        filter: Optional[Dict] = {},
+        sort: Optional[Dict] = {},
+        limit: Optional[int] = None, adjust: int = 0, to_frame=True
+    ) -> Union[List[Dict], pd.DataFrame]:
+        pass
+
+    def load_index_constituent(
+        self, *,
+        filter: Optional[Dict] = {},
+        sort: Optional[Dict] = {},
+        limit: Optional[int] = None, to_frame=True
+    ) -> Union[List[Dict], pd.DataFrame]:
+        pass
+
+    def load_stock_balance_sheet(
+        self, *,
+        filter: Optional[Dict] = {},
+        sort: Optional[Dict] = {},
+        limit: Optional[int] = None, to_frame=True
+    ) -> Union[List[Dict], pd.DataFrame]:
+        pass
+
+    def load_stock_income(
+        self, *,
+        filter: Optional[Dict] = {},
+        sort: Optional[Dict] = {},
+        limit: Optional[int] = None, to_frame=True
+    ) -> Union[List[Dict], pd.DataFrame]:
+        pass
+
+    def load_stock_cash_flow(
+        self, *,
+        filter: Optional[Dict] = {},
+        sort: Optional[Dict] = {},
+        limit: Optional[int] = None, to_frame=True
+    ) -> Union[List[Dict], pd.DataFrame]:
+        pass
+
+    def load_stock_holder_num(
+        self, *,
+        filter: Optional[Dict] = {},
+        sort: Optional[Dict] = {},
+        limit: Optional[int] = None, to_frame=True
+    ) -> Union[List[Dict], pd.DataFrame]:
+        pass
+
+    def load_stock_top_holder(
+        self, *,
+        filter: Optional[Dict] = {},
+        sort: Optional[Dict] = {},
+        limit: Optional[int] = None, to_frame=True
+    ) -> Union[List[Dict], pd.DataFrame]:
+        pass
+
+    def load_stock_north_hold(
+        self, *,
+        filter: Optional[Dict] = {},
+        sort: Optional[Dict] = {},
+        limit: Optional[int] = None, to_frame=True
+    ) -> Union[List[Dict], pd.DataFrame]:
+        pass
+
+    def load_north_flow(
+        self, *,
+        filter: Optional[Dict] = {},
+        sort: Optional[Dict] = {},
+        limit: Optional[int] = None, to_frame=True
+    ) -> Union[List[Dict], pd.DataFrame]:
+        pass
+
+    def load_stock_dragon_tiger(
+        self, *,
+        filter: Optional[Dict] = {},
+        sort: Optional[Dict] = {},
+        limit: Optional[int] = None, to_frame=True
+    ) -> Union[List[Dict], pd.DataFrame]:
+        pass
+
+    def load_stock_limit_pool(
+        self, *,
+        filter: Optional[Dict] = {},
+        sort: Optional[Dict] = {},
+        limit: Optional[int] = None, to_frame=True
+    ) -> Union[List[Dict], pd.DataFrame]:
+        pass
+
+    def load_stock_status(
+        self, *,
+        filter: Optional[Dict] = {},
+        sort: Optional[Dict] = {},
+        limit: Optional[int] = None, to_frame=True
+    ) -> Union[List[Dict], pd.DataFrame]:
+        pass
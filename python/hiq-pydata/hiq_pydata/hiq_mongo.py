@@ -26,13 +26,13 @@ class HiqMongoLoader(HiqLoader):
         self, *,
         filter: Optional[Dict] = {},
         sort: Optional[Dict] = {},
-        limit: Optional[int] = None, to_frame=True
+        limit: Optional[int] = None, adjust: int = 0, to_frame=True
     ) -> Union[List[Dict], pd.DataFrame]:
 
         filter, sort = json.dumps(
             filter, default=self.json_def_handler), json.dumps(sort, default=self.json_def_handler)
         return self.to_dataframe(to_frame,
-                                  await self.loader.load_bond_daily(filter=filter, sort=sort, limit=limit))
+                                  await self.loader.load_bond_daily(filter=filter, sort=sort, limit=limit, adjust=adjust))
 
     async def load_fund_info(
         self, *,
@@ -50,13 +50,13 @@ class HiqMongoLoader(HiqLoader):
         self, *,
         filter: Optional[Dict] = {},
         sort: Optional[Dict] = {},
-        limit: Optional[int] = None, to_frame=True
+        limit: Optional[int] = None, adjust: int = 0, to_frame=True
     ) -> Union[List[Dict], pd.DataFrame]:
 
         filter, sort = json.dumps(
             filter, default=self.json_def_handler), json.dumps(sort, default=self.json_def_handler)
         return self.to_dataframe(to_frame,
-                                  await self.loader.load_fund_daily(filter=filter, sort=sort, limit=limit))
+                                  await self.loader.load_fund_daily(filter=filter, sort=sort, limit=limit, adjust=adjust))
 
     async def load_fund_net(
         self, *,
@@ -86,13 +86,13 @@ class HiqMongoLoader(HiqLoader):
         self, *,
         filter: Optional[Dict] = {},
         sort: Optional[Dict] = {},
-        limit: Optional[int] = None, to_frame=True
+        limit: Optional[int] = None, adjust: int = 0, to_frame=True
     ) -> Union[List[Dict], pd.DataFrame]:
 
         filter, sort = json.dumps(
             filter, default=self.json_def_handler), json.dumps(sort, default=self.json_def_handler)
         return self.to_dataframe(to_frame,
-                                  await self.loader.load_index_daily(filter=filter, sort=sort, limit=limit))
+                                  await self.loader.load_index_daily(filter=filter, sort=sort, limit=limit, adjust=adjust))
 
     async def load_stock_info(
         self, *,
@@ -110,13 +110,13 @@ class HiqMongoLoader(HiqLoader):
         self, *,
         filter: Optional[Dict] = {},
         sort: Optional[Dict] = {},
-        limit: Optional[int] = None, to_frame=True
+        limit: Optional[int] = None, adjust: int = 0, to_frame=True
     ) -> Union[List[Dict], pd.DataFrame]:
 
         filter, sort = json.dumps(
             filter, default=self.json_def_handler), json.dumps(sort, default=self.json_def_handler)
         return self.to_dataframe(to_frame,
-                                  await self.loader.load_stock_daily(filter=filter, sort=sort, limit=limit))
+                                  await self.loader.load_stock_daily(filter=filter, sort=sort, limit=limit, adjust=adjust))
 
     async def load_stock_index(
         self, *,
@@ -146,13 +146,13 @@ class HiqMongoLoader(HiqLoader):
         self, *,
         filter: Optional[Dict] = {},
         sort: Optional[Dict] = {},
-        limit: Optional[int] = None, to_frame=True
+        limit: Optional[int] = None, adjust: int = 0, to_frame=True
     ) -> Union[List[Dict], pd.DataFrame]:
 
         filter, sort = json.dumps(
             filter, default=self.json_def_handler), json.dumps(sort, default=self.json_def_handler)
         return self.to_dataframe(to_frame,
-                                  await self.loader.load_stock_industry_daily(filter=filter, sort=sort, limit=limit))
+                                  await self.loader.load_stock_industry_daily(filter=filter, sort=sort, limit=limit, adjust=adjust))
 
     async def load_stock_industry_detail(
         self, *,
@@ -182,13 +182,13 @@ class HiqMongoLoader(HiqLoader):
         self, *,
         filter: Optional[Dict] = {},
         sort: Optional[Dict] = {},
-        limit: Optional[int] = None, to_frame=True
+        limit: Optional[int] = None, adjust: int = 0, to_frame=True
     ) -> Union[List[Dict], pd.DataFrame]:
 
         filter, sort = json.dumps(
             filter, default=self.json_def_handler), json.dumps(sort, default=self.json_def_handler)
         return self.to_dataframe(to_frame,
-                                  await self.loader.load_stock_concept_daily(filter=filter, sort=sort, limit=limit))
+                                  await self.loader.load_stock_concept_daily(filter=filter, sort=sort, limit=limit, adjust=adjust))
 
     async def load_stock_concept_detail(
         self, *,
@@ -226,6 +226,198 @@ class HiqMongoLoader(HiqLoader):
         return self.to_dataframe(to_frame,
                                   await self.loader.load_stock_margin(filter=filter, sort=sort, limit=limit))
 
+    async def load_stock_corp_action(
+        self, *,
+        filter: Optional[Dict] = {},
+        sort: Optional[Dict] = {},
+        limit: Optional[int] = None, to_frame=True
+    ) -> Union[List[Dict], pd.DataFrame]:
+
+        filter, sort = json.dumps(
+            filter, default=self.json_def_handler), json.dumps(sort, default=self.json_def_handler)
+        return self.to_dataframe(to_frame,
+                                  await self.loader.load_stock_corp_action(filter=filter, sort=sort, limit=limit))
+
+    async def load_stock_tick(
+        self, *,
+        filter: Optional[Dict] = {},
+        sort: Optional[Dict] = {},
+        limit: Optional[int] = None, to_frame=True
+    ) -> Union[List[Dict], pd.DataFrame]:
+
+        filter, sort = json.dumps(
+            filter, default=self.json_def_handler), json.dumps(sort, default=self.json_def_handler)
+        return self.to_dataframe(to_frame,
+                                  await self.loader.load_stock_tick(filter=filter, sort=sort, limit=limit))
+
+    async def load_stock_auction(
+        self, *,
+        filter: Optional[Dict] = {},
+        sort: Optional[Dict] = {},
+        limit: Optional[int] = None, to_frame=True
+    ) -> Union[List[Dict], pd.DataFrame]:
+
+        filter, sort = json.dumps(
+            filter, default=self.json_def_handler), json.dumps(sort, default=self.json_def_handler)
+        return self.to_dataframe(to_frame,
+                                  await self.loader.load_stock_auction(filter=filter, sort=sort, limit=limit))
+
+    async def load_overseas_stock_info(
+        self, *,
+        filter: Optional[Dict] = {},
+        sort: Optional[Dict] = {},
+        limit: Optional[int] = None, to_frame=True
+    ) -> Union[List[Dict], pd.DataFrame]:
+
+        filter, sort = json.dumps(
+            filter, default=self.json_def_handler), json.dumps(sort, default=self.json_def_handler)
+        return self.to_dataframe(to_frame,
+                                  await self.loader.load_overseas_stock_info(filter=filter, sort=sort, limit=limit))
+
+    async def load_overseas_stock_daily(
+        self, *,
+        filter: Optional[Dict] = {},
+        sort: Optional[Dict] = {},
+        limit: Optional[int] = None, adjust: int = 0, to_frame=True
+    ) -> Union[List[Dict], pd.DataFrame]:
+
+        filter, sort = json.dumps(
+            filter, default=self.json_def_handler), json.dumps(sort, default=self.json_def_handler)
+        return self.to_dataframe(to_frame,
+                                  await self.loader.load_overseas_stock_daily(filter=filter, sort=sort, limit=limit, adjust=adjust))
+
+    async def load_index_constituent(
+        self, *,
+        filter: Optional[Dict] = {},
+        sort: Optional[Dict] = {},
+        limit: Optional[int] = None, to_frame=True
+    ) -> Union[List[Dict], pd.DataFrame]:
+
+        filter, sort = json.dumps(
+            filter, default=self.json_def_handler), json.dumps(sort, default=self.json_def_handler)
+        return self.to_dataframe(to_frame,
+                                  await self.loader.load_index_constituent(filter=filter, sort=sort, limit=limit))
+
+    async def load_stock_balance_sheet(
+        self, *,
+        filter: Optional[Dict] = {},
+        sort: Optional[Dict] = {},
+        limit: Optional[int] = None, to_frame=True
+    ) -> Union[List[Dict], pd.DataFrame]:
+
+        filter, sort = json.dumps(
+            filter, default=self.json_def_handler), json.dumps(sort, default=self.json_def_handler)
+        return self.to_dataframe(to_frame,
+                                  await self.loader.load_stock_balance_sheet(filter=filter, sort=sort, limit=limit))
+
+    async def load_stock_income(
+        self, *,
+        filter: Optional[Dict] = {},
+        sort: Optional[Dict] = {},
+        limit: Optional[int] = None, to_frame=True
+    ) -> Union[List[Dict], pd.DataFrame]:
+
+        filter, sort = json.dumps(
+            filter, default=self.json_def_handler), json.dumps(sort, default=self.json_def_handler)
+        return self.to_dataframe(to_frame,
+                                  await self.loader.load_stock_income(filter=filter, sort=sort, limit=limit))
+
+    async def load_stock_cash_flow(
+        self, *,
+        filter: Optional[Dict] = {},
+        sort: Optional[Dict] = {},
+        limit: Optional[int] = None, to_frame=True
+    ) -> Union[List[Dict], pd.DataFrame]:
+
+        filter, sort = json.dumps(
+            filter, default=self.json_def_handler), json.dumps(sort, default=self.json_def_handler)
+        return self.to_dataframe(to_frame,
+                                  await self.loader.load_stock_cash_flow(filter=filter, sort=sort, limit=limit))
+
+    async def load_stock_holder_num(
+        self, *,
+        filter: Optional[Dict] = {},
+        sort: Optional[Dict] = {},
+        limit: Optional[int] = None, to_frame=True
+    ) -> Union[List[Dict], pd.DataFrame]:
+
+        filter, sort = json.dumps(
+            filter, default=self.json_def_handler), json.dumps(sort, default=self.json_def_handler)
+        return self.to_dataframe(to_frame,
+                                  await self.loader.load_stock_holder_num(filter=filter, sort=sort, limit=limit))
+
+    async def load_stock_top_holder(
+        self, *,
+        filter: Optional[Dict] = {},
+        sort: Optional[Dict] = {},
+        limit: Optional[int] = None, to_frame=True
+    ) -> Union[List[Dict], pd.DataFrame]:
+
+        filter, sort = json.dumps(
+            filter, default=self.json_def_handler), json.dumps(sort, default=self.json_def_handler)
+        return self.to_dataframe(to_frame,
+                                  await self.loader.load_stock_top_holder(filter=filter, sort=sort, limit=limit))
+
+    async def load_stock_north_hold(
+        self, *,
+        filter: Optional[Dict] = {},
+        sort: Optional[Dict] = {},
+        limit: Optional[int] = None, to_frame=True
+    ) -> Union[List[Dict], pd.DataFrame]:
+
+        filter, sort = json.dumps(
+            filter, default=self.json_def_handler), json.dumps(sort, default=self.json_def_handler)
+        return self.to_dataframe(to_frame,
+                                  await self.loader.load_stock_north_hold(filter=filter, sort=sort, limit=limit))
+
+    async def load_north_flow(
+        self, *,
+        filter: Optional[Dict] = {},
+        sort: Optional[Dict] = {},
+        limit: Optional[int] = None, to_frame=True
+    ) -> Union[List[Dict], pd.DataFrame]:
+
+        filter, sort = json.dumps(
+            filter, default=self.json_def_handler), json.dumps(sort, default=self.json_def_handler)
+        return self.to_dataframe(to_frame,
+                                  await self.loader.load_north_flow(filter=filter, sort=sort, limit=limit))
+
+    async def load_stock_dragon_tiger(
+        self, *,
+        filter: Optional[Dict] = {},
+        sort: Optional[Dict] = {},
+        limit: Optional[int] = None, to_frame=True
+    ) -> Union[List[Dict], pd.DataFrame]:
+
+        filter, sort = json.dumps(
+            filter, default=self.json_def_handler), json.dumps(sort, default=self.json_def_handler)
+        return self.to_dataframe(to_frame,
+                                  await self.loader.load_stock_dragon_tiger(filter=filter, sort=sort, limit=limit))
+
+    async def load_stock_limit_pool(
+        self, *,
+        filter: Optional[Dict] = {},
+        sort: Optional[Dict] = {},
+        limit: Optional[int] = None, to_frame=True
+    ) -> Union[List[Dict], pd.DataFrame]:
+
+        filter, sort = json.dumps(
+            filter, default=self.json_def_handler), json.dumps(sort, default=self.json_def_handler)
+        return self.to_dataframe(to_frame,
+                                  await self.loader.load_stock_limit_pool(filter=filter, sort=sort, limit=limit))
+
+    async def load_stock_status(
+        self, *,
+        filter: Optional[Dict] = {},
+        sort: Optional[Dict] = {},
+        limit: Optional[int] = None, to_frame=True
+    ) -> Union[List[Dict], pd.DataFrame]:
+
+        filter, sort = json.dumps(
+            filter, default=self.json_def_handler), json.dumps(sort, default=self.json_def_handler)
+        return self.to_dataframe(to_frame,
+                                  await self.loader.load_stock_status(filter=filter, sort=sort, limit=limit))
+
 
 class HiqBlockMongoLoader(BlockHiqLoader):
     def __init__(self, url: str):
@@ -253,13 +445,13 @@ class HiqBlockMongoLoader(BlockHiqLoader):
         self, *,
         filter: Optional[Dict] = {},
         sort: Optional[Dict] = {},
-        limit: Optional[int] = None, to_frame=True
+        limit: Optional[int] = None, adjust: int = 0, to_frame=True
     ) -> Union[List[Dict], pd.DataFrame]:
 
         filter, sort = json.dumps(
             filter, default=self.json_def_handler), json.dumps(sort, default=self.json_def_handler)
         return self.to_dataframe(to_frame,
-                                  self.loader.load_bond_daily(filter=filter, sort=sort, limit=limit))
+                                  self.loader.load_bond_daily(filter=filter, sort=sort, limit=limit, adjust=adjust))
 
     def load_fund_info(
         self, *,
@@ -277,13 +469,13 @@ class HiqBlockMongoLoader(BlockHiqLoader):
         self, *,
         filter: Optional[Dict] = {},
         sort: Optional[Dict] = {},
-        limit: Optional[int] = None, to_frame=True
+        limit: Optional[int] = None, adjust: int = 0, to_frame=True
     ) -> Union[List[Dict], pd.DataFrame]:
 
         filter, sort = json.dumps(
             filter, default=self.json_def_handler), json.dumps(sort, default=self.json_def_handler)
         return self.to_dataframe(to_frame,
-                                  self.loader.load_fund_daily(filter=filter, sort=sort, limit=limit))
+                                  self.loader.load_fund_daily(filter=filter, sort=sort, limit=limit, adjust=adjust))
 
     def load_fund_net(
         self, *,
@@ -313,13 +505,13 @@ class HiqBlockMongoLoader(BlockHiqLoader):
         self, *,
         filter: Optional[Dict] = {},
         sort: Optional[Dict] = {},
-        limit: Optional[int] = None, to_frame=True
+        limit: Optional[int] = None, adjust: int = 0, to_frame=True
     ) -> Union[List[Dict], pd.DataFrame]:
 
         filter, sort = json.dumps(
             filter, default=self.json_def_handler), json.dumps(sort, default=self.json_def_handler)
         return self.to_dataframe(to_frame,
-                                  self.loader.load_index_daily(filter=filter, sort=sort, limit=limit))
+                                  self.loader.load_index_daily(filter=filter, sort=sort, limit=limit, adjust=adjust))
 
     def load_stock_info(
         self, *,
@@ -337,13 +529,13 @@ class HiqBlockMongoLoader(BlockHiqLoader):
         self, *,
         filter: Optional[Dict] = {},
         sort: Optional[Dict] = {},
-        limit: Optional[int] = None, to_frame=True
+        limit: Optional[int] = None, adjust: int = 0, to_frame=True
     ) -> Union[List[Dict], pd.DataFrame]:
 
         filter, sort = json.dumps(
             filter, default=self.json_def_handler), json.dumps(sort, default=self.json_def_handler)
         return self.to_dataframe(to_frame,
-                                  self.loader.load_stock_daily(filter=filter, sort=sort, limit=limit))
+                                  self.loader.load_stock_daily(filter=filter, sort=sort, limit=limit, adjust=adjust))
 
     def load_stock_index(
         self, *,
@@ -373,13 +565,13 @@ class HiqBlockMongoLoader(BlockHiqLoader):
         self, *,
         filter: Optional[Dict] = {},
         sort: Optional[Dict] = {},
-        limit: Optional[int] = None, to_frame=True
+        limit: Optional[int] = None, adjust: int = 0, to_frame=True
     ) -> Union[List[Dict], pd.DataFrame]:
 
         filter, sort = json.dumps(
             filter, default=self.json_def_handler), json.dumps(sort, default=self.json_def_handler)
         return self.to_dataframe(to_frame,
-                                  self.loader.load_stock_industry_daily(filter=filter, sort=sort, limit=limit))
+                                  self.loader.load_stock_industry_daily(filter=filter, sort=sort, limit=limit, adjust=adjust))
 
     def load_stock_industry_detail(
         self, *,
@@ -409,13 +601,13 @@ class HiqBlockMongoLoader(BlockHiqLoader):
         self, *,
         filter: Optional[Dict] = {},
         sort: Optional[Dict] = {},
-        limit: Optional[int] = None, to_frame=True
+        limit: Optional[int] = None, adjust: int = 0, to_frame=True
     ) -> Union[List[Dict], pd.DataFrame]:
 
         filter, sort = json.dumps(
             filter, default=self.json_def_handler), json.dumps(sort, default=self.json_def_handler)
         return self.to_dataframe(to_frame,
-                                  self.loader.load_stock_concept_daily(filter=filter, sort=sort, limit=limit))
+                                  self.loader.load_stock_concept_daily(filter=filter, sort=sort, limit=limit, adjust=adjust))
 
     def load_stock_concept_detail(
         self, *,
@@ -452,3 +644,195 @@ class HiqBlockMongoLoader(BlockHiqLoader):
             filter, default=self.json_def_handler), json.dumps(sort, default=self.json_def_handler)
         return self.to_dataframe(to_frame,
                                   self.loader.load_stock_margin(filter=filter, sort=sort, limit=limit))
+
+    def load_stock_corp_action(
+        self, *,
+        filter: Optional[Dict] = {},
+        sort: Optional[Dict] = {},
+        limit: Optional[int] = None, to_frame=True
+    ) -> Union[List[Dict], pd.DataFrame]:
+
+        filter, sort = json.dumps(
+            filter, default=self.json_def_handler), json.dumps(sort, default=self.json_def_handler)
+        return self.to_dataframe(to_frame,
+                                  self.loader.load_stock_corp_action(filter=filter, sort=sort, limit=limit))
+
+    def load_stock_tick(
+        self, *,
+        filter: Optional[Dict] = {},
+        sort: Optional[Dict] = {},
+        limit: Optional[int] = None, to_frame=True
+    ) -> Union[List[Dict], pd.DataFrame]:
+
+        filter, sort = json.dumps(
+            filter, default=self.json_def_handler), json.dumps(sort, default=self.json_def_handler)
+        return self.to_dataframe(to_frame,
+                                  self.loader.load_stock_tick(filter=filter, sort=sort, limit=limit))
+
+    def load_stock_auction(
+        self, *,
+        filter: Optional[Dict] = {},
+        sort: Optional[Dict] = {},
+        limit: Optional[int] = None, to_frame=True
+    ) -> Union[List[Dict], pd.DataFrame]:
+
+        filter, sort = json.dumps(
+            filter, default=self.json_def_handler), json.dumps(sort, default=self.json_def_handler)
+        return self.to_dataframe(to_frame,
+                                  self.loader.load_stock_auction(filter=filter, sort=sort, limit=limit))
+
+    def load_overseas_stock_info(
+        self, *,
+        filter: Optional[Dict] = {},
+        sort: Optional[Dict] = {},
+        limit: Optional[int] = None, to_frame=True
+    ) -> Union[List[Dict], pd.DataFrame]:
+
+        filter, sort = json.dumps(
+            filter, default=self.json_def_handler), json.dumps(sort, default=self.json_def_handler)
+        return self.to_dataframe(to_frame,
+                                  self.loader.load_overseas_stock_info(filter=filter, sort=sort, limit=limit))
+
+    def load_overseas_stock_daily(
+        self, *,
+        filter: Optional[Dict] = {},
+        sort: Optional[Dict] = {},
+        limit: Optional[int] = None, adjust: int = 0, to_frame=True
+    ) -> Union[List[Dict], pd.DataFrame]:
+
+        filter, sort = json.dumps(
+            filter, default=self.json_def_handler), json.dumps(sort, default=self.json_def_handler)
+        return self.to_dataframe(to_frame,
+                                  self.loader.load_overseas_stock_daily(filter=filter, sort=sort, limit=limit, adjust=adjust))
+
+    def load_index_constituent(
+        self, *,
+        filter: Optional[Dict] = {},
+        sort: Optional[Dict] = {},
+        limit: Optional[int] = None, to_frame=True
+    ) -> Union[List[Dict], pd.DataFrame]:
+
+        filter, sort = json.dumps(
+            filter, default=self.json_def_handler), json.dumps(sort, default=self.json_def_handler)
+        return self.to_dataframe(to_frame,
+                                  self.loader.load_index_constituent(filter=filter, sort=sort, limit=limit))
+
+    def load_stock_balance_sheet(
+        self, *,
+        filter: Optional[Dict] = {},
+        sort: Optional[Dict] = {},
+        limit: Optional[int] = None, to_frame=True
+    ) -> Union[List[Dict], pd.DataFrame]:
+
+        filter, sort = json.dumps(
+            filter, default=self.json_def_handler), json.dumps(sort, default=self.json_def_handler)
+        return self.to_dataframe(to_frame,
+                                  self.loader.load_stock_balance_sheet(filter=filter, sort=sort, limit=limit))
+
+    def load_stock_income(
+        self, *,
+        filter: Optional[Dict] = {},
+        sort: Optional[Dict] = {},
+        limit: Optional[int] = None, to_frame=True
+    ) -> Union[List[Dict], pd.DataFrame]:
+
+        filter, sort = json.dumps(
+            filter, default=self.json_def_handler), json.dumps(sort, default=self.json_def_handler)
+        return self.to_dataframe(to_frame,
+                                  self.loader.load_stock_income(filter=filter, sort=sort, limit=limit))
+
+    def load_stock_cash_flow(
+        self, *,
+        filter: Optional[Dict] = {},
+        sort: Optional[Dict] = {},
+        limit: Optional[int] = None, to_frame=True
+    ) -> Union[List[Dict], pd.DataFrame]:
+
+        filter, sort = json.dumps(
+            filter, default=self.json_def_handler), json.dumps(sort, default=self.json_def_handler)
+        return self.to_dataframe(to_frame,
+                                  self.loader.load_stock_cash_flow(filter=filter, sort=sort, limit=limit))
+
+    def load_stock_holder_num(
+        self, *,
+        filter: Optional[Dict] = {},
+        sort: Optional[Dict] = {},
+        limit: Optional[int] = None, to_frame=True
+    ) -> Union[List[Dict], pd.DataFrame]:
+
+        filter, sort = json.dumps(
+            filter, default=self.json_def_handler), json.dumps(sort, default=self.json_def_handler)
+        return self.to_dataframe(to_frame,
+                                  self.loader.load_stock_holder_num(filter=filter, sort=sort, limit=limit))
+
+    def load_stock_top_holder(
+        self, *,
+        filter: Optional[Dict] = {},
+        sort: Optional[Dict] = {},
+        limit: Optional[int] = None, to_frame=True
+    ) -> Union[List[Dict], pd.DataFrame]:
+
+        filter, sort = json.dumps(
+            filter, default=self.json_def_handler), json.dumps(sort, default=self.json_def_handler)
+        return self.to_dataframe(to_frame,
+                                  self.loader.load_stock_top_holder(filter=filter, sort=sort, limit=limit))
+
+    def load_stock_north_hold(
+        self, *,
+        filter: Optional[Dict] = {},
+        sort: Optional[Dict] = {},
+        limit: Optional[int] = None, to_frame=True
+    ) -> Union[List[Dict], pd.DataFrame]:
+
+        filter, sort = json.dumps(
+            filter, default=self.json_def_handler), json.dumps(sort, default=self.json_def_handler)
+        return self.to_dataframe(to_frame,
+                                  self.loader.load_stock_north_hold(filter=filter, sort=sort, limit=limit))
+
+    def load_north_flow(
+        self, *,
+        filter: Optional[Dict] = {},
+        sort: Optional[Dict] = {},
+        limit: Optional[int] = None, to_frame=True
+    ) -> Union[List[Dict], pd.DataFrame]:
+
+        filter, sort = json.dumps(
+            filter, default=self.json_def_handler), json.dumps(sort, default=self.json_def_handler)
+        return self.to_dataframe(to_frame,
+                                  self.loader.load_north_flow(filter=filter, sort=sort, limit=limit))
+
+    def load_stock_dragon_tiger(
+        self, *,
+        filter: Optional[Dict] = {},
+        sort: Optional[Dict] = {},
+        limit: Optional[int] = None, to_frame=True
+    ) -> Union[List[Dict], pd.DataFrame]:
+
+        filter, sort = json.dumps(
+            filter, default=self.json_def_handler), json.dumps(sort, default=self.json_def_handler)
+        return self.to_dataframe(to_frame,
+                                  self.loader.load_stock_dragon_tiger(filter=filter, sort=sort, limit=limit))
+
+    def load_stock_limit_pool(
+        self, *,
+        filter: Optional[Dict] = {},
+        sort: Optional[Dict] = {},
+        limit: Optional[int] = None, to_frame=True
+    ) -> Union[List[Dict], pd.DataFrame]:
+
+        filter, sort = json.dumps(
+            filter, default=self.json_def_handler), json.dumps(sort, default=self.json_def_handler)
+        return self.to_dataframe(to_frame,
+                                  self.loader.load_stock_limit_pool(filter=filter, sort=sort, limit=limit))
+
+    def load_stock_status(
+        self, *,
+        filter: Optional[Dict] = {},
+        sort: Optional[Dict] = {},
+        limit: Optional[int] = None, to_frame=True
+    ) -> Union[List[Dict], pd.DataFrame]:
+
+        filter, sort = json.dumps(
+            filter, default=self.json_def_handler), json.dumps(sort, default=self.json_def_handler)
+        return self.to_dataframe(to_frame,
+                                  self.loader.load_stock_status(filter=filter, sort=sort, limit=limit))
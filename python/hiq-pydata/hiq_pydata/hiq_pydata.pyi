@@ -2,7 +2,9 @@ from typing import List, Dict, Optional
 
 
 class HiqSync:
-    def __init__(dest: List[(str, str)], funcs: Optional[List[int]] = None):
+    def __init__(dest: List[(str, str)], funcs: Optional[List[int]] = None,
+                 proxy: Optional[str] = None, connect_timeout: Optional[float] = None,
+                 read_timeout: Optional[float] = None):
         pass
 
     async def sync(self, skip_basic, task_count, split_count):
@@ -13,7 +15,9 @@ class HiqSync:
 
 
 class BlockHiqSync:
-    def __init__(dest: List[(str, str)], funcs: Optional[List[int]]):
+    def __init__(dest: List[(str, str)], funcs: Optional[List[int]] = None,
+                 proxy: Optional[str] = None, connect_timeout: Optional[float] = None,
+                 read_timeout: Optional[float] = None):
         pass
 
     def sync(self, skip_basic, task_count, split_count):
@@ -40,6 +44,7 @@ class MongoLoader:
         filter: Optional[str],
         sort: Optional[str],
         limit: Optional[int],
+        adjust: Optional[int],
     ) -> List[Dict]:
         pass
 
@@ -56,6 +61,7 @@ class MongoLoader:
         filter: Optional[str],
         sort: Optional[str],
         limit: Optional[int],
+        adjust: Optional[int],
     ) -> List[Dict]:
         pass
 
@@ -80,6 +86,7 @@ class MongoLoader:
         filter: Optional[str],
         sort: Optional[str],
         limit: Optional[int],
+        adjust: Optional[int],
     ) -> List[Dict]:
         pass
 
@@ -96,6 +103,7 @@ class MongoLoader:
         filter: Optional[str],
         sort: Optional[str],
         limit: Optional[int],
+        adjust: Optional[int],
     ) -> List[Dict]:
         pass
 
@@ -120,6 +128,7 @@ class MongoLoader:
         filter: Optional[str],
         sort: Optional[str],
         limit: Optional[int],
+        adjust: Optional[int],
     ) -> List[Dict]:
         pass
 
@@ -144,6 +153,7 @@ class MongoLoader:
         filter: Optional[str],
         sort: Optional[str],
         limit: Optional[int],
+        adjust: Optional[int],
     ) -> List[Dict]:
         pass
 
@@ -171,6 +181,135 @@ class MongoLoader:
     ) -> List[Dict]:
         pass
 
+    async def load_stock_corp_action(
+        self,
+        filter: Optional[str],
+        sort: Optional[str],
+        limit: Optional[int],
+    ) -> List[Dict]:
+        pass
+
+    async def load_stock_tick(
+        self,
+        filter: Optional[str],
+        sort: Optional[str],
+        limit: Optional[int],
+    ) -> List[Dict]:
+        pass
+
+    async def load_stock_auction(
+        self,
+        filter: Optional[str],
+        sort: Optional[str],
+        limit: Optional[int],
+    ) -> List[Dict]:
+        pass
+
+    async def load_overseas_stock_info(
+        self,
+        filter: Optional[str],
+        sort: Optional[str],
+        limit: Optional[int],
+    ) -> List[Dict]:
+        pass
+
+    async def load_overseas_stock_daily(
+        self,
+        filter: Optional[str],
+        sort: Optional[str],
+        limit: Optional[int],
+        adjust: Optional[int],
+    ) -> List[Dict]:
+        pass
+
+    async def load_index_constituent(
+        self,
+        filter: Optional[str],
+        sort: Optional[str],
+        limit: Optional[int],
+    ) -> List[Dict]:
+        pass
+
+    async def load_stock_balance_sheet(
+        self,
+        filter: Optional[str],
+        sort: Optional[str],
+        limit: Optional[int],
+    ) -> List[Dict]:
+        pass
+
+    async def load_stock_income(
+        self,
+        filter: Optional[str],
+        sort: Optional[str],
+        limit: Optional[int],
+    ) -> List[Dict]:
+        pass
+
+    async def load_stock_cash_flow(
+        self,
+        filter: Optional[str],
+        sort: Optional[str],
+        limit: Optional[int],
+    ) -> List[Dict]:
+        pass
+
+    async def load_stock_holder_num(
+        self,
+        filter: Optional[str],
+        sort: Optional[str],
+        limit: Optional[int],
+    ) -> List[Dict]:
+        pass
+
+    async def load_stock_top_holder(
+        self,
+        filter: Optional[str],
+        sort: Optional[str],
+        limit: Optional[int],
+    ) -> List[Dict]:
+        pass
+
+    async def load_stock_north_hold(
+        self,
+        filter: Optional[str],
+        sort: Optional[str],
+        limit: Optional[int],
+    ) -> List[Dict]:
+        pass
+
+    async def load_north_flow(
+        self,
+        filter: Optional[str],
+        sort: Optional[str],
+        limit: Optional[int],
+    ) -> List[Dict]:
+        pass
+
+    async def load_stock_dragon_tiger(
+        self,
+        filter: Optional[str],
+        sort: Optional[str],
+        limit: Optional[int],
+    ) -> List[Dict]:
+        pass
+
+    async def load_stock_limit_pool(
+        self,
+        filter: Optional[str],
+        sort: Optional[str],
+        limit: Optional[int],
+    ) -> List[Dict]:
+        pass
+
+    async def load_stock_status(
+        self,
+        filter: Optional[str],
+        sort: Optional[str],
+        limit: Optional[int],
+    ) -> List[Dict]:
+        pass
+
 
 class BlockMongoLoader:
     def __init__(url: str):
@@ -189,6 +328,7 @@ class BlockMongoLoader:
         filter: Optional[str],
         sort: Optional[str],
         limit: Optional[int],
+        adjust: Optional[int],
     ) -> List[Dict]:
         pass
 
@@ -205,6 +345,7 @@ class BlockMongoLoader:
         filter: Optional[str],
         sort: Optional[str],
         limit: Optional[int],
+        adjust: Optional[int],
     ) -> List[Dict]:
         pass
 
@@ -229,6 +370,7 @@ class BlockMongoLoader:
         filter: Optional[str],
         sort: Optional[str],
         limit: Optional[int],
+        adjust: Optional[int],
     ) -> List[Dict]:
         pass
 
@@ -245,6 +387,7 @@ class BlockMongoLoader:
         filter: Optional[str],
         sort: Optional[str],
         limit: Optional[int],
+        adjust: Optional[int],
     ) -> List[Dict]:
         pass
 
@@ -269,6 +412,7 @@ class BlockMongoLoader:
         filter: Optional[str],
         sort: Optional[str],
         limit: Optional[int],
+        adjust: Optional[int],
     ) -> List[Dict]:
         pass
 
@@ -293,6 +437,7 @@ class BlockMongoLoader:
         filter: Optional[str],
         sort: Optional[str],
         limit: Optional[int],
+        adjust: Optional[int],
     ) -> List[Dict]:
         pass
 
@@ -319,3 +464,132 @@ class BlockMongoLoader:
         limit: Optional[int],
     ) -> List[Dict]:
         pass
+
+    def load_stock_corp_action(
+        self,
+        filter: Optional[str],
+        sort: Optional[str],
+        limit: Optional[int],
+    ) -> List[Dict]:
+        pass
+
+    def load_stock_tick(
+        self,
+        filter: Optional[str],
+        sort: Optional[str],
+        limit: Optional[int],
+    ) -> List[Dict]:
+        pass
+
+    def load_stock_auction(
+        self,
+        filter: Optional[str],
+        sort: Optional[str],
+        limit: Optional[int],
+    ) -> List[Dict]:
+        pass
+
+    def load_overseas_stock_info(
+        self,
+        filter: Optional[str],
+        sort: Optional[str],
+        limit: Optional[int],
+    ) -> List[Dict]:
+        pass
+
+    def load_overseas_stock_daily(
+        self,
+        filter: Optional[str],
+        sort: Optional[str],
+        limit: Optional[int],
+        adjust: Optional[int],
+    ) -> List[Dict]:
+        pass
+
+    def load_index_constituent(
+        self,
+        filter: Optional[str],
+        sort: Optional[str],
+        limit: Optional[int],
+    ) -> List[Dict]:
+        pass
+
+    def load_stock_balance_sheet(
+        self,
+        filter: Optional[str],
+        sort: Optional[str],
+        limit: Optional[int],
+    ) -> List[Dict]:
+        pass
+
+    def load_stock_income(
+        self,
+        filter: Optional[str],
+        sort: Optional[str],
+        limit: Optional[int],
+    ) -> List[Dict]:
+        pass
+
+    def load_stock_cash_flow(
+        self,
+        filter: Optional[str],
+        sort: Optional[str],
+        limit: Optional[int],
+    ) -> List[Dict]:
+        pass
+
+    def load_stock_holder_num(
+        self,
+        filter: Optional[str],
+        sort: Optional[str],
+        limit: Optional[int],
+    ) -> List[Dict]:
+        pass
+
+    def load_stock_top_holder(
+        self,
+        filter: Optional[str],
+        sort: Optional[str],
+        limit: Optional[int],
+    ) -> List[Dict]:
+        pass
+
+    def load_stock_north_hold(
+        self,
+        filter: Optional[str],
+        sort: Optional[str],
+        limit: Optional[int],
+    ) -> List[Dict]:
+        pass
+
+    def load_north_flow(
+        self,
+        filter: Optional[str],
+        sort: Optional[str],
+        limit: Optional[int],
+    ) -> List[Dict]:
+        pass
+
+    def load_stock_dragon_tiger(
+        self,
+        filter: Optional[str],
+        sort: Optional[str],
+        limit: Optional[int],
+    ) -> List[Dict]:
+        pass
+
+    def load_stock_limit_pool(
+        self,
+        filter: Optional[str],
+        sort: Optional[str],
+        limit: Optional[int],
+    ) -> List[Dict]:
+        pass
+
+    def load_stock_status(
+        self,
+        filter: Optional[str],
+        sort: Optional[str],
+        limit: Optional[int],
+    ) -> List[Dict]:
+        pass
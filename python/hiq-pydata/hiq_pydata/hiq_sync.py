@@ -56,8 +56,10 @@ class Funcs:
 
 
 class MyHiqSync:
-    def __init__(self, dest: Dest, funcs: Optional[List[int]] = None):
-        self.inner = HiqSync(dest.to_list(), funcs)
+    def __init__(self, dest: Dest, funcs: Optional[List[int]] = None, *,
+                 proxy: Optional[str] = None, connect_timeout: Optional[float] = None,
+                 read_timeout: Optional[float] = None):
+        self.inner = HiqSync(dest.to_list(), funcs, proxy, connect_timeout, read_timeout)
 
     async def sync(self, skip_basic=False, task_count=4, split_count=5):
         await self.inner.sync(skip_basic, task_count, split_count)
@@ -67,8 +69,10 @@ class MyHiqSync:
 
 
 class MyBlockHiqSync:
-    def __init__(self, dest: Dest, funcs: Optional[List[int]] = None):
-        self.inner = BlockHiqSync(dest.to_list(), funcs)
+    def __init__(self, dest: Dest, funcs: Optional[List[int]] = None, *,
+                 proxy: Optional[str] = None, connect_timeout: Optional[float] = None,
+                 read_timeout: Optional[float] = None):
+        self.inner = BlockHiqSync(dest.to_list(), funcs, proxy, connect_timeout, read_timeout)
 
     def sync(self, skip_basic=False, task_count=4, split_count=5):
         self.inner.sync(skip_basic, task_count, split_count)
@@ -1,9 +1,14 @@
+use hiq_common::AdjustFactor;
 use hiq_data::store::mongo::{query, query_one};
 use hiq_data::store::{
-    TAB_BOND_DAILY, TAB_BOND_INFO, TAB_FUND_DAILY, TAB_FUND_INFO, TAB_FUND_NET, TAB_INDEX_DAILY,
-    TAB_INDEX_INFO, TAB_STOCK_CONCEPT, TAB_STOCK_CONCEPT_DAILY, TAB_STOCK_CONCEPT_DETAIL,
-    TAB_STOCK_DAILY, TAB_STOCK_INDEX, TAB_STOCK_INDUSTRY, TAB_STOCK_INDUSTRY_DAILY,
-    TAB_STOCK_INDUSTRY_DETAIL, TAB_STOCK_INFO, TAB_STOCK_MARGIN, TAB_STOCK_YJBB,
+    TAB_BOND_DAILY, TAB_BOND_INFO, TAB_FUND_DAILY, TAB_FUND_INFO, TAB_FUND_NET,
+    TAB_INDEX_CONSTITUENT, TAB_INDEX_DAILY, TAB_INDEX_INFO, TAB_NORTH_FLOW,
+    TAB_OVERSEAS_STOCK_DAILY, TAB_OVERSEAS_STOCK_INFO, TAB_STOCK_AUCTION, TAB_STOCK_BALANCE_SHEET,
+    TAB_STOCK_CASH_FLOW, TAB_STOCK_CONCEPT, TAB_STOCK_CONCEPT_DAILY, TAB_STOCK_CONCEPT_DETAIL,
+    TAB_STOCK_CORP_ACTION, TAB_STOCK_DAILY, TAB_STOCK_DRAGON_TIGER, TAB_STOCK_HOLDER_NUM,
+    TAB_STOCK_INCOME, TAB_STOCK_INDEX, TAB_STOCK_INDUSTRY, TAB_STOCK_INDUSTRY_DAILY,
+    TAB_STOCK_INDUSTRY_DETAIL, TAB_STOCK_INFO, TAB_STOCK_LIMIT_POOL, TAB_STOCK_MARGIN,
+    TAB_STOCK_NORTH_HOLD, TAB_STOCK_STATUS, TAB_STOCK_TICK, TAB_STOCK_TOP_HOLDER, TAB_STOCK_YJBB,
 };
 use hiq_pycommon::runtime;
 use mongodb::bson::{doc, Document};
@@ -76,6 +81,20 @@ where
     Ok(data)
 }
 
+/// 加载k线，adjust: 0 不复权，1 前复权，2 后复权
+async fn load_bars(
+    client: Client,
+    collection: &str,
+    filter: Option<String>,
+    sort: Option<String>,
+    limit: Option<i64>,
+    adjust: Option<i32>,
+) -> PyResult<Vec<hiq_pycommon::Bar>> {
+    let mut data: Vec<hiq_common::Bar> = load_data(client, collection, filter, sort, limit).await?;
+    hiq_common::adjust_bars(&mut data, AdjustFactor::from(adjust.unwrap_or(0)));
+    Ok(data.into_iter().map(hiq_pycommon::Bar::from).collect())
+}
+
 #[pyclass]
 pub struct MongoLoader {
     client: Option<Client>,
@@ -117,17 +136,11 @@ impl MongoLoader {
         filter: Option<String>,
         sort: Option<String>,
         limit: Option<i64>,
+        adjust: Option<i32>,
     ) -> PyResult<&'a PyAny> {
         let client = self.client.clone().unwrap();
         pyo3_asyncio::tokio::future_into_py(py, async move {
-            load_data::<hiq_common::Bar, hiq_pycommon::Bar>(
-                client,
-                TAB_BOND_DAILY,
-                filter,
-                sort,
-                limit,
-            )
-            .await
+            load_bars(client, TAB_BOND_DAILY, filter, sort, limit, adjust).await
         })
     }
 
@@ -156,17 +169,11 @@ impl MongoLoader {
         filter: Option<String>,
         sort: Option<String>,
         limit: Option<i64>,
+        adjust: Option<i32>,
     ) -> PyResult<&'a PyAny> {
         let client = self.client.clone().unwrap();
         pyo3_asyncio::tokio::future_into_py(py, async move {
-            load_data::<hiq_common::Bar, hiq_pycommon::Bar>(
-                client,
-                TAB_FUND_DAILY,
-                filter,
-                sort,
-                limit,
-            )
-            .await
+            load_bars(client, TAB_FUND_DAILY, filter, sort, limit, adjust).await
         })
     }
     fn load_fund_net<'a>(
@@ -215,17 +222,11 @@ impl MongoLoader {
         filter: Option<String>,
         sort: Option<String>,
         limit: Option<i64>,
+        adjust: Option<i32>,
     ) -> PyResult<&'a PyAny> {
         let client = self.client.clone().unwrap();
         pyo3_asyncio::tokio::future_into_py(py, async move {
-            load_data::<hiq_common::Bar, hiq_pycommon::Bar>(
-                client,
-                TAB_INDEX_DAILY,
-                filter,
-                sort,
-                limit,
-            )
-            .await
+            load_bars(client, TAB_INDEX_DAILY, filter, sort, limit, adjust).await
         })
     }
 
@@ -255,17 +256,11 @@ impl MongoLoader {
         filter: Option<String>,
         sort: Option<String>,
         limit: Option<i64>,
+        adjust: Option<i32>,
     ) -> PyResult<&'a PyAny> {
         let client = self.client.clone().unwrap();
         pyo3_asyncio::tokio::future_into_py(py, async move {
-            load_data::<hiq_common::Bar, hiq_pycommon::Bar>(
-                client,
-                TAB_STOCK_DAILY,
-                filter,
-                sort,
-                limit,
-            )
-            .await
+            load_bars(client, TAB_STOCK_DAILY, filter, sort, limit, adjust).await
         })
     }
 
@@ -314,15 +309,17 @@ impl MongoLoader {
         filter: Option<String>,
         sort: Option<String>,
         limit: Option<i64>,
+        adjust: Option<i32>,
     ) -> PyResult<&'a PyAny> {
         let client = self.client.clone().unwrap();
         pyo3_asyncio::tokio::future_into_py(py, async move {
-            load_data::<hiq_common::Bar, hiq_pycommon::Bar>(
+            load_bars(
                 client,
                 TAB_STOCK_INDUSTRY_DAILY,
                 filter,
                 sort,
                 limit,
+                adjust,
             )
             .await
         })
@@ -373,17 +370,11 @@ impl MongoLoader {
         filter: Option<String>,
         sort: Option<String>,
         limit: Option<i64>,
+        adjust: Option<i32>,
     ) -> PyResult<&'a PyAny> {
         let client = self.client.clone().unwrap();
         pyo3_asyncio::tokio::future_into_py(py, async move {
-            load_data::<hiq_common::Bar, hiq_pycommon::Bar>(
-                client,
-                TAB_STOCK_CONCEPT_DAILY,
-                filter,
-                sort,
-                limit,
-            )
-            .await
+            load_bars(client, TAB_STOCK_CONCEPT_DAILY, filter, sort, limit, adjust).await
         })
     }
     fn load_stock_concept_detail<'a>(
@@ -445,6 +436,314 @@ impl MongoLoader {
             .await
         })
     }
+
+    fn load_stock_corp_action<'a>(
+        &self,
+        py: Python<'a>,
+        filter: Option<String>,
+        sort: Option<String>,
+        limit: Option<i64>,
+    ) -> PyResult<&'a PyAny> {
+        let client = self.client.clone().unwrap();
+        pyo3_asyncio::tokio::future_into_py(py, async move {
+            load_data::<hiq_common::StockCorpAction, hiq_pycommon::StockCorpAction>(
+                client,
+                TAB_STOCK_CORP_ACTION,
+                filter,
+                sort,
+                limit,
+            )
+            .await
+        })
+    }
+
+    fn load_stock_tick<'a>(
+        &self,
+        py: Python<'a>,
+        filter: Option<String>,
+        sort: Option<String>,
+        limit: Option<i64>,
+    ) -> PyResult<&'a PyAny> {
+        let client = self.client.clone().unwrap();
+        pyo3_asyncio::tokio::future_into_py(py, async move {
+            load_data::<hiq_common::Tick, hiq_pycommon::Tick>(
+                client,
+                TAB_STOCK_TICK,
+                filter,
+                sort,
+                limit,
+            )
+            .await
+        })
+    }
+    fn load_stock_auction<'a>(
+        &self,
+        py: Python<'a>,
+        filter: Option<String>,
+        sort: Option<String>,
+        limit: Option<i64>,
+    ) -> PyResult<&'a PyAny> {
+        let client = self.client.clone().unwrap();
+        pyo3_asyncio::tokio::future_into_py(py, async move {
+            load_data::<hiq_common::AuctionSnapshot, hiq_pycommon::AuctionSnapshot>(
+                client,
+                TAB_STOCK_AUCTION,
+                filter,
+                sort,
+                limit,
+            )
+            .await
+        })
+    }
+    fn load_overseas_stock_info<'a>(
+        &self,
+        py: Python<'a>,
+        filter: Option<String>,
+        sort: Option<String>,
+        limit: Option<i64>,
+    ) -> PyResult<&'a PyAny> {
+        let client = self.client.clone().unwrap();
+        pyo3_asyncio::tokio::future_into_py(py, async move {
+            load_data::<hiq_common::StockInfo, hiq_pycommon::StockInfo>(
+                client,
+                TAB_OVERSEAS_STOCK_INFO,
+                filter,
+                sort,
+                limit,
+            )
+            .await
+        })
+    }
+    fn load_overseas_stock_daily<'a>(
+        &self,
+        py: Python<'a>,
+        filter: Option<String>,
+        sort: Option<String>,
+        limit: Option<i64>,
+        adjust: Option<i32>,
+    ) -> PyResult<&'a PyAny> {
+        let client = self.client.clone().unwrap();
+        pyo3_asyncio::tokio::future_into_py(py, async move {
+            load_bars(
+                client,
+                TAB_OVERSEAS_STOCK_DAILY,
+                filter,
+                sort,
+                limit,
+                adjust,
+            )
+            .await
+        })
+    }
+    fn load_index_constituent<'a>(
+        &self,
+        py: Python<'a>,
+        filter: Option<String>,
+        sort: Option<String>,
+        limit: Option<i64>,
+    ) -> PyResult<&'a PyAny> {
+        let client = self.client.clone().unwrap();
+        pyo3_asyncio::tokio::future_into_py(py, async move {
+            load_data::<hiq_common::IndexConstituent, hiq_pycommon::IndexConstituent>(
+                client,
+                TAB_INDEX_CONSTITUENT,
+                filter,
+                sort,
+                limit,
+            )
+            .await
+        })
+    }
+    fn load_stock_balance_sheet<'a>(
+        &self,
+        py: Python<'a>,
+        filter: Option<String>,
+        sort: Option<String>,
+        limit: Option<i64>,
+    ) -> PyResult<&'a PyAny> {
+        let client = self.client.clone().unwrap();
+        pyo3_asyncio::tokio::future_into_py(py, async move {
+            load_data::<hiq_common::StockBalanceSheet, hiq_pycommon::StockBalanceSheet>(
+                client,
+                TAB_STOCK_BALANCE_SHEET,
+                filter,
+                sort,
+                limit,
+            )
+            .await
+        })
+    }
+    fn load_stock_income<'a>(
+        &self,
+        py: Python<'a>,
+        filter: Option<String>,
+        sort: Option<String>,
+        limit: Option<i64>,
+    ) -> PyResult<&'a PyAny> {
+        let client = self.client.clone().unwrap();
+        pyo3_asyncio::tokio::future_into_py(py, async move {
+            load_data::<hiq_common::StockIncome, hiq_pycommon::StockIncome>(
+                client,
+                TAB_STOCK_INCOME,
+                filter,
+                sort,
+                limit,
+            )
+            .await
+        })
+    }
+    fn load_stock_cash_flow<'a>(
+        &self,
+        py: Python<'a>,
+        filter: Option<String>,
+        sort: Option<String>,
+        limit: Option<i64>,
+    ) -> PyResult<&'a PyAny> {
+        let client = self.client.clone().unwrap();
+        pyo3_asyncio::tokio::future_into_py(py, async move {
+            load_data::<hiq_common::StockCashFlow, hiq_pycommon::StockCashFlow>(
+                client,
+                TAB_STOCK_CASH_FLOW,
+                filter,
+                sort,
+                limit,
+            )
+            .await
+        })
+    }
+    fn load_stock_holder_num<'a>(
+        &self,
+        py: Python<'a>,
+        filter: Option<String>,
+        sort: Option<String>,
+        limit: Option<i64>,
+    ) -> PyResult<&'a PyAny> {
+        let client = self.client.clone().unwrap();
+        pyo3_asyncio::tokio::future_into_py(py, async move {
+            load_data::<hiq_common::StockHolderNum, hiq_pycommon::StockHolderNum>(
+                client,
+                TAB_STOCK_HOLDER_NUM,
+                filter,
+                sort,
+                limit,
+            )
+            .await
+        })
+    }
+    fn load_stock_top_holder<'a>(
+        &self,
+        py: Python<'a>,
+        filter: Option<String>,
+        sort: Option<String>,
+        limit: Option<i64>,
+    ) -> PyResult<&'a PyAny> {
+        let client = self.client.clone().unwrap();
+        pyo3_asyncio::tokio::future_into_py(py, async move {
+            load_data::<hiq_common::StockTopHolder, hiq_pycommon::StockTopHolder>(
+                client,
+                TAB_STOCK_TOP_HOLDER,
+                filter,
+                sort,
+                limit,
+            )
+            .await
+        })
+    }
+    fn load_stock_north_hold<'a>(
+        &self,
+        py: Python<'a>,
+        filter: Option<String>,
+        sort: Option<String>,
+        limit: Option<i64>,
+    ) -> PyResult<&'a PyAny> {
+        let client = self.client.clone().unwrap();
+        pyo3_asyncio::tokio::future_into_py(py, async move {
+            load_data::<hiq_common::StockNorthHold, hiq_pycommon::StockNorthHold>(
+                client,
+                TAB_STOCK_NORTH_HOLD,
+                filter,
+                sort,
+                limit,
+            )
+            .await
+        })
+    }
+    fn load_north_flow<'a>(
+        &self,
+        py: Python<'a>,
+        filter: Option<String>,
+        sort: Option<String>,
+        limit: Option<i64>,
+    ) -> PyResult<&'a PyAny> {
+        let client = self.client.clone().unwrap();
+        pyo3_asyncio::tokio::future_into_py(py, async move {
+            load_data::<hiq_common::NorthFlow, hiq_pycommon::NorthFlow>(
+                client,
+                TAB_NORTH_FLOW,
+                filter,
+                sort,
+                limit,
+            )
+            .await
+        })
+    }
+    fn load_stock_dragon_tiger<'a>(
+        &self,
+        py: Python<'a>,
+        filter: Option<String>,
+        sort: Option<String>,
+        limit: Option<i64>,
+    ) -> PyResult<&'a PyAny> {
+        let client = self.client.clone().unwrap();
+        pyo3_asyncio::tokio::future_into_py(py, async move {
+            load_data::<hiq_common::StockDragonTiger, hiq_pycommon::StockDragonTiger>(
+                client,
+                TAB_STOCK_DRAGON_TIGER,
+                filter,
+                sort,
+                limit,
+            )
+            .await
+        })
+    }
+    fn load_stock_limit_pool<'a>(
+        &self,
+        py: Python<'a>,
+        filter: Option<String>,
+        sort: Option<String>,
+        limit: Option<i64>,
+    ) -> PyResult<&'a PyAny> {
+        let client = self.client.clone().unwrap();
+        pyo3_asyncio::tokio::future_into_py(py, async move {
+            load_data::<hiq_common::StockLimitPool, hiq_pycommon::StockLimitPool>(
+                client,
+                TAB_STOCK_LIMIT_POOL,
+                filter,
+                sort,
+                limit,
+            )
+            .await
+        })
+    }
+    fn load_stock_status<'a>(
+        &self,
+        py: Python<'a>,
+        filter: Option<String>,
+        sort: Option<String>,
+        limit: Option<i64>,
+    ) -> PyResult<&'a PyAny> {
+        let client = self.client.clone().unwrap();
+        pyo3_asyncio::tokio::future_into_py(py, async move {
+            load_data::<hiq_common::StockStatus, hiq_pycommon::StockStatus>(
+                client,
+                TAB_STOCK_STATUS,
+                filter,
+                sort,
+                limit,
+            )
+            .await
+        })
+    }
 }
 
 #[pyclass]
@@ -483,14 +782,16 @@ impl BlockMongoLoader {
         filter: Option<String>,
         sort: Option<String>,
         limit: Option<i64>,
+        adjust: Option<i32>,
     ) -> PyResult<Vec<hiq_pycommon::Bar>> {
         let client = self.client.clone().unwrap();
-        runtime()?.block_on(load_data::<hiq_common::Bar, hiq_pycommon::Bar>(
+        runtime()?.block_on(load_bars(
             client,
             TAB_BOND_DAILY,
             filter,
             sort,
             limit,
+            adjust,
         ))
     }
 
@@ -514,14 +815,16 @@ impl BlockMongoLoader {
         filter: Option<String>,
         sort: Option<String>,
         limit: Option<i64>,
+        adjust: Option<i32>,
     ) -> PyResult<Vec<hiq_pycommon::Bar>> {
         let client = self.client.clone().unwrap();
-        runtime()?.block_on(load_data::<hiq_common::Bar, hiq_pycommon::Bar>(
+        runtime()?.block_on(load_bars(
             client,
             TAB_FUND_DAILY,
             filter,
             sort,
             limit,
+            adjust,
         ))
     }
     fn load_fund_net(
@@ -561,14 +864,16 @@ impl BlockMongoLoader {
         filter: Option<String>,
         sort: Option<String>,
         limit: Option<i64>,
+        adjust: Option<i32>,
     ) -> PyResult<Vec<hiq_pycommon::Bar>> {
         let client = self.client.clone().unwrap();
-        runtime()?.block_on(load_data::<hiq_common::Bar, hiq_pycommon::Bar>(
+        runtime()?.block_on(load_bars(
             client,
             TAB_INDEX_DAILY,
             filter,
             sort,
             limit,
+            adjust,
         ))
     }
 
@@ -593,14 +898,16 @@ impl BlockMongoLoader {
         filter: Option<String>,
         sort: Option<String>,
         limit: Option<i64>,
+        adjust: Option<i32>,
     ) -> PyResult<Vec<hiq_pycommon::Bar>> {
         let client = self.client.clone().unwrap();
-        runtime()?.block_on(load_data::<hiq_common::Bar, hiq_pycommon::Bar>(
+        runtime()?.block_on(load_bars(
             client,
             TAB_STOCK_DAILY,
             filter,
             sort,
             limit,
+            adjust,
         ))
     }
 
@@ -639,14 +946,16 @@ impl BlockMongoLoader {
         filter: Option<String>,
         sort: Option<String>,
         limit: Option<i64>,
+        adjust: Option<i32>,
     ) -> PyResult<Vec<hiq_pycommon::Bar>> {
         let client = self.client.clone().unwrap();
-        runtime()?.block_on(load_data::<hiq_common::Bar, hiq_pycommon::Bar>(
+        runtime()?.block_on(load_bars(
             client,
             TAB_STOCK_INDUSTRY_DAILY,
             filter,
             sort,
             limit,
+            adjust,
         ))
     }
     fn load_stock_industry_detail(
@@ -682,14 +991,16 @@ impl BlockMongoLoader {
         filter: Option<String>,
         sort: Option<String>,
         limit: Option<i64>,
+        adjust: Option<i32>,
     ) -> PyResult<Vec<hiq_pycommon::Bar>> {
         let client = self.client.clone().unwrap();
-        runtime()?.block_on(load_data::<hiq_common::Bar, hiq_pycommon::Bar>(
+        runtime()?.block_on(load_bars(
             client,
             TAB_STOCK_CONCEPT_DAILY,
             filter,
             sort,
             limit,
+            adjust,
         ))
     }
     fn load_stock_concept_detail(
@@ -735,4 +1046,216 @@ impl BlockMongoLoader {
             hiq_pycommon::StockMargin,
         >(client, TAB_STOCK_MARGIN, filter, sort, limit))
     }
+
+    fn load_stock_corp_action(
+        &self,
+        filter: Option<String>,
+        sort: Option<String>,
+        limit: Option<i64>,
+    ) -> PyResult<Vec<hiq_pycommon::StockCorpAction>> {
+        let client = self.client.clone().unwrap();
+        runtime()?.block_on(load_data::<
+            hiq_common::StockCorpAction,
+            hiq_pycommon::StockCorpAction,
+        >(client, TAB_STOCK_CORP_ACTION, filter, sort, limit))
+    }
+
+    fn load_stock_tick(
+        &self,
+        filter: Option<String>,
+        sort: Option<String>,
+        limit: Option<i64>,
+    ) -> PyResult<Vec<hiq_pycommon::Tick>> {
+        let client = self.client.clone().unwrap();
+        runtime()?.block_on(load_data::<hiq_common::Tick, hiq_pycommon::Tick>(
+            client,
+            TAB_STOCK_TICK,
+            filter,
+            sort,
+            limit,
+        ))
+    }
+    fn load_stock_auction(
+        &self,
+        filter: Option<String>,
+        sort: Option<String>,
+        limit: Option<i64>,
+    ) -> PyResult<Vec<hiq_pycommon::AuctionSnapshot>> {
+        let client = self.client.clone().unwrap();
+        runtime()?.block_on(load_data::<
+            hiq_common::AuctionSnapshot,
+            hiq_pycommon::AuctionSnapshot,
+        >(client, TAB_STOCK_AUCTION, filter, sort, limit))
+    }
+    fn load_overseas_stock_info(
+        &self,
+        filter: Option<String>,
+        sort: Option<String>,
+        limit: Option<i64>,
+    ) -> PyResult<Vec<hiq_pycommon::StockInfo>> {
+        let client = self.client.clone().unwrap();
+        runtime()?.block_on(load_data::<hiq_common::StockInfo, hiq_pycommon::StockInfo>(
+            client,
+            TAB_OVERSEAS_STOCK_INFO,
+            filter,
+            sort,
+            limit,
+        ))
+    }
+    fn load_overseas_stock_daily(
+        &self,
+        filter: Option<String>,
+        sort: Option<String>,
+        limit: Option<i64>,
+        adjust: Option<i32>,
+    ) -> PyResult<Vec<hiq_pycommon::Bar>> {
+        let client = self.client.clone().unwrap();
+        runtime()?.block_on(load_bars(
+            client,
+            TAB_OVERSEAS_STOCK_DAILY,
+            filter,
+            sort,
+            limit,
+            adjust,
+        ))
+    }
+    fn load_index_constituent(
+        &self,
+        filter: Option<String>,
+        sort: Option<String>,
+        limit: Option<i64>,
+    ) -> PyResult<Vec<hiq_pycommon::IndexConstituent>> {
+        let client = self.client.clone().unwrap();
+        runtime()?.block_on(load_data::<
+            hiq_common::IndexConstituent,
+            hiq_pycommon::IndexConstituent,
+        >(client, TAB_INDEX_CONSTITUENT, filter, sort, limit))
+    }
+    fn load_stock_balance_sheet(
+        &self,
+        filter: Option<String>,
+        sort: Option<String>,
+        limit: Option<i64>,
+    ) -> PyResult<Vec<hiq_pycommon::StockBalanceSheet>> {
+        let client = self.client.clone().unwrap();
+        runtime()?.block_on(load_data::<
+            hiq_common::StockBalanceSheet,
+            hiq_pycommon::StockBalanceSheet,
+        >(
+            client, TAB_STOCK_BALANCE_SHEET, filter, sort, limit
+        ))
+    }
+    fn load_stock_income(
+        &self,
+        filter: Option<String>,
+        sort: Option<String>,
+        limit: Option<i64>,
+    ) -> PyResult<Vec<hiq_pycommon::StockIncome>> {
+        let client = self.client.clone().unwrap();
+        runtime()?.block_on(load_data::<
+            hiq_common::StockIncome,
+            hiq_pycommon::StockIncome,
+        >(client, TAB_STOCK_INCOME, filter, sort, limit))
+    }
+    fn load_stock_cash_flow(
+        &self,
+        filter: Option<String>,
+        sort: Option<String>,
+        limit: Option<i64>,
+    ) -> PyResult<Vec<hiq_pycommon::StockCashFlow>> {
+        let client = self.client.clone().unwrap();
+        runtime()?.block_on(load_data::<
+            hiq_common::StockCashFlow,
+            hiq_pycommon::StockCashFlow,
+        >(client, TAB_STOCK_CASH_FLOW, filter, sort, limit))
+    }
+    fn load_stock_holder_num(
+        &self,
+        filter: Option<String>,
+        sort: Option<String>,
+        limit: Option<i64>,
+    ) -> PyResult<Vec<hiq_pycommon::StockHolderNum>> {
+        let client = self.client.clone().unwrap();
+        runtime()?.block_on(load_data::<
+            hiq_common::StockHolderNum,
+            hiq_pycommon::StockHolderNum,
+        >(client, TAB_STOCK_HOLDER_NUM, filter, sort, limit))
+    }
+    fn load_stock_top_holder(
+        &self,
+        filter: Option<String>,
+        sort: Option<String>,
+        limit: Option<i64>,
+    ) -> PyResult<Vec<hiq_pycommon::StockTopHolder>> {
+        let client = self.client.clone().unwrap();
+        runtime()?.block_on(load_data::<
+            hiq_common::StockTopHolder,
+            hiq_pycommon::StockTopHolder,
+        >(client, TAB_STOCK_TOP_HOLDER, filter, sort, limit))
+    }
+    fn load_stock_north_hold(
+        &self,
+        filter: Option<String>,
+        sort: Option<String>,
+        limit: Option<i64>,
+    ) -> PyResult<Vec<hiq_pycommon::StockNorthHold>> {
+        let client = self.client.clone().unwrap();
+        runtime()?.block_on(load_data::<
+            hiq_common::StockNorthHold,
+            hiq_pycommon::StockNorthHold,
+        >(client, TAB_STOCK_NORTH_HOLD, filter, sort, limit))
+    }
+    fn load_north_flow(
+        &self,
+        filter: Option<String>,
+        sort: Option<String>,
+        limit: Option<i64>,
+    ) -> PyResult<Vec<hiq_pycommon::NorthFlow>> {
+        let client = self.client.clone().unwrap();
+        runtime()?.block_on(load_data::<hiq_common::NorthFlow, hiq_pycommon::NorthFlow>(
+            client,
+            TAB_NORTH_FLOW,
+            filter,
+            sort,
+            limit,
+        ))
+    }
+    fn load_stock_dragon_tiger(
+        &self,
+        filter: Option<String>,
+        sort: Option<String>,
+        limit: Option<i64>,
+    ) -> PyResult<Vec<hiq_pycommon::StockDragonTiger>> {
+        let client = self.client.clone().unwrap();
+        runtime()?.block_on(load_data::<
+            hiq_common::StockDragonTiger,
+            hiq_pycommon::StockDragonTiger,
+        >(
+            client, TAB_STOCK_DRAGON_TIGER, filter, sort, limit
+        ))
+    }
+    fn load_stock_limit_pool(
+        &self,
+        filter: Option<String>,
+        sort: Option<String>,
+        limit: Option<i64>,
+    ) -> PyResult<Vec<hiq_pycommon::StockLimitPool>> {
+        let client = self.client.clone().unwrap();
+        runtime()?.block_on(load_data::<
+            hiq_common::StockLimitPool,
+            hiq_pycommon::StockLimitPool,
+        >(client, TAB_STOCK_LIMIT_POOL, filter, sort, limit))
+    }
+    fn load_stock_status(
+        &self,
+        filter: Option<String>,
+        sort: Option<String>,
+        limit: Option<i64>,
+    ) -> PyResult<Vec<hiq_pycommon::StockStatus>> {
+        let client = self.client.clone().unwrap();
+        runtime()?.block_on(load_data::<
+            hiq_common::StockStatus,
+            hiq_pycommon::StockStatus,
+        >(client, TAB_STOCK_STATUS, filter, sort, limit))
+    }
 }
@@ -1,20 +1,50 @@
-use hiq_data::{HiqSyncDataType, HiqSyncDest};
+use std::time::Duration;
+
+use hiq_data::{FetchConfig, HiqSyncDataType, HiqSyncDest};
 use hiq_pycommon::runtime;
 use pyo3::exceptions::PyException;
 use pyo3::prelude::*;
 use tokio::sync::broadcast;
 
+/// 远程获取数据的http配置，超时单位为秒
+fn fetch_config(
+    proxy: Option<String>,
+    connect_timeout: Option<f64>,
+    read_timeout: Option<f64>,
+) -> PyResult<FetchConfig> {
+    let timeout = |secs: Option<f64>| {
+        secs.map(|secs| {
+            Duration::try_from_secs_f64(secs)
+                .map_err(|e| PyException::new_err(format!("invalid timeout {}: {}", secs, e)))
+        })
+        .transpose()
+    };
+    Ok(FetchConfig {
+        proxy,
+        connect_timeout: timeout(connect_timeout)?,
+        read_timeout: timeout(read_timeout)?,
+        ..Default::default()
+    })
+}
+
 #[pyclass]
 pub struct HiqSync {
     dest: Vec<HiqSyncDest>,
     funcs: Option<Vec<HiqSyncDataType>>,
+    config: FetchConfig,
     shutdown_tx: broadcast::Sender<()>,
 }
 
 #[pymethods]
 impl HiqSync {
     #[new]
-    fn new(dest: Vec<(String, String)>, funcs: Option<Vec<i32>>) -> Self {
+    fn new(
+        dest: Vec<(String, String)>,
+        funcs: Option<Vec<i32>>,
+        proxy: Option<String>,
+        connect_timeout: Option<f64>,
+        read_timeout: Option<f64>,
+    ) -> PyResult<Self> {
         let dest: Vec<_> = dest
             .into_iter()
             .map(|e| {
@@ -29,19 +59,23 @@ impl HiqSync {
                 .collect();
             v
         });
+        let config = fetch_config(proxy, connect_timeout, read_timeout)?;
         let (shutdown_tx, _) = broadcast::channel(1);
-        Self {
+        Ok(Self {
             dest,
             funcs,
+            config,
             shutdown_tx,
-        }
+        })
     }
     fn sync<'a>(&self, py: Python<'a>, skip_basic: bool, task_count: usize, split_count: usize, ) -> PyResult<&'a PyAny> {
         let dest = self.dest.clone();
         let funcs = self.funcs.clone();
+        let config = self.config.clone();
         let shutdown_rx = self.shutdown_tx.subscribe();
         pyo3_asyncio::tokio::future_into_py(py, async move {
-            let mut hiq_sync = hiq_data::HiqSync::new(dest, shutdown_rx, funcs);
+            let mut hiq_sync =
+                hiq_data::HiqSync::new(dest, shutdown_rx, funcs).with_fetch_config(config);
             Ok(hiq_sync
                 .sync(skip_basic, task_count, split_count)
                 .await
@@ -60,13 +94,20 @@ impl HiqSync {
 pub(crate) struct BlockHiqSync {
     dest: Vec<HiqSyncDest>,
     funcs: Option<Vec<HiqSyncDataType>>,
+    config: FetchConfig,
     shutdown_tx: broadcast::Sender<()>,
 }
 
 #[pymethods]
 impl BlockHiqSync {
     #[new]
-    fn new(dest: Vec<(String, String)>, funcs: Option<Vec<i32>>) -> Self {
+    fn new(
+        dest: Vec<(String, String)>,
+        funcs: Option<Vec<i32>>,
+        proxy: Option<String>,
+        connect_timeout: Option<f64>,
+        read_timeout: Option<f64>,
+    ) -> PyResult<Self> {
         let dest: Vec<_> = dest
             .into_iter()
             .map(|e| {
@@ -81,18 +122,29 @@ impl BlockHiqSync {
                 .collect();
             v
         });
+        let config = fetch_config(proxy, connect_timeout, read_timeout)?;
         let (shutdown_tx, _) = broadcast::channel(1);
-        Self {
+        Ok(Self {
             dest,
             funcs,
+            config,
             shutdown_tx,
-        }
+        })
     }
     fn sync(&self, skip_basic: bool, task_count: usize, split_count: usize) -> PyResult<()> {
         let dest = self.dest.clone();
         let funcs = self.funcs.clone();
+        let config = self.config.clone();
         let shutdown_rx = self.shutdown_tx.subscribe();
-        Ok(runtime()?.block_on(block_sync(dest, shutdown_rx, funcs, skip_basic, task_count, split_count))?)
+        Ok(runtime()?.block_on(block_sync(
+            dest,
+            shutdown_rx,
+            funcs,
+            config,
+            skip_basic,
+            task_count,
+            split_count,
+        ))?)
     }
     fn shutdown(&self) -> PyResult<()> {
         self.shutdown_tx
@@ -106,11 +158,12 @@ async fn block_sync(
     dest: Vec<HiqSyncDest>,
     shutdown_rx: broadcast::Receiver<()>,
     funcs: Option<Vec<HiqSyncDataType>>,
+    config: FetchConfig,
     skip_basic: bool,
     task_count: usize,
     split_count: usize,
 ) -> PyResult<()> {
-    let mut hiq_sync = hiq_data::HiqSync::new(dest, shutdown_rx, funcs);
+    let mut hiq_sync = hiq_data::HiqSync::new(dest, shutdown_rx, funcs).with_fetch_config(config);
 
     hiq_sync
         .sync(skip_basic, task_count, split_count)
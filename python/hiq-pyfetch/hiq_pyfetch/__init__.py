@@ -1,5 +1,5 @@
 from hiq_pyfetch.hiq_fetch import MyBlockFetch, HiqFetch
-from hiq_pyfetch.hiq_pyfetch import to_std_code
+from hiq_pyfetch.hiq_pyfetch import to_std_code, security_id
 
 
 class BarFreq:
@@ -18,6 +18,8 @@ class MarketType:
     Bond = 0
     Fund = 1
     Stock = 2
+    HkStock = 3
+    UsStock = 4
 
 
 hiq_fetch = HiqFetch()
@@ -29,18 +31,25 @@ fetch_prev_trade_date = hiq_fetch.fetch_prev_trade_date
 # bond
 fetch_bond_info = hiq_fetch.fetch_bond_info
 fetch_bond_bar = hiq_fetch.fetch_bond_bar
+fetch_bond_minute_bar = hiq_fetch.fetch_bond_minute_bar
+fetch_bond_order_book = hiq_fetch.fetch_bond_order_book
 
 # fund
 fetch_fund_info = hiq_fetch.fetch_fund_info
 fetch_fund_net = hiq_fetch.fetch_fund_net
 fetch_fund_bar = hiq_fetch.fetch_fund_bar
+fetch_fund_minute_bar = hiq_fetch.fetch_fund_minute_bar
+fetch_fund_order_book = hiq_fetch.fetch_fund_order_book
 
 # stock
 fetch_index_info = hiq_fetch.fetch_index_info
 fetch_index_bar = hiq_fetch.fetch_index_bar
+fetch_index_constituents = hiq_fetch.fetch_index_constituents
 fetch_stock_info = hiq_fetch.fetch_stock_info
+fetch_overseas_stock_info = hiq_fetch.fetch_overseas_stock_info
 fetch_stock_is_margin = hiq_fetch.fetch_stock_is_margin
 fetch_stock_bar = hiq_fetch.fetch_stock_bar
+fetch_stock_minute_bar = hiq_fetch.fetch_stock_minute_bar
 fetch_stock_index = hiq_fetch.fetch_stock_index
 fetch_stock_industry = hiq_fetch.fetch_stock_industry
 fetch_stock_industry_detail = hiq_fetch.fetch_stock_industry_detail
@@ -49,8 +58,27 @@ fetch_stock_concept = hiq_fetch.fetch_stock_concept
 fetch_stock_concept_detail = hiq_fetch.fetch_stock_concept_detail
 fetch_stock_concept_daily = hiq_fetch.fetch_stock_concept_daily
 fetch_stock_yjbb = hiq_fetch.fetch_stock_yjbb
+fetch_stock_balance_sheet = hiq_fetch.fetch_stock_balance_sheet
+fetch_stock_income = hiq_fetch.fetch_stock_income
+fetch_stock_cash_flow = hiq_fetch.fetch_stock_cash_flow
+fetch_stock_holder_num = hiq_fetch.fetch_stock_holder_num
+fetch_stock_top_holders = hiq_fetch.fetch_stock_top_holders
 fetch_stock_margin = hiq_fetch.fetch_stock_margin
+fetch_stock_north_hold = hiq_fetch.fetch_stock_north_hold
+fetch_north_flow = hiq_fetch.fetch_north_flow
+fetch_stock_dragon_tiger = hiq_fetch.fetch_stock_dragon_tiger
+fetch_stock_limit_pool = hiq_fetch.fetch_stock_limit_pool
+fetch_stock_st = hiq_fetch.fetch_stock_st
+fetch_stock_st_history = hiq_fetch.fetch_stock_st_history
+fetch_stock_st_notice = hiq_fetch.fetch_stock_st_notice
+fetch_stock_suspend = hiq_fetch.fetch_stock_suspend
+fetch_stock_delist = hiq_fetch.fetch_stock_delist
+fetch_stock_corp_action = hiq_fetch.fetch_stock_corp_action
 fetch_stock_rt_quot = hiq_fetch.fetch_stock_rt_quot
+fetch_stock_order_book = hiq_fetch.fetch_stock_order_book
+fetch_stock_ticks = hiq_fetch.fetch_stock_ticks
+fetch_stock_time_share = hiq_fetch.fetch_stock_time_share
+fetch_stock_auction = hiq_fetch.fetch_stock_auction
 
 
 my_block_fetch = MyBlockFetch()
@@ -60,18 +88,25 @@ block_fetch_trade_date = my_block_fetch.fetch_trade_date
 # bond
 block_fetch_bond_info = my_block_fetch.fetch_bond_info
 block_fetch_bond_bar = my_block_fetch.fetch_bond_bar
+block_fetch_bond_minute_bar = my_block_fetch.fetch_bond_minute_bar
+block_fetch_bond_order_book = my_block_fetch.fetch_bond_order_book
 
 # fund
 block_fetch_fund_info = my_block_fetch.fetch_fund_info
 block_fetch_fund_net = my_block_fetch.fetch_fund_net
 block_fetch_fund_bar = my_block_fetch.fetch_fund_bar
+block_fetch_fund_minute_bar = my_block_fetch.fetch_fund_minute_bar
+block_fetch_fund_order_book = my_block_fetch.fetch_fund_order_book
 
 # stock
 block_fetch_index_info = my_block_fetch.fetch_index_info
 block_fetch_index_bar = my_block_fetch.fetch_index_bar
+block_fetch_index_constituents = my_block_fetch.fetch_index_constituents
 block_fetch_stock_info = my_block_fetch.fetch_stock_info
+block_fetch_overseas_stock_info = my_block_fetch.fetch_overseas_stock_info
 block_fetch_stock_is_margin = my_block_fetch.fetch_stock_is_margin
 block_fetch_stock_bar = my_block_fetch.fetch_stock_bar
+block_fetch_stock_minute_bar = my_block_fetch.fetch_stock_minute_bar
 block_fetch_stock_index = my_block_fetch.fetch_stock_index
 block_fetch_stock_industry = my_block_fetch.fetch_stock_industry
 block_fetch_stock_industry_detail = my_block_fetch.fetch_stock_industry_detail
@@ -80,5 +115,24 @@ block_fetch_stock_concept = my_block_fetch.fetch_stock_concept
 block_fetch_stock_concept_detail = my_block_fetch.fetch_stock_concept_detail
 block_fetch_stock_concept_daily = my_block_fetch.fetch_stock_concept_daily
 block_fetch_stock_yjbb = my_block_fetch.fetch_stock_yjbb
+block_fetch_stock_balance_sheet = my_block_fetch.fetch_stock_balance_sheet
+block_fetch_stock_income = my_block_fetch.fetch_stock_income
+block_fetch_stock_cash_flow = my_block_fetch.fetch_stock_cash_flow
+block_fetch_stock_holder_num = my_block_fetch.fetch_stock_holder_num
+block_fetch_stock_top_holders = my_block_fetch.fetch_stock_top_holders
 block_fetch_stock_margin = my_block_fetch.fetch_stock_margin
+block_fetch_stock_north_hold = my_block_fetch.fetch_stock_north_hold
+block_fetch_north_flow = my_block_fetch.fetch_north_flow
+block_fetch_stock_dragon_tiger = my_block_fetch.fetch_stock_dragon_tiger
+block_fetch_stock_limit_pool = my_block_fetch.fetch_stock_limit_pool
+block_fetch_stock_st = my_block_fetch.fetch_stock_st
+block_fetch_stock_st_history = my_block_fetch.fetch_stock_st_history
+block_fetch_stock_st_notice = my_block_fetch.fetch_stock_st_notice
+block_fetch_stock_suspend = my_block_fetch.fetch_stock_suspend
+block_fetch_stock_delist = my_block_fetch.fetch_stock_delist
+block_fetch_stock_corp_action = my_block_fetch.fetch_stock_corp_action
 block_fetch_stock_rt_quot = my_block_fetch.fetch_stock_rt_quot
+block_fetch_stock_order_book = my_block_fetch.fetch_stock_order_book
+block_fetch_stock_ticks = my_block_fetch.fetch_stock_ticks
+block_fetch_stock_time_share = my_block_fetch.fetch_stock_time_share
+block_fetch_stock_auction = my_block_fetch.fetch_stock_auction
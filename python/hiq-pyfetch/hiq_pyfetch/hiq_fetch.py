@@ -7,10 +7,11 @@ import pandas as pd
 
 
 class HiqFetch:
-    def __init__(self):
-        self.bond_fetch = BondFetch()
-        self.fund_fetch = FundFetch()
-        self.stock_fetch = StockFetch()
+    def __init__(self, *, proxy: Optional[str] = None, connect_timeout: Optional[float] = None,
+                 read_timeout: Optional[float] = None):
+        self.bond_fetch = BondFetch(proxy, connect_timeout, read_timeout)
+        self.fund_fetch = FundFetch(proxy, connect_timeout, read_timeout)
+        self.stock_fetch = StockFetch(proxy, connect_timeout, read_timeout)
 
     @staticmethod
     def _to_dataframe(to_frame, data):
@@ -52,6 +53,19 @@ class HiqFetch:
         data['bars'] = self._to_dataframe(to_frame, data['bars'])
         return data
 
+    async def fetch_bond_minute_bar(self, *, code: str, name: Optional[str] = None,
+                                    freq: Optional[int] = None,
+                                    start: Optional[date] = None, end: Optional[date] = None,
+                                    to_frame=True) -> Dict:
+        data = await self.bond_fetch.fetch_bond_minute_bar(code=code, name=name,
+                                                           freq=freq, start=start, end=end)
+        data['bars'] = self._to_dataframe(to_frame, data['bars'])
+        return data
+
+    async def fetch_bond_order_book(self, *, code: List[str], to_frame=True) -> Union[Dict[str, Dict], pd.DataFrame]:
+        return self._to_dataframe(to_frame,
+                                  await self.bond_fetch.fetch_bond_order_book(code))
+
     # fund
     async def fetch_fund_info(self, *, to_frame=True) -> Union[List[Dict], pd.DataFrame]:
         return self._to_dataframe(to_frame,
@@ -73,6 +87,19 @@ class HiqFetch:
         data['bars'] = self._to_dataframe(to_frame, data['bars'])
         return data
 
+    async def fetch_fund_minute_bar(self, *, code: str, name: Optional[str] = None,
+                                    freq: Optional[int] = None,
+                                    start: Optional[date] = None, end: Optional[date] = None,
+                                    to_frame=True) -> Dict:
+        data = await self.fund_fetch.fetch_fund_minute_bar(code=code, name=name,
+                                                           freq=freq, start=start, end=end)
+        data['bars'] = self._to_dataframe(to_frame, data['bars'])
+        return data
+
+    async def fetch_fund_order_book(self, *, code: List[str], to_frame=True) -> Union[Dict[str, Dict], pd.DataFrame]:
+        return self._to_dataframe(to_frame,
+                                  await self.fund_fetch.fetch_fund_order_book(code))
+
     # stock
     async def fetch_index_info(self, *, to_frame=True) -> Union[List[Dict], pd.DataFrame]:
         return self._to_dataframe(to_frame,
@@ -87,10 +114,20 @@ class HiqFetch:
         data['bars'] = self._to_dataframe(to_frame, data['bars'])
         return data
 
+    async def fetch_index_constituents(self, *, code: str, date: Optional[date] = None,
+                                       to_frame=True) -> Union[List[Dict], pd.DataFrame]:
+        return self._to_dataframe(to_frame,
+                                  await self.stock_fetch.fetch_index_constituents(code, date))
+
     async def fetch_stock_info(self, *, to_frame=True) -> Union[List[Dict], pd.DataFrame]:
         return self._to_dataframe(to_frame,
                                   await self.stock_fetch.fetch_stock_info())
 
+    async def fetch_overseas_stock_info(self, *, market: int,
+                                        to_frame=True) -> Union[List[Dict], pd.DataFrame]:
+        return self._to_dataframe(to_frame,
+                                  await self.stock_fetch.fetch_overseas_stock_info(market=market))
+
     async def fetch_stock_is_margin(self, *, to_frame=True) -> Union[Set[str], pd.DataFrame]:
         data = await self.stock_fetch.fetch_stock_is_margin()
         if to_frame:
@@ -107,6 +144,15 @@ class HiqFetch:
         data['bars'] = self._to_dataframe(to_frame, data['bars'])
         return data
 
+    async def fetch_stock_minute_bar(self, *, code: str, name: Optional[str] = None,
+                                     freq: Optional[int] = None,
+                                     start: Optional[date] = None, end: Optional[date] = None,
+                                     to_frame=True) -> Union[Dict, pd.DataFrame]:
+        data = await self.stock_fetch.fetch_stock_minute_bar(code=code, name=name,
+                                                             freq=freq, start=start, end=end)
+        data['bars'] = self._to_dataframe(to_frame, data['bars'])
+        return data
+
     async def fetch_stock_index(self, *, index_date=None, to_frame=True) -> Union[Dict[str, Dict], pd.DataFrame]:
         data = await self.stock_fetch.fetch_stock_index(index_date)
         if to_frame:
@@ -154,21 +200,112 @@ class HiqFetch:
         return self._to_dataframe(to_frame,
                                   await self.stock_fetch.fetch_stock_yjbb(year, season))
 
+    async def fetch_stock_balance_sheet(self, *, year: int, season: int,
+                                        to_frame=True) -> Union[List[Dict], pd.DataFrame]:
+        return self._to_dataframe(to_frame,
+                                  await self.stock_fetch.fetch_stock_balance_sheet(year, season))
+
+    async def fetch_stock_income(self, *, year: int, season: int,
+                                 to_frame=True) -> Union[List[Dict], pd.DataFrame]:
+        return self._to_dataframe(to_frame,
+                                  await self.stock_fetch.fetch_stock_income(year, season))
+
+    async def fetch_stock_cash_flow(self, *, year: int, season: int,
+                                    to_frame=True) -> Union[List[Dict], pd.DataFrame]:
+        return self._to_dataframe(to_frame,
+                                  await self.stock_fetch.fetch_stock_cash_flow(year, season))
+
+    async def fetch_stock_holder_num(self, *, year: int, season: int,
+                                     to_frame=True) -> Union[List[Dict], pd.DataFrame]:
+        return self._to_dataframe(to_frame,
+                                  await self.stock_fetch.fetch_stock_holder_num(year, season))
+
+    async def fetch_stock_top_holders(self, *, year: int, season: int, is_float: bool = True,
+                                      to_frame=True) -> Union[List[Dict], pd.DataFrame]:
+        return self._to_dataframe(to_frame,
+                                  await self.stock_fetch.fetch_stock_top_holders(year, season, is_float))
+
     async def fetch_stock_margin(self, *, code: str, start: Optional[date] = None, end: Optional[date] = None,
                                  to_frame=True) -> Union[List[Dict], pd.DataFrame]:
         return self._to_dataframe(to_frame,
                                   await self.stock_fetch.fetch_stock_margin(code, start, end))
 
+    async def fetch_stock_north_hold(self, *, date: date,
+                                     to_frame=True) -> Union[List[Dict], pd.DataFrame]:
+        return self._to_dataframe(to_frame,
+                                  await self.stock_fetch.fetch_stock_north_hold(date))
+
+    async def fetch_north_flow(self, *, start: Optional[date] = None, end: Optional[date] = None,
+                               to_frame=True) -> Union[List[Dict], pd.DataFrame]:
+        return self._to_dataframe(to_frame,
+                                  await self.stock_fetch.fetch_north_flow(start, end))
+
+    async def fetch_stock_dragon_tiger(self, *, date: date,
+                                       to_frame=True) -> Union[List[Dict], pd.DataFrame]:
+        return self._to_dataframe(to_frame,
+                                  await self.stock_fetch.fetch_stock_dragon_tiger(date))
+
+    async def fetch_stock_limit_pool(self, *, date: date, pool: int = 1,
+                                     to_frame=True) -> Union[List[Dict], pd.DataFrame]:
+        return self._to_dataframe(to_frame,
+                                  await self.stock_fetch.fetch_stock_limit_pool(date, pool))
+
+    async def fetch_stock_st(self, *, to_frame=True) -> Union[List[Dict], pd.DataFrame]:
+        return self._to_dataframe(to_frame,
+                                  await self.stock_fetch.fetch_stock_st())
+
+    async def fetch_stock_st_notice(self, *, date: date,
+                                    to_frame=True) -> Union[List[Dict], pd.DataFrame]:
+        return self._to_dataframe(to_frame,
+                                  await self.stock_fetch.fetch_stock_st_notice(date))
+
+    async def fetch_stock_st_history(self, *, to_frame=True) -> Union[List[Dict], pd.DataFrame]:
+        return self._to_dataframe(to_frame,
+                                  await self.stock_fetch.fetch_stock_st_history())
+
+    async def fetch_stock_suspend(self, *, date: date,
+                                  to_frame=True) -> Union[List[Dict], pd.DataFrame]:
+        return self._to_dataframe(to_frame,
+                                  await self.stock_fetch.fetch_stock_suspend(date))
+
+    async def fetch_stock_delist(self, *, to_frame=True) -> Union[List[Dict], pd.DataFrame]:
+        return self._to_dataframe(to_frame,
+                                  await self.stock_fetch.fetch_stock_delist())
+
+    async def fetch_stock_corp_action(self, *, code: Optional[str] = None, start: Optional[date] = None,
+                                      end: Optional[date] = None,
+                                      to_frame=True) -> Union[List[Dict], pd.DataFrame]:
+        return self._to_dataframe(to_frame,
+                                  await self.stock_fetch.fetch_stock_corp_action(code, start, end))
+
     async def fetch_stock_rt_quot(self, *, code: List[str], to_frame=True) -> Union[Dict[str, Dict], pd.DataFrame]:
         return self._to_dataframe(to_frame,
                                   await self.stock_fetch.fetch_stock_rt_quot(code))
 
+    async def fetch_stock_order_book(self, *, code: List[str], to_frame=True) -> Union[Dict[str, Dict], pd.DataFrame]:
+        return self._to_dataframe(to_frame,
+                                  await self.stock_fetch.fetch_stock_order_book(code))
+
+    async def fetch_stock_ticks(self, *, code: str, trade_date: date, to_frame=True) -> Union[List[Dict], pd.DataFrame]:
+        return self._to_dataframe(to_frame,
+                                  await self.stock_fetch.fetch_stock_ticks(code, trade_date))
+
+    async def fetch_stock_time_share(self, *, code: str, to_frame=True) -> Dict:
+        data = await self.stock_fetch.fetch_stock_time_share(code)
+        data['points'] = self._to_dataframe(to_frame, data['points'])
+        return data
+
+    async def fetch_stock_auction(self, *, code: str, to_frame=True) -> Union[List[Dict], pd.DataFrame]:
+        return self._to_dataframe(to_frame,
+                                  await self.stock_fetch.fetch_stock_auction(code))
+
 
 class MyBlockFetch:
-    def __init__(self):
-        self.bond_fetch = BlockBondFetch()
-        self.fund_fetch = BlockFundFetch()
-        self.stock_fetch = BlockStockFetch()
+    def __init__(self, *, proxy: Optional[str] = None, connect_timeout: Optional[float] = None,
+                 read_timeout: Optional[float] = None):
+        self.bond_fetch = BlockBondFetch(proxy, connect_timeout, read_timeout)
+        self.fund_fetch = BlockFundFetch(proxy, connect_timeout, read_timeout)
+        self.stock_fetch = BlockStockFetch(proxy, connect_timeout, read_timeout)
 
     @staticmethod
     def _to_dataframe(to_frame, data):
@@ -210,6 +347,19 @@ class MyBlockFetch:
         data['bars'] = self._to_dataframe(to_frame, data['bars'])
         return data
 
+    def fetch_bond_minute_bar(self, *, code: str, name: Optional[str] = None,
+                              freq: Optional[int] = None,
+                              start: Optional[date] = None, end: Optional[date] = None,
+                              to_frame=True) -> Dict:
+        data = self.bond_fetch.fetch_bond_minute_bar(code=code, name=name,
+                                                     freq=freq, start=start, end=end)
+        data['bars'] = self._to_dataframe(to_frame, data['bars'])
+        return data
+
+    def fetch_bond_order_book(self, *, code: List[str], to_frame=True) -> Union[Dict[str, Dict], pd.DataFrame]:
+        return self._to_dataframe(to_frame,
+                                  self.bond_fetch.fetch_bond_order_book(code))
+
     # fund
     def fetch_fund_info(self, *, to_frame=True) -> Union[List[Dict], pd.DataFrame]:
         return self._to_dataframe(to_frame,
@@ -231,6 +381,19 @@ class MyBlockFetch:
         data['bars'] = self._to_dataframe(to_frame, data['bars'])
         return data
 
+    def fetch_fund_minute_bar(self, *, code: str, name: Optional[str] = None,
+                              freq: Optional[int] = None,
+                              start: Optional[date] = None, end: Optional[date] = None,
+                              to_frame=True) -> Dict:
+        data = self.fund_fetch.fetch_fund_minute_bar(code=code, name=name,
+                                                     freq=freq, start=start, end=end)
+        data['bars'] = self._to_dataframe(to_frame, data['bars'])
+        return data
+
+    def fetch_fund_order_book(self, *, code: List[str], to_frame=True) -> Union[Dict[str, Dict], pd.DataFrame]:
+        return self._to_dataframe(to_frame,
+                                  self.fund_fetch.fetch_fund_order_book(code))
+
     # stock
     def fetch_index_info(self, *, to_frame=True) -> Union[List[Dict], pd.DataFrame]:
         return self._to_dataframe(to_frame,
@@ -245,10 +408,20 @@ class MyBlockFetch:
         data['bars'] = self._to_dataframe(to_frame, data['bars'])
         return data
 
+    def fetch_index_constituents(self, *, code: str, date: Optional[date] = None,
+                                 to_frame=True) -> Union[List[Dict], pd.DataFrame]:
+        return self._to_dataframe(to_frame,
+                                  self.stock_fetch.fetch_index_constituents(code, date))
+
     def fetch_stock_info(self, *, to_frame=True) -> Union[List[Dict], pd.DataFrame]:
         return self._to_dataframe(to_frame,
                                   self.stock_fetch.fetch_stock_info())
 
+    def fetch_overseas_stock_info(self, *, market: int,
+                                  to_frame=True) -> Union[List[Dict], pd.DataFrame]:
+        return self._to_dataframe(to_frame,
+                                  self.stock_fetch.fetch_overseas_stock_info(market=market))
+
     def fetch_stock_is_margin(self, *, to_frame=True) -> Union[Set[str], pd.DataFrame]:
         data = self.stock_fetch.fetch_stock_is_margin()
         if to_frame:
@@ -265,6 +438,15 @@ class MyBlockFetch:
         data['bars'] = self._to_dataframe(to_frame, data['bars'])
         return data
 
+    def fetch_stock_minute_bar(self, *, code: str, name: Optional[str] = None,
+                               freq: Optional[int] = None,
+                               start: Optional[date] = None, end: Optional[date] = None,
+                               to_frame=True) -> Union[Dict, pd.DataFrame]:
+        data = self.stock_fetch.fetch_stock_minute_bar(code=code, name=name,
+                                                       freq=freq, start=start, end=end)
+        data['bars'] = self._to_dataframe(to_frame, data['bars'])
+        return data
+
     def fetch_stock_index(self, *, index_date=None, to_frame=True) -> Union[Dict[str, Dict], pd.DataFrame]:
         data = self.stock_fetch.fetch_stock_index(index_date)
         if to_frame:
@@ -312,11 +494,101 @@ class MyBlockFetch:
         return self._to_dataframe(to_frame,
                                   self.stock_fetch.fetch_stock_yjbb(year, season))
 
+    def fetch_stock_balance_sheet(self, *, year: int, season: int,
+                                  to_frame=True) -> Union[List[Dict], pd.DataFrame]:
+        return self._to_dataframe(to_frame,
+                                  self.stock_fetch.fetch_stock_balance_sheet(year, season))
+
+    def fetch_stock_income(self, *, year: int, season: int,
+                           to_frame=True) -> Union[List[Dict], pd.DataFrame]:
+        return self._to_dataframe(to_frame,
+                                  self.stock_fetch.fetch_stock_income(year, season))
+
+    def fetch_stock_cash_flow(self, *, year: int, season: int,
+                              to_frame=True) -> Union[List[Dict], pd.DataFrame]:
+        return self._to_dataframe(to_frame,
+                                  self.stock_fetch.fetch_stock_cash_flow(year, season))
+
+    def fetch_stock_holder_num(self, *, year: int, season: int,
+                               to_frame=True) -> Union[List[Dict], pd.DataFrame]:
+        return self._to_dataframe(to_frame,
+                                  self.stock_fetch.fetch_stock_holder_num(year, season))
+
+    def fetch_stock_top_holders(self, *, year: int, season: int, is_float: bool = True,
+                                to_frame=True) -> Union[List[Dict], pd.DataFrame]:
+        return self._to_dataframe(to_frame,
+                                  self.stock_fetch.fetch_stock_top_holders(year, season, is_float))
+
     def fetch_stock_margin(self, *, code: str, start: Optional[date] = None, end: Optional[date] = None,
                            to_frame=True) -> Union[List[Dict], pd.DataFrame]:
         return self._to_dataframe(to_frame,
                                   self.stock_fetch.fetch_stock_margin(code, start, end))
 
+    def fetch_stock_north_hold(self, *, date: date,
+                               to_frame=True) -> Union[List[Dict], pd.DataFrame]:
+        return self._to_dataframe(to_frame,
+                                  self.stock_fetch.fetch_stock_north_hold(date))
+
+    def fetch_north_flow(self, *, start: Optional[date] = None, end: Optional[date] = None,
+                         to_frame=True) -> Union[List[Dict], pd.DataFrame]:
+        return self._to_dataframe(to_frame,
+                                  self.stock_fetch.fetch_north_flow(start, end))
+
+    def fetch_stock_dragon_tiger(self, *, date: date,
+                                 to_frame=True) -> Union[List[Dict], pd.DataFrame]:
+        return self._to_dataframe(to_frame,
+                                  self.stock_fetch.fetch_stock_dragon_tiger(date))
+
+    def fetch_stock_limit_pool(self, *, date: date, pool: int = 1,
+                               to_frame=True) -> Union[List[Dict], pd.DataFrame]:
+        return self._to_dataframe(to_frame,
+                                  self.stock_fetch.fetch_stock_limit_pool(date, pool))
+
+    def fetch_stock_st(self, *, to_frame=True) -> Union[List[Dict], pd.DataFrame]:
+        return self._to_dataframe(to_frame,
+                                  self.stock_fetch.fetch_stock_st())
+
+    def fetch_stock_st_notice(self, *, date: date,
+                              to_frame=True) -> Union[List[Dict], pd.DataFrame]:
+        return self._to_dataframe(to_frame,
+                                  self.stock_fetch.fetch_stock_st_notice(date))
+
+    def fetch_stock_st_history(self, *, to_frame=True) -> Union[List[Dict], pd.DataFrame]:
+        return self._to_dataframe(to_frame,
+                                  self.stock_fetch.fetch_stock_st_history())
+
+    def fetch_stock_suspend(self, *, date: date,
+                            to_frame=True) -> Union[List[Dict], pd.DataFrame]:
+        return self._to_dataframe(to_frame,
+                                  self.stock_fetch.fetch_stock_suspend(date))
+
+    def fetch_stock_delist(self, *, to_frame=True) -> Union[List[Dict], pd.DataFrame]:
+        return self._to_dataframe(to_frame,
+                                  self.stock_fetch.fetch_stock_delist())
+
+    def fetch_stock_corp_action(self, *, code: Optional[str] = None, start: Optional[date] = None,
+                                end: Optional[date] = None,
+                                to_frame=True) -> Union[List[Dict], pd.DataFrame]:
+        return self._to_dataframe(to_frame,
+                                  self.stock_fetch.fetch_stock_corp_action(code, start, end))
+
     def fetch_stock_rt_quot(self, *, code: List[str], to_frame=True) -> Union[Dict[str, Dict], pd.DataFrame]:
         return self._to_dataframe(to_frame,
                                   self.stock_fetch.fetch_stock_rt_quot(code))
+
+    def fetch_stock_order_book(self, *, code: List[str], to_frame=True) -> Union[Dict[str, Dict], pd.DataFrame]:
+        return self._to_dataframe(to_frame,
+                                  self.stock_fetch.fetch_stock_order_book(code))
+
+    def fetch_stock_ticks(self, *, code: str, trade_date: date, to_frame=True) -> Union[List[Dict], pd.DataFrame]:
+        return self._to_dataframe(to_frame,
+                                  self.stock_fetch.fetch_stock_ticks(code, trade_date))
+
+    def fetch_stock_time_share(self, *, code: str, to_frame=True) -> Dict:
+        data = self.stock_fetch.fetch_stock_time_share(code)
+        data['points'] = self._to_dataframe(to_frame, data['points'])
+        return data
+
+    def fetch_stock_auction(self, *, code: str, to_frame=True) -> Union[List[Dict], pd.DataFrame]:
+        return self._to_dataframe(to_frame,
+                                  self.stock_fetch.fetch_stock_auction(code))
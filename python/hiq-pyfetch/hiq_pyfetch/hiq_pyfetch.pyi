@@ -2,10 +2,12 @@ from typing import List, Dict, Optional, Set
 from datetime import date
 
 
-async def fetch_trade_date() -> Set[int]:
+async def fetch_trade_date(proxy: Optional[str] = None, connect_timeout: Optional[float] = None,
+                           read_timeout: Optional[float] = None) -> Set[int]:
     pass
 
-def block_fetch_trade_date() -> Set[int]:
+def block_fetch_trade_date(proxy: Optional[str] = None, connect_timeout: Optional[float] = None,
+                           read_timeout: Optional[float] = None) -> Set[int]:
     pass
 
 async def fetch_next_trade_date(d: date) -> int:
@@ -20,13 +22,63 @@ async def fetch_prev_trade_date(d: date) -> int:
 def block_fetch_prev_trade_date(d: date) -> int:
     pass
 
+def load_trade_date_file(path: str) -> Set[int]:
+    pass
+
+async def update_trade_date_file(path: str) -> Set[int]:
+    pass
+
+def block_update_trade_date_file(path: str) -> Set[int]:
+    pass
+
 
 def to_std_code(int, str) -> str:
     pass
 
 
+def security_id(code: str) -> Dict:
+    pass
+
+
+def set_rate_limit(host: str, per_second: float, max_in_flight: int):
+    pass
+
+
+class TradeCalendar:
+    def __init__(self, dates: Optional[List[date]] = None):
+        pass
+
+    def __len__(self) -> int:
+        pass
+
+    def __contains__(self, d: date) -> bool:
+        pass
+
+    def first(self) -> Optional[date]:
+        pass
+
+    def last(self) -> Optional[date]:
+        pass
+
+    def next(self, d: date) -> Optional[date]:
+        pass
+
+    def prev(self, d: date) -> Optional[date]:
+        pass
+
+    def offset(self, d: date, n: int) -> Optional[date]:
+        pass
+
+    def trading_days_between(self, start: date, end: date) -> int:
+        pass
+
+    def range(self, start: date, end: date) -> List[date]:
+        pass
+
+
 class BondFetch:
-    def __init__(self):
+    def __init__(self, proxy: Optional[str] = None, connect_timeout: Optional[float] = None,
+                 read_timeout: Optional[float] = None):
         pass
 
     async def fetch_bond_info(self) -> List[Dict]:
@@ -37,9 +89,18 @@ class BondFetch:
                        freq: Optional[int],
                        start: Optional[date], end: Optional[date]) -> Dict:
         pass
+
+    async def fetch_bond_minute_bar(self, code: str, name: Optional[str],
+                                    freq: Optional[int],
+                                    start: Optional[date], end: Optional[date]) -> Dict:
+        pass
+
+    async def fetch_bond_order_book(self, code: List[str]) -> Dict[str, Dict]:
+        pass
     
 class BlockBondFetch:
-    def __init__(self):
+    def __init__(self, proxy: Optional[str] = None, connect_timeout: Optional[float] = None,
+                 read_timeout: Optional[float] = None):
         pass
 
     async def fetch_bond_info(self) -> List[Dict]:
@@ -51,9 +112,18 @@ class BlockBondFetch:
                        start: Optional[date], end: Optional[date]) -> Dict:
         pass
 
+    def fetch_bond_minute_bar(self, code: str, name: Optional[str],
+                              freq: Optional[int],
+                              start: Optional[date], end: Optional[date]) -> Dict:
+        pass
+
+    def fetch_bond_order_book(self, code: List[str]) -> Dict[str, Dict]:
+        pass
+
 
 class FundFetch:
-    def __init__(self):
+    def __init__(self, proxy: Optional[str] = None, connect_timeout: Optional[float] = None,
+                 read_timeout: Optional[float] = None):
         pass
 
     async def fetch_fund_info(self) -> List[Dict]:
@@ -68,8 +138,17 @@ class FundFetch:
                        start: Optional[date], end: Optional[date]) -> Dict:
         pass
 
+    async def fetch_fund_minute_bar(self, code: str, name: Optional[str],
+                                    freq: Optional[int],
+                                    start: Optional[date], end: Optional[date]) -> Dict:
+        pass
+
+    async def fetch_fund_order_book(self, code: List[str]) -> Dict[str, Dict]:
+        pass
+
 class BlockFundFetch:
-    def __init__(self):
+    def __init__(self, proxy: Optional[str] = None, connect_timeout: Optional[float] = None,
+                 read_timeout: Optional[float] = None):
         pass
 
     def fetch_fund_info(self) -> List[Dict]:
@@ -84,11 +163,20 @@ class BlockFundFetch:
                        start: Optional[date], end: Optional[date]) -> Dict:
         pass
 
+    def fetch_fund_minute_bar(self, code: str, name: Optional[str],
+                              freq: Optional[int],
+                              start: Optional[date], end: Optional[date]) -> Dict:
+        pass
+
+    def fetch_fund_order_book(self, code: List[str]) -> Dict[str, Dict]:
+        pass
+
 
 
 
 class StockFetch:
-    def __init__(self):
+    def __init__(self, proxy: Optional[str] = None, connect_timeout: Optional[float] = None,
+                 read_timeout: Optional[float] = None):
         pass
 
     async def fetch_index_info(self) -> List[Dict]:
@@ -99,9 +187,15 @@ class StockFetch:
                         start: Optional[date] = None, end: Optional[date] = None) -> Dict:
         pass
 
+    async def fetch_index_constituents(self, code: str, date: Optional[date] = None) -> List[Dict]:
+        pass
+
     async def fetch_stock_info(self) -> List[Dict]:
         pass
 
+    async def fetch_overseas_stock_info(self, market: int) -> List[Dict]:
+        pass
+
     async def fetch_stock_is_margin(self) -> Set[str]:
         pass
 
@@ -110,6 +204,11 @@ class StockFetch:
                         start: Optional[date] = None, end: Optional[date] = None) -> Dict:
         pass
 
+    async def fetch_stock_minute_bar(self, code: str, name: Optional[str] = None,
+                                     freq: Optional[int] = None,
+                                     start: Optional[date] = None, end: Optional[date] = None) -> Dict:
+        pass
+
     async def fetch_stock_index(self, index_date: Optional[date]) -> Dict[str, Dict]:
         pass
 
@@ -137,15 +236,74 @@ class StockFetch:
     async def fetch_stock_yjbb(self, year: int, season: int) -> List[Dict]:
         pass
 
+    async def fetch_stock_balance_sheet(self, year: int, season: int) -> List[Dict]:
+        pass
+
+    async def fetch_stock_income(self, year: int, season: int) -> List[Dict]:
+        pass
+
+    async def fetch_stock_cash_flow(self, year: int, season: int) -> List[Dict]:
+        pass
+
+    async def fetch_stock_holder_num(self, year: int, season: int) -> List[Dict]:
+        pass
+
+    async def fetch_stock_top_holders(self, year: int, season: int, is_float: bool) -> List[Dict]:
+        pass
+
     async def fetch_stock_margin(self, code: str, start: Optional[date] = None, end: Optional[date] = None) -> List[Dict]:
         pass
 
+    async def fetch_stock_north_hold(self, date: date) -> List[Dict]:
+        pass
+
+    async def fetch_north_flow(self, start: Optional[date] = None, end: Optional[date] = None) -> List[Dict]:
+        pass
+
+    async def fetch_stock_dragon_tiger(self, date: date) -> List[Dict]:
+        pass
+
+    async def fetch_stock_limit_pool(self, date: date, pool: int = 1) -> List[Dict]:
+        pass
+
+    async def fetch_stock_st(self) -> List[Dict]:
+        pass
+
+    async def fetch_stock_st_history(self) -> List[Dict]:
+        pass
+
+    async def fetch_stock_st_notice(self, date: date) -> List[Dict]:
+        pass
+
+    async def fetch_stock_suspend(self, date: date) -> List[Dict]:
+        pass
+
+    async def fetch_stock_delist(self) -> List[Dict]:
+        pass
+
+    async def fetch_stock_corp_action(self, code: Optional[str] = None, start: Optional[date] = None,
+                                      end: Optional[date] = None) -> List[Dict]:
+        pass
+
     async def fetch_stock_rt_quot(self, code: List[str]) -> Dict[str, Dict]:
         pass
 
+    async def fetch_stock_order_book(self, code: List[str]) -> Dict[str, Dict]:
+        pass
+
+    async def fetch_stock_ticks(self, code: str, date: date) -> List[Dict]:
+        pass
+
+    async def fetch_stock_time_share(self, code: str) -> Dict:
+        pass
+
+    async def fetch_stock_auction(self, code: str) -> List[Dict]:
+        pass
+
 
 class BlockStockFetch:
-    def __init__(self):
+    def __init__(self, proxy: Optional[str] = None, connect_timeout: Optional[float] = None,
+                 read_timeout: Optional[float] = None):
         pass
 
     def fetch_index_info(self) -> List[Dict]:
@@ -156,9 +314,15 @@ class BlockStockFetch:
                         start: Optional[date] = None, end: Optional[date] = None) -> Dict:
         pass
 
+    def fetch_index_constituents(self, code: str, date: Optional[date] = None) -> List[Dict]:
+        pass
+
     def fetch_stock_info(self) -> List[Dict]:
         pass
 
+    def fetch_overseas_stock_info(self, market: int) -> List[Dict]:
+        pass
+
     def fetch_stock_is_margin(self) -> Set[str]:
         pass
 
@@ -167,6 +331,11 @@ class BlockStockFetch:
                         start: Optional[date] = None, end: Optional[date] = None) -> Dict:
         pass
 
+    def fetch_stock_minute_bar(self, code: str, name: Optional[str] = None,
+                               freq: Optional[int] = None,
+                               start: Optional[date] = None, end: Optional[date] = None) -> Dict:
+        pass
+
     def fetch_stock_index(self, index_date: Optional[date]) -> Dict[str, Dict]:
         pass
 
@@ -194,8 +363,66 @@ class BlockStockFetch:
     def fetch_stock_yjbb(self, year: int, season: int) -> List[Dict]:
         pass
 
+    def fetch_stock_balance_sheet(self, year: int, season: int) -> List[Dict]:
+        pass
+
+    def fetch_stock_income(self, year: int, season: int) -> List[Dict]:
+        pass
+
+    def fetch_stock_cash_flow(self, year: int, season: int) -> List[Dict]:
+        pass
+
+    def fetch_stock_holder_num(self, year: int, season: int) -> List[Dict]:
+        pass
+
+    def fetch_stock_top_holders(self, year: int, season: int, is_float: bool) -> List[Dict]:
+        pass
+
     def fetch_stock_margin(self, code: str, start: Optional[date] = None, end: Optional[date] = None) -> List[Dict]:
         pass
 
+    def fetch_stock_north_hold(self, date: date) -> List[Dict]:
+        pass
+
+    def fetch_north_flow(self, start: Optional[date] = None, end: Optional[date] = None) -> List[Dict]:
+        pass
+
+    def fetch_stock_dragon_tiger(self, date: date) -> List[Dict]:
+        pass
+
+    def fetch_stock_limit_pool(self, date: date, pool: int = 1) -> List[Dict]:
+        pass
+
+    def fetch_stock_st(self) -> List[Dict]:
+        pass
+
+    def fetch_stock_st_history(self) -> List[Dict]:
+        pass
+
+    def fetch_stock_st_notice(self, date: date) -> List[Dict]:
+        pass
+
+    def fetch_stock_suspend(self, date: date) -> List[Dict]:
+        pass
+
+    def fetch_stock_delist(self) -> List[Dict]:
+        pass
+
+    def fetch_stock_corp_action(self, code: Optional[str] = None, start: Optional[date] = None,
+                                end: Optional[date] = None) -> List[Dict]:
+        pass
+
     def fetch_stock_rt_quot(self, code: List[str]) -> Dict[str, Dict]:
         pass
+
+    def fetch_stock_order_book(self, code: List[str]) -> Dict[str, Dict]:
+        pass
+
+    def fetch_stock_ticks(self, code: str, date: date) -> List[Dict]:
+        pass
+
+    def fetch_stock_time_share(self, code: str) -> Dict:
+        pass
+
+    def fetch_stock_auction(self, code: str) -> List[Dict]:
+        pass
@@ -1,6 +1,7 @@
+use std::collections::HashMap;
 use std::sync::Arc;
 
-use crate::{BondBar, BondInfo};
+use crate::{fetch_config, BondBar, BondInfo, MinuteBar, OrderBook};
 use chrono::NaiveDate;
 use hiq_pycommon::runtime;
 use pyo3::exceptions::PyException;
@@ -14,10 +15,17 @@ pub(crate) struct BondFetch {
 #[pymethods]
 impl BondFetch {
     #[new]
-    fn new() -> Self {
-        BondFetch {
-            fetch: Arc::new(hiq_fetch::bond_fetch()),
-        }
+    fn new(
+        proxy: Option<String>,
+        connect_timeout: Option<f64>,
+        read_timeout: Option<f64>,
+    ) -> PyResult<Self> {
+        let config = fetch_config(proxy, connect_timeout, read_timeout)?;
+        let fetch =
+            hiq_fetch::bond_fetch(&config).map_err(|e| PyException::new_err(e.to_string()))?;
+        Ok(BondFetch {
+            fetch: Arc::new(fetch),
+        })
     }
     /// 获取可转债基本信息
     fn fetch_bond_info<'a>(&self, py: Python<'a>) -> PyResult<&'a PyAny> {
@@ -51,11 +59,7 @@ impl BondFetch {
 
         let fetch = self.fetch.clone();
         pyo3_asyncio::tokio::future_into_py(py, async move {
-            let fr: Option<hiq_fetch::BarFreq> = if let Some(v) = freq {
-                Some(v.into())
-            } else {
-                None
-            };
+            let fr: Option<hiq_fetch::BarFreq> = freq.map(|v| v.into());
 
             let bar: BondBar = fetch
                 .fetch_bond_bar(
@@ -73,6 +77,45 @@ impl BondFetch {
             Ok(bar)
         })
     }
+    /// 可转债分钟k线，分页获取
+    fn fetch_bond_minute_bar<'a>(
+        &self,
+        py: Python<'a>,
+        code: &str,
+        name: Option<&str>,
+        freq: Option<i32>,
+        start: Option<NaiveDate>,
+        end: Option<NaiveDate>,
+    ) -> PyResult<&'a PyAny> {
+        let fetch = self.fetch.clone();
+        let code = code.to_owned();
+        let name = name.map(String::from);
+        pyo3_asyncio::tokio::future_into_py(py, async move {
+            let fr: Option<hiq_fetch::BarFreq> = freq.map(|v| v.into());
+            let name = name.as_deref();
+            let bar: MinuteBar = fetch
+                .fetch_bond_minute_bar(&code[..], name, fr, start, end)
+                .await
+                .map_err(|e| PyException::new_err(e.to_string()))?
+                .into();
+            Ok(bar)
+        })
+    }
+    /// 可转债五档盘口
+    fn fetch_bond_order_book<'a>(&self, py: Python<'a>, code: Vec<&str>) -> PyResult<&'a PyAny> {
+        let fetch = self.fetch.clone();
+        let code: Vec<_> = code.into_iter().map(String::from).collect();
+        pyo3_asyncio::tokio::future_into_py(py, async move {
+            let code: Vec<_> = code.iter().map(|e| &e[..]).collect();
+            Ok(fetch
+                .fetch_bond_order_book(code)
+                .await
+                .map_err(|e| PyException::new_err(e.to_string()))?
+                .into_iter()
+                .map(|(key, value)| (key, OrderBook::from(value)))
+                .collect::<HashMap<String, OrderBook>>())
+        })
+    }
 }
 
 #[pyclass]
@@ -83,10 +126,17 @@ pub(crate) struct BlockBondFetch {
 #[pymethods]
 impl BlockBondFetch {
     #[new]
-    fn new() -> Self {
-        BlockBondFetch {
-            fetch: Box::new(hiq_fetch::bond_fetch()),
-        }
+    fn new(
+        proxy: Option<String>,
+        connect_timeout: Option<f64>,
+        read_timeout: Option<f64>,
+    ) -> PyResult<Self> {
+        let config = fetch_config(proxy, connect_timeout, read_timeout)?;
+        let fetch =
+            hiq_fetch::bond_fetch(&config).map_err(|e| PyException::new_err(e.to_string()))?;
+        Ok(BlockBondFetch {
+            fetch: Box::new(fetch),
+        })
     }
     /// 获取可转债基本信息
     fn fetch_bond_info(&self) -> PyResult<Vec<BondInfo>> {
@@ -108,11 +158,7 @@ impl BlockBondFetch {
         start: Option<NaiveDate>,
         end: Option<NaiveDate>,
     ) -> PyResult<BondBar> {
-        let fr: Option<hiq_fetch::BarFreq> = if let Some(v) = freq {
-            Some(v.into())
-        } else {
-            None
-        };
+        let fr: Option<hiq_fetch::BarFreq> = freq.map(|v| v.into());
         Ok(runtime()?
             .block_on(
                 self.fetch
@@ -121,4 +167,28 @@ impl BlockBondFetch {
             .map_err(|e| PyException::new_err(e.to_string()))?
             .into())
     }
+    /// 可转债分钟k线，分页获取
+    fn fetch_bond_minute_bar(
+        &self,
+        code: &str,
+        name: Option<&str>,
+        freq: Option<i32>,
+        start: Option<NaiveDate>,
+        end: Option<NaiveDate>,
+    ) -> PyResult<MinuteBar> {
+        let fr: Option<hiq_fetch::BarFreq> = freq.map(|v| v.into());
+        Ok(runtime()?
+            .block_on(self.fetch.fetch_bond_minute_bar(code, name, fr, start, end))
+            .map_err(|e| PyException::new_err(e.to_string()))?
+            .into())
+    }
+    /// 可转债五档盘口
+    fn fetch_bond_order_book(&self, code: Vec<&str>) -> PyResult<HashMap<String, OrderBook>> {
+        Ok(runtime()?
+            .block_on(self.fetch.fetch_bond_order_book(code))
+            .map_err(|e| PyException::new_err(e.to_string()))?
+            .into_iter()
+            .map(|(key, value)| (key, OrderBook::from(value)))
+            .collect())
+    }
 }
@@ -0,0 +1,54 @@
+use chrono::NaiveDate;
+use pyo3::prelude::*;
+
+#[pyclass]
+pub(crate) struct TradeCalendar {
+    inner: hiq_fetch::TradeCalendar,
+}
+
+#[pymethods]
+impl TradeCalendar {
+    /// 指定交易日，不指定时使用内置(或已更新)的交易日历
+    #[new]
+    fn new(dates: Option<Vec<NaiveDate>>) -> Self {
+        let inner = match dates {
+            Some(dates) => hiq_fetch::TradeCalendar::new(dates),
+            None => hiq_fetch::trade_calendar(),
+        };
+        TradeCalendar { inner }
+    }
+    fn __len__(&self) -> usize {
+        self.inner.len()
+    }
+    fn __contains__(&self, date: NaiveDate) -> bool {
+        self.inner.contains(&date)
+    }
+    /// 第一个交易日
+    fn first(&self) -> Option<NaiveDate> {
+        self.inner.first()
+    }
+    /// 最后一个交易日
+    fn last(&self) -> Option<NaiveDate> {
+        self.inner.last()
+    }
+    /// 某日之后的第一个交易日(不含当日)
+    fn next(&self, date: NaiveDate) -> Option<NaiveDate> {
+        self.inner.next(&date)
+    }
+    /// 某日之前的第一个交易日(不含当日)
+    fn prev(&self, date: NaiveDate) -> Option<NaiveDate> {
+        self.inner.prev(&date)
+    }
+    /// 某日前后第n个交易日，n > 0往后，n < 0往前
+    fn offset(&self, date: NaiveDate, n: i32) -> Option<NaiveDate> {
+        self.inner.offset(&date, n)
+    }
+    /// [start, end]之间的交易日数量
+    fn trading_days_between(&self, start: NaiveDate, end: NaiveDate) -> usize {
+        self.inner.trading_days_between(&start, &end)
+    }
+    /// [start, end]之间的交易日
+    fn range(&self, start: NaiveDate, end: NaiveDate) -> Vec<NaiveDate> {
+        self.inner.range(&start, &end).collect()
+    }
+}
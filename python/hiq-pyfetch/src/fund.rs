@@ -1,6 +1,7 @@
+use std::collections::HashMap;
 use std::sync::Arc;
 
-use crate::{FundBar, FundInfo, FundNet};
+use crate::{fetch_config, FundBar, FundInfo, FundNet, MinuteBar, OrderBook};
 use chrono::NaiveDate;
 use hiq_pycommon::runtime;
 use pyo3::exceptions::PyException;
@@ -14,10 +15,17 @@ pub(crate) struct FundFetch {
 #[pymethods]
 impl FundFetch {
     #[new]
-    fn new() -> Self {
-        FundFetch {
-            fetch: Arc::new(hiq_fetch::fund_fetch()),
-        }
+    fn new(
+        proxy: Option<String>,
+        connect_timeout: Option<f64>,
+        read_timeout: Option<f64>,
+    ) -> PyResult<Self> {
+        let config = fetch_config(proxy, connect_timeout, read_timeout)?;
+        let fetch =
+            hiq_fetch::fund_fetch(&config).map_err(|e| PyException::new_err(e.to_string()))?;
+        Ok(FundFetch {
+            fetch: Arc::new(fetch),
+        })
     }
     /// etf基金基本信息
     fn fetch_fund_info<'a>(&self, py: Python<'a>) -> PyResult<&'a PyAny> {
@@ -70,11 +78,7 @@ impl FundFetch {
         let code = code.to_owned();
         let name = name.map(String::from);
         pyo3_asyncio::tokio::future_into_py(py, async move {
-            let fr: Option<hiq_fetch::BarFreq> = if let Some(v) = freq {
-                Some(v.into())
-            } else {
-                None
-            };
+            let fr: Option<hiq_fetch::BarFreq> = freq.map(|v| v.into());
             let name = name.as_deref();
             let bar: FundBar = fetch
                 .fetch_fund_bar(&code[..], name, fr, start, end)
@@ -84,6 +88,45 @@ impl FundFetch {
             Ok(bar)
         })
     }
+    /// etf基金分钟k线，分页获取
+    fn fetch_fund_minute_bar<'a>(
+        &self,
+        py: Python<'a>,
+        code: &str,
+        name: Option<&str>,
+        freq: Option<i32>,
+        start: Option<NaiveDate>,
+        end: Option<NaiveDate>,
+    ) -> PyResult<&'a PyAny> {
+        let fetch = self.fetch.clone();
+        let code = code.to_owned();
+        let name = name.map(String::from);
+        pyo3_asyncio::tokio::future_into_py(py, async move {
+            let fr: Option<hiq_fetch::BarFreq> = freq.map(|v| v.into());
+            let name = name.as_deref();
+            let bar: MinuteBar = fetch
+                .fetch_fund_minute_bar(&code[..], name, fr, start, end)
+                .await
+                .map_err(|e| PyException::new_err(e.to_string()))?
+                .into();
+            Ok(bar)
+        })
+    }
+    /// etf基金五档盘口
+    fn fetch_fund_order_book<'a>(&self, py: Python<'a>, code: Vec<&str>) -> PyResult<&'a PyAny> {
+        let fetch = self.fetch.clone();
+        let code: Vec<_> = code.into_iter().map(String::from).collect();
+        pyo3_asyncio::tokio::future_into_py(py, async move {
+            let code: Vec<_> = code.iter().map(|e| &e[..]).collect();
+            Ok(fetch
+                .fetch_fund_order_book(code)
+                .await
+                .map_err(|e| PyException::new_err(e.to_string()))?
+                .into_iter()
+                .map(|(key, value)| (key, OrderBook::from(value)))
+                .collect::<HashMap<String, OrderBook>>())
+        })
+    }
 }
 
 #[pyclass]
@@ -94,10 +137,17 @@ pub(crate) struct BlockFundFetch {
 #[pymethods]
 impl BlockFundFetch {
     #[new]
-    fn new() -> Self {
-        BlockFundFetch {
-            fetch: Box::new(hiq_fetch::fund_fetch()),
-        }
+    fn new(
+        proxy: Option<String>,
+        connect_timeout: Option<f64>,
+        read_timeout: Option<f64>,
+    ) -> PyResult<Self> {
+        let config = fetch_config(proxy, connect_timeout, read_timeout)?;
+        let fetch =
+            hiq_fetch::fund_fetch(&config).map_err(|e| PyException::new_err(e.to_string()))?;
+        Ok(BlockFundFetch {
+            fetch: Box::new(fetch),
+        })
     }
     /// etf基金基本信息
     fn fetch_fund_info(&self) -> PyResult<Vec<FundInfo>> {
@@ -133,14 +183,34 @@ impl BlockFundFetch {
         start: Option<NaiveDate>,
         end: Option<NaiveDate>,
     ) -> PyResult<FundBar> {
-        let fr: Option<hiq_fetch::BarFreq> = if let Some(v) = freq {
-            Some(v.into())
-        } else {
-            None
-        };
+        let fr: Option<hiq_fetch::BarFreq> = freq.map(|v| v.into());
         Ok(runtime()?
             .block_on(self.fetch.fetch_fund_bar(code, name, fr, start, end))
             .map_err(|e| PyException::new_err(e.to_string()))?
             .into())
     }
+    /// etf基金分钟k线，分页获取
+    fn fetch_fund_minute_bar(
+        &self,
+        code: &str,
+        name: Option<&str>,
+        freq: Option<i32>,
+        start: Option<NaiveDate>,
+        end: Option<NaiveDate>,
+    ) -> PyResult<MinuteBar> {
+        let fr: Option<hiq_fetch::BarFreq> = freq.map(|v| v.into());
+        Ok(runtime()?
+            .block_on(self.fetch.fetch_fund_minute_bar(code, name, fr, start, end))
+            .map_err(|e| PyException::new_err(e.to_string()))?
+            .into())
+    }
+    /// etf基金五档盘口
+    fn fetch_fund_order_book(&self, code: Vec<&str>) -> PyResult<HashMap<String, OrderBook>> {
+        Ok(runtime()?
+            .block_on(self.fetch.fetch_fund_order_book(code))
+            .map_err(|e| PyException::new_err(e.to_string()))?
+            .into_iter()
+            .map(|(key, value)| (key, OrderBook::from(value)))
+            .collect())
+    }
 }
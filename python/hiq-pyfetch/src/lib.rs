@@ -1,4 +1,5 @@
 mod bond;
+mod calendar;
 mod fund;
 mod stock;
 
@@ -6,31 +7,65 @@ use chrono::NaiveDate;
 use pyo3::exceptions::PyException;
 use pyo3::prelude::*;
 use std::collections::BTreeSet;
+use std::time::Duration;
 
 use crate::bond::{BlockBondFetch, BondFetch};
+use crate::calendar::TradeCalendar;
 use crate::fund::{BlockFundFetch, FundFetch};
 use crate::stock::{BlockStockFetch, StockFetch};
 pub(crate) use hiq_pycommon::*;
 
+/// 远程获取数据的http配置，超时单位为秒
+pub(crate) fn fetch_config(
+    proxy: Option<String>,
+    connect_timeout: Option<f64>,
+    read_timeout: Option<f64>,
+) -> PyResult<hiq_fetch::FetchConfig> {
+    let timeout = |secs: Option<f64>| {
+        secs.map(|secs| {
+            Duration::try_from_secs_f64(secs)
+                .map_err(|e| PyException::new_err(format!("invalid timeout {}: {}", secs, e)))
+        })
+        .transpose()
+    };
+    Ok(hiq_fetch::FetchConfig {
+        proxy,
+        connect_timeout: timeout(connect_timeout)?,
+        read_timeout: timeout(read_timeout)?,
+        ..Default::default()
+    })
+}
+
 /// Fetch trade_date.
 #[pyfunction]
-fn fetch_trade_date(py: Python) -> PyResult<&PyAny> {
+fn fetch_trade_date<'a>(
+    py: Python<'a>,
+    proxy: Option<String>,
+    connect_timeout: Option<f64>,
+    read_timeout: Option<f64>,
+) -> PyResult<&'a PyAny> {
+    let config = fetch_config(proxy, connect_timeout, read_timeout)?;
     pyo3_asyncio::tokio::future_into_py(py, async move {
-        Ok(hiq_fetch::fetch_trade_date()
+        Ok(hiq_fetch::fetch_trade_date_with(&config)
             .await
             .map_err(|e| PyException::new_err(e.to_string()))?)
     })
 }
 
 #[pyfunction]
-fn block_fetch_trade_date() -> PyResult<BTreeSet<i32>> {
+fn block_fetch_trade_date(
+    proxy: Option<String>,
+    connect_timeout: Option<f64>,
+    read_timeout: Option<f64>,
+) -> PyResult<BTreeSet<i32>> {
+    let config = fetch_config(proxy, connect_timeout, read_timeout)?;
     Ok(runtime()?
-        .block_on(hiq_fetch::fetch_trade_date())
+        .block_on(hiq_fetch::fetch_trade_date_with(&config))
         .map_err(|e| PyException::new_err(e.to_string()))?)
 }
 
 #[pyfunction]
-fn fetch_next_trade_date(py: Python, date: NaiveDate) -> PyResult<&PyAny> {
+fn fetch_next_trade_date<'a>(py: Python<'a>, date: NaiveDate) -> PyResult<&'a PyAny> {
     pyo3_asyncio::tokio::future_into_py(py, async move {
         Ok(hiq_fetch::fetch_next_trade_date(&date)
             .await
@@ -46,7 +81,7 @@ fn block_fetch_next_trade_date(date: NaiveDate) -> PyResult<i32> {
 }
 
 #[pyfunction]
-fn fetch_prev_trade_date(py: Python, date: NaiveDate) -> PyResult<&PyAny> {
+fn fetch_prev_trade_date<'a>(py: Python<'a>, date: NaiveDate) -> PyResult<&'a PyAny> {
     pyo3_asyncio::tokio::future_into_py(py, async move {
         Ok(hiq_fetch::fetch_prev_trade_date(&date)
             .await
@@ -61,12 +96,48 @@ fn block_fetch_prev_trade_date(date: NaiveDate) -> PyResult<i32> {
         .map_err(|e| PyException::new_err(e.to_string()))?)
 }
 
+/// Load trade_date from file.
+#[pyfunction]
+fn load_trade_date_file(path: &str) -> PyResult<BTreeSet<i32>> {
+    hiq_fetch::load_trade_date_file(path).map_err(|e| PyException::new_err(e.to_string()))
+}
+
+/// Fetch trade_date and save to file.
+#[pyfunction]
+fn update_trade_date_file<'a>(py: Python<'a>, path: String) -> PyResult<&'a PyAny> {
+    pyo3_asyncio::tokio::future_into_py(py, async move {
+        hiq_fetch::update_trade_date_file(&path)
+            .await
+            .map_err(|e| PyException::new_err(e.to_string()))
+    })
+}
+
+#[pyfunction]
+fn block_update_trade_date_file(path: &str) -> PyResult<BTreeSet<i32>> {
+    runtime()?
+        .block_on(hiq_fetch::update_trade_date_file(path))
+        .map_err(|e| PyException::new_err(e.to_string()))
+}
+
 #[pyfunction]
 fn to_std_code(typ: i32, code: &str) -> PyResult<String> {
     let typ: hiq_fetch::MarketType = typ.into();
     Ok(hiq_fetch::to_std_code(typ, code))
 }
 
+/// Parse security id, returns dict of vendor formats.
+#[pyfunction]
+fn security_id(code: SecurityId) -> PyResult<SecurityId> {
+    Ok(code)
+}
+
+/// Set rate limit of host.
+#[pyfunction]
+fn set_rate_limit(host: &str, per_second: f64, max_in_flight: usize) -> PyResult<()> {
+    hiq_fetch::set_rate_limit(host, hiq_fetch::RateLimit::new(per_second, max_in_flight))
+        .map_err(|e| PyException::new_err(e.to_string()))
+}
+
 /// A Python module implemented in Rust.
 #[pymodule]
 fn hiq_pyfetch(_py: Python, m: &PyModule) -> PyResult<()> {
@@ -76,7 +147,13 @@ fn hiq_pyfetch(_py: Python, m: &PyModule) -> PyResult<()> {
     m.add_function(wrap_pyfunction!(block_fetch_next_trade_date, m)?)?;
     m.add_function(wrap_pyfunction!(fetch_prev_trade_date, m)?)?;
     m.add_function(wrap_pyfunction!(block_fetch_prev_trade_date, m)?)?;
+    m.add_function(wrap_pyfunction!(load_trade_date_file, m)?)?;
+    m.add_function(wrap_pyfunction!(update_trade_date_file, m)?)?;
+    m.add_function(wrap_pyfunction!(block_update_trade_date_file, m)?)?;
     m.add_function(wrap_pyfunction!(to_std_code, m)?)?;
+    m.add_function(wrap_pyfunction!(security_id, m)?)?;
+    m.add_function(wrap_pyfunction!(set_rate_limit, m)?)?;
+    m.add_class::<TradeCalendar>()?;
     m.add_class::<BondFetch>()?;
     m.add_class::<BlockBondFetch>()?;
     m.add_class::<FundFetch>()?;
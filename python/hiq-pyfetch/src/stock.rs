@@ -1,6 +1,9 @@
 use crate::{
-    StockBar, StockConcept, StockConceptBar, StockConceptDetail, StockIndex, StockIndustry,
-    StockIndustryBar, StockIndustryDetail, StockInfo, StockMargin, StockRtQuot, StockYJBB,
+    fetch_config, AuctionSnapshot, IndexConstituent, MinuteBar, NorthFlow, OrderBook,
+    StockBalanceSheet, StockBar, StockCashFlow, StockConcept, StockConceptBar, StockConceptDetail,
+    StockCorpAction, StockDragonTiger, StockHolderNum, StockIncome, StockIndex, StockIndustry,
+    StockIndustryBar, StockIndustryDetail, StockInfo, StockLimitPool, StockMargin, StockNorthHold,
+    StockRtQuot, StockStatus, StockStatusNotice, StockTopHolder, StockYJBB, Tick, TimeShare,
 };
 use chrono::NaiveDate;
 use hiq_pycommon::runtime;
@@ -19,10 +22,17 @@ pub(crate) struct StockFetch {
 #[pymethods]
 impl StockFetch {
     #[new]
-    fn new() -> Self {
-        StockFetch {
-            fetch: Arc::new(hiq_fetch::stock_fetch()),
-        }
+    fn new(
+        proxy: Option<String>,
+        connect_timeout: Option<f64>,
+        read_timeout: Option<f64>,
+    ) -> PyResult<Self> {
+        let config = fetch_config(proxy, connect_timeout, read_timeout)?;
+        let fetch =
+            hiq_fetch::stock_fetch(&config).map_err(|e| PyException::new_err(e.to_string()))?;
+        Ok(StockFetch {
+            fetch: Arc::new(fetch),
+        })
     }
     /// 股票大盘指数（列举几个而已）
     fn fetch_index_info<'a>(&self, py: Python<'a>) -> PyResult<&'a PyAny> {
@@ -52,11 +62,7 @@ impl StockFetch {
         let name = name.map(String::from);
         pyo3_asyncio::tokio::future_into_py(py, async move {
             let name = name.as_deref();
-            let fr: Option<hiq_fetch::BarFreq> = if let Some(v) = freq {
-                Some(v.into())
-            } else {
-                None
-            };
+            let fr: Option<hiq_fetch::BarFreq> = freq.map(|v| v.into());
             let bar: StockBar = fetch
                 .fetch_stock_bar(&code[..], name, fr, start, end)
                 .await
@@ -65,6 +71,25 @@ impl StockFetch {
             Ok(bar)
         })
     }
+    /// 指数成分股及权重，date为None时取最新一期
+    fn fetch_index_constituents<'a>(
+        &self,
+        py: Python<'a>,
+        code: &str,
+        date: Option<NaiveDate>,
+    ) -> PyResult<&'a PyAny> {
+        let fetch = self.fetch.clone();
+        let code = code.to_owned();
+        pyo3_asyncio::tokio::future_into_py(py, async move {
+            Ok(fetch
+                .fetch_index_constituents(&code, date)
+                .await
+                .map_err(|e| PyException::new_err(e.to_string()))?
+                .into_iter()
+                .map(IndexConstituent::from)
+                .collect::<Vec<_>>())
+        })
+    }
     /// 获取股票基本信息
     fn fetch_stock_info<'a>(&self, py: Python<'a>) -> PyResult<&'a PyAny> {
         let fetch = self.fetch.clone();
@@ -78,6 +103,19 @@ impl StockFetch {
                 .collect::<Vec<_>>())
         })
     }
+    /// 获取港股(3)或美股(4)基本信息
+    fn fetch_overseas_stock_info<'a>(&self, py: Python<'a>, market: i32) -> PyResult<&'a PyAny> {
+        let fetch = self.fetch.clone();
+        pyo3_asyncio::tokio::future_into_py(py, async move {
+            Ok(fetch
+                .fetch_overseas_stock_info(market.into())
+                .await
+                .map_err(|e| PyException::new_err(e.to_string()))?
+                .into_iter()
+                .map(StockInfo::from)
+                .collect::<Vec<_>>())
+        })
+    }
     /// 获取融资融券股票代码
     fn fetch_stock_is_margin<'a>(&self, py: Python<'a>) -> PyResult<&'a PyAny> {
         let fetch = self.fetch.clone();
@@ -103,11 +141,7 @@ impl StockFetch {
         let name = name.map(String::from);
         pyo3_asyncio::tokio::future_into_py(py, async move {
             let name = name.as_deref();
-            let fr: Option<hiq_fetch::BarFreq> = if let Some(v) = freq {
-                Some(v.into())
-            } else {
-                None
-            };
+            let fr: Option<hiq_fetch::BarFreq> = freq.map(|v| v.into());
             let bar: StockBar = fetch
                 .fetch_stock_bar(&code[..], name, fr, start, end)
                 .await
@@ -116,6 +150,30 @@ impl StockFetch {
             Ok(bar)
         })
     }
+    /// 股票分钟k线，分页获取
+    fn fetch_stock_minute_bar<'a>(
+        &self,
+        py: Python<'a>,
+        code: &str,
+        name: Option<&str>,
+        freq: Option<i32>,
+        start: Option<NaiveDate>,
+        end: Option<NaiveDate>,
+    ) -> PyResult<&'a PyAny> {
+        let fetch = self.fetch.clone();
+        let code = code.to_owned();
+        let name = name.map(String::from);
+        pyo3_asyncio::tokio::future_into_py(py, async move {
+            let name = name.as_deref();
+            let fr: Option<hiq_fetch::BarFreq> = freq.map(|v| v.into());
+            let bar: MinuteBar = fetch
+                .fetch_stock_minute_bar(&code[..], name, fr, start, end)
+                .await
+                .map_err(|e| PyException::new_err(e.to_string()))?
+                .into();
+            Ok(bar)
+        })
+    }
     /// 股票最新指标
     fn fetch_stock_index<'a>(
         &self,
@@ -255,6 +313,97 @@ impl StockFetch {
                 .collect::<Vec<_>>())
         })
     }
+    /// 资产负债表
+    fn fetch_stock_balance_sheet<'a>(
+        &self,
+        py: Python<'a>,
+        year: u16,
+        season: u16,
+    ) -> PyResult<&'a PyAny> {
+        let fetch = self.fetch.clone();
+        pyo3_asyncio::tokio::future_into_py(py, async move {
+            Ok(fetch
+                .fetch_stock_balance_sheet(year, season)
+                .await
+                .map_err(|e| PyException::new_err(e.to_string()))?
+                .into_iter()
+                .map(StockBalanceSheet::from)
+                .collect::<Vec<_>>())
+        })
+    }
+    /// 利润表
+    fn fetch_stock_income<'a>(
+        &self,
+        py: Python<'a>,
+        year: u16,
+        season: u16,
+    ) -> PyResult<&'a PyAny> {
+        let fetch = self.fetch.clone();
+        pyo3_asyncio::tokio::future_into_py(py, async move {
+            Ok(fetch
+                .fetch_stock_income(year, season)
+                .await
+                .map_err(|e| PyException::new_err(e.to_string()))?
+                .into_iter()
+                .map(StockIncome::from)
+                .collect::<Vec<_>>())
+        })
+    }
+    /// 现金流量表
+    fn fetch_stock_cash_flow<'a>(
+        &self,
+        py: Python<'a>,
+        year: u16,
+        season: u16,
+    ) -> PyResult<&'a PyAny> {
+        let fetch = self.fetch.clone();
+        pyo3_asyncio::tokio::future_into_py(py, async move {
+            Ok(fetch
+                .fetch_stock_cash_flow(year, season)
+                .await
+                .map_err(|e| PyException::new_err(e.to_string()))?
+                .into_iter()
+                .map(StockCashFlow::from)
+                .collect::<Vec<_>>())
+        })
+    }
+    /// 股东户数
+    fn fetch_stock_holder_num<'a>(
+        &self,
+        py: Python<'a>,
+        year: u16,
+        season: u16,
+    ) -> PyResult<&'a PyAny> {
+        let fetch = self.fetch.clone();
+        pyo3_asyncio::tokio::future_into_py(py, async move {
+            Ok(fetch
+                .fetch_stock_holder_num(year, season)
+                .await
+                .map_err(|e| PyException::new_err(e.to_string()))?
+                .into_iter()
+                .map(StockHolderNum::from)
+                .collect::<Vec<_>>())
+        })
+    }
+    /// 十大股东/十大流通股东
+    fn fetch_stock_top_holders<'a>(
+        &self,
+        py: Python<'a>,
+        year: u16,
+        season: u16,
+        is_float: bool,
+    ) -> PyResult<&'a PyAny> {
+        let fetch = self.fetch.clone();
+        pyo3_asyncio::tokio::future_into_py(py, async move {
+            Ok(fetch
+                .fetch_stock_top_holders(year, season, is_float)
+                .await
+                .map_err(|e| PyException::new_err(e.to_string()))?
+                .into_iter()
+                .map(StockTopHolder::from)
+                .collect::<Vec<_>>())
+        })
+    }
     /// 融资融券
     fn fetch_stock_margin<'a>(
         &self,
@@ -275,6 +424,155 @@ impl StockFetch {
                 .collect::<Vec<_>>())
         })
     }
+    /// 北向资金个股持股，全市场某交易日
+    fn fetch_stock_north_hold<'a>(&self, py: Python<'a>, date: NaiveDate) -> PyResult<&'a PyAny> {
+        let fetch = self.fetch.clone();
+        pyo3_asyncio::tokio::future_into_py(py, async move {
+            Ok(fetch
+                .fetch_stock_north_hold(date)
+                .await
+                .map_err(|e| PyException::new_err(e.to_string()))?
+                .into_iter()
+                .map(StockNorthHold::from)
+                .collect::<Vec<_>>())
+        })
+    }
+    /// 北向资金每日成交(沪股通、深股通)
+    fn fetch_north_flow<'a>(
+        &self,
+        py: Python<'a>,
+        start: Option<NaiveDate>,
+        end: Option<NaiveDate>,
+    ) -> PyResult<&'a PyAny> {
+        let fetch = self.fetch.clone();
+        pyo3_asyncio::tokio::future_into_py(py, async move {
+            Ok(fetch
+                .fetch_north_flow(start, end)
+                .await
+                .map_err(|e| PyException::new_err(e.to_string()))?
+                .into_iter()
+                .map(NorthFlow::from)
+                .collect::<Vec<_>>())
+        })
+    }
+    /// 龙虎榜，全市场某交易日
+    fn fetch_stock_dragon_tiger<'a>(&self, py: Python<'a>, date: NaiveDate) -> PyResult<&'a PyAny> {
+        let fetch = self.fetch.clone();
+        pyo3_asyncio::tokio::future_into_py(py, async move {
+            Ok(fetch
+                .fetch_stock_dragon_tiger(date)
+                .await
+                .map_err(|e| PyException::new_err(e.to_string()))?
+                .into_iter()
+                .map(StockDragonTiger::from)
+                .collect::<Vec<_>>())
+        })
+    }
+    /// 涨停、跌停或炸板股池，pool: 1 涨停，2 跌停，3 炸板
+    fn fetch_stock_limit_pool<'a>(
+        &self,
+        py: Python<'a>,
+        date: NaiveDate,
+        pool: i32,
+    ) -> PyResult<&'a PyAny> {
+        let pool = hiq_fetch::LimitPool::try_from(pool)
+            .map_err(|e| PyException::new_err(e.to_string()))?;
+        let fetch = self.fetch.clone();
+        pyo3_asyncio::tokio::future_into_py(py, async move {
+            Ok(fetch
+                .fetch_stock_limit_pool(date, pool)
+                .await
+                .map_err(|e| PyException::new_err(e.to_string()))?
+                .into_iter()
+                .map(StockLimitPool::from)
+                .collect::<Vec<_>>())
+        })
+    }
+    /// 当前风险警示(ST、*ST)股票
+    fn fetch_stock_st<'a>(&self, py: Python<'a>) -> PyResult<&'a PyAny> {
+        let fetch = self.fetch.clone();
+        pyo3_asyncio::tokio::future_into_py(py, async move {
+            Ok(fetch
+                .fetch_stock_st()
+                .await
+                .map_err(|e| PyException::new_err(e.to_string()))?
+                .into_iter()
+                .map(StockStatus::from)
+                .collect::<Vec<_>>())
+        })
+    }
+    /// 某日发布的风险警示变更公告(沪市、北交所)
+    fn fetch_stock_st_notice<'a>(&self, py: Python<'a>, date: NaiveDate) -> PyResult<&'a PyAny> {
+        let fetch = self.fetch.clone();
+        pyo3_asyncio::tokio::future_into_py(py, async move {
+            Ok(fetch
+                .fetch_stock_st_notice(date)
+                .await
+                .map_err(|e| PyException::new_err(e.to_string()))?
+                .into_iter()
+                .map(StockStatusNotice::from)
+                .collect::<Vec<_>>())
+        })
+    }
+    /// 风险警示(ST、*ST)历史区间
+    fn fetch_stock_st_history<'a>(&self, py: Python<'a>) -> PyResult<&'a PyAny> {
+        let fetch = self.fetch.clone();
+        pyo3_asyncio::tokio::future_into_py(py, async move {
+            Ok(fetch
+                .fetch_stock_st_history()
+                .await
+                .map_err(|e| PyException::new_err(e.to_string()))?
+                .into_iter()
+                .map(StockStatus::from)
+                .collect::<Vec<_>>())
+        })
+    }
+    /// 停牌股票，全市场某交易日
+    fn fetch_stock_suspend<'a>(&self, py: Python<'a>, date: NaiveDate) -> PyResult<&'a PyAny> {
+        let fetch = self.fetch.clone();
+        pyo3_asyncio::tokio::future_into_py(py, async move {
+            Ok(fetch
+                .fetch_stock_suspend(date)
+                .await
+                .map_err(|e| PyException::new_err(e.to_string()))?
+                .into_iter()
+                .map(StockStatus::from)
+                .collect::<Vec<_>>())
+        })
+    }
+    /// 终止上市股票(沪深)
+    fn fetch_stock_delist<'a>(&self, py: Python<'a>) -> PyResult<&'a PyAny> {
+        let fetch = self.fetch.clone();
+        pyo3_asyncio::tokio::future_into_py(py, async move {
+            Ok(fetch
+                .fetch_stock_delist()
+                .await
+                .map_err(|e| PyException::new_err(e.to_string()))?
+                .into_iter()
+                .map(StockStatus::from)
+                .collect::<Vec<_>>())
+        })
+    }
+    /// 除权除息事件(分红送转及配股)，code为None时为全市场
+    fn fetch_stock_corp_action<'a>(
+        &self,
+        py: Python<'a>,
+        code: Option<&str>,
+        start: Option<NaiveDate>,
+        end: Option<NaiveDate>,
+    ) -> PyResult<&'a PyAny> {
+        let fetch = self.fetch.clone();
+        let code = code.map(String::from);
+        pyo3_asyncio::tokio::future_into_py(py, async move {
+            Ok(fetch
+                .fetch_stock_corp_action(code.as_deref(), start, end)
+                .await
+                .map_err(|e| PyException::new_err(e.to_string()))?
+                .into_iter()
+                .map(StockCorpAction::from)
+                .collect::<Vec<_>>())
+        })
+    }
     /// 实时行情
     fn fetch_stock_rt_quot<'a>(&self, py: Python<'a>, code: Vec<&str>) -> PyResult<&'a PyAny> {
         let fetch = self.fetch.clone();
@@ -290,6 +588,67 @@ impl StockFetch {
                 .collect::<HashMap<String, StockRtQuot>>())
         })
     }
+    /// 股票五档盘口
+    fn fetch_stock_order_book<'a>(&self, py: Python<'a>, code: Vec<&str>) -> PyResult<&'a PyAny> {
+        let fetch = self.fetch.clone();
+        let code: Vec<_> = code.into_iter().map(String::from).collect();
+        pyo3_asyncio::tokio::future_into_py(py, async move {
+            let code: Vec<_> = code.iter().map(|e| &e[..]).collect();
+            Ok(fetch
+                .fetch_stock_order_book(code)
+                .await
+                .map_err(|e| PyException::new_err(e.to_string()))?
+                .into_iter()
+                .map(|(key, value)| (key, OrderBook::from(value)))
+                .collect::<HashMap<String, OrderBook>>())
+        })
+    }
+    /// 分笔成交，只支持最新交易日，可转债同样适用
+    fn fetch_stock_ticks<'a>(
+        &self,
+        py: Python<'a>,
+        code: &str,
+        date: NaiveDate,
+    ) -> PyResult<&'a PyAny> {
+        let fetch = self.fetch.clone();
+        let code = code.to_owned();
+        pyo3_asyncio::tokio::future_into_py(py, async move {
+            Ok(fetch
+                .fetch_stock_ticks(&code, date)
+                .await
+                .map_err(|e| PyException::new_err(e.to_string()))?
+                .into_iter()
+                .map(Tick::from)
+                .collect::<Vec<_>>())
+        })
+    }
+    /// 最新交易日分时走势
+    fn fetch_stock_time_share<'a>(&self, py: Python<'a>, code: &str) -> PyResult<&'a PyAny> {
+        let fetch = self.fetch.clone();
+        let code = code.to_owned();
+        pyo3_asyncio::tokio::future_into_py(py, async move {
+            Ok(TimeShare::from(
+                fetch
+                    .fetch_stock_time_share(&code)
+                    .await
+                    .map_err(|e| PyException::new_err(e.to_string()))?,
+            ))
+        })
+    }
+    /// 最新交易日集合竞价(9:15-9:25)每分钟的虚拟匹配
+    fn fetch_stock_auction<'a>(&self, py: Python<'a>, code: &str) -> PyResult<&'a PyAny> {
+        let fetch = self.fetch.clone();
+        let code = code.to_owned();
+        pyo3_asyncio::tokio::future_into_py(py, async move {
+            Ok(fetch
+                .fetch_stock_auction(&code)
+                .await
+                .map_err(|e| PyException::new_err(e.to_string()))?
+                .into_iter()
+                .map(AuctionSnapshot::from)
+                .collect::<Vec<_>>())
+        })
+    }
 }
 
 #[pyclass]
@@ -300,10 +659,17 @@ pub(crate) struct BlockStockFetch {
 #[pymethods]
 impl BlockStockFetch {
     #[new]
-    fn new() -> Self {
-        BlockStockFetch {
-            fetch: Box::new(hiq_fetch::stock_fetch()),
-        }
+    fn new(
+        proxy: Option<String>,
+        connect_timeout: Option<f64>,
+        read_timeout: Option<f64>,
+    ) -> PyResult<Self> {
+        let config = fetch_config(proxy, connect_timeout, read_timeout)?;
+        let fetch =
+            hiq_fetch::stock_fetch(&config).map_err(|e| PyException::new_err(e.to_string()))?;
+        Ok(BlockStockFetch {
+            fetch: Box::new(fetch),
+        })
     }
     /// 股票大盘指数（列举几个而已）
     fn fetch_index_info(&self) -> PyResult<Vec<StockInfo>> {
@@ -323,16 +689,25 @@ impl BlockStockFetch {
         start: Option<NaiveDate>,
         end: Option<NaiveDate>,
     ) -> PyResult<StockBar> {
-        let fr: Option<hiq_fetch::BarFreq> = if let Some(v) = freq {
-            Some(v.into())
-        } else {
-            None
-        };
+        let fr: Option<hiq_fetch::BarFreq> = freq.map(|v| v.into());
         Ok(runtime()?
             .block_on(self.fetch.fetch_stock_bar(code, name, fr, start, end))
             .map_err(|e| PyException::new_err(e.to_string()))?
             .into())
     }
+    /// 指数成分股及权重，date为None时取最新一期
+    fn fetch_index_constituents(
+        &self,
+        code: &str,
+        date: Option<NaiveDate>,
+    ) -> PyResult<Vec<IndexConstituent>> {
+        Ok(runtime()?
+            .block_on(self.fetch.fetch_index_constituents(code, date))
+            .map_err(|e| PyException::new_err(e.to_string()))?
+            .into_iter()
+            .map(IndexConstituent::from)
+            .collect())
+    }
     /// 获取股票基本信息
     fn fetch_stock_info(&self) -> PyResult<Vec<StockInfo>> {
         Ok(runtime()?
@@ -342,6 +717,15 @@ impl BlockStockFetch {
             .map(StockInfo::from)
             .collect())
     }
+    /// 获取港股(3)或美股(4)基本信息
+    fn fetch_overseas_stock_info(&self, market: i32) -> PyResult<Vec<StockInfo>> {
+        Ok(runtime()?
+            .block_on(self.fetch.fetch_overseas_stock_info(market.into()))
+            .map_err(|e| PyException::new_err(e.to_string()))?
+            .into_iter()
+            .map(StockInfo::from)
+            .collect())
+    }
     /// 获取融资融券股票代码
     fn fetch_stock_is_margin(&self) -> PyResult<HashSet<String>> {
         Ok(runtime()?
@@ -357,16 +741,30 @@ impl BlockStockFetch {
         start: Option<NaiveDate>,
         end: Option<NaiveDate>,
     ) -> PyResult<StockBar> {
-        let fr: Option<hiq_fetch::BarFreq> = if let Some(v) = freq {
-            Some(v.into())
-        } else {
-            None
-        };
+        let fr: Option<hiq_fetch::BarFreq> = freq.map(|v| v.into());
         Ok(runtime()?
             .block_on(self.fetch.fetch_stock_bar(code, name, fr, start, end))
             .map_err(|e| PyException::new_err(e.to_string()))?
             .into())
     }
+    /// 股票分钟k线，分页获取
+    fn fetch_stock_minute_bar(
+        &self,
+        code: &str,
+        name: Option<&str>,
+        freq: Option<i32>,
+        start: Option<NaiveDate>,
+        end: Option<NaiveDate>,
+    ) -> PyResult<MinuteBar> {
+        let fr: Option<hiq_fetch::BarFreq> = freq.map(|v| v.into());
+        Ok(runtime()?
+            .block_on(
+                self.fetch
+                    .fetch_stock_minute_bar(code, name, fr, start, end),
+            )
+            .map_err(|e| PyException::new_err(e.to_string()))?
+            .into())
+    }
     /// 股票最新指标
     fn fetch_stock_index(&self, date: Option<NaiveDate>) -> PyResult<HashMap<String, StockIndex>> {
         Ok(runtime()?
@@ -459,6 +857,60 @@ impl BlockStockFetch {
             .map(StockYJBB::from)
             .collect())
     }
+    /// 资产负债表
+    fn fetch_stock_balance_sheet(
+        &self,
+        year: u16,
+        season: u16,
+    ) -> PyResult<Vec<StockBalanceSheet>> {
+        Ok(runtime()?
+            .block_on(self.fetch.fetch_stock_balance_sheet(year, season))
+            .map_err(|e| PyException::new_err(e.to_string()))?
+            .into_iter()
+            .map(StockBalanceSheet::from)
+            .collect())
+    }
+    /// 利润表
+    fn fetch_stock_income(&self, year: u16, season: u16) -> PyResult<Vec<StockIncome>> {
+        Ok(runtime()?
+            .block_on(self.fetch.fetch_stock_income(year, season))
+            .map_err(|e| PyException::new_err(e.to_string()))?
+            .into_iter()
+            .map(StockIncome::from)
+            .collect())
+    }
+    /// 现金流量表
+    fn fetch_stock_cash_flow(&self, year: u16, season: u16) -> PyResult<Vec<StockCashFlow>> {
+        Ok(runtime()?
+            .block_on(self.fetch.fetch_stock_cash_flow(year, season))
+            .map_err(|e| PyException::new_err(e.to_string()))?
+            .into_iter()
+            .map(StockCashFlow::from)
+            .collect())
+    }
+    /// 股东户数
+    fn fetch_stock_holder_num(&self, year: u16, season: u16) -> PyResult<Vec<StockHolderNum>> {
+        Ok(runtime()?
+            .block_on(self.fetch.fetch_stock_holder_num(year, season))
+            .map_err(|e| PyException::new_err(e.to_string()))?
+            .into_iter()
+            .map(StockHolderNum::from)
+            .collect())
+    }
+    /// 十大股东/十大流通股东
+    fn fetch_stock_top_holders(
+        &self,
+        year: u16,
+        season: u16,
+        is_float: bool,
+    ) -> PyResult<Vec<StockTopHolder>> {
+        Ok(runtime()?
+            .block_on(self.fetch.fetch_stock_top_holders(year, season, is_float))
+            .map_err(|e| PyException::new_err(e.to_string()))?
+            .into_iter()
+            .map(StockTopHolder::from)
+            .collect())
+    }
     /// 融资融券
     fn fetch_stock_margin(
         &self,
@@ -473,6 +925,107 @@ impl BlockStockFetch {
             .map(StockMargin::from)
             .collect())
     }
+    /// 北向资金个股持股，全市场某交易日
+    fn fetch_stock_north_hold(&self, date: NaiveDate) -> PyResult<Vec<StockNorthHold>> {
+        Ok(runtime()?
+            .block_on(self.fetch.fetch_stock_north_hold(date))
+            .map_err(|e| PyException::new_err(e.to_string()))?
+            .into_iter()
+            .map(StockNorthHold::from)
+            .collect())
+    }
+    /// 北向资金每日成交(沪股通、深股通)
+    fn fetch_north_flow(
+        &self,
+        start: Option<NaiveDate>,
+        end: Option<NaiveDate>,
+    ) -> PyResult<Vec<NorthFlow>> {
+        Ok(runtime()?
+            .block_on(self.fetch.fetch_north_flow(start, end))
+            .map_err(|e| PyException::new_err(e.to_string()))?
+            .into_iter()
+            .map(NorthFlow::from)
+            .collect())
+    }
+    /// 龙虎榜，全市场某交易日
+    fn fetch_stock_dragon_tiger(&self, date: NaiveDate) -> PyResult<Vec<StockDragonTiger>> {
+        Ok(runtime()?
+            .block_on(self.fetch.fetch_stock_dragon_tiger(date))
+            .map_err(|e| PyException::new_err(e.to_string()))?
+            .into_iter()
+            .map(StockDragonTiger::from)
+            .collect())
+    }
+    /// 涨停、跌停或炸板股池，pool: 1 涨停，2 跌停，3 炸板
+    fn fetch_stock_limit_pool(&self, date: NaiveDate, pool: i32) -> PyResult<Vec<StockLimitPool>> {
+        let pool = hiq_fetch::LimitPool::try_from(pool)
+            .map_err(|e| PyException::new_err(e.to_string()))?;
+        Ok(runtime()?
+            .block_on(self.fetch.fetch_stock_limit_pool(date, pool))
+            .map_err(|e| PyException::new_err(e.to_string()))?
+            .into_iter()
+            .map(StockLimitPool::from)
+            .collect())
+    }
+    /// 当前风险警示(ST、*ST)股票
+    fn fetch_stock_st(&self) -> PyResult<Vec<StockStatus>> {
+        Ok(runtime()?
+            .block_on(self.fetch.fetch_stock_st())
+            .map_err(|e| PyException::new_err(e.to_string()))?
+            .into_iter()
+            .map(StockStatus::from)
+            .collect())
+    }
+    /// 某日发布的风险警示变更公告(沪市、北交所)
+    fn fetch_stock_st_notice(&self, date: NaiveDate) -> PyResult<Vec<StockStatusNotice>> {
+        Ok(runtime()?
+            .block_on(self.fetch.fetch_stock_st_notice(date))
+            .map_err(|e| PyException::new_err(e.to_string()))?
+            .into_iter()
+            .map(StockStatusNotice::from)
+            .collect())
+    }
+    /// 风险警示(ST、*ST)历史区间
+    fn fetch_stock_st_history(&self) -> PyResult<Vec<StockStatus>> {
+        Ok(runtime()?
+            .block_on(self.fetch.fetch_stock_st_history())
+            .map_err(|e| PyException::new_err(e.to_string()))?
+            .into_iter()
+            .map(StockStatus::from)
+            .collect())
+    }
+    /// 停牌股票，全市场某交易日
+    fn fetch_stock_suspend(&self, date: NaiveDate) -> PyResult<Vec<StockStatus>> {
+        Ok(runtime()?
+            .block_on(self.fetch.fetch_stock_suspend(date))
+            .map_err(|e| PyException::new_err(e.to_string()))?
+            .into_iter()
+            .map(StockStatus::from)
+            .collect())
+    }
+    /// 终止上市股票(沪深)
+    fn fetch_stock_delist(&self) -> PyResult<Vec<StockStatus>> {
+        Ok(runtime()?
+            .block_on(self.fetch.fetch_stock_delist())
+            .map_err(|e| PyException::new_err(e.to_string()))?
+            .into_iter()
+            .map(StockStatus::from)
+            .collect())
+    }
+    /// 除权除息事件(分红送转及配股)，code为None时为全市场
+    fn fetch_stock_corp_action(
+        &self,
+        code: Option<&str>,
+        start: Option<NaiveDate>,
+        end: Option<NaiveDate>,
+    ) -> PyResult<Vec<StockCorpAction>> {
+        Ok(runtime()?
+            .block_on(self.fetch.fetch_stock_corp_action(code, start, end))
+            .map_err(|e| PyException::new_err(e.to_string()))?
+            .into_iter()
+            .map(StockCorpAction::from)
+            .collect())
+    }
     /// 实时行情
     fn fetch_stock_rt_quot(&self, code: Vec<&str>) -> PyResult<HashMap<String, StockRtQuot>> {
         Ok(runtime()?
@@ -482,4 +1035,39 @@ impl BlockStockFetch {
             .map(|(key, value)| (key, StockRtQuot::from(value)))
             .collect())
     }
+    /// 股票五档盘口
+    fn fetch_stock_order_book(&self, code: Vec<&str>) -> PyResult<HashMap<String, OrderBook>> {
+        Ok(runtime()?
+            .block_on(self.fetch.fetch_stock_order_book(code))
+            .map_err(|e| PyException::new_err(e.to_string()))?
+            .into_iter()
+            .map(|(key, value)| (key, OrderBook::from(value)))
+            .collect())
+    }
+    /// 分笔成交，只支持最新交易日，可转债同样适用
+    fn fetch_stock_ticks(&self, code: &str, date: NaiveDate) -> PyResult<Vec<Tick>> {
+        Ok(runtime()?
+            .block_on(self.fetch.fetch_stock_ticks(code, date))
+            .map_err(|e| PyException::new_err(e.to_string()))?
+            .into_iter()
+            .map(Tick::from)
+            .collect())
+    }
+    /// 最新交易日分时走势
+    fn fetch_stock_time_share(&self, code: &str) -> PyResult<TimeShare> {
+        Ok(TimeShare::from(
+            runtime()?
+                .block_on(self.fetch.fetch_stock_time_share(code))
+                .map_err(|e| PyException::new_err(e.to_string()))?,
+        ))
+    }
+    /// 最新交易日集合竞价(9:15-9:25)每分钟的虚拟匹配
+    fn fetch_stock_auction(&self, code: &str) -> PyResult<Vec<AuctionSnapshot>> {
+        Ok(runtime()?
+            .block_on(self.fetch.fetch_stock_auction(code))
+            .map_err(|e| PyException::new_err(e.to_string()))?
+            .into_iter()
+            .map(AuctionSnapshot::from)
+            .collect())
+    }
 }
@@ -2,14 +2,14 @@ use std::{collections::HashMap, str::FromStr, sync::Arc};
 
 use anyhow::Context;
 use argh::FromArgs;
-use chrono::{NaiveDate, NaiveDateTime, NaiveTime};
+use chrono::{Local, NaiveDate, NaiveDateTime, NaiveTime};
 use hiq_data::{
     store::{get_loader, Loader},
     HiqSyncDest,
 };
 use hiq_strategy::{
-    fit, get_strategy, run, strategies, CommonParam, ProgressFunc, Strategy, StrategyType, Symbol,
-    SYMBOL_NAME,
+    fit, get_strategy, run, strategies, universe_codes, CommonParam, ProgressFunc, Strategy,
+    StrategyType, Symbol, SYMBOL_NAME,
 };
 use tokio::{signal, sync::broadcast};
 
@@ -112,6 +112,19 @@ async fn main() -> anyhow::Result<()> {
         .await?;
 
     if s.function == "run".to_string() {
+        let mut the_codes = None;
+        if s.universe {
+            let test_end_date = cmm_params
+                .as_ref()
+                .and_then(|p| p.test_end_date)
+                .unwrap_or_else(|| Local::now().naive_local());
+            let rs = universe_codes(loader.clone(), &test_end_date.date(), true, true).await;
+            if rs.is_err() {
+                println!("universe_codes error: {:?}", rs.err());
+                return Ok(());
+            }
+            the_codes = Some(rs.unwrap());
+        }
         let func: Option<ProgressFunc> = if s.level.as_str() == "error" {
             Some(Box::new(progress))
         } else {
@@ -123,7 +136,7 @@ async fn main() -> anyhow::Result<()> {
                 loader,
                 s.concurrent,
                 shutdown_tx.subscribe(),
-                None,
+                the_codes,
                 func
             ) => {
                 if rs.is_err() {
@@ -374,6 +387,10 @@ struct HiqStrategy {
     #[argh(option, short = 'c')]
     code: Option<String>,
 
+    /// function == run 有效，使用回测结束日期当日的股票池(含之后退市的股票，剔除ST及停牌)
+    #[argh(switch, short = 'u')]
+    universe: bool,
+
     /// 策略参数，key=val格式
     #[argh(positional)]
     params: Vec<String>,
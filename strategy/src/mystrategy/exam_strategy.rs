@@ -2,7 +2,7 @@ use std::{collections::HashMap, sync::Arc};
 
 use async_trait::async_trait;
 use bson::doc;
-use hiq_data::store::Loader;
+use hiq_data::{store::Loader, AdjustFactor};
 
 use crate::{
     stat_result, strategy_to_data_type, Error, Result, Strategy, StrategyResult, StrategyType,
@@ -42,6 +42,7 @@ impl Strategy for ExamStrategy {
                     doc! {"code": &code},
                     doc! {"trade_date": -1},
                     Some(60),
+                    AdjustFactor::NFQ,
                 )
                 .await
                 .map_err(|e| Error::Custom(format!("load_daily error: {}", e.to_string())))?;
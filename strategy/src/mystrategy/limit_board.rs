@@ -0,0 +1,161 @@
+use std::{collections::HashMap, sync::Arc};
+
+use async_trait::async_trait;
+use bson::doc;
+use hiq_data::{store::Loader, AdjustFactor, LimitPool, StockLimitPool};
+
+use crate::{
+    stat_result, strategy_to_data_type, CommonParam, Error, Result, Strategy, StrategyResult,
+    StrategyType,
+};
+
+#[derive(Debug, Clone)]
+pub(crate) struct LimitBoard {
+    cmm_params: CommonParam,
+    min_limit_days: i32,
+    max_limit_days: i32,
+    /// 回测区间内各标的的涨停记录，按交易日升序
+    pool: HashMap<String, Vec<StockLimitPool>>,
+}
+
+impl Default for LimitBoard {
+    fn default() -> Self {
+        Self {
+            cmm_params: Default::default(),
+            min_limit_days: 1,
+            max_limit_days: 1,
+            pool: HashMap::new(),
+        }
+    }
+}
+
+#[async_trait]
+impl Strategy for LimitBoard {
+    fn name(&self) -> String {
+        String::from("LimitBoard")
+    }
+    fn help(&self) -> String {
+        String::from(
+            r###"名称: 连板策略(基于涨停股池)
+                 说明: 选择回测区间内涨停且连板数在范围内的标的，默认为首板，以区间内最后一次涨停统计。
+
+                 参数: min_limit_days -- 最小连板数(默认: 1)
+                       max_limit_days -- 最大连板数(默认: 同最小连板数)"###,
+        )
+    }
+    async fn prepare(
+        &mut self,
+        loader: Arc<Box<dyn Loader>>,
+        cmm_params: Option<CommonParam>,
+        params: Option<HashMap<String, String>>,
+    ) -> Result<()> {
+        if let Some(cmm_params) = cmm_params {
+            self.cmm_params = cmm_params.clone();
+        }
+        if let Some(params) = params {
+            if params.contains_key("min_limit_days") {
+                self.min_limit_days =
+                    params.get("min_limit_days").unwrap().parse().map_err(|e| {
+                        Error::Custom(format!("parse min_limit_days error: {:?}", e))
+                    })?;
+                self.max_limit_days = self.max_limit_days.max(self.min_limit_days);
+            }
+            if params.contains_key("max_limit_days") {
+                self.max_limit_days =
+                    params.get("max_limit_days").unwrap().parse().map_err(|e| {
+                        Error::Custom(format!("parse max_limit_days error: {:?}", e))
+                    })?;
+            }
+        }
+        let test_end_date = self.cmm_params.test_end_date.unwrap();
+        let calendar = loader
+            .load_trade_calendar()
+            .await
+            .map_err(|e| Error::Custom(format!("load_trade_calendar error: {}", e)))?;
+        let test_start_date = self
+            .cmm_params
+            .test_start_date(&calendar)
+            .ok_or_else(|| Error::Custom("invalid test_end_date or test_trade_days".to_string()))?;
+        let start = loader
+            .naive_date_to_datetime_str(&test_start_date)
+            .map_err(|e| Error::Custom(format!("naive_date_to_datetime_str error: {}", e)))?;
+        let end = loader
+            .naive_date_time_to_datetime_str(&test_end_date)
+            .map_err(|e| Error::Custom(format!("naive_date_to_datetime_str error: {}", e)))?;
+        let pool = bson::to_bson(&LimitPool::LimitUp)
+            .map_err(|e| Error::Custom(format!("to_bson error: {}", e)))?;
+        let data = loader
+            .load_stock_limit_pool(
+                doc! {
+                    "pool": pool,
+                    "trade_date": {"$gte": start, "$lte": end},
+                    "limit_days": {"$gte": self.min_limit_days, "$lte": self.max_limit_days},
+                },
+                doc! {"trade_date": 1},
+                None,
+            )
+            .await
+            .map_err(|e| Error::Custom(format!("load_stock_limit_pool error: {}", e)))?;
+        self.pool.clear();
+        for limit in data.into_iter() {
+            self.pool.entry(limit.code.clone()).or_default().push(limit);
+        }
+        log::info!(
+            "limit board pool size: {}, limit_days: {}~{}",
+            self.pool.len(),
+            self.min_limit_days,
+            self.max_limit_days
+        );
+        Ok(())
+    }
+    async fn test(
+        &self,
+        loader: Arc<Box<dyn Loader>>,
+        typ: StrategyType,
+        code: String,
+        name: String,
+    ) -> Result<Option<StrategyResult>> {
+        let limits = match self.pool.get(&code) {
+            Some(limits) => limits,
+            None => return Ok(None),
+        };
+        let test_trade_days = self.cmm_params.test_trade_days.unwrap();
+
+        let dt_str = loader
+            .naive_date_time_to_datetime_str(&self.cmm_params.test_end_date.unwrap())
+            .map_err(|e| Error::Custom(format!("naive_date_to_datetime_str error: {}", e)))?;
+        let kdata = loader
+            .load_daily(
+                strategy_to_data_type(typ),
+                doc! {"code": &code, "trade_date": {"$lte": dt_str}},
+                doc! {"trade_date": -1},
+                Some(test_trade_days),
+                AdjustFactor::NFQ,
+            )
+            .await
+            .map_err(|e| Error::Custom(format!("load_daily error: {}", e)))?;
+
+        // 区间内每次涨停均标记，最后一次涨停当日为hit，其首板当日为hit_max
+        let mut mark = HashMap::new();
+        for limit in limits.iter() {
+            let hit_mark = serde_json::to_string(limit)
+                .map_err(|e| Error::Custom(format!("serde_json::to_string error: {}", e)))?;
+            mark.insert(limit.trade_date.date(), hit_mark);
+        }
+        let limit = limits.last().unwrap();
+        let hit = match kdata.iter().position(|e| e.trade_date == limit.trade_date) {
+            Some(hit) => hit,
+            None => return Ok(None),
+        };
+        let hit_max = (hit + limit.limit_days.max(1) as usize - 1).min(kdata.len() - 1);
+
+        let stat = stat_result(&kdata, hit, hit_max)?;
+
+        Ok(Some(StrategyResult::new(
+            code,
+            name,
+            Some(mark),
+            Some(stat),
+        )))
+    }
+}
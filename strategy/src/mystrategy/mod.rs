@@ -1,18 +1,24 @@
 use crate::{Error, Result, Strategy};
 
-use self::{exam_strategy::ExamStrategy, right_side::RightSide};
+use self::{exam_strategy::ExamStrategy, limit_board::LimitBoard, right_side::RightSide};
 
 mod exam_strategy;
+mod limit_board;
 mod right_side;
 
 pub fn get_strategy(name: &str) -> Result<Box<dyn Strategy>> {
     match name {
         "ExamStrategy" => Ok(Box::new(ExamStrategy::default())),
         "RightSide" => Ok(Box::new(RightSide::default())),
+        "LimitBoard" => Ok(Box::new(LimitBoard::default())),
         _ => Err(Error::Custom(format!("strategy {} not found", name))),
     }
 }
 
 pub fn strategies() -> Vec<String> {
-    vec![String::from("ExamStrategy"), String::from("RightSide")]
+    vec![
+        String::from("ExamStrategy"),
+        String::from("RightSide"),
+        String::from("LimitBoard"),
+    ]
 }
@@ -2,7 +2,7 @@ use std::{collections::HashMap, sync::Arc};
 
 use async_trait::async_trait;
 use bson::doc;
-use hiq_data::store::Loader;
+use hiq_data::{store::Loader, AdjustFactor};
 
 use crate::{
     stat_result, strategy_to_data_type, util::shadow, CommonParam, Error, Result, Strategy,
@@ -112,6 +112,7 @@ impl Strategy for RightSide {
                 doc! {"code": &code, "trade_date": {"$lte": dt_str}},
                 doc! {"trade_date": -1},
                 Some(test_trade_days),
+                AdjustFactor::NFQ,
             )
             .await
             .map_err(|e| Error::Custom(format!("load_daily error: {}", e.to_string())))?;
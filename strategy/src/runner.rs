@@ -1,6 +1,7 @@
 use std::{collections::HashMap, sync::Arc};
 
 use bson::doc;
+use chrono::NaiveDate;
 use futures::future::{join, join_all};
 use hiq_data::store::Loader;
 
@@ -21,6 +22,47 @@ pub fn strategy_to_data_type(typ: StrategyType) -> hiq_data::store::DataType {
     }
 }
 
+/// 指数在`date`当日的成分股，作为`run`的`the_codes`，将股票池限定为当时的指数成分
+pub async fn index_member_codes(
+    loader: Arc<Box<dyn Loader>>,
+    index_code: &str,
+    date: &NaiveDate,
+) -> Result<HashMap<StrategyType, Vec<(String, String)>>> {
+    let codes: Vec<_> = loader
+        .load_index_members(index_code, date)
+        .await
+        .map_err(|e| Error::Custom(format!("query index members error: {:?}", e)))?
+        .into_iter()
+        .map(|e| (e.code, e.name))
+        .collect();
+    let mut the_codes = HashMap::new();
+    if !codes.is_empty() {
+        the_codes.insert(StrategyType::Stock, codes);
+    }
+    Ok(the_codes)
+}
+
+/// `date`当日的股票池，作为`run`的`the_codes`，包含之后才退市的股票以避免幸存者偏差，
+/// `exclude_st`、`exclude_suspend`分别剔除当日ST(*ST)及停牌的股票
+pub async fn universe_codes(
+    loader: Arc<Box<dyn Loader>>,
+    date: &NaiveDate,
+    exclude_st: bool,
+    exclude_suspend: bool,
+) -> Result<HashMap<StrategyType, Vec<(String, String)>>> {
+    let codes = loader
+        .load_stock_universe(date, exclude_st, exclude_suspend)
+        .await
+        .map_err(|e| Error::Custom(format!("query stock universe error: {:?}", e)))?;
+    let mut the_codes = HashMap::new();
+    if !codes.is_empty() {
+        the_codes.insert(StrategyType::Stock, codes);
+    }
+    Ok(the_codes)
+}
+
+/// 运行策略，`the_codes`为各类型的(代码, 名称)，代码为内部格式的字符串，行业、概念为板块代码，
+/// 为None时运行策略接受的类型的全部代码
 pub async fn run(
     strategy: Arc<Box<dyn Strategy>>,
     loader: Arc<Box<dyn Loader>>,
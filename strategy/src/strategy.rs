@@ -4,7 +4,7 @@ use std::{collections::HashMap, sync::Arc};
 
 use async_trait::async_trait;
 use chrono::{Local, NaiveDate, NaiveDateTime, NaiveTime};
-use hiq_data::{store::Loader, Bar};
+use hiq_data::{store::Loader, Bar, TradeCalendar};
 use serde::{Deserialize, Serialize};
 
 use crate::{Result, StrategyType};
@@ -57,6 +57,12 @@ impl CommonParam {
             test_trade_days,
         }
     }
+    /// 回测开始日期，即test_end_date前第test_trade_days个交易日
+    pub fn test_start_date(&self, calendar: &TradeCalendar) -> Option<NaiveDate> {
+        let end = self.test_end_date?.date();
+        let days = i32::try_from(self.test_trade_days?).ok()?;
+        calendar.offset(&end, -days)
+    }
 }
 
 impl Default for CommonParam {